@@ -1,7 +1,8 @@
 use super::{
-    ApiClient, ApiError, ApiErrorResp, ApiSuccess, ConnectionInfo, PeerStatus, ProfileUpdateResp,
-    Server,
+    ApiClient, ApiError, ApiErrorResp, ApiSuccess, ConnectionInfo, PeerStatus, ProfileResp, Server,
 };
+use reqwest::blocking::{RequestBuilder, Response};
+use std::time::Instant;
 
 impl ApiClient {
     pub fn new(base_url: &str, token: &str) -> Self {
@@ -17,103 +18,121 @@ impl ApiClient {
     }
 
     pub fn list_servers(&self) -> Result<Vec<Server>, ApiError> {
-        let resp = self
-            .client
-            .get(format!("{}/vpn/servers", self.base_url))
-            .bearer_auth(&self.token)
-            .send()?;
-
-        if !resp.status().is_success() {
-            return Err(self.parse_error(resp));
-        }
+        let resp = self.send(
+            "/vpn/servers",
+            self.client
+                .get(format!("{}/vpn/servers", self.base_url))
+                .bearer_auth(&self.token),
+        )?;
 
         let success: ApiSuccess<Vec<Server>> = resp.json()?;
         Ok(success.data)
     }
 
     pub fn connect(&self, server_id: u64) -> Result<ConnectionInfo, ApiError> {
-        let resp = self
-            .client
-            .post(format!("{}/vpn/connect", self.base_url))
-            .bearer_auth(&self.token)
-            .json(&serde_json::json!({ "server_id": server_id }))
-            .send()?;
-
-        if !resp.status().is_success() {
-            return Err(self.parse_error(resp));
-        }
+        let resp = self.send(
+            "/vpn/connect",
+            self.client
+                .post(format!("{}/vpn/connect", self.base_url))
+                .bearer_auth(&self.token)
+                .json(&serde_json::json!({ "server_id": server_id })),
+        )?;
 
         let success: ApiSuccess<ConnectionInfo> = resp.json()?;
         Ok(success.data)
     }
 
     pub fn disconnect(&self, server_id: u64) -> Result<(), ApiError> {
-        let resp = self
-            .client
-            .post(format!("{}/vpn/disconnect", self.base_url))
-            .bearer_auth(&self.token)
-            .json(&serde_json::json!({ "server_id": server_id }))
-            .send()?;
-
-        if !resp.status().is_success() {
-            return Err(self.parse_error(resp));
-        }
+        self.send(
+            "/vpn/disconnect",
+            self.client
+                .post(format!("{}/vpn/disconnect", self.base_url))
+                .bearer_auth(&self.token)
+                .json(&serde_json::json!({ "server_id": server_id })),
+        )?;
 
         Ok(())
     }
 
     pub fn status(&self) -> Result<Vec<PeerStatus>, ApiError> {
-        let resp = self
-            .client
-            .get(format!("{}/vpn/status", self.base_url))
-            .bearer_auth(&self.token)
-            .send()?;
-
-        if !resp.status().is_success() {
-            return Err(self.parse_error(resp));
-        }
+        let resp = self.send(
+            "/vpn/status",
+            self.client
+                .get(format!("{}/vpn/status", self.base_url))
+                .bearer_auth(&self.token),
+        )?;
 
         let success: ApiSuccess<Vec<PeerStatus>> = resp.json()?;
         Ok(success.data)
     }
 
+    pub fn profile(&self) -> Result<crate::auth::UserInfo, ApiError> {
+        let resp = self.send(
+            "/profile/info",
+            self.client
+                .get(format!("{}/profile/info", self.base_url))
+                .bearer_auth(&self.token),
+        )?;
+
+        let body: ProfileResp = resp.json()?;
+        Ok(body.user)
+    }
+
     pub fn update_profile(
         &self,
         username: &str,
         email: &str,
         password: &str,
     ) -> Result<crate::auth::UserInfo, ApiError> {
-        let resp = self
-            .client
-            .put(format!("{}/profile/update", self.base_url))
-            .bearer_auth(&self.token)
-            .json(&serde_json::json!({
-                "username": username,
-                "email": email,
-                "password": password,
-            }))
-            .send()?;
-
-        if !resp.status().is_success() {
-            return Err(self.parse_error(resp));
-        }
-
-        let body: ProfileUpdateResp = resp.json()?;
+        let resp = self.send(
+            "/profile/update",
+            self.client
+                .put(format!("{}/profile/update", self.base_url))
+                .bearer_auth(&self.token)
+                .json(&serde_json::json!({
+                    "username": username,
+                    "email": email,
+                    "password": password,
+                })),
+        )?;
+
+        let body: ProfileResp = resp.json()?;
         Ok(body.user)
     }
 
     pub fn delete_account(&self) -> Result<(), ApiError> {
-        let resp = self
-            .client
-            .delete(format!("{}/profile/delete", self.base_url))
-            .bearer_auth(&self.token)
-            .send()?;
+        self.send(
+            "/profile/delete",
+            self.client
+                .delete(format!("{}/profile/delete", self.base_url))
+                .bearer_auth(&self.token),
+        )?;
+
+        Ok(())
+    }
 
+    /// Sends an authenticated request; non-2xx statuses become
+    /// `ApiError::Api` with the message returned by the API, or
+    /// `ApiError::Unauthorized` when the token is refused.
+    fn send(&self, path: &str, request: RequestBuilder) -> Result<Response, ApiError> {
+        let started = Instant::now();
+        let resp = request.send().map_err(|e| {
+            tracing::warn!(path, error = %e, "API request failed");
+            ApiError::from(e)
+        })?;
+        let status = resp.status().as_u16();
+        let elapsed_ms = started.elapsed().as_millis() as u64;
         if !resp.status().is_success() {
-            return Err(self.parse_error(resp));
+            let unauthorized = resp.status() == reqwest::StatusCode::UNAUTHORIZED;
+            let error = match self.parse_error(resp) {
+                ApiError::Api(message) if unauthorized => ApiError::Unauthorized(message),
+                error => error,
+            };
+            tracing::warn!(path, status, elapsed_ms, %error, "API request rejected");
+            return Err(error);
         }
-
-        Ok(())
+        tracing::debug!(path, status, elapsed_ms, "API request");
+        Ok(resp)
     }
 
     fn parse_error(&self, resp: reqwest::blocking::Response) -> ApiError {
@@ -1,5 +1,6 @@
 mod client;
 
+use crate::secret::Secret;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -9,6 +10,9 @@ pub enum ApiError {
     Request(#[from] reqwest::Error),
     #[error("API error: {0}")]
     Api(String),
+    /// Jeton refusé (HTTP 401) : expiré, révoqué ou compte supprimé.
+    #[error("session expired: {0}")]
+    Unauthorized(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,12 +25,16 @@ pub struct Server {
     pub listen_port: u16,
     pub subnet: String,
     pub is_active: bool,
+    /// Charge en pourcentage, absente des API qui ne la publient pas.
+    #[serde(default)]
+    pub load: Option<u8>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ConnectionInfo {
     pub peer_ip: String,
-    pub config: String,
+    /// Config INI, contient la clé privée du peer.
+    pub config: Secret,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,7 +58,7 @@ pub(crate) struct ApiErrorResp {
 }
 
 #[derive(Deserialize)]
-pub(crate) struct ProfileUpdateResp {
+pub(crate) struct ProfileResp {
     pub user: crate::auth::UserInfo,
 }
 
@@ -14,14 +14,18 @@ pub fn register(
             "email": email,
             "password": password,
         }))
-        .send()?;
+        .send()
+        .inspect_err(|e| tracing::warn!(email, error = %e, "register request failed"))?;
 
     if !resp.status().is_success() {
+        let status = resp.status().as_u16();
         let err: ApiError = resp.json().unwrap_or(ApiError {
             error: "unknown error".into(),
         });
+        tracing::warn!(email, status, error = %err.error, "register rejected");
         return Err(AuthError::Api(err.error));
     }
+    tracing::info!(email, "register succeeded");
 
     let success: ApiSuccess = resp.json()?;
     Ok(AuthResponse {
@@ -38,14 +42,18 @@ pub fn login(base_url: &str, email: &str, password: &str) -> Result<AuthResponse
             "email": email,
             "password": password,
         }))
-        .send()?;
+        .send()
+        .inspect_err(|e| tracing::warn!(email, error = %e, "login request failed"))?;
 
     if !resp.status().is_success() {
+        let status = resp.status().as_u16();
         let err: ApiError = resp.json().unwrap_or(ApiError {
             error: "unknown error".into(),
         });
+        tracing::warn!(email, status, error = %err.error, "login rejected");
         return Err(AuthError::Api(err.error));
     }
+    tracing::info!(email, "login succeeded");
 
     let success: ApiSuccess = resp.json()?;
     Ok(AuthResponse {
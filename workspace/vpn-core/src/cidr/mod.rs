@@ -0,0 +1,164 @@
+pub mod set;
+pub use set::*;
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CidrError {
+    #[error("invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("invalid prefix length: {0}")]
+    InvalidPrefix(String),
+}
+
+/// Un bloc d'adresses IPv4 ou IPv6 (`10.0.0.0/8`, `fd00::/8`).
+///
+/// L'adresse est toujours normalisée sur l'adresse réseau.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    pub fn new(addr: IpAddr, prefix: u8) -> Result<Self, CidrError> {
+        let bits = family_bits(&addr);
+        if prefix > bits {
+            return Err(CidrError::InvalidPrefix(prefix.to_string()));
+        }
+        let network = to_bits(&addr) & mask(prefix, bits);
+        Ok(Self {
+            addr: from_bits(network, addr.is_ipv4()),
+            prefix,
+        })
+    }
+
+    /// Bloc couvrant une seule adresse (/32 ou /128).
+    pub fn host(addr: IpAddr) -> Self {
+        Self {
+            prefix: family_bits(&addr),
+            addr,
+        }
+    }
+
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    pub fn is_ipv4(&self) -> bool {
+        self.addr.is_ipv4()
+    }
+
+    pub fn is_ipv6(&self) -> bool {
+        self.addr.is_ipv6()
+    }
+
+    pub fn contains(&self, addr: &IpAddr) -> bool {
+        if addr.is_ipv4() != self.is_ipv4() {
+            return false;
+        }
+        let bits = family_bits(addr);
+        to_bits(addr) & mask(self.prefix, bits) == to_bits(&self.addr)
+    }
+
+    /// Première et dernière adresse du bloc, en entiers.
+    pub(crate) fn range(&self) -> (u128, u128) {
+        let bits = family_bits(&self.addr);
+        let start = to_bits(&self.addr);
+        let host_mask = !mask(self.prefix, bits) & max_value(bits);
+        (start, start | host_mask)
+    }
+
+    /// Plages privées RFC1918 et adresses link-local, IPv4 et IPv6.
+    pub fn local_networks() -> Vec<Cidr> {
+        [
+            "10.0.0.0/8",
+            "172.16.0.0/12",
+            "192.168.0.0/16",
+            "169.254.0.0/16",
+            "fc00::/7",
+            "fe80::/10",
+        ]
+        .iter()
+        .map(|s| s.parse().expect("static CIDR"))
+        .collect()
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = CidrError;
+
+    /// Accepte `addr/prefix` ou une adresse seule (bloc hôte).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (addr_part, prefix_part) = match s.split_once('/') {
+            Some((a, p)) => (a, Some(p)),
+            None => (s, None),
+        };
+
+        let addr: IpAddr = addr_part
+            .parse()
+            .map_err(|_| CidrError::InvalidAddress(addr_part.to_string()))?;
+
+        match prefix_part {
+            Some(p) => {
+                let prefix = p
+                    .parse()
+                    .map_err(|_| CidrError::InvalidPrefix(p.to_string()))?;
+                Cidr::new(addr, prefix)
+            }
+            None => Ok(Cidr::host(addr)),
+        }
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+fn family_bits(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn max_value(bits: u8) -> u128 {
+    if bits == 128 {
+        u128::MAX
+    } else {
+        (1u128 << bits) - 1
+    }
+}
+
+fn mask(prefix: u8, bits: u8) -> u128 {
+    if prefix == 0 {
+        0
+    } else {
+        max_value(bits) & !(max_value(bits - prefix))
+    }
+}
+
+fn to_bits(addr: &IpAddr) -> u128 {
+    match addr {
+        IpAddr::V4(a) => u32::from(*a) as u128,
+        IpAddr::V6(a) => u128::from(*a),
+    }
+}
+
+fn from_bits(value: u128, ipv4: bool) -> IpAddr {
+    if ipv4 {
+        IpAddr::V4(Ipv4Addr::from(value as u32))
+    } else {
+        IpAddr::V6(Ipv6Addr::from(value))
+    }
+}
@@ -0,0 +1,264 @@
+use super::*;
+
+/// Retire `excluded` de `allowed` et retourne la liste minimale de préfixes
+/// couvrant exactement le reste. Chaque famille (IPv4 / IPv6) est traitée à part.
+pub fn exclude(allowed: &[Cidr], excluded: &[Cidr]) -> Vec<Cidr> {
+    let mut result = Vec::new();
+    for ipv4 in [true, false] {
+        let bits = if ipv4 { 32 } else { 128 };
+        let allowed = merge(ranges_of(allowed, ipv4));
+        let excluded = merge(ranges_of(excluded, ipv4));
+        for (start, end) in subtract(&allowed, &excluded) {
+            result.extend(range_to_cidrs(start, end, bits, ipv4));
+        }
+    }
+    result
+}
+
+/// Regroupe des blocs qui se chevauchent ou se touchent en une liste minimale.
+pub fn aggregate(cidrs: &[Cidr]) -> Vec<Cidr> {
+    exclude(cidrs, &[])
+}
+
+fn ranges_of(cidrs: &[Cidr], ipv4: bool) -> Vec<(u128, u128)> {
+    cidrs
+        .iter()
+        .filter(|c| c.is_ipv4() == ipv4)
+        .map(Cidr::range)
+        .collect()
+}
+
+/// Trie et fusionne des plages inclusives.
+fn merge(mut ranges: Vec<(u128, u128)>) -> Vec<(u128, u128)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(u128, u128)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Différence de deux listes triées et fusionnées.
+fn subtract(allowed: &[(u128, u128)], excluded: &[(u128, u128)]) -> Vec<(u128, u128)> {
+    let mut result = Vec::new();
+    for &(start, end) in allowed {
+        let mut cursor = Some(start);
+        for &(ex_start, ex_end) in excluded {
+            let current = match cursor {
+                Some(c) => c,
+                None => break,
+            };
+            if ex_end < current || ex_start > end {
+                continue;
+            }
+            if ex_start > current {
+                result.push((current, ex_start - 1));
+            }
+            cursor = if ex_end >= end {
+                None
+            } else {
+                Some(ex_end + 1)
+            };
+        }
+        if let Some(current) = cursor {
+            result.push((current, end));
+        }
+    }
+    result
+}
+
+/// Découpe une plage inclusive en blocs CIDR alignés, du plus grand possible.
+fn range_to_cidrs(mut start: u128, end: u128, bits: u8, ipv4: bool) -> Vec<Cidr> {
+    let mut cidrs = Vec::new();
+    loop {
+        // Taille maximale permise par l'alignement de `start`
+        let mut host_bits = if start == 0 {
+            bits
+        } else {
+            (start.trailing_zeros() as u8).min(bits)
+        };
+        // ...puis réduite tant que le bloc dépasse `end`
+        while host_bits > 0 && block_end(start, host_bits) > end {
+            host_bits -= 1;
+        }
+
+        cidrs.push(Cidr {
+            addr: from_bits(start, ipv4),
+            prefix: bits - host_bits,
+        });
+
+        let last = block_end(start, host_bits);
+        if last >= end {
+            break;
+        }
+        start = last + 1;
+    }
+    cidrs
+}
+
+fn block_end(start: u128, host_bits: u8) -> u128 {
+    start | max_value(host_bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cidrs(list: &[&str]) -> Vec<Cidr> {
+        list.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    fn strings(cidrs: &[Cidr]) -> Vec<String> {
+        cidrs.iter().map(Cidr::to_string).collect()
+    }
+
+    /// Nombre d'adresses couvertes, les blocs étant disjoints.
+    fn size(cidrs: &[Cidr]) -> u128 {
+        cidrs
+            .iter()
+            .map(|c| {
+                let (start, end) = c.range();
+                end - start + 1
+            })
+            .sum()
+    }
+
+    #[test]
+    fn excludes_endpoint_from_ipv4_default_route() {
+        let endpoint: IpAddr = "1.2.3.4".parse().unwrap();
+        let result = exclude(&cidrs(&["0.0.0.0/0"]), &[Cidr::host(endpoint)]);
+
+        assert_eq!(result.len(), 32);
+        assert_eq!(size(&result), (1 << 32) - 1);
+        assert!(result.iter().all(|c| !c.contains(&endpoint)));
+        assert_eq!(result[0].to_string(), "0.0.0.0/8");
+        assert_eq!(result[31].to_string(), "128.0.0.0/1");
+    }
+
+    #[test]
+    fn excludes_endpoint_from_ipv6_default_route() {
+        let endpoint: IpAddr = "2001:db8::1".parse().unwrap();
+        let result = exclude(&cidrs(&["::/0"]), &[Cidr::host(endpoint)]);
+
+        assert_eq!(result.len(), 128);
+        assert_eq!(size(&result), u128::MAX);
+        assert!(result.iter().all(|c| !c.contains(&endpoint)));
+        assert_eq!(result[0].to_string(), "::/3");
+        assert_eq!(result[127].to_string(), "8000::/1");
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_blocks() {
+        assert_eq!(
+            strings(&aggregate(&cidrs(&["10.0.0.128/25", "10.0.0.0/24"]))),
+            ["10.0.0.0/24"]
+        );
+        assert_eq!(
+            strings(&aggregate(&cidrs(&["10.0.0.128/25", "10.0.0.0/25"]))),
+            ["10.0.0.0/24"]
+        );
+        // Contigus mais non alignés : la plage reste en deux blocs
+        assert_eq!(
+            strings(&aggregate(&cidrs(&["10.0.2.0/24", "10.0.1.0/24"]))),
+            ["10.0.1.0/24", "10.0.2.0/24"]
+        );
+        assert_eq!(
+            strings(&aggregate(&cidrs(&[
+                "fd00::/16",
+                "fd00:1::/32",
+                "fd01::/16"
+            ]))),
+            ["fd00::/15"]
+        );
+    }
+
+    #[test]
+    fn keeps_families_apart() {
+        assert_eq!(
+            strings(&aggregate(&cidrs(&["::/96", "0.0.0.0/1", "128.0.0.0/1"]))),
+            ["0.0.0.0/0", "::/96"]
+        );
+    }
+
+    #[test]
+    fn excludes_hosts_at_the_edges_of_the_range() {
+        let result = exclude(&cidrs(&["0.0.0.0/0"]), &cidrs(&["0.0.0.0/32"]));
+        assert_eq!(result.len(), 32);
+        assert_eq!(result[0].to_string(), "0.0.0.1/32");
+        assert_eq!(result[31].to_string(), "128.0.0.0/1");
+
+        let result = exclude(&cidrs(&["0.0.0.0/0"]), &cidrs(&["255.255.255.255/32"]));
+        assert_eq!(result.len(), 32);
+        assert_eq!(result[0].to_string(), "0.0.0.0/1");
+        assert_eq!(result[31].to_string(), "255.255.255.254/32");
+
+        let last = "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff";
+        let result = exclude(&cidrs(&["::/0"]), &cidrs(&["::/128", last]));
+        assert_eq!(result.len(), 254);
+        assert_eq!(size(&result), u128::MAX - 1);
+        assert_eq!(result[0].to_string(), "::1/128");
+        assert_eq!(
+            result[253].to_string(),
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe/128"
+        );
+    }
+
+    #[test]
+    fn merges_up_to_the_last_address() {
+        let last = "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff";
+        assert_eq!(strings(&aggregate(&cidrs(&["::/0", last]))), ["::/0"]);
+        assert_eq!(
+            strings(&aggregate(&cidrs(&[
+                "255.255.255.254/32",
+                "255.255.255.255/32"
+            ]))),
+            ["255.255.255.254/31"]
+        );
+    }
+
+    #[test]
+    fn excluding_a_supernet_removes_everything() {
+        assert!(exclude(&cidrs(&["10.1.0.0/16"]), &cidrs(&["10.0.0.0/8"])).is_empty());
+        assert!(exclude(&cidrs(&["10.1.0.0/16"]), &cidrs(&["0.0.0.0/0"])).is_empty());
+        assert_eq!(
+            strings(&exclude(
+                &cidrs(&["0.0.0.0/0", "::/0"]),
+                &cidrs(&["0.0.0.0/0"])
+            )),
+            ["::/0"]
+        );
+    }
+
+    #[test]
+    fn excluding_a_disjoint_block_changes_nothing() {
+        assert_eq!(
+            strings(&exclude(
+                &cidrs(&["10.0.0.0/8"]),
+                &cidrs(&["192.168.0.0/16", "fd00::/8"])
+            )),
+            ["10.0.0.0/8"]
+        );
+    }
+
+    #[test]
+    fn excludes_several_blocks_from_one_range() {
+        assert_eq!(
+            strings(&exclude(
+                &cidrs(&["10.0.0.0/24"]),
+                &cidrs(&["10.0.0.0/26", "10.0.0.192/26", "10.0.0.100/32"])
+            )),
+            [
+                "10.0.0.64/27",
+                "10.0.0.96/30",
+                "10.0.0.101/32",
+                "10.0.0.102/31",
+                "10.0.0.104/29",
+                "10.0.0.112/28",
+                "10.0.0.128/26"
+            ]
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Table des pays ISO 3166-1, générée depuis `iso-codes` (noms et
+//! traductions) et `tzdata` (continent et position des fuseaux).
+//!
+//! Les alias sont déjà passés par [`super::fold`].
+
+use super::{Continent, Entry};
+
+#[rustfmt::skip]
+pub(super) const COUNTRIES: &[Entry] = &[
+    Entry { alpha2: "AD", alpha3: "AND", english: "Andorra", french: "Andorre", continent: Continent::Europe, centroid: (42.5, 1.5), aliases: &["principality of andorra"] },
+    Entry { alpha2: "AE", alpha3: "ARE", english: "United Arab Emirates", french: "Émirats arabes unis", continent: Continent::Asia, centroid: (25.3, 55.3), aliases: &["emirados arabes unidos", "emirates", "emirati arabi uniti", "emiratos arabes unidos", "emirats", "uae", "vereinigte arabische emirate", "verenigde arabische emiraten"] },
+    Entry { alpha2: "AF", alpha3: "AFG", english: "Afghanistan", french: "Afghanistan", continent: Continent::Asia, centroid: (33.9, 67.7), aliases: &["afeganistao", "afganistan", "islamic republic of afghanistan"] },
+    Entry { alpha2: "AG", alpha3: "ATG", english: "Antigua and Barbuda", french: "Antigua-et-Barbuda", continent: Continent::NorthAmerica, centroid: (17.1, -61.8), aliases: &["antigua e barbuda", "antigua en barbuda", "antigua und barbuda", "antigua y barbuda"] },
+    Entry { alpha2: "AI", alpha3: "AIA", english: "Anguilla", french: "Anguilla", continent: Continent::NorthAmerica, centroid: (18.2, -63.1), aliases: &["anguila"] },
+    Entry { alpha2: "AL", alpha3: "ALB", english: "Albania", french: "Albanie", continent: Continent::Europe, centroid: (41.3, 19.8), aliases: &["albanien", "republic of albania"] },
+    Entry { alpha2: "AM", alpha3: "ARM", english: "Armenia", french: "Arménie", continent: Continent::Asia, centroid: (40.2, 44.5), aliases: &["armenien", "republic of armenia"] },
+    Entry { alpha2: "AO", alpha3: "AGO", english: "Angola", french: "Angola", continent: Continent::Africa, centroid: (-11.2, 17.9), aliases: &["republic of angola"] },
+    Entry { alpha2: "AQ", alpha3: "ATA", english: "Antarctica", french: "Antarctique", continent: Continent::Antarctica, centroid: (-82.9, 135.0), aliases: &["antarktis", "antartida", "antartide"] },
+    Entry { alpha2: "AR", alpha3: "ARG", english: "Argentina", french: "Argentine", continent: Continent::SouthAmerica, centroid: (-38.4, -63.6), aliases: &["argentine republic", "argentinie", "argentinien"] },
+    Entry { alpha2: "AS", alpha3: "ASM", english: "American Samoa", french: "Samoa américaines", continent: Continent::Oceania, centroid: (-14.3, -170.7), aliases: &["amerikaans samoa", "amerikanisch samoa", "samoa americana", "samoa americane", "samoa estadounidense"] },
+    Entry { alpha2: "AT", alpha3: "AUT", english: "Austria", french: "Autriche", continent: Continent::Europe, centroid: (48.2, 16.3), aliases: &["oostenrijk", "osterreich", "republic of austria"] },
+    Entry { alpha2: "AU", alpha3: "AUS", english: "Australia", french: "Australie", continent: Continent::Oceania, centroid: (-25.7, 134.5), aliases: &["australien"] },
+    Entry { alpha2: "AW", alpha3: "ABW", english: "Aruba", french: "Aruba", continent: Continent::NorthAmerica, centroid: (12.5, -70.0), aliases: &[] },
+    Entry { alpha2: "AX", alpha3: "ALA", english: "Åland Islands", french: "Îles Åland", continent: Continent::Europe, centroid: (60.1, 19.9), aliases: &["aland iles", "aland inseln", "alandseilanden", "ilhas alanda", "islas aland", "isole aland"] },
+    Entry { alpha2: "AZ", alpha3: "AZE", english: "Azerbaijan", french: "Azerbaïdjan", continent: Continent::Asia, centroid: (40.4, 49.9), aliases: &["aserbaidschan", "azerbaigian", "azerbaijao", "azerbaiyan", "azerbeidzjan", "republic of azerbaijan"] },
+    Entry { alpha2: "BA", alpha3: "BIH", english: "Bosnia and Herzegovina", french: "Bosnie-Herzégovine", continent: Continent::Europe, centroid: (43.9, 18.4), aliases: &["bosnia e herzegovina", "bosnia erzegovina", "bosnia y herzegovina", "bosnie en herzegovina", "bosnien und herzegowina", "republic of bosnia and herzegovina"] },
+    Entry { alpha2: "BB", alpha3: "BRB", english: "Barbados", french: "Barbade", continent: Continent::NorthAmerica, centroid: (13.1, -59.6), aliases: &[] },
+    Entry { alpha2: "BD", alpha3: "BGD", english: "Bangladesh", french: "Bangladesh", continent: Continent::Asia, centroid: (23.7, 90.4), aliases: &["bangladeche", "banglades", "bangladesch", "people s republic of bangladesh"] },
+    Entry { alpha2: "BE", alpha3: "BEL", english: "Belgium", french: "Belgique", continent: Continent::Europe, centroid: (50.6, 4.6), aliases: &["belgica", "belgie", "belgien", "belgio", "kingdom of belgium"] },
+    Entry { alpha2: "BF", alpha3: "BFA", english: "Burkina Faso", french: "Burkina Faso", continent: Continent::Africa, centroid: (12.4, -1.5), aliases: &["burquina faso"] },
+    Entry { alpha2: "BG", alpha3: "BGR", english: "Bulgaria", french: "Bulgarie", continent: Continent::Europe, centroid: (42.7, 23.3), aliases: &["bulgarien", "bulgarije", "republic of bulgaria"] },
+    Entry { alpha2: "BH", alpha3: "BHR", english: "Bahrain", french: "Bahreïn", continent: Continent::Asia, centroid: (26.4, 50.6), aliases: &["barein", "barem", "kingdom of bahrain"] },
+    Entry { alpha2: "BI", alpha3: "BDI", english: "Burundi", french: "Burundi", continent: Continent::Africa, centroid: (-3.4, 29.4), aliases: &["republic of burundi"] },
+    Entry { alpha2: "BJ", alpha3: "BEN", english: "Benin", french: "Bénin", continent: Continent::Africa, centroid: (6.5, 2.6), aliases: &["benim", "republic of benin"] },
+    Entry { alpha2: "BL", alpha3: "BLM", english: "Saint Barthélemy", french: "Saint-Barthélemy", continent: Continent::NorthAmerica, centroid: (17.9, -62.8), aliases: &["san bartolome"] },
+    Entry { alpha2: "BM", alpha3: "BMU", english: "Bermuda", french: "Bermudes", continent: Continent::NorthAmerica, centroid: (32.3, -64.8), aliases: &["bermudas", "islas bermudas"] },
+    Entry { alpha2: "BN", alpha3: "BRN", english: "Brunei", french: "Brunei", continent: Continent::Asia, centroid: (4.9, 114.9), aliases: &["brunei darussalam"] },
+    Entry { alpha2: "BO", alpha3: "BOL", english: "Bolivia", french: "Bolivie", continent: Continent::SouthAmerica, centroid: (-16.3, -63.6), aliases: &["bolivia estado plurinacional da", "bolivia estado plurinacional de", "bolivia multinationale staat", "bolivia plurinational state of", "bolivia stato plurinazionale della", "bolivie etat plurinational de", "bolivien", "bolivien plurinationaler staat", "plurinational state of bolivia"] },
+    Entry { alpha2: "BQ", alpha3: "BES", english: "Bonaire, Sint Eustatius and Saba", french: "Bonaire, Saint-Eustache et Saba", continent: Continent::NorthAmerica, centroid: (12.2, -68.3), aliases: &["bonaire santo eustaquio e saba", "bonaire sint eustatius en saba", "bonaire sint eustatius und saba", "islas bes caribe neerlandes", "paesi bassi caraibici"] },
+    Entry { alpha2: "BR", alpha3: "BRA", english: "Brazil", french: "Brésil", continent: Continent::SouthAmerica, centroid: (-10.8, -52.9), aliases: &["brasil", "brasile", "brasilien", "brazilie", "federative republic of brazil"] },
+    Entry { alpha2: "BS", alpha3: "BHS", english: "Bahamas", french: "Bahamas", continent: Continent::NorthAmerica, centroid: (25.1, -77.3), aliases: &["bahama s", "commonwealth of the bahamas"] },
+    Entry { alpha2: "BT", alpha3: "BTN", english: "Bhutan", french: "Bhoutan", continent: Continent::Asia, centroid: (27.5, 89.7), aliases: &["butan", "butao", "kingdom of bhutan"] },
+    Entry { alpha2: "BV", alpha3: "BVT", english: "Bouvet Island", french: "Île Bouvet", continent: Continent::Antarctica, centroid: (-54.4, 3.4), aliases: &["bouvet insel", "bouveteiland", "ilha bouvet", "isla bouvet", "isola bouvet"] },
+    Entry { alpha2: "BW", alpha3: "BWA", english: "Botswana", french: "Botswana", continent: Continent::Africa, centroid: (-24.7, 25.9), aliases: &["botsuana", "republic of botswana"] },
+    Entry { alpha2: "BY", alpha3: "BLR", english: "Belarus", french: "Bélarus", continent: Continent::Europe, centroid: (53.9, 27.6), aliases: &["bielorrusia", "bielorussia", "republic of belarus", "wit rusland"] },
+    Entry { alpha2: "BZ", alpha3: "BLZ", english: "Belize", french: "Belize", continent: Continent::NorthAmerica, centroid: (17.5, -88.2), aliases: &["belice"] },
+    Entry { alpha2: "CA", alpha3: "CAN", english: "Canada", french: "Canada", continent: Continent::NorthAmerica, centroid: (56.1, -106.3), aliases: &["kanada"] },
+    Entry { alpha2: "CC", alpha3: "CCK", english: "Cocos (Keeling) Islands", french: "Îles Cocos", continent: Continent::Asia, centroid: (-12.2, 96.9), aliases: &["cocos keeling iles", "cocoseilanden keelingeilanden", "ilhas cocos", "islas cocos keeling", "isole cocos keeling", "kokos keeling inseln"] },
+    Entry { alpha2: "CD", alpha3: "COD", english: "Democratic Republic of the Congo", french: "République démocratique du Congo", continent: Continent::Africa, centroid: (-4.0, 21.8), aliases: &["congo democratische republiek", "congo kinshasa", "congo republica democratica del", "congo republica democratica do", "congo the democratic republic of the", "demokratische republik kongo", "drc", "rdc", "repubblica democratica del congo"] },
+    Entry { alpha2: "CF", alpha3: "CAF", english: "Central African Republic", french: "République centrafricaine", continent: Continent::Africa, centroid: (4.4, 18.6), aliases: &["centraal afrikaanse republiek", "repubblica centrafricana", "republica centro africana", "republica centroafricana", "zentralafrikanische republik"] },
+    Entry { alpha2: "CG", alpha3: "COG", english: "Republic of the Congo", french: "République du Congo", continent: Continent::Africa, centroid: (-4.3, 15.3), aliases: &["congo", "congo brazzaville", "kongo"] },
+    Entry { alpha2: "CH", alpha3: "CHE", english: "Switzerland", french: "Suisse", continent: Continent::Europe, centroid: (46.8, 8.2), aliases: &["schweiz", "suica", "suiza", "svizzera", "swiss confederation", "zwitserland"] },
+    Entry { alpha2: "CI", alpha3: "CIV", english: "Côte d'Ivoire", french: "Côte d'Ivoire", continent: Continent::Africa, centroid: (5.3, -4.0), aliases: &["costa d avorio", "costa de marfil", "costa do marfim", "cote divoire", "ivoorkust", "ivory coast", "republic of cote d ivoire"] },
+    Entry { alpha2: "CK", alpha3: "COK", english: "Cook Islands", french: "Îles Cook", continent: Continent::Oceania, centroid: (-21.2, -159.8), aliases: &["cookeilanden", "cookinseln", "ilhas cook", "islas cook", "isole cook"] },
+    Entry { alpha2: "CL", alpha3: "CHL", english: "Chile", french: "Chili", continent: Continent::SouthAmerica, centroid: (-35.7, -71.5), aliases: &["cile", "republic of chile"] },
+    Entry { alpha2: "CM", alpha3: "CMR", english: "Cameroon", french: "Cameroun", continent: Continent::Africa, centroid: (4.0, 9.7), aliases: &["camaroes", "camerun", "kameroen", "kamerun", "republic of cameroon"] },
+    Entry { alpha2: "CN", alpha3: "CHN", english: "China", french: "Chine", continent: Continent::Asia, centroid: (35.9, 104.2), aliases: &["cina", "people s republic of china"] },
+    Entry { alpha2: "CO", alpha3: "COL", english: "Colombia", french: "Colombie", continent: Continent::SouthAmerica, centroid: (4.6, -74.3), aliases: &["kolumbien", "republic of colombia"] },
+    Entry { alpha2: "CR", alpha3: "CRI", english: "Costa Rica", french: "Costa Rica", continent: Continent::NorthAmerica, centroid: (9.9, -84.1), aliases: &["republic of costa rica"] },
+    Entry { alpha2: "CU", alpha3: "CUB", english: "Cuba", french: "Cuba", continent: Continent::NorthAmerica, centroid: (23.1, -82.4), aliases: &["kuba", "republic of cuba"] },
+    Entry { alpha2: "CV", alpha3: "CPV", english: "Cape Verde", french: "Cap-Vert", continent: Continent::Africa, centroid: (14.9, -23.5), aliases: &["cabo verde", "capo verde", "kaapverdie", "kap verde", "republic of cabo verde"] },
+    Entry { alpha2: "CW", alpha3: "CUW", english: "Curaçao", french: "Curaçao", continent: Continent::NorthAmerica, centroid: (12.2, -69.0), aliases: &["curazao"] },
+    Entry { alpha2: "CX", alpha3: "CXR", english: "Christmas Island", french: "Île Christmas", continent: Continent::Asia, centroid: (-10.4, 105.7), aliases: &["christmas ile", "christmaseiland", "ilha natal", "isla de navidad", "isola di natale", "weihnachtsinseln"] },
+    Entry { alpha2: "CY", alpha3: "CYP", english: "Cyprus", french: "Chypre", continent: Continent::Europe, centroid: (35.1, 33.4), aliases: &["chipre", "cipro", "republic of cyprus", "zypern"] },
+    Entry { alpha2: "CZ", alpha3: "CZE", english: "Czechia", french: "Tchéquie", continent: Continent::Europe, centroid: (50.1, 14.4), aliases: &["cechia", "chequia", "czech republic", "republique tcheque", "tschechien", "tsjechie"] },
+    Entry { alpha2: "DE", alpha3: "DEU", english: "Germany", french: "Allemagne", continent: Continent::Europe, centroid: (51.1, 10.4), aliases: &["alemanha", "alemania", "deutschland", "duitsland", "federal republic of germany", "germania"] },
+    Entry { alpha2: "DJ", alpha3: "DJI", english: "Djibouti", french: "Djibouti", continent: Continent::Africa, centroid: (11.6, 43.1), aliases: &["dschibuti", "gibuti", "republic of djibouti", "yibuti"] },
+    Entry { alpha2: "DK", alpha3: "DNK", english: "Denmark", french: "Danemark", continent: Continent::Europe, centroid: (56.3, 9.5), aliases: &["danimarca", "denemarken", "dinamarca", "kingdom of denmark"] },
+    Entry { alpha2: "DM", alpha3: "DMA", english: "Dominica", french: "Dominique", continent: Continent::NorthAmerica, centroid: (15.3, -61.4), aliases: &["commonwealth of dominica"] },
+    Entry { alpha2: "DO", alpha3: "DOM", english: "Dominican Republic", french: "République dominicaine", continent: Continent::NorthAmerica, centroid: (18.5, -69.9), aliases: &["dominicaanse republiek", "dominikanische republik", "repubblica dominicana", "republica dominicana"] },
+    Entry { alpha2: "DZ", alpha3: "DZA", english: "Algeria", french: "Algérie", continent: Continent::Africa, centroid: (28.0, 1.7), aliases: &["algerien", "algerije", "argelia", "people s democratic republic of algeria"] },
+    Entry { alpha2: "EC", alpha3: "ECU", english: "Ecuador", french: "Équateur", continent: Continent::SouthAmerica, centroid: (-1.8, -78.2), aliases: &["equador", "republic of ecuador"] },
+    Entry { alpha2: "EE", alpha3: "EST", english: "Estonia", french: "Estonie", continent: Continent::Europe, centroid: (59.4, 24.8), aliases: &["estland", "republic of estonia"] },
+    Entry { alpha2: "EG", alpha3: "EGY", english: "Egypt", french: "Égypte", continent: Continent::Africa, centroid: (26.8, 30.8), aliases: &["agypten", "arab republic of egypt", "egipto", "egito", "egitto"] },
+    Entry { alpha2: "EH", alpha3: "ESH", english: "Western Sahara", french: "Sahara occidental", continent: Continent::Africa, centroid: (27.2, -13.2), aliases: &["saara ocidental", "sahara occidentale", "westelijke sahara", "westsahara"] },
+    Entry { alpha2: "ER", alpha3: "ERI", english: "Eritrea", french: "Érythrée", continent: Continent::Africa, centroid: (15.3, 38.9), aliases: &["eritreia", "the state of eritrea"] },
+    Entry { alpha2: "ES", alpha3: "ESP", english: "Spain", french: "Espagne", continent: Continent::Europe, centroid: (40.2, -3.6), aliases: &["espana", "espanha", "kingdom of spain", "spagna", "spanien", "spanje"] },
+    Entry { alpha2: "ET", alpha3: "ETH", english: "Ethiopia", french: "Éthiopie", continent: Continent::Africa, centroid: (9.1, 40.5), aliases: &["athiopien", "etiopia", "federal democratic republic of ethiopia"] },
+    Entry { alpha2: "FI", alpha3: "FIN", english: "Finland", french: "Finlande", continent: Continent::Europe, centroid: (64.5, 26.0), aliases: &["finlandia", "finnland", "republic of finland"] },
+    Entry { alpha2: "FJ", alpha3: "FJI", english: "Fiji", french: "Fidji", continent: Continent::Oceania, centroid: (-18.1, 178.4), aliases: &["fidschi", "figi", "fiyi", "republic of fiji"] },
+    Entry { alpha2: "FK", alpha3: "FLK", english: "Falkland Islands", french: "Îles Malouines", continent: Continent::SouthAmerica, centroid: (-51.7, -57.9), aliases: &["falkland islands malvinas", "falklandeilanden malvinas", "falklandinseln malwinen", "ilhas falkland malvinas", "islas falkland malvinas", "isole falkland malvine", "malouines iles falkland"] },
+    Entry { alpha2: "FM", alpha3: "FSM", english: "Micronesia", french: "Micronésie", continent: Continent::Oceania, centroid: (7.4, 150.6), aliases: &["federated states of micronesia", "micronesia estados federados da", "micronesia estados federados de", "micronesia federated states of", "micronesie etats federes de", "mikronesien foderierte staaten von"] },
+    Entry { alpha2: "FO", alpha3: "FRO", english: "Faroe Islands", french: "Îles Féroé", continent: Continent::Europe, centroid: (62.0, -6.8), aliases: &["faeroer", "faroer inseln", "ilhas faroe", "islas feroe", "isole faer oer"] },
+    Entry { alpha2: "FR", alpha3: "FRA", english: "France", french: "France", continent: Continent::Europe, centroid: (46.6, 2.4), aliases: &["franca", "francia", "frankreich", "frankrijk", "french republic"] },
+    Entry { alpha2: "GA", alpha3: "GAB", english: "Gabon", french: "Gabon", continent: Continent::Africa, centroid: (0.4, 9.4), aliases: &["gabao", "gabonese republic", "gabun"] },
+    Entry { alpha2: "GB", alpha3: "GBR", english: "United Kingdom", french: "Royaume-Uni", continent: Continent::Europe, centroid: (54.0, -2.5), aliases: &["angleterre", "britain", "ecosse", "england", "grande bretagne", "great britain", "regno unito", "reino unido", "scotland", "uk", "united kingdom of great britain and northern ireland", "vereinigtes konigreich", "verenigd koninkrijk", "wales"] },
+    Entry { alpha2: "GD", alpha3: "GRD", english: "Grenada", french: "Grenade", continent: Continent::NorthAmerica, centroid: (12.1, -61.8), aliases: &["granada"] },
+    Entry { alpha2: "GE", alpha3: "GEO", english: "Georgia", french: "Géorgie", continent: Continent::Asia, centroid: (41.7, 44.8), aliases: &["georgien"] },
+    Entry { alpha2: "GF", alpha3: "GUF", english: "French Guiana", french: "Guyane française", continent: Continent::SouthAmerica, centroid: (4.9, -52.3), aliases: &["frans guyana", "franzosisch guyana", "guayana francesa", "guiana francesa", "guyana francese"] },
+    Entry { alpha2: "GG", alpha3: "GGY", english: "Guernsey", french: "Guernesey", continent: Continent::Europe, centroid: (49.5, -2.5), aliases: &[] },
+    Entry { alpha2: "GH", alpha3: "GHA", english: "Ghana", french: "Ghana", continent: Continent::Africa, centroid: (5.6, -0.2), aliases: &["gana", "republic of ghana"] },
+    Entry { alpha2: "GI", alpha3: "GIB", english: "Gibraltar", french: "Gibraltar", continent: Continent::Europe, centroid: (36.1, -5.3), aliases: &["gibilterra"] },
+    Entry { alpha2: "GL", alpha3: "GRL", english: "Greenland", french: "Groënland", continent: Continent::NorthAmerica, centroid: (71.7, -42.6), aliases: &["groenlandia", "gronelandia", "gronland"] },
+    Entry { alpha2: "GM", alpha3: "GMB", english: "Gambia", french: "Gambie", continent: Continent::Africa, centroid: (13.5, -16.6), aliases: &["republic of the gambia"] },
+    Entry { alpha2: "GN", alpha3: "GIN", english: "Guinea", french: "Guinée", continent: Continent::Africa, centroid: (9.5, -13.7), aliases: &["guine", "republic of guinea"] },
+    Entry { alpha2: "GP", alpha3: "GLP", english: "Guadeloupe", french: "Guadeloupe", continent: Continent::NorthAmerica, centroid: (16.2, -61.5), aliases: &["guadalupa", "guadalupe"] },
+    Entry { alpha2: "GQ", alpha3: "GNQ", english: "Equatorial Guinea", french: "Guinée équatoriale", continent: Continent::Africa, centroid: (3.7, 8.8), aliases: &["aquatorialguinea", "equatoriaal guinea", "guine equatorial", "guinea ecuatorial", "guinea equatoriale", "republic of equatorial guinea"] },
+    Entry { alpha2: "GR", alpha3: "GRC", english: "Greece", french: "Grèce", continent: Continent::Europe, centroid: (39.1, 21.8), aliases: &["grecia", "griechenland", "griekenland", "hellenic republic"] },
+    Entry { alpha2: "GS", alpha3: "SGS", english: "South Georgia and the South Sandwich Islands", french: "Géorgie du Sud et les îles Sandwich du Sud", continent: Continent::Antarctica, centroid: (-54.3, -36.5), aliases: &["georgia del sud e isole sandwich australi", "ilhas georgia do sul e sandwich do sul", "islas georgias del sur y sandwich del sur", "south georgia und die sudlichen sandwichinseln", "zuid georgia en de zuidelijke sandwicheilanden"] },
+    Entry { alpha2: "GT", alpha3: "GTM", english: "Guatemala", french: "Guatemala", continent: Continent::NorthAmerica, centroid: (14.6, -90.5), aliases: &["republic of guatemala"] },
+    Entry { alpha2: "GU", alpha3: "GUM", english: "Guam", french: "Guam", continent: Continent::Oceania, centroid: (13.5, 144.8), aliases: &[] },
+    Entry { alpha2: "GW", alpha3: "GNB", english: "Guinea-Bissau", french: "Guinée-Bissau", continent: Continent::Africa, centroid: (11.8, -15.6), aliases: &["guine bissau", "guinea bisau", "republic of guinea bissau"] },
+    Entry { alpha2: "GY", alpha3: "GUY", english: "Guyana", french: "Guyana", continent: Continent::SouthAmerica, centroid: (6.8, -58.2), aliases: &["guiana", "republic of guyana"] },
+    Entry { alpha2: "HK", alpha3: "HKG", english: "Hong Kong", french: "Hong Kong", continent: Continent::Asia, centroid: (22.3, 114.2), aliases: &["hong kong special administrative region of china", "hongkong"] },
+    Entry { alpha2: "HM", alpha3: "HMD", english: "Heard Island and McDonald Islands", french: "Îles Heard-et-MacDonald", continent: Continent::Antarctica, centroid: (-53.1, 73.5), aliases: &["heard und mcdonaldinseln", "heardeiland en mcdonaldeilanden", "ilha heard e ilhas mcdonald", "islas heard y mcdonald", "isole heard e mcdonald"] },
+    Entry { alpha2: "HN", alpha3: "HND", english: "Honduras", french: "Honduras", continent: Continent::NorthAmerica, centroid: (14.1, -87.2), aliases: &["republic of honduras"] },
+    Entry { alpha2: "HR", alpha3: "HRV", english: "Croatia", french: "Croatie", continent: Continent::Europe, centroid: (45.8, 16.0), aliases: &["croacia", "croazia", "kroatie", "kroatien", "republic of croatia"] },
+    Entry { alpha2: "HT", alpha3: "HTI", english: "Haiti", french: "Haïti", continent: Continent::NorthAmerica, centroid: (18.5, -72.3), aliases: &["republic of haiti"] },
+    Entry { alpha2: "HU", alpha3: "HUN", english: "Hungary", french: "Hongrie", continent: Continent::Europe, centroid: (47.5, 19.1), aliases: &["hongarije", "hungria", "ungarn", "ungheria"] },
+    Entry { alpha2: "ID", alpha3: "IDN", english: "Indonesia", french: "Indonésie", continent: Continent::Asia, centroid: (-0.8, 113.9), aliases: &["indonesien", "republic of indonesia"] },
+    Entry { alpha2: "IE", alpha3: "IRL", english: "Ireland", french: "Irlande", continent: Continent::Europe, centroid: (53.4, -8.2), aliases: &["ierland", "irland", "irlanda"] },
+    Entry { alpha2: "IL", alpha3: "ISR", english: "Israel", french: "Israël", continent: Continent::Asia, centroid: (31.8, 35.2), aliases: &["israele", "state of israel"] },
+    Entry { alpha2: "IM", alpha3: "IMN", english: "Isle of Man", french: "Île de Man", continent: Continent::Europe, centroid: (54.1, -4.5), aliases: &["eiland man", "ilha de man", "insel man", "isla de man", "isola di man"] },
+    Entry { alpha2: "IN", alpha3: "IND", english: "India", french: "Inde", continent: Continent::Asia, centroid: (22.0, 79.0), aliases: &["indien", "republic of india"] },
+    Entry { alpha2: "IO", alpha3: "IOT", english: "British Indian Ocean Territory", french: "Territoire britannique de l'océan Indien", continent: Continent::Asia, centroid: (-7.3, 72.4), aliases: &["britisches territorium im indischen ozean", "brits indische oceaanterritorium", "territorio britanico del oceano indico", "territorio britanico do oceano indico", "territorio britannico dell oceano indiano"] },
+    Entry { alpha2: "IQ", alpha3: "IRQ", english: "Iraq", french: "Irak", continent: Continent::Asia, centroid: (33.4, 44.4), aliases: &["iraque", "republic of iraq"] },
+    Entry { alpha2: "IR", alpha3: "IRN", english: "Iran", french: "Iran", continent: Continent::Asia, centroid: (32.4, 53.7), aliases: &["iran islamic republic of", "iran islamische republik", "iran republica islamica de", "iran republique islamique d", "irao republica islamica do", "islamic republic of iran"] },
+    Entry { alpha2: "IS", alpha3: "ISL", english: "Iceland", french: "Islande", continent: Continent::Europe, centroid: (65.0, -19.0), aliases: &["ijsland", "island", "islanda", "islandia", "republic of iceland"] },
+    Entry { alpha2: "IT", alpha3: "ITA", english: "Italy", french: "Italie", continent: Continent::Europe, centroid: (42.8, 12.6), aliases: &["italia", "italian republic", "italien"] },
+    Entry { alpha2: "JE", alpha3: "JEY", english: "Jersey", french: "Jersey", continent: Continent::Europe, centroid: (49.2, -2.1), aliases: &[] },
+    Entry { alpha2: "JM", alpha3: "JAM", english: "Jamaica", french: "Jamaïque", continent: Continent::NorthAmerica, centroid: (18.0, -76.8), aliases: &["giamaica", "jamaika"] },
+    Entry { alpha2: "JO", alpha3: "JOR", english: "Jordan", french: "Jordanie", continent: Continent::Asia, centroid: (32.0, 35.9), aliases: &["giordania", "hashemite kingdom of jordan", "jordania", "jordanien"] },
+    Entry { alpha2: "JP", alpha3: "JPN", english: "Japan", french: "Japon", continent: Continent::Asia, centroid: (36.2, 138.3), aliases: &["giappone", "japao"] },
+    Entry { alpha2: "KE", alpha3: "KEN", english: "Kenya", french: "Kenya", continent: Continent::Africa, centroid: (-0.0, 37.9), aliases: &["kenia", "quenia", "republic of kenya"] },
+    Entry { alpha2: "KG", alpha3: "KGZ", english: "Kyrgyzstan", french: "Kirghizistan", continent: Continent::Asia, centroid: (42.9, 74.6), aliases: &["kirgisistan", "kirgizie", "kirguistan", "kyrgyz republic", "quirguistao"] },
+    Entry { alpha2: "KH", alpha3: "KHM", english: "Cambodia", french: "Cambodge", continent: Continent::Asia, centroid: (11.6, 104.9), aliases: &["cambodja", "cambogia", "camboja", "camboya", "kambodscha", "kingdom of cambodia"] },
+    Entry { alpha2: "KI", alpha3: "KIR", english: "Kiribati", french: "Kiribati", continent: Continent::Oceania, centroid: (1.9, -157.4), aliases: &["republic of kiribati"] },
+    Entry { alpha2: "KM", alpha3: "COM", english: "Comoros", french: "Comores", continent: Continent::Africa, centroid: (-11.7, 43.3), aliases: &["comore", "comoren", "comores islas", "komoren", "union of the comoros"] },
+    Entry { alpha2: "KN", alpha3: "KNA", english: "Saint Kitts and Nevis", french: "Saint-Christophe-et-Niévès", continent: Continent::NorthAmerica, centroid: (17.3, -62.7), aliases: &["saint kitts e nevis", "saint kitts en nevis", "san cristobal y nieves", "sao cristovao e nevis", "st kitts und nevis"] },
+    Entry { alpha2: "KP", alpha3: "PRK", english: "North Korea", french: "Corée du Nord", continent: Continent::Asia, centroid: (39.0, 125.7), aliases: &["corea del nord", "corea republica democratica popular de", "coree republique populaire democratique de", "coreia do norte", "coreia republica popular democratica da", "democratic people s republic of korea", "korea democratic people s republic of", "korea democratische volksrepubliek", "korea demokratische volksrepublik", "noord korea", "nordkorea"] },
+    Entry { alpha2: "KR", alpha3: "KOR", english: "South Korea", french: "Corée du Sud", continent: Continent::Asia, centroid: (35.9, 127.8), aliases: &["corea del sud", "corea republica de", "coree", "coree republique de", "coreia do sul", "coreia republica da", "korea", "korea republic of", "korea republiek", "korea republik", "republic of korea", "sudkorea", "zuid korea"] },
+    Entry { alpha2: "KW", alpha3: "KWT", english: "Kuwait", french: "Koweït", continent: Continent::Asia, centroid: (29.3, 48.0), aliases: &["koeweit", "state of kuwait"] },
+    Entry { alpha2: "KY", alpha3: "CYM", english: "Cayman Islands", french: "Îles Caïmans", continent: Continent::NorthAmerica, centroid: (19.3, -81.4), aliases: &["cayman inseln", "ilhas caimao", "islas caiman", "isole cayman", "kaaimaneilanden"] },
+    Entry { alpha2: "KZ", alpha3: "KAZ", english: "Kazakhstan", french: "Kazakhstan", continent: Continent::Asia, centroid: (48.0, 66.9), aliases: &["cazaquistao", "kasachstan", "kazachstan", "kazajistan", "kazakistan", "republic of kazakhstan"] },
+    Entry { alpha2: "LA", alpha3: "LAO", english: "Laos", french: "Laos", continent: Continent::Asia, centroid: (18.0, 102.6), aliases: &["lao people s democratic republic", "lao republique democratique populaire", "laos democratische volksrepubliek", "laos demokratische volksrepublik", "republica democratica popular de lao", "republica democratica popular do laos"] },
+    Entry { alpha2: "LB", alpha3: "LBN", english: "Lebanon", french: "Liban", continent: Continent::Asia, centroid: (33.9, 35.5), aliases: &["lebanese republic", "libano", "libanon"] },
+    Entry { alpha2: "LC", alpha3: "LCA", english: "Saint Lucia", french: "Sainte-Lucie", continent: Continent::NorthAmerica, centroid: (14.0, -61.0), aliases: &["santa lucia", "st lucia"] },
+    Entry { alpha2: "LI", alpha3: "LIE", english: "Liechtenstein", french: "Liechtenstein", continent: Continent::Europe, centroid: (47.1, 9.5), aliases: &["principality of liechtenstein"] },
+    Entry { alpha2: "LK", alpha3: "LKA", english: "Sri Lanka", french: "Sri Lanka", continent: Continent::Asia, centroid: (6.9, 79.8), aliases: &["democratic socialist republic of sri lanka"] },
+    Entry { alpha2: "LR", alpha3: "LBR", english: "Liberia", french: "Libéria", continent: Continent::Africa, centroid: (6.3, -10.8), aliases: &["republic of liberia"] },
+    Entry { alpha2: "LS", alpha3: "LSO", english: "Lesotho", french: "Lesotho", continent: Continent::Africa, centroid: (-29.5, 27.5), aliases: &["kingdom of lesotho", "lesoto"] },
+    Entry { alpha2: "LT", alpha3: "LTU", english: "Lithuania", french: "Lituanie", continent: Continent::Europe, centroid: (54.7, 25.3), aliases: &["litauen", "litouwen", "lituania", "republic of lithuania"] },
+    Entry { alpha2: "LU", alpha3: "LUX", english: "Luxembourg", french: "Luxembourg", continent: Continent::Europe, centroid: (49.6, 6.2), aliases: &["grand duchy of luxembourg", "lussemburgo", "luxemburg", "luxemburgo"] },
+    Entry { alpha2: "LV", alpha3: "LVA", english: "Latvia", french: "Lettonie", continent: Continent::Europe, centroid: (57.0, 24.1), aliases: &["letland", "letonia", "lettland", "lettonia", "republic of latvia"] },
+    Entry { alpha2: "LY", alpha3: "LBY", english: "Libya", french: "Libye", continent: Continent::Africa, centroid: (26.3, 17.2), aliases: &["libia", "libie", "libyen"] },
+    Entry { alpha2: "MA", alpha3: "MAR", english: "Morocco", french: "Maroc", continent: Continent::Africa, centroid: (33.6, -7.6), aliases: &["kingdom of morocco", "marocco", "marokko", "marrocos", "marruecos"] },
+    Entry { alpha2: "MC", alpha3: "MCO", english: "Monaco", french: "Monaco", continent: Continent::Europe, centroid: (43.7, 7.4), aliases: &["principality of monaco"] },
+    Entry { alpha2: "MD", alpha3: "MDA", english: "Moldova", french: "Moldavie", continent: Continent::Europe, centroid: (47.0, 28.8), aliases: &["moldau", "moldau republik", "moldavia", "moldavia republica da", "moldavia republica de", "moldavie republiek", "moldova republic of", "moldova republique de", "republic of moldova"] },
+    Entry { alpha2: "ME", alpha3: "MNE", english: "Montenegro", french: "Monténégro", continent: Continent::Europe, centroid: (42.4, 19.3), aliases: &[] },
+    Entry { alpha2: "MF", alpha3: "MAF", english: "Saint Martin", french: "Saint-Martin", continent: Continent::NorthAmerica, centroid: (18.1, -63.1), aliases: &["saint martin francia", "saint martin franzosischer teil", "saint martin french part", "saint martin partie francaise", "san martin zona francesa", "sao martin territorio frances", "sint maarten frans deel"] },
+    Entry { alpha2: "MG", alpha3: "MDG", english: "Madagascar", french: "Madagascar", continent: Continent::Africa, centroid: (-18.9, 47.5), aliases: &["madagaskar", "republic of madagascar"] },
+    Entry { alpha2: "MH", alpha3: "MHL", english: "Marshall Islands", french: "Îles Marshall", continent: Continent::Oceania, centroid: (8.1, 169.3), aliases: &["ilhas marshall", "islas marshall", "isole marshall", "marshalleilanden", "marshallinseln", "republic of the marshall islands"] },
+    Entry { alpha2: "MK", alpha3: "MKD", english: "North Macedonia", french: "Macédoine du Nord", continent: Continent::Europe, centroid: (42.0, 21.4), aliases: &["macedoine", "macedonia", "macedonia del nord", "macedonia del norte", "macedonia do norte", "noord macedonie", "nordmazedonien", "republic of north macedonia"] },
+    Entry { alpha2: "ML", alpha3: "MLI", english: "Mali", french: "Mali", continent: Continent::Africa, centroid: (17.6, -4.0), aliases: &["republic of mali"] },
+    Entry { alpha2: "MM", alpha3: "MMR", english: "Myanmar", french: "Birmanie", continent: Continent::Asia, centroid: (21.9, 95.9), aliases: &["birmania", "burma", "republic of myanmar"] },
+    Entry { alpha2: "MN", alpha3: "MNG", english: "Mongolia", french: "Mongolie", continent: Continent::Asia, centroid: (46.9, 103.8), aliases: &["mongolei"] },
+    Entry { alpha2: "MO", alpha3: "MAC", english: "Macao", french: "Macao", continent: Continent::Asia, centroid: (22.2, 113.5), aliases: &["macao special administrative region of china", "macau"] },
+    Entry { alpha2: "MP", alpha3: "MNP", english: "Northern Mariana Islands", french: "Îles Mariannes du Nord", continent: Continent::Oceania, centroid: (15.2, 145.8), aliases: &["commonwealth of the northern mariana islands", "ilhas marianas do norte", "islas marianas del norte", "isole marianne settentrionali", "noordelijke marianen", "nordliche marianen"] },
+    Entry { alpha2: "MQ", alpha3: "MTQ", english: "Martinique", french: "Martinique", continent: Continent::NorthAmerica, centroid: (14.6, -61.1), aliases: &["martinica"] },
+    Entry { alpha2: "MR", alpha3: "MRT", english: "Mauritania", french: "Mauritanie", continent: Continent::Africa, centroid: (18.1, -16.0), aliases: &["islamic republic of mauritania", "mauretanien"] },
+    Entry { alpha2: "MS", alpha3: "MSR", english: "Montserrat", french: "Montserrat", continent: Continent::NorthAmerica, centroid: (16.7, -62.2), aliases: &["monserrate"] },
+    Entry { alpha2: "MT", alpha3: "MLT", english: "Malta", french: "Malte", continent: Continent::Europe, centroid: (35.9, 14.5), aliases: &["republic of malta"] },
+    Entry { alpha2: "MU", alpha3: "MUS", english: "Mauritius", french: "Maurice", continent: Continent::Africa, centroid: (-20.2, 57.5), aliases: &["mauricia", "mauricio", "maurizio", "republic of mauritius"] },
+    Entry { alpha2: "MV", alpha3: "MDV", english: "Maldives", french: "Maldives", continent: Continent::Asia, centroid: (4.2, 73.5), aliases: &["islas maldivas", "maldivas", "maldive", "maldiven", "malediven", "republic of maldives"] },
+    Entry { alpha2: "MW", alpha3: "MWI", english: "Malawi", french: "Malawi", continent: Continent::Africa, centroid: (-15.8, 35.0), aliases: &["malaui", "republic of malawi"] },
+    Entry { alpha2: "MX", alpha3: "MEX", english: "Mexico", french: "Mexique", continent: Continent::NorthAmerica, centroid: (23.6, -102.6), aliases: &["messico", "mexiko", "united mexican states"] },
+    Entry { alpha2: "MY", alpha3: "MYS", english: "Malaysia", french: "Malaisie", continent: Continent::Asia, centroid: (4.2, 102.0), aliases: &["malasia", "maleisie"] },
+    Entry { alpha2: "MZ", alpha3: "MOZ", english: "Mozambique", french: "Mozambique", continent: Continent::Africa, centroid: (-18.7, 35.5), aliases: &["mocambique", "mosambik", "mozambico", "republic of mozambique"] },
+    Entry { alpha2: "NA", alpha3: "NAM", english: "Namibia", french: "Namibie", continent: Continent::Africa, centroid: (-22.6, 17.1), aliases: &["republic of namibia"] },
+    Entry { alpha2: "NC", alpha3: "NCL", english: "New Caledonia", french: "Nouvelle-Calédonie", continent: Continent::Oceania, centroid: (-22.3, 166.4), aliases: &["neukaledonien", "nieuw caledonie", "nova caledonia", "nueva caledonia", "nuova caledonia"] },
+    Entry { alpha2: "NE", alpha3: "NER", english: "Niger", french: "Niger", continent: Continent::Africa, centroid: (17.6, 8.1), aliases: &["republic of the niger"] },
+    Entry { alpha2: "NF", alpha3: "NFK", english: "Norfolk Island", french: "Île Norfolk", continent: Continent::Oceania, centroid: (-29.1, 168.0), aliases: &["ilha norfolk", "isla norfolk", "isola norfolk", "norfolk", "norfolkinsel"] },
+    Entry { alpha2: "NG", alpha3: "NGA", english: "Nigeria", french: "Nigeria", continent: Continent::Africa, centroid: (9.1, 8.7), aliases: &["federal republic of nigeria"] },
+    Entry { alpha2: "NI", alpha3: "NIC", english: "Nicaragua", french: "Nicaragua", continent: Continent::NorthAmerica, centroid: (12.2, -86.3), aliases: &["republic of nicaragua"] },
+    Entry { alpha2: "NL", alpha3: "NLD", english: "Netherlands", french: "Pays-Bas", continent: Continent::Europe, centroid: (52.2, 5.3), aliases: &["holland", "hollande", "kingdom of the netherlands", "nederland", "niederlande", "paesi bassi", "paises baixos", "paises bajos"] },
+    Entry { alpha2: "NO", alpha3: "NOR", english: "Norway", french: "Norvège", continent: Continent::Europe, centroid: (61.5, 9.0), aliases: &["kingdom of norway", "noorwegen", "noruega", "norvegia", "norwegen"] },
+    Entry { alpha2: "NP", alpha3: "NPL", english: "Nepal", french: "Népal", continent: Continent::Asia, centroid: (27.7, 85.3), aliases: &["federal democratic republic of nepal"] },
+    Entry { alpha2: "NR", alpha3: "NRU", english: "Nauru", french: "Nauru", continent: Continent::Oceania, centroid: (-0.5, 166.9), aliases: &["republic of nauru"] },
+    Entry { alpha2: "NU", alpha3: "NIU", english: "Niue", french: "Nioue", continent: Continent::Oceania, centroid: (-19.0, -169.9), aliases: &[] },
+    Entry { alpha2: "NZ", alpha3: "NZL", english: "New Zealand", french: "Nouvelle-Zélande", continent: Continent::Oceania, centroid: (-40.9, 174.9), aliases: &["neuseeland", "nieuw zeeland", "nova zelandia", "nueva zelanda", "nuova zelanda"] },
+    Entry { alpha2: "OM", alpha3: "OMN", english: "Oman", french: "Oman", continent: Continent::Asia, centroid: (23.6, 58.6), aliases: &["oma", "sultanate of oman"] },
+    Entry { alpha2: "PA", alpha3: "PAN", english: "Panama", french: "Panama", continent: Continent::NorthAmerica, centroid: (9.0, -79.5), aliases: &["republic of panama"] },
+    Entry { alpha2: "PE", alpha3: "PER", english: "Peru", french: "Pérou", continent: Continent::SouthAmerica, centroid: (-9.2, -75.0), aliases: &["republic of peru"] },
+    Entry { alpha2: "PF", alpha3: "PYF", english: "French Polynesia", french: "Polynésie française", continent: Continent::Oceania, centroid: (-17.7, -149.4), aliases: &["frans polynesie", "franzosisch polynesien", "polinesia francesa", "polinesia francese"] },
+    Entry { alpha2: "PG", alpha3: "PNG", english: "Papua New Guinea", french: "Papouasie-Nouvelle-Guinée", continent: Continent::Oceania, centroid: (-6.3, 143.9), aliases: &["independent state of papua new guinea", "papoea nieuw guinea", "papua neuguinea", "papua nova guine", "papua nueva guinea", "papua nuova guinea"] },
+    Entry { alpha2: "PH", alpha3: "PHL", english: "Philippines", french: "Philippines", continent: Continent::Asia, centroid: (12.9, 121.8), aliases: &["filipijnen", "filipinas", "filippine", "philippinen", "republic of the philippines"] },
+    Entry { alpha2: "PK", alpha3: "PAK", english: "Pakistan", french: "Pakistan", continent: Continent::Asia, centroid: (30.4, 69.3), aliases: &["islamic republic of pakistan", "paquistao"] },
+    Entry { alpha2: "PL", alpha3: "POL", english: "Poland", french: "Pologne", continent: Continent::Europe, centroid: (52.1, 19.4), aliases: &["polen", "polonia", "republic of poland"] },
+    Entry { alpha2: "PM", alpha3: "SPM", english: "Saint Pierre and Miquelon", french: "Saint-Pierre-et-Miquelon", continent: Continent::NorthAmerica, centroid: (47.0, -56.3), aliases: &["saint pierre e miquelon", "saint pierre en miquelon", "san pedro y miquelon", "st pierre und miquelon"] },
+    Entry { alpha2: "PN", alpha3: "PCN", english: "Pitcairn", french: "Îles Pitcairn", continent: Continent::Oceania, centroid: (-25.1, -130.1), aliases: &["pitcairneilanden"] },
+    Entry { alpha2: "PR", alpha3: "PRI", english: "Puerto Rico", french: "Porto Rico", continent: Continent::NorthAmerica, centroid: (18.5, -66.1), aliases: &["portorico"] },
+    Entry { alpha2: "PS", alpha3: "PSE", english: "Palestine", french: "Palestine", continent: Continent::Asia, centroid: (31.5, 34.8), aliases: &["palastina staat", "palestina estado da", "palestina estado de", "palestina staat", "palestina stato di", "palestine etat de", "palestine state of", "the state of palestine"] },
+    Entry { alpha2: "PT", alpha3: "PRT", english: "Portugal", french: "Portugal", continent: Continent::Europe, centroid: (39.6, -8.0), aliases: &["portogallo", "portuguese republic"] },
+    Entry { alpha2: "PW", alpha3: "PLW", english: "Palau", french: "Palaos", continent: Continent::Oceania, centroid: (7.3, 134.5), aliases: &["republic of palau"] },
+    Entry { alpha2: "PY", alpha3: "PRY", english: "Paraguay", french: "Paraguay", continent: Continent::SouthAmerica, centroid: (-25.3, -57.7), aliases: &["paraguai", "republic of paraguay"] },
+    Entry { alpha2: "QA", alpha3: "QAT", english: "Qatar", french: "Qatar", continent: Continent::Asia, centroid: (25.3, 51.5), aliases: &["catar", "katar", "state of qatar"] },
+    Entry { alpha2: "RE", alpha3: "REU", english: "Réunion", french: "La Réunion", continent: Continent::Africa, centroid: (-20.9, 55.5), aliases: &["ilha reuniao", "reunion ile de la", "riunione"] },
+    Entry { alpha2: "RO", alpha3: "ROU", english: "Romania", french: "Roumanie", continent: Continent::Europe, centroid: (45.9, 25.0), aliases: &["roemenie", "romenia", "rumania", "rumanien"] },
+    Entry { alpha2: "RS", alpha3: "SRB", english: "Serbia", french: "Serbie", continent: Continent::Europe, centroid: (44.8, 20.5), aliases: &["republic of serbia", "serbien", "servia", "servie"] },
+    Entry { alpha2: "RU", alpha3: "RUS", english: "Russia", french: "Russie", continent: Continent::Europe, centroid: (61.5, 105.3), aliases: &["federacao russa", "federacion rusa", "rusia", "rusland", "russian federation", "russie federation de", "russische foderation", "russland"] },
+    Entry { alpha2: "RW", alpha3: "RWA", english: "Rwanda", french: "Rwanda", continent: Continent::Africa, centroid: (-1.9, 30.1), aliases: &["ruanda", "rwandese republic"] },
+    Entry { alpha2: "SA", alpha3: "SAU", english: "Saudi Arabia", french: "Arabie saoudite", continent: Continent::Asia, centroid: (23.9, 45.1), aliases: &["arabia saudi", "arabia saudita", "kingdom of saudi arabia", "saoedi arabie", "saudi arabien"] },
+    Entry { alpha2: "SB", alpha3: "SLB", english: "Solomon Islands", french: "Îles Salomon", continent: Continent::Oceania, centroid: (-9.5, 160.2), aliases: &["ilhas salomao", "islas salomon", "isole salomone", "salomon iles", "salomoninseln", "salomonseilanden"] },
+    Entry { alpha2: "SC", alpha3: "SYC", english: "Seychelles", french: "Seychelles", continent: Continent::Africa, centroid: (-4.7, 55.5), aliases: &["republic of seychelles", "seychellen"] },
+    Entry { alpha2: "SD", alpha3: "SDN", english: "Sudan", french: "Soudan", continent: Continent::Africa, centroid: (12.9, 30.2), aliases: &["republic of the sudan", "soedan", "sudao"] },
+    Entry { alpha2: "SE", alpha3: "SWE", english: "Sweden", french: "Suède", continent: Continent::Europe, centroid: (62.0, 15.0), aliases: &["kingdom of sweden", "schweden", "suecia", "svezia", "zweden"] },
+    Entry { alpha2: "SG", alpha3: "SGP", english: "Singapore", french: "Singapour", continent: Continent::Asia, centroid: (1.4, 103.8), aliases: &["republic of singapore", "singapur", "singapura"] },
+    Entry { alpha2: "SH", alpha3: "SHN", english: "Saint Helena", french: "Sainte-Hélène", continent: Continent::Africa, centroid: (-15.9, -5.7), aliases: &["saint helena ascension and tristan da cunha", "sainte helene ascension et tristan da cunha", "sant elena ascensione e tristan da cunha", "santa elena ascension y tristan de acuna", "santa helena ascensao e tristao da cunha", "sint helena ascension en tristan da cunha", "st helena ascension und tristan da cunha"] },
+    Entry { alpha2: "SI", alpha3: "SVN", english: "Slovenia", french: "Slovénie", continent: Continent::Europe, centroid: (46.0, 14.5), aliases: &["eslovenia", "republic of slovenia", "slowenien"] },
+    Entry { alpha2: "SJ", alpha3: "SJM", english: "Svalbard and Jan Mayen", french: "Svalbard et Jan Mayen", continent: Continent::Europe, centroid: (78.0, 16.0), aliases: &["spitsbergen en jan mayen", "svalbard e jan mayen", "svalbard et ile jan mayen", "svalbard und jan mayen", "svalbard y jan mayen"] },
+    Entry { alpha2: "SK", alpha3: "SVK", english: "Slovakia", french: "Slovaquie", continent: Continent::Europe, centroid: (48.1, 17.1), aliases: &["eslovaquia", "slovacchia", "slovak republic", "slowakei", "slowakije"] },
+    Entry { alpha2: "SL", alpha3: "SLE", english: "Sierra Leone", french: "Sierra Leone", continent: Continent::Africa, centroid: (8.5, -13.2), aliases: &["republic of sierra leone", "serra leoa", "sierra leona"] },
+    Entry { alpha2: "SM", alpha3: "SMR", english: "San Marino", french: "Saint-Marin", continent: Continent::Europe, centroid: (43.9, 12.5), aliases: &["republic of san marino"] },
+    Entry { alpha2: "SN", alpha3: "SEN", english: "Senegal", french: "Sénégal", continent: Continent::Africa, centroid: (14.7, -17.4), aliases: &["republic of senegal"] },
+    Entry { alpha2: "SO", alpha3: "SOM", english: "Somalia", french: "Somalie", continent: Continent::Africa, centroid: (2.1, 45.4), aliases: &["federal republic of somalia"] },
+    Entry { alpha2: "SR", alpha3: "SUR", english: "Suriname", french: "Surinam", continent: Continent::SouthAmerica, centroid: (5.8, -55.2), aliases: &["republic of suriname"] },
+    Entry { alpha2: "SS", alpha3: "SSD", english: "South Sudan", french: "Soudan du Sud", continent: Continent::Africa, centroid: (4.8, 31.6), aliases: &["republic of south sudan", "sudan del sud", "sudan del sur", "sudao do sul", "sudsudan", "zuid soedan"] },
+    Entry { alpha2: "ST", alpha3: "STP", english: "Sao Tome and Principe", french: "Sao Tomé-et-Principe", continent: Continent::Africa, centroid: (0.3, 6.7), aliases: &["democratic republic of sao tome and principe", "santo tome y principe", "sao tome e principe", "sao tome en principe", "sao tome und principe"] },
+    Entry { alpha2: "SV", alpha3: "SLV", english: "El Salvador", french: "Salvador", continent: Continent::NorthAmerica, centroid: (13.7, -89.2), aliases: &["republic of el salvador"] },
+    Entry { alpha2: "SX", alpha3: "SXM", english: "Sint Maarten", french: "Sint Maarten", continent: Continent::NorthAmerica, centroid: (18.1, -63.0), aliases: &["isla de san martin zona holandsea", "saint martin niederlandischer teil", "saint martin partie neerlandaise", "sao martinho paises baixos", "sint maarten dutch part", "sint maarten nederlands deel", "sint maarten olanda"] },
+    Entry { alpha2: "SY", alpha3: "SYR", english: "Syria", french: "Syrie", continent: Continent::Asia, centroid: (33.5, 36.3), aliases: &["republica arabe de siria", "republica arabe siria", "siria", "syrian arab republic", "syrien", "syrien arabische republik", "syrienne republique arabe"] },
+    Entry { alpha2: "SZ", alpha3: "SWZ", english: "Eswatini", french: "Eswatini", continent: Continent::Africa, centroid: (-26.3, 31.1), aliases: &["esuatini", "kingdom of eswatini", "suazilandia", "swaziland"] },
+    Entry { alpha2: "TC", alpha3: "TCA", english: "Turks and Caicos Islands", french: "Îles Turques-et-Caïques", continent: Continent::NorthAmerica, centroid: (21.5, -71.1), aliases: &["ilhas turcas e caicos", "islas turcas y caicos", "isole turks e caicos", "turks en caicoseilanden", "turks und caicosinseln"] },
+    Entry { alpha2: "TD", alpha3: "TCD", english: "Chad", french: "Tchad", continent: Continent::Africa, centroid: (15.5, 18.7), aliases: &["chade", "ciad", "republic of chad", "tschad", "tsjaad"] },
+    Entry { alpha2: "TF", alpha3: "ATF", english: "French Southern Territories", french: "Terres australes françaises", continent: Continent::Antarctica, centroid: (-49.3, 69.3), aliases: &["franse zuidelijke gebieden", "franzosische sud und antarktisgebiete", "territori francesi meridionali", "territorios franceses del sur", "territorios franceses do sul"] },
+    Entry { alpha2: "TG", alpha3: "TGO", english: "Togo", french: "Togo", continent: Continent::Africa, centroid: (6.1, 1.2), aliases: &["togolese republic"] },
+    Entry { alpha2: "TH", alpha3: "THA", english: "Thailand", french: "Thaïlande", continent: Continent::Asia, centroid: (15.9, 101.0), aliases: &["kingdom of thailand", "tailandia", "thailandia"] },
+    Entry { alpha2: "TJ", alpha3: "TJK", english: "Tajikistan", french: "Tadjikistan", continent: Continent::Asia, centroid: (38.6, 68.8), aliases: &["republic of tajikistan", "tadschikistan", "tadzjikistan", "tagikistan", "tajiquistao", "tayikistan"] },
+    Entry { alpha2: "TK", alpha3: "TKL", english: "Tokelau", french: "Tokelau", continent: Continent::Oceania, centroid: (-9.4, -171.2), aliases: &[] },
+    Entry { alpha2: "TL", alpha3: "TLS", english: "Timor-Leste", french: "Timor oriental", continent: Continent::Asia, centroid: (-8.6, 125.6), aliases: &["democratic republic of timor leste", "east timor", "oost timor", "timor est"] },
+    Entry { alpha2: "TM", alpha3: "TKM", english: "Turkmenistan", french: "Turkménistan", continent: Continent::Asia, centroid: (38.0, 58.4), aliases: &["turquemenistao"] },
+    Entry { alpha2: "TN", alpha3: "TUN", english: "Tunisia", french: "Tunisie", continent: Continent::Africa, centroid: (36.8, 10.2), aliases: &["republic of tunisia", "tunesie", "tunesien", "tunez"] },
+    Entry { alpha2: "TO", alpha3: "TON", english: "Tonga", french: "Tonga", continent: Continent::Oceania, centroid: (-21.1, -175.2), aliases: &["kingdom of tonga"] },
+    Entry { alpha2: "TR", alpha3: "TUR", english: "Türkiye", french: "Turquie", continent: Continent::Europe, centroid: (39.0, 35.2), aliases: &["republic of turkiye", "turchia", "turkei", "turkey", "turkije", "turquia"] },
+    Entry { alpha2: "TT", alpha3: "TTO", english: "Trinidad and Tobago", french: "Trinité-et-Tobago", continent: Continent::NorthAmerica, centroid: (10.7, -61.5), aliases: &["republic of trinidad and tobago", "trindade e tobago", "trinidad e tobago", "trinidad en tobago", "trinidad und tobago", "trinidad y tobago"] },
+    Entry { alpha2: "TV", alpha3: "TUV", english: "Tuvalu", french: "Tuvalu", continent: Continent::Oceania, centroid: (-8.5, 179.2), aliases: &[] },
+    Entry { alpha2: "TW", alpha3: "TWN", english: "Taiwan", french: "Taïwan", continent: Continent::Asia, centroid: (25.1, 121.5), aliases: &["taiwan chinesische provinz", "taiwan province de chine", "taiwan province of china", "taiwan provincia da china", "taiwan provincia de china", "taiwan repubblica di cina"] },
+    Entry { alpha2: "TZ", alpha3: "TZA", english: "Tanzania", french: "Tanzanie", continent: Continent::Africa, centroid: (-6.4, 34.9), aliases: &["tansania", "tansania vereinigte republik", "tanzania republica unida da", "tanzania republica unida de", "tanzania united republic of", "tanzanie republique unie de", "united republic of tanzania"] },
+    Entry { alpha2: "UA", alpha3: "UKR", english: "Ukraine", french: "Ukraine", continent: Continent::Europe, centroid: (48.4, 31.2), aliases: &["oekraine", "ucraina", "ucrania"] },
+    Entry { alpha2: "UG", alpha3: "UGA", english: "Uganda", french: "Ouganda", continent: Continent::Africa, centroid: (0.3, 32.4), aliases: &["oeganda", "republic of uganda"] },
+    Entry { alpha2: "UM", alpha3: "UMI", english: "United States Minor Outlying Islands", french: "Îles mineures éloignées des États-Unis", continent: Continent::Oceania, centroid: (19.3, 166.6), aliases: &["ilhas menores distantes dos estados unidos", "islas ultramarinas menores de estados unidos", "isole minori esterne degli stati uniti d america", "kleine afgelegen eilanden van de verenigde staten"] },
+    Entry { alpha2: "US", alpha3: "USA", english: "United States", french: "États-Unis", continent: Continent::NorthAmerica, centroid: (39.8, -98.6), aliases: &["america", "amerique", "estados unidos", "etats unis d amerique", "stati uniti", "united states of america", "usa", "vereinigte staaten", "verenigde staten"] },
+    Entry { alpha2: "UY", alpha3: "URY", english: "Uruguay", french: "Uruguay", continent: Continent::SouthAmerica, centroid: (-34.9, -56.2), aliases: &["eastern republic of uruguay", "uruguai"] },
+    Entry { alpha2: "UZ", alpha3: "UZB", english: "Uzbekistan", french: "Ouzbékistan", continent: Continent::Asia, centroid: (41.4, 64.6), aliases: &["oezbekistan", "republic of uzbekistan", "usbekistan", "uzbequistao"] },
+    Entry { alpha2: "VA", alpha3: "VAT", english: "Vatican City", french: "Vatican", continent: Continent::Europe, centroid: (41.9, 12.5), aliases: &["heiliger stuhl staat vatikanstadt", "holy see", "holy see vatican city state", "saint siege etat de la cite du vatican", "santa se estado da cidade do vaticano", "santa sede ciudad estado del vaticano", "santa sede stato della citta del vaticano", "vaticaanstad staat"] },
+    Entry { alpha2: "VC", alpha3: "VCT", english: "Saint Vincent and the Grenadines", french: "Saint-Vincent-et-les-Grenadines", continent: Continent::NorthAmerica, centroid: (13.2, -61.2), aliases: &["saint vincent e grenadine", "saint vincent en de grenadines", "san vicente y las granadinas", "sao vicente e granadinas", "st vincent und die grenadinen"] },
+    Entry { alpha2: "VE", alpha3: "VEN", english: "Venezuela", french: "Vénézuela", continent: Continent::SouthAmerica, centroid: (6.4, -66.6), aliases: &["bolivarian republic of venezuela", "venezuela bolivariaanse republiek", "venezuela bolivarian republic of", "venezuela bolivarische republik", "venezuela repubblica bolivariana del", "venezuela republica bolivariana da", "venezuela republica bolivariana de", "venezuela republique bolivarienne du"] },
+    Entry { alpha2: "VG", alpha3: "VGB", english: "British Virgin Islands", french: "Îles Vierges britanniques", continent: Continent::NorthAmerica, centroid: (18.5, -64.6), aliases: &["britische jungferninseln", "ilhas virgens britanicas", "islas virgenes britanicas", "isole vergini regno unito", "maagdeneilanden britse", "virgin islands british"] },
+    Entry { alpha2: "VI", alpha3: "VIR", english: "U.S. Virgin Islands", french: "Îles Vierges des États-Unis", continent: Continent::NorthAmerica, centroid: (18.4, -64.9), aliases: &["amerikanische jungferninseln", "iles vierges etats unis", "ilhas virgens estados unidos", "islas virgenes de eeuu", "isole vergini u s a", "maagdeneilanden amerikaanse", "virgin islands of the united states", "virgin islands u s"] },
+    Entry { alpha2: "VN", alpha3: "VNM", english: "Vietnam", french: "Viêt Nam", continent: Continent::Asia, centroid: (14.1, 108.3), aliases: &["socialist republic of viet nam", "vietname"] },
+    Entry { alpha2: "VU", alpha3: "VUT", english: "Vanuatu", french: "Vanuatu", continent: Continent::Oceania, centroid: (-17.7, 168.4), aliases: &["republic of vanuatu"] },
+    Entry { alpha2: "WF", alpha3: "WLF", english: "Wallis and Futuna", french: "Wallis et Futuna", continent: Continent::Oceania, centroid: (-13.3, -176.2), aliases: &["wallis e futuna", "wallis en futuna", "wallis und futuna", "wallis y futuna"] },
+    Entry { alpha2: "WS", alpha3: "WSM", english: "Samoa", french: "Samoa", continent: Continent::Oceania, centroid: (-13.8, -171.7), aliases: &["independent state of samoa"] },
+    Entry { alpha2: "YE", alpha3: "YEM", english: "Yemen", french: "Yémen", continent: Continent::Asia, centroid: (12.8, 45.2), aliases: &["iemen", "jemen", "republic of yemen"] },
+    Entry { alpha2: "YT", alpha3: "MYT", english: "Mayotte", french: "Mayotte", continent: Continent::Africa, centroid: (-12.8, 45.2), aliases: &[] },
+    Entry { alpha2: "ZA", alpha3: "ZAF", english: "South Africa", french: "Afrique du Sud", continent: Continent::Africa, centroid: (-29.0, 24.7), aliases: &["africa do sul", "republic of south africa", "sudafrica", "sudafrika", "zuid afrika"] },
+    Entry { alpha2: "ZM", alpha3: "ZMB", english: "Zambia", french: "Zambie", continent: Continent::Africa, centroid: (-15.4, 28.3), aliases: &["republic of zambia", "sambia"] },
+    Entry { alpha2: "ZW", alpha3: "ZWE", english: "Zimbabwe", french: "Zimbabwe", continent: Continent::Africa, centroid: (-17.8, 31.1), aliases: &["republic of zimbabwe", "simbabwe", "zimbabue"] },
+];
@@ -0,0 +1,244 @@
+//! Pays ISO 3166-1.
+//!
+//! L'API décrit le pays d'un serveur en texte libre : un code (`fr`, `FRA`)
+//! ou un nom dans une langue quelconque (`France`, `Allemagne`,
+//! `Deutschland`). [`Country`] ramène tout cela à un code alpha-2 et porte ce
+//! qu'il faut pour l'afficher : nom localisé, continent, position et drapeau.
+
+mod data;
+
+use data::COUNTRIES;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CountryError {
+    #[error("unknown country: {0}")]
+    Unknown(String),
+}
+
+/// Langue des noms affichés.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    English,
+    French,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Continent {
+    Europe,
+    NorthAmerica,
+    SouthAmerica,
+    Asia,
+    Oceania,
+    Africa,
+    Antarctica,
+}
+
+impl Continent {
+    pub const ALL: [Continent; 7] = [
+        Continent::Europe,
+        Continent::NorthAmerica,
+        Continent::SouthAmerica,
+        Continent::Asia,
+        Continent::Oceania,
+        Continent::Africa,
+        Continent::Antarctica,
+    ];
+
+    pub fn name(self, language: Language) -> &'static str {
+        match (self, language) {
+            (Continent::Europe, _) => "Europe",
+            (Continent::NorthAmerica, Language::English) => "North America",
+            (Continent::NorthAmerica, Language::French) => "Amérique du Nord",
+            (Continent::SouthAmerica, Language::English) => "South America",
+            (Continent::SouthAmerica, Language::French) => "Amérique du Sud",
+            (Continent::Asia, Language::English) => "Asia",
+            (Continent::Asia, Language::French) => "Asie",
+            (Continent::Oceania, Language::English) => "Oceania",
+            (Continent::Oceania, Language::French) => "Océanie",
+            (Continent::Africa, Language::English) => "Africa",
+            (Continent::Africa, Language::French) => "Afrique",
+            (Continent::Antarctica, Language::English) => "Antarctica",
+            (Continent::Antarctica, Language::French) => "Antarctique",
+        }
+    }
+}
+
+/// Une ligne de la table.
+struct Entry {
+    alpha2: &'static str,
+    alpha3: &'static str,
+    english: &'static str,
+    french: &'static str,
+    continent: Continent,
+    centroid: (f64, f64),
+    /// Noms dans d'autres langues et appellations courantes, déjà repliés.
+    aliases: &'static [&'static str],
+}
+
+/// Un pays de la table ISO 3166-1. Copier un `Country` ne coûte qu'un
+/// pointeur.
+#[derive(Clone, Copy)]
+pub struct Country(&'static Entry);
+
+impl Country {
+    /// Tous les pays, triés par code.
+    pub fn all() -> impl Iterator<Item = Country> {
+        COUNTRIES.iter().map(Country)
+    }
+
+    /// Code alpha-2 en majuscules (`FR`).
+    pub fn code(&self) -> &'static str {
+        self.0.alpha2
+    }
+
+    /// Code alpha-3 en majuscules (`FRA`).
+    pub fn alpha3(&self) -> &'static str {
+        self.0.alpha3
+    }
+
+    pub fn name(&self, language: Language) -> &'static str {
+        match language {
+            Language::English => self.0.english,
+            Language::French => self.0.french,
+        }
+    }
+
+    pub fn continent(&self) -> Continent {
+        self.0.continent
+    }
+
+    /// `(latitude, longitude)` en degrés : centre approximatif du pays, de
+    /// quoi placer un point sur une carte.
+    pub fn centroid(&self) -> (f64, f64) {
+        self.0.centroid
+    }
+
+    /// Drapeau emoji, formé des deux indicateurs régionaux du code.
+    pub fn flag(&self) -> String {
+        self.0
+            .alpha2
+            .bytes()
+            .filter_map(|b| char::from_u32(0x1F1E6 + u32::from(b - b'A')))
+            .collect()
+    }
+
+    /// Vrai si un mot d'un des noms du pays commence par `query`, sans tenir
+    /// compte de la casse ni des accents (`etats` et `unis` trouvent les
+    /// États-Unis), ou si `query` en est le code exact.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = fold(query);
+        if query.is_empty() {
+            return true;
+        }
+        let starts_word =
+            |name: &str| name.starts_with(&query) || name.contains(&format!(" {query}"));
+        self.0.alpha2.eq_ignore_ascii_case(&query)
+            || self.0.alpha3.eq_ignore_ascii_case(&query)
+            || starts_word(&fold(self.0.english))
+            || starts_word(&fold(self.0.french))
+            || self.0.aliases.iter().any(|alias| starts_word(alias))
+    }
+}
+
+impl FromStr for Country {
+    type Err = CountryError;
+
+    /// Accepte les codes alpha-2 et alpha-3 et les noms connus, quelle que
+    /// soit la casse ou l'accentuation (`fr`, `FRA`, `États-Unis`,
+    /// `etats unis`, `Deutschland`, `uk`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        index()
+            .get(&fold(s))
+            .copied()
+            .ok_or_else(|| CountryError::Unknown(s.trim().to_string()))
+    }
+}
+
+impl PartialEq for Country {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.alpha2 == other.0.alpha2
+    }
+}
+
+impl Eq for Country {}
+
+impl Hash for Country {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.alpha2.hash(state);
+    }
+}
+
+impl fmt::Debug for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Country({})", self.0.alpha2)
+    }
+}
+
+/// Tous les noms repliés vers leur pays, construit au premier appel.
+fn index() -> &'static HashMap<String, Country> {
+    static INDEX: OnceLock<HashMap<String, Country>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut index = HashMap::new();
+        for country in Country::all() {
+            let entry = country.0;
+            let names = [entry.alpha2, entry.alpha3, entry.english, entry.french];
+            for name in names.into_iter().chain(entry.aliases.iter().copied()) {
+                index.entry(fold(name)).or_insert(country);
+            }
+        }
+        index
+    })
+}
+
+/// Minuscules sans accents, ponctuation ramenée à des espaces simples :
+/// `Côte d'Ivoire` devient `cote d ivoire`.
+fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    let mut pending_space = false;
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if !c.is_alphanumeric() {
+            pending_space = !folded.is_empty();
+            continue;
+        }
+        if pending_space {
+            folded.push(' ');
+            pending_space = false;
+        }
+        match fold_char(c) {
+            Some(plain) => folded.push_str(plain),
+            None => folded.push(c),
+        }
+    }
+    folded
+}
+
+fn fold_char(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ś' | 'š' | 'ş' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    })
+}
@@ -0,0 +1,152 @@
+pub mod packet;
+pub use packet::*;
+
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DnsError {
+    #[error("truncated DNS packet")]
+    Truncated,
+    #[error("malformed DNS packet")]
+    Malformed,
+    #[error("invalid domain pattern: {0}")]
+    InvalidPattern(String),
+}
+
+/// Adresse obtenue dans une réponse DNS (enregistrement A ou AAAA).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DnsAnswer {
+    pub addr: IpAddr,
+    pub ttl: u32,
+}
+
+/// Motif de domaine pour le routage par domaine.
+///
+/// `example.com` correspond au domaine et à tous ses sous-domaines,
+/// `*.example.com` uniquement aux sous-domaines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DomainPattern {
+    suffix: String,
+    subdomains_only: bool,
+}
+
+impl DomainPattern {
+    pub fn matches(&self, name: &str) -> bool {
+        let name = name.trim_end_matches('.').to_ascii_lowercase();
+        if name == self.suffix {
+            return !self.subdomains_only;
+        }
+        name.len() > self.suffix.len()
+            && name.ends_with(&self.suffix)
+            && name.as_bytes()[name.len() - self.suffix.len() - 1] == b'.'
+    }
+}
+
+impl FromStr for DomainPattern {
+    type Err = DnsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().trim_end_matches('.').to_ascii_lowercase();
+        let (suffix, subdomains_only) = match s.strip_prefix("*.") {
+            Some(rest) => (rest.to_string(), true),
+            None => (s.clone(), false),
+        };
+
+        let valid = !suffix.is_empty()
+            && suffix.split('.').all(|label| {
+                !label.is_empty()
+                    && label.len() <= 63
+                    && label
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            });
+        if !valid {
+            return Err(DnsError::InvalidPattern(s));
+        }
+
+        Ok(Self {
+            suffix,
+            subdomains_only,
+        })
+    }
+}
+
+impl fmt::Display for DomainPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.subdomains_only {
+            write!(f, "*.{}", self.suffix)
+        } else {
+            write!(f, "{}", self.suffix)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(s: &str) -> DomainPattern {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn exact_pattern_matches_domain_and_subdomains() {
+        let pattern = pattern("example.com");
+        assert!(pattern.matches("example.com"));
+        assert!(pattern.matches("www.example.com"));
+        assert!(pattern.matches("a.b.example.com"));
+        assert!(pattern.matches("WWW.Example.COM."));
+        assert!(!pattern.matches("notexample.com"));
+        assert!(!pattern.matches("example.com.evil.net"));
+        assert!(!pattern.matches("com"));
+    }
+
+    #[test]
+    fn wildcard_pattern_matches_subdomains_only() {
+        let pattern = pattern("*.example.com");
+        assert!(!pattern.matches("example.com"));
+        assert!(!pattern.matches("example.com."));
+        assert!(pattern.matches("www.example.com"));
+        assert!(pattern.matches("a.b.example.com"));
+        assert!(!pattern.matches("notexample.com"));
+    }
+
+    #[test]
+    fn patterns_are_normalized() {
+        assert_eq!(pattern(" Example.COM. ").to_string(), "example.com");
+        assert_eq!(pattern("*.Example.com").to_string(), "*.example.com");
+        assert_eq!(pattern("*.example.com"), pattern("*.EXAMPLE.com."));
+        assert_ne!(pattern("*.example.com"), pattern("example.com"));
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        for invalid in [
+            "",
+            "*.",
+            "*",
+            "a..com",
+            "ex ample.com",
+            "*.*.com",
+            "a.*.com",
+        ] {
+            assert!(
+                matches!(
+                    invalid.parse::<DomainPattern>(),
+                    Err(DnsError::InvalidPattern(_))
+                ),
+                "{:?} is accepted",
+                invalid
+            );
+        }
+        assert!(format!("{}.com", "a".repeat(64))
+            .parse::<DomainPattern>()
+            .is_err());
+        assert!(format!("{}.com", "a".repeat(63))
+            .parse::<DomainPattern>()
+            .is_ok());
+    }
+}
@@ -0,0 +1,297 @@
+use super::*;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+const HEADER_LEN: usize = 12;
+const TYPE_A: u16 = 1;
+const TYPE_AAAA: u16 = 28;
+const CLASS_IN: u16 = 1;
+/// Limite de sauts de compression, contre les boucles de pointeurs.
+const MAX_POINTERS: usize = 16;
+
+/// Nom de la première question d'un paquet DNS (requête ou réponse).
+pub fn query_name(packet: &[u8]) -> Result<String, DnsError> {
+    if question_count(packet)? == 0 {
+        return Err(DnsError::Malformed);
+    }
+    let (name, _) = read_name(packet, HEADER_LEN)?;
+    Ok(name)
+}
+
+/// Adresses A et AAAA de la section réponse, avec leur TTL.
+pub fn answers(packet: &[u8]) -> Result<Vec<DnsAnswer>, DnsError> {
+    let questions = question_count(packet)?;
+    let answer_count = read_u16(packet, 6)?;
+
+    let mut offset = HEADER_LEN;
+    for _ in 0..questions {
+        offset = skip_name(packet, offset)? + 4; // QTYPE + QCLASS
+    }
+
+    let mut result = Vec::new();
+    for _ in 0..answer_count {
+        offset = skip_name(packet, offset)?;
+        let rtype = read_u16(packet, offset)?;
+        let class = read_u16(packet, offset + 2)?;
+        let ttl = read_u32(packet, offset + 4)?;
+        let len = read_u16(packet, offset + 8)? as usize;
+        let data = packet
+            .get(offset + 10..offset + 10 + len)
+            .ok_or(DnsError::Truncated)?;
+        offset += 10 + len;
+
+        if class != CLASS_IN {
+            continue;
+        }
+        let addr = match (rtype, data.len()) {
+            (TYPE_A, 4) => IpAddr::V4(Ipv4Addr::new(data[0], data[1], data[2], data[3])),
+            (TYPE_AAAA, 16) => {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(data);
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => continue,
+        };
+        result.push(DnsAnswer { addr, ttl });
+    }
+    Ok(result)
+}
+
+fn question_count(packet: &[u8]) -> Result<u16, DnsError> {
+    read_u16(packet, 4)
+}
+
+/// Lit un nom (avec pointeurs de compression) et retourne l'offset suivant.
+fn read_name(packet: &[u8], start: usize) -> Result<(String, usize), DnsError> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut offset = start;
+    let mut next = None;
+    let mut jumps = 0;
+
+    loop {
+        let len = *packet.get(offset).ok_or(DnsError::Truncated)? as usize;
+        match len {
+            0 => {
+                offset += 1;
+                break;
+            }
+            l if l & 0xC0 == 0xC0 => {
+                let low = *packet.get(offset + 1).ok_or(DnsError::Truncated)? as usize;
+                if next.is_none() {
+                    next = Some(offset + 2);
+                }
+                jumps += 1;
+                if jumps > MAX_POINTERS {
+                    return Err(DnsError::Malformed);
+                }
+                offset = ((l & 0x3F) << 8) | low;
+            }
+            l if l <= 63 => {
+                let label = packet
+                    .get(offset + 1..offset + 1 + l)
+                    .ok_or(DnsError::Truncated)?;
+                labels.push(String::from_utf8_lossy(label).to_ascii_lowercase());
+                offset += 1 + l;
+            }
+            _ => return Err(DnsError::Malformed),
+        }
+    }
+
+    Ok((labels.join("."), next.unwrap_or(offset)))
+}
+
+fn skip_name(packet: &[u8], start: usize) -> Result<usize, DnsError> {
+    read_name(packet, start).map(|(_, next)| next)
+}
+
+fn read_u16(packet: &[u8], offset: usize) -> Result<u16, DnsError> {
+    let bytes = packet.get(offset..offset + 2).ok_or(DnsError::Truncated)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(packet: &[u8], offset: usize) -> Result<u32, DnsError> {
+    let bytes = packet.get(offset..offset + 4).ok_or(DnsError::Truncated)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pointeur de compression vers le nom de la question.
+    const QUESTION_NAME: [u8; 2] = [0xC0, HEADER_LEN as u8];
+
+    fn header(questions: u16, answers: u16) -> Vec<u8> {
+        let mut packet = vec![0x12, 0x34, 0x81, 0x80];
+        for count in [questions, answers, 0, 0] {
+            packet.extend(count.to_be_bytes());
+        }
+        packet
+    }
+
+    fn name(name: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for label in name.split('.') {
+            bytes.push(label.len() as u8);
+            bytes.extend(label.as_bytes());
+        }
+        bytes.push(0);
+        bytes
+    }
+
+    fn question(packet: &mut Vec<u8>, qname: &str) {
+        packet.extend(name(qname));
+        packet.extend(TYPE_A.to_be_bytes());
+        packet.extend(CLASS_IN.to_be_bytes());
+    }
+
+    fn record(packet: &mut Vec<u8>, owner: &[u8], rtype: u16, class: u16, data: &[u8]) {
+        packet.extend(owner);
+        packet.extend(rtype.to_be_bytes());
+        packet.extend(class.to_be_bytes());
+        packet.extend(300u32.to_be_bytes());
+        packet.extend((data.len() as u16).to_be_bytes());
+        packet.extend(data);
+    }
+
+    fn response(records: &[(u16, u16, &[u8])]) -> Vec<u8> {
+        let mut packet = header(1, records.len() as u16);
+        question(&mut packet, "www.example.com");
+        for &(rtype, class, data) in records {
+            record(&mut packet, &QUESTION_NAME, rtype, class, data);
+        }
+        packet
+    }
+
+    #[test]
+    fn reads_the_question_name() {
+        let mut packet = header(1, 0);
+        question(&mut packet, "WWW.Example.com");
+        assert_eq!(query_name(&packet).unwrap(), "www.example.com");
+    }
+
+    #[test]
+    fn rejects_a_packet_without_question() {
+        assert_eq!(query_name(&header(0, 0)), Err(DnsError::Malformed));
+        assert_eq!(query_name(&[0x12, 0x34, 0x01]), Err(DnsError::Truncated));
+    }
+
+    #[test]
+    fn reads_a_and_aaaa_answers() {
+        let ipv6: Ipv6Addr = "2001:db8::1".parse().unwrap();
+        let packet = response(&[
+            (TYPE_A, CLASS_IN, &[93, 184, 216, 34]),
+            (TYPE_AAAA, CLASS_IN, &ipv6.octets()),
+        ]);
+        assert_eq!(
+            answers(&packet).unwrap(),
+            [
+                DnsAnswer {
+                    addr: IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34)),
+                    ttl: 300,
+                },
+                DnsAnswer {
+                    addr: IpAddr::V6(ipv6),
+                    ttl: 300,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_other_records() {
+        const TYPE_CNAME: u16 = 5;
+        const CLASS_CH: u16 = 3;
+        let mut cname = name("edge");
+        cname.truncate(cname.len() - 1);
+        cname.extend(QUESTION_NAME);
+
+        let packet = response(&[
+            (TYPE_CNAME, CLASS_IN, &cname),
+            (TYPE_A, CLASS_CH, &[10, 0, 0, 1]),
+            (TYPE_A, CLASS_IN, &[10, 0, 0, 2, 0]),
+            (TYPE_AAAA, CLASS_IN, &[10, 0, 0, 3]),
+            (TYPE_A, CLASS_IN, &[10, 0, 0, 4]),
+        ]);
+        let addrs: Vec<_> = answers(&packet).unwrap().iter().map(|a| a.addr).collect();
+        assert_eq!(addrs, [IpAddr::V4(Ipv4Addr::new(10, 0, 0, 4))]);
+    }
+
+    #[test]
+    fn rejects_compression_pointer_loops() {
+        // Pointeur vers lui-même
+        let mut packet = header(1, 0);
+        packet.extend(QUESTION_NAME);
+        assert_eq!(query_name(&packet), Err(DnsError::Malformed));
+
+        // Deux pointeurs qui se renvoient l'un à l'autre, derrière un label
+        let mut packet = header(1, 0);
+        packet.extend([3, b'w', b'w', b'w', 0xC0, 18, 0xC0, 16]);
+        assert_eq!(query_name(&packet), Err(DnsError::Malformed));
+
+        // Même boucle dans le nom d'un enregistrement
+        let mut packet = header(1, 1);
+        question(&mut packet, "example.com");
+        let at = packet.len() as u8;
+        record(&mut packet, &[0xC0, at], TYPE_A, CLASS_IN, &[10, 0, 0, 1]);
+        assert_eq!(answers(&packet), Err(DnsError::Malformed));
+    }
+
+    /// Question dont le nom passe par `jumps` pointeurs successifs.
+    fn pointer_chain(jumps: usize) -> Vec<u8> {
+        let mut packet = header(1, 0);
+        for i in 1..=jumps {
+            let next = HEADER_LEN + 2 * i;
+            packet.extend([0xC0 | (next >> 8) as u8, next as u8]);
+        }
+        packet.extend(name("example.com"));
+        packet
+    }
+
+    #[test]
+    fn follows_pointers_up_to_the_limit() {
+        assert_eq!(
+            query_name(&pointer_chain(MAX_POINTERS)).unwrap(),
+            "example.com"
+        );
+        assert_eq!(
+            query_name(&pointer_chain(MAX_POINTERS + 1)),
+            Err(DnsError::Malformed)
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_records() {
+        let packet = response(&[(TYPE_A, CLASS_IN, &[10, 0, 0, 1])]);
+
+        // Données, champs fixes ou nom de l'enregistrement coupés
+        for len in [packet.len() - 1, packet.len() - 4, packet.len() - 15] {
+            assert_eq!(answers(&packet[..len]), Err(DnsError::Truncated), "{}", len);
+        }
+
+        // Plus de réponses annoncées que présentes
+        let mut missing = packet.clone();
+        missing[7] = 2;
+        assert_eq!(answers(&missing), Err(DnsError::Truncated));
+
+        // Longueur de données au-delà de la fin du paquet
+        let mut overlong = packet.clone();
+        let rdlength = overlong.len() - 6;
+        overlong[rdlength + 1] = 5;
+        assert_eq!(answers(&overlong), Err(DnsError::Truncated));
+
+        // Label ou pointeur coupé dans la question
+        let mut label = header(1, 0);
+        label.extend([7, b'e', b'x']);
+        assert_eq!(query_name(&label), Err(DnsError::Truncated));
+        let mut pointer = header(1, 0);
+        pointer.push(0xC0);
+        assert_eq!(query_name(&pointer), Err(DnsError::Truncated));
+    }
+
+    #[test]
+    fn rejects_reserved_label_types() {
+        let mut packet = header(1, 0);
+        packet.extend([0x40, 0]);
+        assert_eq!(query_name(&packet), Err(DnsError::Malformed));
+    }
+}
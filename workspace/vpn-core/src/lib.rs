@@ -1,4 +1,8 @@
 pub mod api;
 pub mod auth;
+pub mod cidr;
+pub mod country;
+pub mod dns;
+pub mod secret;
 pub mod session;
 pub mod wireguard;
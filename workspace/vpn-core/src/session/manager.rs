@@ -21,6 +21,21 @@ impl Session {
         Ok(Self::from_auth(base_url, auth_resp))
     }
 
+    /// Reprend une session à partir d'un jeton enregistré ; échoue si
+    /// l'API ne l'accepte plus.
+    pub fn resume(base_url: &str, token: &str) -> Result<Self, SessionError> {
+        let client = ApiClient::new(base_url, token);
+        let user = client.profile()?;
+        tracing::info!(email = %user.email, "session resumed");
+        Ok(Self {
+            token: token.to_string(),
+            user,
+            client,
+            current_server: None,
+            config: None,
+        })
+    }
+
     fn from_auth(base_url: &str, auth_resp: AuthResponse) -> Self {
         Self {
             token: auth_resp.token.clone(),
@@ -66,11 +81,12 @@ impl Session {
 
     pub fn connect(&mut self, server_id: u64) -> Result<&WireGuardConfig, SessionError> {
         let conn = self.client.connect(server_id)?;
-        let wg_config = WireGuardConfig::parse(&conn.config)?;
+        let wg_config = WireGuardConfig::parse(conn.config.expose())?;
 
         let servers = self.client.list_servers()?;
         self.current_server = servers.into_iter().find(|s| s.id == server_id);
         self.config = Some(wg_config);
+        tracing::info!(server_id, peer_ip = %conn.peer_ip, "session connected");
 
         Ok(self.config.as_ref().unwrap())
     }
@@ -84,10 +100,16 @@ impl Session {
         self.client.disconnect(server_id)?;
         self.current_server = None;
         self.config = None;
+        tracing::info!(server_id, "session disconnected");
         Ok(())
     }
 
     pub fn switch_server(&mut self, new_server_id: u64) -> Result<&WireGuardConfig, SessionError> {
+        tracing::info!(
+            from = ?self.current_server.as_ref().map(|s| s.id),
+            to = new_server_id,
+            "switching server"
+        );
         if self.is_connected() {
             self.disconnect()?;
         }
@@ -1,4 +1,13 @@
 use super::*;
+use crate::cidr::{self, Cidr};
+use std::net::{IpAddr, SocketAddr};
+
+/// Erreur de champ manquant, journalisée sans le contenu de la config.
+fn missing(field: &str) -> WireGuardError {
+    tracing::warn!(field, "WireGuard config is missing a field");
+    WireGuardError::MissingField(field.into())
+}
+
 impl WireGuardConfig {
     /// Parse une config WireGuard au format INI retournée par l'API.
     pub fn parse(config_str: &str) -> Result<Self, WireGuardError> {
@@ -21,37 +30,38 @@ impl WireGuardConfig {
                 let value = value.trim();
 
                 match key {
-                    "PrivateKey" => private_key = Some(value.to_string()),
+                    "PrivateKey" => private_key = Some(Secret::new(value)),
                     "Address" => address = Some(value.to_string()),
                     "DNS" => dns = Some(value.to_string()),
                     "PublicKey" => peer_public_key = Some(value.to_string()),
                     "Endpoint" => endpoint = Some(value.to_string()),
                     "AllowedIPs" => allowed_ips = Some(value.to_string()),
                     "PersistentKeepalive" => {
-                        persistent_keepalive = value.parse().unwrap_or(25);
+                        persistent_keepalive = value.parse().unwrap_or_else(|_| {
+                            tracing::debug!(value, "invalid PersistentKeepalive, using 25");
+                            25
+                        });
                     }
-                    _ => {}
+                    _ => tracing::debug!(key, "unknown WireGuard config key"),
                 }
             }
         }
 
         Ok(WireGuardConfig {
-            private_key: private_key
-                .ok_or_else(|| WireGuardError::MissingField("PrivateKey".into()))?,
-            address: address.ok_or_else(|| WireGuardError::MissingField("Address".into()))?,
-            dns: dns.ok_or_else(|| WireGuardError::MissingField("DNS".into()))?,
-            peer_public_key: peer_public_key
-                .ok_or_else(|| WireGuardError::MissingField("PublicKey".into()))?,
-            endpoint: endpoint.ok_or_else(|| WireGuardError::MissingField("Endpoint".into()))?,
-            allowed_ips: allowed_ips
-                .ok_or_else(|| WireGuardError::MissingField("AllowedIPs".into()))?,
+            private_key: private_key.ok_or_else(|| missing("PrivateKey"))?,
+            address: address.ok_or_else(|| missing("Address"))?,
+            dns: dns.ok_or_else(|| missing("DNS"))?,
+            peer_public_key: peer_public_key.ok_or_else(|| missing("PublicKey"))?,
+            endpoint: endpoint.ok_or_else(|| missing("Endpoint"))?,
+            allowed_ips: allowed_ips.ok_or_else(|| missing("AllowedIPs"))?,
             persistent_keepalive,
         })
     }
 
-    /// Sérialise la config en format INI WireGuard standard.
-    pub fn to_ini(&self) -> String {
-        format!(
+    /// Sérialise la config en format INI WireGuard standard. Le résultat
+    /// contient la clé privée.
+    pub fn to_ini(&self) -> Secret {
+        Secret::new(format!(
             "[Interface]\n\
              PrivateKey = {}\n\
              Address = {}\n\
@@ -62,13 +72,172 @@ impl WireGuardConfig {
              Endpoint = {}\n\
              AllowedIPs = {}\n\
              PersistentKeepalive = {}\n",
-            self.private_key,
+            self.private_key.expose(),
             self.address,
             self.dns,
             self.peer_public_key,
             self.endpoint,
             self.allowed_ips,
             self.persistent_keepalive,
-        )
+        ))
+    }
+
+    /// Retire des plages de `AllowedIPs` (tunnel fractionné) et remplace la
+    /// liste par les préfixes minimaux restants. L'endpoint du peer est
+    /// toujours exclu pour que le trafic chiffré ne reboucle pas dans le tunnel.
+    pub fn exclude_routes(&mut self, excluded: &[Cidr]) -> Result<(), WireGuardError> {
+        let allowed = self.allowed_cidrs()?;
+
+        let mut excluded = excluded.to_vec();
+        if let Some(ip) = self.endpoint_ip() {
+            excluded.push(Cidr::host(ip));
+        }
+
+        self.allowed_ips = cidr::exclude(&allowed, &excluded)
+            .iter()
+            .map(Cidr::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        Ok(())
+    }
+
+    /// Parse la liste `AllowedIPs` séparée par des virgules.
+    pub fn allowed_cidrs(&self) -> Result<Vec<Cidr>, WireGuardError> {
+        self.allowed_ips
+            .split(',')
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.parse().map_err(WireGuardError::from))
+            .collect()
+    }
+
+    /// Parse la liste `Address` de l'interface (IPv4 et/ou IPv6).
+    pub fn addresses(&self) -> Result<Vec<Cidr>, WireGuardError> {
+        self.address
+            .split(',')
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.parse().map_err(WireGuardError::from))
+            .collect()
+    }
+
+    /// Adresses de l'interface avec leur préfixe, sans normalisation
+    /// (`10.8.0.2/24` reste `10.8.0.2`, préfixe 24).
+    pub fn interface_addresses(&self) -> Result<Vec<(IpAddr, u8)>, WireGuardError> {
+        self.addresses()?
+            .iter()
+            .zip(self.address.split(',').filter(|s| !s.trim().is_empty()))
+            .map(|(cidr, raw)| {
+                let host = raw.trim().split('/').next().unwrap_or_default();
+                let addr = host.parse().map_err(|_| WireGuardError::InvalidFormat)?;
+                Ok((addr, cidr.prefix()))
+            })
+            .collect()
+    }
+
+    /// Le serveur fournit IPv6 : adresse IPv6 sur l'interface et au moins
+    /// une route IPv6 dans `AllowedIPs`.
+    pub fn has_ipv6(&self) -> bool {
+        let has_address = self
+            .addresses()
+            .map(|a| a.iter().any(Cidr::is_ipv6))
+            .unwrap_or(false);
+        let has_route = self
+            .allowed_cidrs()
+            .map(|a| a.iter().any(Cidr::is_ipv6))
+            .unwrap_or(false);
+        has_address && has_route
+    }
+
+    /// Sans IPv6 côté serveur, ajoute `::/0` à `AllowedIPs` : le trafic IPv6
+    /// part dans le tunnel, où il est abandonné, au lieu de fuir en clair.
+    /// Sans effet si le serveur fournit IPv6.
+    pub fn sink_ipv6(&mut self) -> Result<(), WireGuardError> {
+        if self.has_ipv6() {
+            return Ok(());
+        }
+        let mut allowed = self.allowed_cidrs()?;
+        allowed.retain(Cidr::is_ipv4);
+        allowed.push("::/0".parse()?);
+        self.allowed_ips = allowed
+            .iter()
+            .map(Cidr::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        Ok(())
+    }
+
+    /// Adresse IP de l'endpoint si elle est littérale (`1.2.3.4:51820`).
+    pub fn endpoint_ip(&self) -> Option<IpAddr> {
+        self.endpoint.parse::<SocketAddr>().ok().map(|a| a.ip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(endpoint: &str, allowed_ips: &str) -> WireGuardConfig {
+        WireGuardConfig::parse(&format!(
+            "[Interface]\n\
+             PrivateKey = key\n\
+             Address = 10.8.0.2/32\n\
+             DNS = 10.8.0.1\n\
+             [Peer]\n\
+             PublicKey = peer\n\
+             Endpoint = {}\n\
+             AllowedIPs = {}\n",
+            endpoint, allowed_ips
+        ))
+        .unwrap()
+    }
+
+    fn routed(config: &WireGuardConfig, addr: &str) -> bool {
+        let addr: IpAddr = addr.parse().unwrap();
+        config
+            .allowed_cidrs()
+            .unwrap()
+            .iter()
+            .any(|c| c.contains(&addr))
+    }
+
+    #[test]
+    fn exclude_routes_always_excludes_the_endpoint() {
+        let mut ipv4 = config("1.2.3.4:51820", "0.0.0.0/0, ::/0");
+        ipv4.exclude_routes(&[]).unwrap();
+        assert!(!routed(&ipv4, "1.2.3.4"));
+        assert!(routed(&ipv4, "1.2.3.5"));
+        assert!(routed(&ipv4, "2001:db8::1"));
+
+        let mut ipv6 = config("[2001:db8::1]:51820", "0.0.0.0/0, ::/0");
+        ipv6.exclude_routes(&[]).unwrap();
+        assert!(!routed(&ipv6, "2001:db8::1"));
+        assert!(routed(&ipv6, "2001:db8::2"));
+        assert!(routed(&ipv6, "1.2.3.4"));
+    }
+
+    #[test]
+    fn exclude_routes_removes_local_networks() {
+        let mut config = config("1.2.3.4:51820", "0.0.0.0/0, ::/0");
+        config.exclude_routes(&Cidr::local_networks()).unwrap();
+
+        for local in [
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "fe80::1",
+            "fd00::1",
+        ] {
+            assert!(!routed(&config, local), "{} is routed", local);
+        }
+        for public in ["8.8.8.8", "172.32.0.1", "2606:4700::1111"] {
+            assert!(routed(&config, public), "{} is not routed", public);
+        }
+    }
+
+    #[test]
+    fn exclude_routes_with_a_hostname_endpoint() {
+        // Pas d'IP à exclure tant que l'endpoint n'est pas résolu
+        let mut config = config("vpn.example.com:51820", "0.0.0.0/1, 128.0.0.0/1");
+        config.exclude_routes(&[]).unwrap();
+        assert_eq!(config.allowed_ips, "0.0.0.0/0");
     }
 }
@@ -1,6 +1,7 @@
 pub mod config;
 
-use serde::{Deserialize, Serialize};
+use crate::cidr::CidrError;
+use crate::secret::Secret;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -9,11 +10,13 @@ pub enum WireGuardError {
     MissingField(String),
     #[error("invalid config format")]
     InvalidFormat,
+    #[error("invalid CIDR: {0}")]
+    Cidr(#[from] CidrError),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub struct WireGuardConfig {
-    pub private_key: String,
+    pub private_key: Secret,
     pub address: String,
     pub dns: String,
     pub peer_public_key: String,
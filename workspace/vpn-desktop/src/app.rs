@@ -1,9 +1,39 @@
+use crate::diagnostics::checks::Check;
+use crate::i18n::{tr, Locale, Localize};
+use crate::network::{self, NetworkInfo, NetworkWatcher, TrustKind, TrustRule};
+use crate::notifications::{self, Notification, NotificationKind, Notifications};
+use crate::power::{Pace, PowerSource};
+use crate::preferences::{self, Language, PowerSaving, Preferences, ServerChoice, ThemeMode};
+use crate::server_list::{self, RecentServer, ServerFilter, ServerSort};
+use crate::tray::{Tray, TrayCommand, TrayState};
+use crate::ui::assets::AssetStore;
 use crate::ui::flags::FlagStore;
+use crate::ui::frame_stats::FrameStats;
+use crate::ui::globe::GlobeView;
+use crate::ui::theme::{self, DesktopAppearance, DesktopWatcher, Variant};
+use crate::vpn::apps::{self, AppMode, AppRouter, AppRule, InstalledApp};
+use crate::vpn::domains::{self, DomainRouter};
+use crate::vpn::killswitch::KillSwitch;
+use crate::vpn::monitor::{self, LinkMonitor, RepairStep};
+use crate::vpn::proxy::{ProxyServer, ProxySettings};
+use crate::vpn::recovery::{self, RuntimeState};
+use crate::vpn::stats::TrafficMonitor;
+use crate::vpn::tunnel::{Ipv6Mode, TunnelOptions, DEFAULT_INTERFACE};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::net::IpAddr;
 use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use vpn_core::api::ApiError;
 use vpn_core::api::Server;
-use vpn_core::session::Session;
+use vpn_core::cidr::Cidr;
+use vpn_core::country::Continent;
+use vpn_core::dns::DomainPattern;
+use vpn_core::session::{Session, SessionError};
+use vpn_core::wireguard::{WireGuardConfig, WireGuardError};
+use zeroize::{Zeroize, Zeroizing};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppState {
@@ -12,12 +42,101 @@ pub enum AppState {
     Connected,
 }
 
+/// Contents of `config.json`; its version and migrations are handled by
+/// [`crate::config`].
 #[derive(Default, Serialize, Deserialize)]
-struct AppConfig {
-    api_url: String,
+pub(crate) struct AppConfig {
+    #[serde(default)]
+    pub(crate) preferences: Preferences,
     saved_token: Option<String>,
-    saved_email: Option<String>,
-    last_server_id: Option<u64>,
+    pub(crate) saved_email: Option<String>,
+    pub(crate) last_server_id: Option<u64>,
+    // Pinned at the top of the server lists
+    #[serde(default)]
+    favourite_servers: BTreeSet<u64>,
+    // Newest first
+    #[serde(default)]
+    recent_servers: Vec<RecentServer>,
+    // Split tunneling
+    #[serde(default)]
+    bypass_local_network: bool,
+    #[serde(default)]
+    excluded_routes: Vec<String>,
+    #[serde(default)]
+    app_rules: Vec<AppRule>,
+    // Domain patterns routed through each server, by server id
+    #[serde(default)]
+    domain_rules: HashMap<u64, Vec<String>>,
+    // Userspace tunnel exposed as a local proxy, no root required
+    #[serde(default)]
+    pub(crate) proxy: ProxySettings,
+}
+
+impl AppConfig {
+    /// Name of the tunnel interface.
+    pub(crate) fn interface_name(&self) -> &str {
+        self.preferences
+            .interface_name
+            .as_deref()
+            .unwrap_or(DEFAULT_INTERFACE)
+    }
+}
+
+/// WireGuard drops a session this long after its handshake; with a keepalive
+/// a new handshake always comes earlier.
+const HANDSHAKE_LOST_AFTER: Duration = Duration::from_secs(180);
+
+/// What the connection is doing, translated when shown so that a language
+/// change applies at once.
+#[derive(Debug, Clone, PartialEq)]
+enum ConnectionStatus {
+    Disconnected,
+    Connecting(String),
+    Switching(String),
+    Connected(String),
+    ConnectedProxy(String),
+    SearchingFastest,
+    NoServer,
+    // Refused before anything was set up
+    Error,
+    // The tunnel or the proxy did not start
+    Failed,
+}
+
+impl ConnectionStatus {
+    fn label(&self) -> String {
+        match self {
+            ConnectionStatus::Disconnected => tr!("status.disconnected").to_string(),
+            ConnectionStatus::Connecting(server) => tr!("status.connecting", server = server),
+            ConnectionStatus::Switching(server) => tr!("status.switching", server = server),
+            ConnectionStatus::Connected(server) => tr!("status.connected", server = server),
+            ConnectionStatus::ConnectedProxy(server) => {
+                tr!("status.connected_proxy", server = server)
+            }
+            ConnectionStatus::SearchingFastest => tr!("status.searching_fastest").to_string(),
+            ConnectionStatus::NoServer => tr!("status.no_server").to_string(),
+            ConnectionStatus::Error => tr!("status.error").to_string(),
+            ConnectionStatus::Failed => tr!("status.failed").to_string(),
+        }
+    }
+}
+
+/// Result of a background thread, `None` until it is done.
+type Pending<T> = Arc<Mutex<Option<T>>>;
+
+/// Domain routing to start once the tunnel is up.
+struct DomainRouting {
+    patterns: Vec<DomainPattern>,
+    tunnel_dns: Vec<IpAddr>,
+    upstream: Vec<IpAddr>,
+}
+
+/// Config handed to the tunnel backend, with what to start once it is up.
+struct PreparedTunnel {
+    config: WireGuardConfig,
+    options: TunnelOptions,
+    ipv6: Ipv6Mode,
+    domain_routing: Option<DomainRouting>,
 }
 
 pub struct VpnApp {
@@ -31,11 +150,28 @@ pub struct VpnApp {
     show_register: bool,
     error_message: Option<String>,
     servers: Vec<Server>,
-    selected_server: Option<usize>,
+    // By id: the list is filtered and sorted on screen
+    selected_server: Option<u64>,
+    // Search box and filters of the server list
+    server_filter: ServerFilter,
+    // Continent groups folded in the server list, `None` for "Others"
+    collapsed_continents: HashSet<Option<Continent>>,
+    // Round trips measured when the list is loaded
+    latencies: HashMap<u64, Duration>,
+    latency_probe: Option<Pending<Vec<(u64, Duration)>>>,
     is_connecting: bool,
-    connection_status: String,
+    connection_status: ConnectionStatus,
     showing_profile: bool,
+    showing_settings: bool,
+    showing_logs: bool,
+    showing_doctor: bool,
     pub flag_store: FlagStore,
+    pub assets: AssetStore,
+    pub globe: GlobeView,
+    // Start of the globe's arc, from the time zone
+    home_location: Option<(f32, f32)>,
+    // Globe instead of cards in the server list
+    show_globe: bool,
     // Profile editing
     profile_editing: bool,
     profile_username: String,
@@ -43,34 +179,96 @@ pub struct VpnApp {
     profile_password: String,
     profile_error: Option<String>,
     profile_success: Option<String>,
+    // Settings editing
+    new_excluded_route: String,
+    new_app_path: String,
+    installed_apps: Vec<InstalledApp>,
+    domain_rule_server: Option<u64>,
+    new_domain_rule: String,
+    new_interface_name: String,
+    pref_api_url: String,
+    pref_dns: String,
+    pref_mtu: String,
+    pref_keepalive: String,
+    settings_error: Option<String>,
+    // Log viewer
+    log_level: tracing::Level,
+    // Outcome of the last diagnostics export: where it went, or the error
+    diagnostics_status: Option<Result<String, String>>,
+    // Prerequisite checks, `None` while they run
+    doctor_checks: Arc<Mutex<Option<Vec<Check>>>>,
     // Public IP (fetched async after connection)
     public_ip: Arc<Mutex<Option<String>>>,
+    // How IPv6 is handled by the active tunnel
+    ipv6_mode: Option<Ipv6Mode>,
+    // Traffic counters of the active tunnel
+    traffic: Option<TrafficMonitor>,
+    // Per-application routing while connected
+    app_router: Option<AppRouter>,
+    // Domain-based routing while connected
+    domain_router: Option<DomainRouter>,
+    // Blocks traffic outside the kernel tunnel while connected
+    kill_switch: Option<KillSwitch>,
+    // Local proxy while connected in proxy mode
+    proxy: Option<Arc<ProxyServer>>,
+    // Interface of the active kernel tunnel
+    tunnel_interface: Option<String>,
+    // Config applied to it, to refresh it after a network change
+    tunnel_config: Option<(WireGuardConfig, TunnelOptions)>,
+    // Network changes and sleep, which can silently break the kernel tunnel
+    link_monitor: Option<LinkMonitor>,
+    // Steps of the last tunnel repair, and their source while it runs
+    repair_steps: Vec<RepairStep>,
+    repair_updates: Option<Receiver<RepairStep>>,
+    // Sessions of previous runs that were not shut down
+    leftovers: Vec<RuntimeState>,
+    // Leftover to reconnect to once logged in
+    resume: Option<RuntimeState>,
+    // Saved session being resumed at launch, `None` inside while it runs
+    resuming_session: Option<Pending<Result<Session, SessionError>>>,
+    // Current network, while connecting on untrusted networks is enabled
+    network_watcher: Option<NetworkWatcher>,
+    network: Option<NetworkInfo>,
+    new_trust_kind: TrustKind,
+    new_trust_value: String,
+    // Fastest server being probed, and whether for an untrusted network
+    fastest_probe: Option<(Pending<Option<u64>>, bool)>,
+    // The tunnel was brought up by an untrusted network: a trusted one
+    // takes it down
+    auto_connected: bool,
+    // Desktop notifications of connection events
+    notifications: Option<Notifications>,
+    // Reported once until a handshake comes back
+    handshake_lost: bool,
+    // Icon and quick connect menu in the system tray
+    tray: Option<Tray>,
+    // Closed to the tray: hidden, the tunnel stays up
+    window_hidden: bool,
+    // Quit from the tray: the close request must go through
+    quitting: bool,
+    // Desktop color scheme, followed by the "system" theme
+    desktop_watcher: Option<DesktopWatcher>,
+    desktop_appearance: DesktopAppearance,
+    // Redraw rate of the animations, chosen each frame
+    pace: Pace,
+    power_source: PowerSource,
+    frame_stats: FrameStats,
+    // Wakes the UI from background threads
+    ctx: egui::Context,
 }
 
 impl VpnApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        // Configure dark visuals matching mobile palette
-        let mut visuals = egui::Visuals::dark();
-        visuals.panel_fill = egui::Color32::from_rgb(0, 0, 0);
-        visuals.window_fill = egui::Color32::from_rgb(0, 0, 0);
-        visuals.extreme_bg_color = egui::Color32::from_rgb(0, 0, 0);
-        visuals.faint_bg_color = egui::Color32::from_rgb(26, 26, 26);
-        visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgb(0, 0, 0);
-        visuals.widgets.inactive.bg_fill = egui::Color32::from_rgb(26, 26, 26);
-        visuals.widgets.inactive.bg_stroke =
-            egui::Stroke::new(1.0, egui::Color32::from_rgb(48, 60, 61));
-        visuals.widgets.active.bg_fill = egui::Color32::from_rgb(26, 26, 26);
-        visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(26, 26, 26);
-        visuals.selection.bg_fill = egui::Color32::from_rgb(75, 107, 251);
-        cc.egui_ctx.set_visuals(visuals);
-
-        let mut flag_store = FlagStore::new();
-        flag_store.load(&cc.egui_ctx);
+        let flag_store = FlagStore::new();
 
         let config_path = Self::get_config_path();
-        let config = Self::load_config(&config_path);
+        let crate::config::Loaded { config, problem } =
+            crate::config::load::<AppConfig>(&config_path);
+        // Not when the file was just moved aside for being unreadable
+        let first_run = !config_path.exists() && problem.is_none();
+        crate::i18n::set_locale(Locale::from_preference(config.preferences.language));
 
-        Self {
+        let mut app = Self {
             state: AppState::Login,
             config_path,
             session: None,
@@ -81,19 +279,105 @@ impl VpnApp {
             error_message: None,
             servers: Vec::new(),
             selected_server: None,
+            server_filter: ServerFilter::default(),
+            collapsed_continents: HashSet::new(),
+            latencies: HashMap::new(),
+            latency_probe: None,
             is_connecting: false,
-            connection_status: "Déconnecté".to_string(),
+            connection_status: ConnectionStatus::Disconnected,
             showing_profile: false,
+            showing_settings: false,
+            showing_logs: false,
+            showing_doctor: false,
             flag_store,
+            assets: AssetStore::new(),
+            globe: GlobeView::default(),
+            home_location: crate::location::estimate(),
+            show_globe: false,
             profile_editing: false,
             profile_username: String::new(),
             profile_email: String::new(),
             profile_password: String::new(),
             profile_error: None,
             profile_success: None,
+            new_excluded_route: String::new(),
+            new_app_path: String::new(),
+            installed_apps: Vec::new(),
+            domain_rule_server: None,
+            new_domain_rule: String::new(),
+            new_interface_name: config.interface_name().to_string(),
+            pref_api_url: String::new(),
+            pref_dns: String::new(),
+            pref_mtu: String::new(),
+            pref_keepalive: String::new(),
+            settings_error: None,
+            log_level: tracing::Level::INFO,
+            diagnostics_status: None,
+            doctor_checks: Arc::new(Mutex::new(None)),
             public_ip: Arc::new(Mutex::new(None)),
+            ipv6_mode: None,
+            traffic: None,
+            app_router: None,
+            domain_router: None,
+            kill_switch: None,
+            proxy: None,
+            tunnel_interface: None,
+            tunnel_config: None,
+            link_monitor: None,
+            repair_steps: Vec::new(),
+            repair_updates: None,
+            leftovers: recovery::leftovers(),
+            resume: None,
+            resuming_session: None,
+            network_watcher: None,
+            network: None,
+            new_trust_kind: TrustKind::default(),
+            new_trust_value: String::new(),
+            fastest_probe: None,
+            auto_connected: false,
+            notifications: notifications::system_notifier().map(Notifications::start),
+            handshake_lost: false,
+            tray: None,
+            window_hidden: false,
+            quitting: false,
+            desktop_watcher: None,
+            desktop_appearance: DesktopAppearance::default(),
+            pace: Pace::Full,
+            power_source: PowerSource::default(),
+            frame_stats: FrameStats::default(),
+            ctx: cc.egui_ctx.clone(),
             config,
+        };
+        let ctx = app.ctx.clone();
+        if let Some((appearance, watcher)) = DesktopWatcher::start(move || ctx.request_repaint()) {
+            app.desktop_appearance = appearance;
+            app.desktop_watcher = Some(watcher);
+        }
+        app.apply_theme();
+        app.update_network_watcher();
+        let ctx = app.ctx.clone();
+        app.link_monitor = LinkMonitor::start(move || ctx.request_repaint());
+        let ctx = app.ctx.clone();
+        app.tray = Tray::start(move || ctx.request_repaint());
+        if app.config.preferences.connect_on_launch {
+            app.resume_saved_session();
+        }
+        if first_run {
+            app.show_doctor();
+        }
+        if let Some(problem) = problem {
+            app.show_error(problem);
+        }
+        app
+    }
+
+    /// Saved preferences, for the command line.
+    pub(crate) fn load_saved_config() -> AppConfig {
+        let loaded = crate::config::read(&Self::get_config_path());
+        if let Some(problem) = loaded.problem {
+            tracing::warn!("{}", problem);
         }
+        loaded.config
     }
 
     fn get_config_path() -> PathBuf {
@@ -104,19 +388,9 @@ impl VpnApp {
         path
     }
 
-    fn load_config(path: &PathBuf) -> AppConfig {
-        std::fs::read_to_string(path)
-            .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
-            .unwrap_or_else(|| AppConfig {
-                api_url: "http://localhost:8080".to_string(),
-                ..Default::default()
-            })
-    }
-
     fn save_config(&self) {
-        if let Ok(json) = serde_json::to_string_pretty(&self.config) {
-            std::fs::write(&self.config_path, json).ok();
+        if let Err(e) = crate::config::save(&self.config_path, &self.config) {
+            tracing::error!(path = %self.config_path.display(), error = %e, "failed to save the config");
         }
     }
 
@@ -124,8 +398,9 @@ impl VpnApp {
         self.error_message = None;
 
         let email = self.email.clone();
-        let password = self.password.clone();
-        let api_url = self.config.api_url.clone();
+        // Moved out of the field and wiped once used, whatever the outcome
+        let password = Zeroizing::new(std::mem::take(&mut self.password));
+        let api_url = self.config.preferences.api_url.clone();
 
         match Session::login(&api_url, &email, &password) {
             Ok(session) => {
@@ -133,13 +408,10 @@ impl VpnApp {
                 self.config.saved_token = Some(session.token().to_string());
                 self.save_config();
 
-                self.session = Some(session);
-                self.password.clear();
-                self.load_servers();
-                self.state = AppState::ServerList;
+                self.start_session(session);
             }
             Err(e) => {
-                self.error_message = Some(format!("Erreur de connexion: {}", e));
+                self.show_error(tr!("error.connect", error = e.localized()));
             }
         }
     }
@@ -149,8 +421,8 @@ impl VpnApp {
 
         let username = self.username.clone();
         let email = self.email.clone();
-        let password = self.password.clone();
-        let api_url = self.config.api_url.clone();
+        let password = Zeroizing::new(std::mem::take(&mut self.password));
+        let api_url = self.config.preferences.api_url.clone();
 
         match Session::register(&api_url, &username, &email, &password) {
             Ok(session) => {
@@ -158,16 +430,83 @@ impl VpnApp {
                 self.config.saved_token = Some(session.token().to_string());
                 self.save_config();
 
-                self.session = Some(session);
-                self.password.clear();
                 self.username.clear();
                 self.show_register = false;
-                self.load_servers();
-                self.state = AppState::ServerList;
+                self.start_session(session);
             }
             Err(e) => {
-                self.error_message = Some(format!("Erreur d'inscription: {}", e));
+                self.show_error(tr!("error.register", error = e.localized()));
+            }
+        }
+    }
+
+    /// Shows the server list once logged in, then connects if a leftover
+    /// session or the auto-connect preferences ask for it.
+    fn start_session(&mut self, session: Session) {
+        self.session = Some(session);
+        self.load_servers();
+        self.state = AppState::ServerList;
+        self.resume_session();
+        let choice = self.config.preferences.auto_connect_server;
+        if self.config.preferences.connect_on_launch {
+            self.auto_connect(choice, false);
+        } else if self.is_untrusted_network() {
+            self.auto_connect(choice, true);
+        }
+    }
+
+    /// Logs in with the saved token in the background; the login screen
+    /// stays if the API refuses it or cannot be reached.
+    fn resume_saved_session(&mut self) {
+        let token = match &self.config.saved_token {
+            Some(t) => t.clone(),
+            None => return,
+        };
+        let api_url = self.config.preferences.api_url.clone();
+        let result = Arc::new(Mutex::new(None));
+        let holder = Arc::clone(&result);
+        let ctx = self.ctx.clone();
+        std::thread::spawn(move || {
+            let session = Session::resume(&api_url, &token);
+            if let Ok(mut lock) = holder.lock() {
+                *lock = Some(session);
+            }
+            ctx.request_repaint();
+        });
+        self.resuming_session = Some(result);
+    }
+
+    pub fn is_resuming_session(&self) -> bool {
+        self.resuming_session.is_some()
+    }
+
+    fn poll_resumed_session(&mut self) {
+        let result = match &self.resuming_session {
+            Some(r) => match r.lock().ok().and_then(|mut l| l.take()) {
+                Some(result) => result,
+                None => return,
+            },
+            None => return,
+        };
+        self.resuming_session = None;
+        // Logged in by hand in the meantime
+        if self.state != AppState::Login {
+            return;
+        }
+        match result {
+            Ok(session) => self.start_session(session),
+            Err(SessionError::Api(ApiError::Unauthorized(e))) => {
+                tracing::info!(error = %e, "saved session refused");
+                self.notify(Notification::TokenExpired);
+                self.config.saved_token = None;
+                self.save_config();
+            }
+            Err(SessionError::Api(ApiError::Api(e))) => {
+                tracing::info!(error = %e, "saved session refused");
+                self.config.saved_token = None;
+                self.save_config();
             }
+            Err(e) => tracing::info!(error = %e, "cannot resume the saved session"),
         }
     }
 
@@ -176,71 +515,144 @@ impl VpnApp {
             match session.list_servers() {
                 Ok(servers) => {
                     self.servers = servers;
-                    if let Some(last_id) = self.config.last_server_id {
-                        self.selected_server = self.servers.iter().position(|s| s.id == last_id);
-                    }
+                    self.selected_server = self
+                        .config
+                        .last_server_id
+                        .filter(|&id| self.servers.iter().any(|s| s.id == id));
+                    self.refresh_latencies();
                 }
                 Err(e) => {
-                    self.error_message = Some(format!("Erreur de chargement des serveurs: {}", e));
+                    self.show_error(tr!("error.load_servers", error = e.localized()));
                 }
             }
         }
     }
 
     pub fn handle_connect(&mut self) {
-        let idx = match self.selected_server {
-            Some(i) => i,
+        let (server_id, server_name) = match self.get_selected_server() {
+            Some(server) => (server.id, server.name.clone()),
             None => return,
         };
 
-        let server_id = self.servers[idx].id;
-        let server_name = self.servers[idx].name.clone();
-
         self.is_connecting = true;
-        self.connection_status = format!("Connexion à {}...", server_name);
+        self.connection_status = ConnectionStatus::Connecting(server_name.clone());
+
+        let interface = self.interface_name().to_string();
+        if !self.config.proxy.enabled {
+            if let Some(conflict) = crate::vpn::tunnel::interface_conflict(&interface) {
+                self.show_error(conflict);
+                self.is_connecting = false;
+                self.connection_status = ConnectionStatus::Error;
+                return;
+            }
+        }
 
         let wg_config = match &mut self.session {
             Some(s) => match s.connect(server_id) {
                 Ok(cfg) => cfg.clone(),
                 Err(e) => {
-                    self.error_message = Some(format!("Erreur de connexion: {}", e));
                     self.is_connecting = false;
-                    self.connection_status = "Erreur".to_string();
+                    self.connection_status = ConnectionStatus::Error;
+                    self.check_session_expired(&e);
+                    self.show_error(tr!("error.connect", error = e.localized()));
                     return;
                 }
             },
             None => return,
         };
 
+        if self.config.proxy.enabled {
+            self.config.last_server_id = Some(server_id);
+            self.save_config();
+            match self.start_proxy(&wg_config) {
+                Ok(()) => {
+                    self.state = AppState::Connected;
+                    self.connection_status = ConnectionStatus::ConnectedProxy(server_name.clone());
+                    self.is_connecting = false;
+                    self.record_session(server_id, &server_name);
+                    self.remember_server(server_id, &server_name);
+                    self.notify(Notification::Connected {
+                        server: server_name.clone(),
+                    });
+                    self.fetch_public_ip();
+                }
+                Err(e) => {
+                    self.show_error(tr!("error.proxy", error = format!("{:#}", e)));
+                    self.is_connecting = false;
+                    self.connection_status = ConnectionStatus::Failed;
+                }
+            }
+            return;
+        }
+
+        let tunnel = match self.prepare_tunnel(server_id, &wg_config) {
+            Ok(t) => t,
+            Err(e) => {
+                self.show_error(tr!("error.tunnel_config", error = e.localized()));
+                self.is_connecting = false;
+                self.connection_status = ConnectionStatus::Error;
+                return;
+            }
+        };
+
         self.config.last_server_id = Some(server_id);
         self.save_config();
 
-        match crate::vpn::tunnel::apply_config(&wg_config) {
+        match crate::vpn::tunnel::apply_config(&tunnel.config, &tunnel.options, &interface) {
             Ok(_) => {
                 self.state = AppState::Connected;
-                self.connection_status = format!("Connecté à {}", server_name);
+                self.connection_status = ConnectionStatus::Connected(server_name.clone());
                 self.is_connecting = false;
+                self.tunnel_interface = Some(interface);
+                self.tunnel_config = Some((tunnel.config, tunnel.options));
+                self.repair_steps.clear();
+                self.record_session(server_id, &server_name);
+                self.remember_server(server_id, &server_name);
+                self.notify(Notification::Connected {
+                    server: server_name.clone(),
+                });
+                self.ipv6_mode = Some(tunnel.ipv6);
                 self.fetch_public_ip();
+                self.start_traffic_monitor();
+                self.start_app_router();
+                self.start_domain_router(tunnel.domain_routing);
+                self.start_kill_switch();
             }
             Err(e) => {
-                self.error_message = Some(format!("Erreur tunnel: {}", e));
+                self.show_error(tr!("error.tunnel", error = e));
                 self.is_connecting = false;
-                self.connection_status = "Erreur de connexion".to_string();
+                self.connection_status = ConnectionStatus::Failed;
             }
         }
     }
 
     pub fn handle_disconnect(&mut self) {
+        // Remove the DNS redirection before the tunnel goes away
+        self.domain_router = None;
+
         // Stop the tunnel and update UI immediately
-        if let Err(e) = crate::vpn::tunnel::stop_tunnel() {
-            eprintln!("Erreur lors de l'arrêt du tunnel: {}", e);
+        self.proxy = None;
+        self.tunnel_config = None;
+        self.repair_steps.clear();
+        if let Some(interface) = self.tunnel_interface.take() {
+            if let Err(e) = crate::vpn::tunnel::stop_tunnel(&interface) {
+                tracing::error!(interface, error = %e, "failed to stop the tunnel");
+            }
         }
+        // Lets the API call below out
+        self.kill_switch = None;
 
         self.state = AppState::ServerList;
-        self.connection_status = "Déconnecté".to_string();
+        self.connection_status = ConnectionStatus::Disconnected;
+        self.auto_connected = false;
+        self.handshake_lost = false;
+        self.notify(Notification::Disconnected);
         if let Ok(mut ip) = self.public_ip.lock() {
             *ip = None;
         }
+        self.ipv6_mode = None;
+        self.traffic = None;
+        self.app_router = None;
 
         // Notify the API in a background thread (non-blocking)
         if let Some(session) = &mut self.session {
@@ -251,204 +663,1468 @@ impl VpnApp {
                 std::thread::spawn(move || {
                     let client = vpn_core::api::ApiClient::new(&base_url, &token);
                     if let Err(e) = client.disconnect(server_id) {
-                        eprintln!("Erreur API lors de la déconnexion: {}", e);
+                        tracing::warn!(server_id, error = %e, "failed to unregister the peer");
                     }
                 });
             }
             session.clear_connection();
         }
+        recovery::end();
     }
 
-    fn fetch_public_ip(&self) {
-        let ip_holder = Arc::clone(&self.public_ip);
-        std::thread::spawn(move || {
-            let result = reqwest::blocking::Client::builder()
-                .timeout(std::time::Duration::from_secs(5))
-                .build()
-                .and_then(|c| c.get("https://api.ipify.org").send())
-                .and_then(|r| r.text());
-            if let Ok(ip) = result {
-                if let Ok(mut lock) = ip_holder.lock() {
-                    *lock = Some(ip.trim().to_string());
-                }
-            }
-        });
+    /// Records the running session for the crash handlers and next launch.
+    fn record_session(&self, server_id: u64, server_name: &str) {
+        if let Some(session) = &self.session {
+            recovery::begin(
+                RuntimeState::new(
+                    self.tunnel_interface.as_deref(),
+                    server_id,
+                    server_name,
+                    session.api_base_url(),
+                ),
+                session.token(),
+            );
+        }
     }
 
-    pub fn get_public_ip(&self) -> Option<String> {
-        self.public_ip.lock().ok().and_then(|ip| ip.clone())
+    /// Last server, and first of the recent ones.
+    fn remember_server(&mut self, server_id: u64, server_name: &str) {
+        self.config.last_server_id = Some(server_id);
+        server_list::push_recent(&mut self.config.recent_servers, server_id, server_name);
+        self.save_config();
     }
 
-    pub fn handle_switch_server(&mut self, new_idx: usize) {
-        let server_id = self.servers[new_idx].id;
-        let server_name = self.servers[new_idx].name.clone();
-
-        self.connection_status = format!("Changement vers {}...", server_name);
-
-        let session = match &mut self.session {
-            Some(s) => s,
-            None => return,
-        };
+    /// Session of a previous run that was not shut down, if any.
+    pub fn get_leftover(&self) -> Option<&RuntimeState> {
+        self.leftovers.first()
+    }
 
-        match session.switch_server(server_id) {
-            Ok(wg_config) => {
-                let _ = crate::vpn::tunnel::stop_tunnel();
+    /// Removes the leftover and reconnects to its server, right away or
+    /// after login.
+    pub fn resume_leftover(&mut self) {
+        if self.leftovers.is_empty() {
+            return;
+        }
+        let leftover = self.leftovers.remove(0);
+        if let Err(e) = recovery::cleanup_local(&leftover) {
+            self.show_error(tr!("error.cleanup", error = format!("{:#}", e)));
+            self.leftovers.insert(0, leftover);
+            return;
+        }
+        self.resume = Some(leftover);
+        if self.session.is_some() {
+            self.resume_session();
+        }
+    }
 
-                match crate::vpn::tunnel::apply_config(wg_config) {
-                    Ok(_) => {
-                        self.config.last_server_id = Some(server_id);
-                        self.save_config();
-                        self.selected_server = Some(new_idx);
-                        self.connection_status = format!("Connecté à {}", server_name);
-                        self.fetch_public_ip();
-                    }
-                    Err(e) => {
-                        self.error_message = Some(format!("Erreur de switch: {}", e));
-                    }
-                }
-            }
+    /// Removes the leftover interface and unregisters its peer.
+    pub fn clean_up_leftover(&mut self) {
+        if self.leftovers.is_empty() {
+            return;
+        }
+        let leftover = self.leftovers.remove(0);
+        let token = self
+            .session
+            .as_ref()
+            .map(|s| s.token().to_string())
+            .or_else(|| self.config.saved_token.clone());
+        let result = recovery::cleanup_local(&leftover).and_then(|()| match &token {
+            Some(token) => recovery::cleanup_backend(&leftover, token),
+            None => Err(anyhow::anyhow!(tr!("recovery.login_required"))),
+        });
+        match result {
+            Ok(()) => recovery::discard(&leftover),
             Err(e) => {
-                self.error_message = Some(format!("Erreur de switch: {}", e));
+                self.show_error(tr!("error.cleanup", error = format!("{:#}", e)));
+                self.leftovers.insert(0, leftover);
             }
         }
     }
 
-    pub fn handle_logout(&mut self) {
-        if self.state == AppState::Connected {
-            self.handle_disconnect();
+    /// Second half of [`Self::resume_leftover`], once a session exists: the
+    /// stale peer must go before the backend accepts a new connection.
+    fn resume_session(&mut self) {
+        let leftover = match self.resume.take() {
+            Some(l) => l,
+            None => return,
+        };
+        if let Some(session) = &self.session {
+            if let Err(e) = recovery::cleanup_backend(&leftover, session.token()) {
+                self.show_error(tr!("error.cleanup", error = format!("{:#}", e)));
+                self.leftovers.insert(0, leftover);
+                return;
+            }
         }
-
-        self.session = None;
-        self.servers.clear();
-        self.selected_server = None;
-        self.showing_profile = false;
-        self.state = AppState::Login;
-        self.email.clear();
-        self.password.clear();
-
-        if let Some(saved_email) = &self.config.saved_email {
-            self.email = saved_email.clone();
+        recovery::discard(&leftover);
+        if self.state != AppState::ServerList {
+            return;
+        }
+        if self.servers.iter().any(|s| s.id == leftover.server_id) {
+            self.selected_server = Some(leftover.server_id);
+            self.handle_connect();
         }
     }
 
-    pub fn handle_delete_account(&mut self) {
-        if let Some(session) = &mut self.session {
-            if let Err(e) = session.delete_account() {
-                self.profile_error = Some(format!("Erreur: {}", e));
-                return;
+    /// Connects without going through the server list, unless something (a
+    /// resumed session) already did. `untrusted` marks the connection to be
+    /// dropped on a trusted network.
+    fn auto_connect(&mut self, choice: ServerChoice, untrusted: bool) {
+        if self.state != AppState::ServerList || self.servers.is_empty() {
+            return;
+        }
+        let last = self
+            .config
+            .last_server_id
+            .filter(|&id| self.servers.iter().any(|s| s.id == id));
+        match (choice, last) {
+            (ServerChoice::Last, Some(id)) => self.connect_automatically(id, untrusted),
+            _ => {
+                if self.fastest_probe.is_some() {
+                    return;
+                }
+                let servers = self.servers.clone();
+                let result = Arc::new(Mutex::new(None));
+                let holder = Arc::clone(&result);
+                let ctx = self.ctx.clone();
+                std::thread::spawn(move || {
+                    let fastest = network::latency::fastest(&servers);
+                    if let Ok(mut lock) = holder.lock() {
+                        *lock = Some(fastest);
+                    }
+                    ctx.request_repaint();
+                });
+                self.connection_status = ConnectionStatus::SearchingFastest;
+                self.fastest_probe = Some((result, untrusted));
             }
         }
-        self.handle_logout();
     }
 
-    pub fn handle_update_profile(&mut self) {
-        self.profile_error = None;
-        self.profile_success = None;
-
-        let username = self.profile_username.trim().to_string();
-        let email = self.profile_email.trim().to_string();
-        let password = self.profile_password.clone();
-
-        if username.len() < 3 {
-            self.profile_error = Some("Le nom doit contenir au moins 3 caracteres.".into());
+    fn poll_fastest_probe(&mut self) {
+        let (fastest, untrusted) = match &self.fastest_probe {
+            Some((r, untrusted)) => match r.lock().ok().and_then(|mut l| l.take()) {
+                Some(fastest) => (fastest, *untrusted),
+                None => return,
+            },
+            None => return,
+        };
+        self.fastest_probe = None;
+        if self.state != AppState::ServerList {
             return;
         }
-        if !email.contains('@') {
-            self.profile_error = Some("Email invalide.".into());
-            return;
+        // Nothing answered: the first active server is as good as any
+        let id = fastest
+            .filter(|&id| self.servers.iter().any(|s| s.id == id))
+            .or_else(|| self.servers.iter().find(|s| s.is_active).map(|s| s.id));
+        match id {
+            Some(id) => self.connect_automatically(id, untrusted),
+            None => self.connection_status = ConnectionStatus::NoServer,
         }
-        if password.len() < 8 {
-            self.profile_error =
-                Some("Le mot de passe doit contenir au moins 8 caracteres.".into());
+    }
+
+    /// Measures every active server in the background, for sorting by
+    /// latency and showing it on the cards.
+    pub fn refresh_latencies(&mut self) {
+        if self.latency_probe.is_some() || self.servers.is_empty() {
             return;
         }
-
-        match &mut self.session {
-            Some(session) => match session.update_profile(&username, &email, &password) {
-                Ok(()) => {
-                    self.profile_password.clear();
-                    self.profile_editing = false;
-                    self.profile_success = Some("Profil mis a jour.".into());
-                }
-                Err(e) => {
-                    self.profile_error = Some(format!("Erreur: {}", e));
-                }
-            },
-            None => {
-                self.profile_error = Some("Session expirée.".into());
+        let servers = self.servers.clone();
+        let result = Arc::new(Mutex::new(None));
+        let holder = Arc::clone(&result);
+        let ctx = self.ctx.clone();
+        std::thread::spawn(move || {
+            let latencies = network::latency::probe_all(&servers);
+            if let Ok(mut lock) = holder.lock() {
+                *lock = Some(latencies);
             }
-        }
+            ctx.request_repaint();
+        });
+        self.latency_probe = Some(result);
     }
 
-    pub fn show_profile(&mut self) {
-        self.showing_profile = true;
-        self.profile_editing = false;
-        self.profile_error = None;
-        self.profile_success = None;
+    fn poll_latency_probe(&mut self) {
+        let latencies = match &self.latency_probe {
+            Some(r) => match r.lock().ok().and_then(|mut l| l.take()) {
+                Some(latencies) => latencies,
+                None => return,
+            },
+            None => return,
+        };
+        self.latency_probe = None;
+        self.latencies = latencies.into_iter().collect();
     }
 
-    pub fn hide_profile(&mut self) {
-        self.showing_profile = false;
-        self.profile_editing = false;
-        self.profile_error = None;
-        self.profile_success = None;
+    fn connect_automatically(&mut self, server_id: u64, untrusted: bool) {
+        tracing::info!(server_id, untrusted, "connecting automatically");
+        self.selected_server = Some(server_id);
+        self.handle_connect();
+        self.auto_connected = untrusted && self.state == AppState::Connected;
     }
 
-    pub fn start_profile_edit(&mut self) {
-        if let Some(session) = &self.session {
-            self.profile_username = session.user().username.clone();
-            self.profile_email = session.user().email.clone();
+    /// Runs the network watcher while connecting on untrusted networks is
+    /// enabled.
+    fn update_network_watcher(&mut self) {
+        if !self.config.preferences.connect_on_untrusted {
+            self.network_watcher = None;
+            self.network = None;
+            return;
+        }
+        if self.network_watcher.is_some() {
+            return;
+        }
+        if let Some(source) = network::system_source() {
+            let ctx = self.ctx.clone();
+            self.network_watcher =
+                Some(NetworkWatcher::start(source, move || ctx.request_repaint()));
         }
-        self.profile_password.clear();
-        self.profile_error = None;
-        self.profile_success = None;
-        self.profile_editing = true;
     }
 
-    pub fn cancel_profile_edit(&mut self) {
-        self.profile_editing = false;
-        self.profile_error = None;
-        self.profile_password.clear();
+    fn poll_network(&mut self) {
+        let network = match self.network_watcher.as_ref().and_then(|w| w.poll()) {
+            Some(n) => n,
+            None => return,
+        };
+        // Our own tunnel is not a network the user joined
+        if let (Some(n), Some(tunnel)) = (&network, &self.tunnel_interface) {
+            if &n.interface == tunnel {
+                return;
+            }
+        }
+        self.network = network;
+        let network = match &self.network {
+            Some(n) => n,
+            None => return,
+        };
+        if !network::is_trusted(&self.config.preferences.trusted_networks, network) {
+            if self.session.is_some() {
+                self.auto_connect(self.config.preferences.auto_connect_server, true);
+            }
+        } else if self.state == AppState::Connected && self.auto_connected {
+            tracing::info!(network = %network.label(), "trusted network, disconnecting");
+            self.handle_disconnect();
+        }
     }
 
-    pub fn is_profile_editing(&self) -> bool {
-        self.profile_editing
+    fn is_untrusted_network(&self) -> bool {
+        self.config.preferences.connect_on_untrusted
+            && self
+                .network
+                .as_ref()
+                .is_some_and(|n| !network::is_trusted(&self.config.preferences.trusted_networks, n))
     }
 
-    pub fn get_profile_username(&mut self) -> &mut String {
-        &mut self.profile_username
+    /// Results of the background work that can change the screen.
+    fn poll_background(&mut self) {
+        self.poll_resumed_session();
+        self.poll_network();
+        self.poll_fastest_probe();
+        self.poll_latency_probe();
+        self.poll_link_monitor();
+        self.poll_repair();
+        self.poll_tray();
+        self.poll_appearance();
+        self.check_handshake();
     }
 
-    pub fn get_profile_email(&mut self) -> &mut String {
-        &mut self.profile_email
+    /// Reports a kernel tunnel whose peer stopped answering. Only with a
+    /// keepalive: an idle tunnel without one has no handshake to expect.
+    fn check_handshake(&mut self) {
+        match &self.tunnel_config {
+            Some((config, _)) if config.persistent_keepalive > 0 => {}
+            _ => return,
+        }
+        let lost = self
+            .traffic
+            .as_ref()
+            .and_then(|t| t.snapshot().handshake_age())
+            .is_some_and(|age| age > HANDSHAKE_LOST_AFTER);
+        if lost && !self.handshake_lost {
+            let server = self
+                .session
+                .as_ref()
+                .and_then(|s| s.current_server())
+                .map(|s| s.name.clone())
+                .unwrap_or_default();
+            tracing::warn!(server, "handshake lost");
+            self.notify(Notification::HandshakeLost { server });
+        }
+        self.handshake_lost = lost;
     }
 
-    pub fn get_profile_password(&mut self) -> &mut String {
-        &mut self.profile_password
+    /// Unless muted in the settings.
+    fn notify(&self, notification: Notification) {
+        if let Some(notifications) = &self.notifications {
+            notifications.send(notification, &self.config.preferences.muted_notifications);
+        }
     }
 
-    pub fn get_profile_error(&self) -> Option<&str> {
-        self.profile_error.as_deref()
+    /// A refused token: notifies and forgets it. Back to the login screen
+    /// unless a tunnel is up, which keeps working until disconnected.
+    fn check_session_expired(&mut self, error: &SessionError) -> bool {
+        if !matches!(error, SessionError::Api(ApiError::Unauthorized(_))) {
+            return false;
+        }
+        self.notify(Notification::TokenExpired);
+        if self.state == AppState::Connected {
+            self.config.saved_token = None;
+            self.save_config();
+        } else {
+            self.handle_logout();
+        }
+        true
     }
 
-    pub fn get_profile_success(&self) -> Option<&str> {
-        self.profile_success.as_deref()
+    fn poll_tray(&mut self) {
+        let commands = match &self.tray {
+            Some(tray) => tray.poll(),
+            None => return,
+        };
+        for command in commands {
+            match command {
+                TrayCommand::ConnectLast => self.auto_connect(ServerChoice::Last, false),
+                TrayCommand::ConnectFastest => self.auto_connect(ServerChoice::Fastest, false),
+                TrayCommand::Connect(server_id) => self.connect_to(server_id),
+                TrayCommand::Disconnect if self.state == AppState::Connected => {
+                    self.handle_disconnect()
+                }
+                TrayCommand::Disconnect => {}
+                TrayCommand::ToggleWindow => self.set_window_visible(self.window_hidden),
+                TrayCommand::ShowWindow => self.set_window_visible(true),
+                TrayCommand::Quit => {
+                    self.quitting = true;
+                    self.ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                }
+            }
+        }
+        let state = self.tray_state();
+        if let Some(tray) = &mut self.tray {
+            tray.update(state);
+        }
     }
 
-    pub fn is_vpn_connected(&self) -> bool {
-        self.state == AppState::Connected
+    fn tray_state(&self) -> TrayState {
+        let status = match self.state {
+            AppState::Login => tr!("status.logged_out").to_string(),
+            _ => self.connection_status.label(),
+        };
+        TrayState {
+            connected: self.state == AppState::Connected,
+            status,
+            can_connect: self.state == AppState::ServerList && !self.servers.is_empty(),
+            last_server: self
+                .config
+                .last_server_id
+                .and_then(|id| self.servers.iter().find(|s| s.id == id))
+                .map(|s| s.name.clone()),
+            favourites: self
+                .servers
+                .iter()
+                .filter(|s| self.config.favourite_servers.contains(&s.id))
+                .map(|s| (s.id, s.name.clone()))
+                .collect(),
+        }
     }
 
-    pub fn get_email(&mut self) -> &mut String {
-        &mut self.email
+    /// Hidden windows are also minimized: Wayland compositors ignore the
+    /// visibility request.
+    fn set_window_visible(&mut self, visible: bool) {
+        self.window_hidden = !visible;
+        self.ctx
+            .send_viewport_cmd(egui::ViewportCommand::Visible(visible));
+        self.ctx
+            .send_viewport_cmd(egui::ViewportCommand::Minimized(!visible));
+        if visible {
+            self.ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        }
     }
 
-    pub fn get_password(&mut self) -> &mut String {
-        &mut self.password
+    /// With "close to tray", closing the window only hides it.
+    fn handle_close_request(&mut self, ctx: &egui::Context) {
+        if !ctx.input(|i| i.viewport().close_requested()) || self.quitting {
+            return;
+        }
+        if self.config.preferences.close_to_tray && self.tray.is_some() {
+            tracing::info!("window closed to the tray");
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            self.set_window_visible(false);
+        }
     }
 
-    pub fn get_username(&mut self) -> &mut String {
-        &mut self.username
+    /// Repairs the kernel tunnel after a network change or a resume. Events
+    /// arriving during a repair are covered by it.
+    fn poll_link_monitor(&mut self) {
+        let events = match &self.link_monitor {
+            Some(monitor) => monitor.poll(),
+            None => return,
+        };
+        let event = match events
+            .into_iter()
+            .rfind(|e| *e != monitor::NetworkEvent::Suspending)
+        {
+            Some(e) => e,
+            None => return,
+        };
+        let (config, options) = match &self.tunnel_config {
+            Some(c) if self.state == AppState::Connected && self.repair_updates.is_none() => {
+                c.clone()
+            }
+            _ => return,
+        };
+        let interface = self.active_interface();
+        tracing::info!(interface, event = ?event, "repairing the tunnel");
+        self.notify(Notification::Reconnecting {
+            reason: event.label(),
+        });
+
+        self.repair_steps = vec![RepairStep::Detected(event)];
+        let (sender, updates) = mpsc::channel();
+        self.repair_updates = Some(updates);
+        let ctx = self.ctx.clone();
+        std::thread::spawn(move || {
+            monitor::repair(&config, &options, &interface, |step| {
+                let _ = sender.send(step);
+                ctx.request_repaint();
+            });
+        });
+    }
+
+    /// Shows the repair progress; reconnects to the same server when the
+    /// tunnel could not be brought back.
+    fn poll_repair(&mut self) {
+        let steps: Vec<RepairStep> = match &self.repair_updates {
+            Some(updates) => updates.try_iter().collect(),
+            None => return,
+        };
+        for step in steps {
+            let done = step.is_done();
+            let failed = matches!(step, RepairStep::Failed(_));
+            self.repair_steps.push(step);
+            if !done {
+                continue;
+            }
+            self.repair_updates = None;
+            if failed && self.state == AppState::Connected {
+                let current = self
+                    .session
+                    .as_ref()
+                    .and_then(|s| s.current_server())
+                    .map(|server| (server.id, server.name.clone()));
+                if let Some((id, name)) = current {
+                    self.repair_steps.push(RepairStep::Reconnecting(name));
+                    self.handle_switch_server(id);
+                }
+            }
+            return;
+        }
+    }
+
+    /// Progress of the last tunnel repair while connected.
+    pub fn get_repair_steps(&self) -> &[RepairStep] {
+        &self.repair_steps
+    }
+
+    fn fetch_public_ip(&self) {
+        let ip_holder = Arc::clone(&self.public_ip);
+        // In proxy mode only proxied traffic uses the VPN
+        let proxy_url = self
+            .proxy
+            .as_ref()
+            .map(|p| format!("http://{}", p.http_addr()));
+        std::thread::spawn(move || {
+            let mut builder =
+                reqwest::blocking::Client::builder().timeout(std::time::Duration::from_secs(5));
+            if let Some(url) = proxy_url {
+                match reqwest::Proxy::all(url) {
+                    Ok(proxy) => builder = builder.proxy(proxy),
+                    Err(_) => return,
+                }
+            }
+            let result = builder
+                .build()
+                .and_then(|c| c.get("https://api.ipify.org").send())
+                .and_then(|r| r.text());
+            if let Ok(ip) = result {
+                if let Ok(mut lock) = ip_holder.lock() {
+                    *lock = Some(ip.trim().to_string());
+                }
+            }
+        });
+    }
+
+    /// Builds the config actually applied for a server from the one returned
+    /// by the API: domain routing, IPv6 leak prevention, then split tunneling.
+    fn prepare_tunnel(
+        &self,
+        server_id: u64,
+        config: &WireGuardConfig,
+    ) -> Result<PreparedTunnel, WireGuardError> {
+        let mut config = config.clone();
+        self.apply_tunnel_preferences(&mut config);
+        let mut options = TunnelOptions {
+            mtu: self.config.preferences.mtu,
+            ..Default::default()
+        };
+        let domain_routing = self.domain_routing(server_id, &mut config, &mut options);
+        let ipv6 = crate::vpn::tunnel::protect_ipv6(&mut config, &mut options)?;
+        let config = self.split_tunnel_config(&config)?;
+        Ok(PreparedTunnel {
+            config,
+            options,
+            ipv6,
+            domain_routing,
+        })
+    }
+
+    /// DNS and keepalive overrides from the preferences.
+    fn apply_tunnel_preferences(&self, config: &mut WireGuardConfig) {
+        let preferences = &self.config.preferences;
+        if !preferences.dns_override.is_empty() {
+            config.dns = preferences
+                .dns_override
+                .iter()
+                .map(IpAddr::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+        }
+        if let Some(keepalive) = preferences.keepalive {
+            config.persistent_keepalive = keepalive;
+        }
+    }
+
+    /// Destinations kept out of the tunnel by the split tunneling preferences.
+    fn excluded_routes(&self) -> Result<Vec<Cidr>, WireGuardError> {
+        let mut excluded = Vec::new();
+        if self.config.bypass_local_network {
+            excluded.extend(Cidr::local_networks());
+        }
+        for route in &self.config.excluded_routes {
+            excluded.push(route.parse::<Cidr>()?);
+        }
+        Ok(excluded)
+    }
+
+    /// Applies the split tunneling preferences to the config returned by the API.
+    fn split_tunnel_config(
+        &self,
+        config: &WireGuardConfig,
+    ) -> Result<WireGuardConfig, WireGuardError> {
+        let mut config = config.clone();
+        let excluded = self.excluded_routes()?;
+        if !excluded.is_empty() {
+            config.exclude_routes(&excluded)?;
+        }
+        Ok(config)
+    }
+
+    /// (Re)applies the kill switch to the kernel tunnel, or removes it when
+    /// disabled. Not used with domain rules, which keep most traffic outside.
+    fn start_kill_switch(&mut self) {
+        let interface = match &self.tunnel_interface {
+            Some(i) if self.config.preferences.kill_switch && self.domain_router.is_none() => {
+                i.clone()
+            }
+            _ => {
+                self.kill_switch = None;
+                return;
+            }
+        };
+        let result = self
+            .excluded_routes()
+            .map_err(anyhow::Error::from)
+            .and_then(|allowed| match &self.kill_switch {
+                // Replaced in place, without a window where traffic leaks
+                Some(kill_switch) => kill_switch.update(&interface, &allowed),
+                None => KillSwitch::start(&interface, &allowed).map(|k| {
+                    self.kill_switch = Some(k);
+                }),
+            });
+        if let Err(e) = result {
+            self.show_error(tr!("error.kill_switch", error = format!("{:#}", e)));
+        }
+    }
+
+    /// Starts the userspace tunnel and its local proxies.
+    fn start_proxy(&mut self, config: &WireGuardConfig) -> anyhow::Result<()> {
+        self.proxy = None;
+        let mut config = config.clone();
+        self.apply_tunnel_preferences(&mut config);
+        let config = &config;
+        let proxy = Arc::new(ProxyServer::start(config, &self.config.proxy)?);
+        let source = Arc::downgrade(&proxy);
+        self.traffic = Some(TrafficMonitor::start_with(move || {
+            let proxy = source.upgrade()?;
+            Some((proxy.counters(), proxy.last_handshake()))
+        }));
+        self.proxy = Some(proxy);
+        Ok(())
+    }
+
+    /// Configured tunnel interface name.
+    fn interface_name(&self) -> &str {
+        self.config.interface_name()
+    }
+
+    /// Interface of the running tunnel, or the configured one.
+    fn active_interface(&self) -> String {
+        self.tunnel_interface
+            .clone()
+            .unwrap_or_else(|| self.interface_name().to_string())
+    }
+
+    /// Kernel tunnel interface while connected.
+    pub fn get_tunnel_interface(&self) -> Option<&str> {
+        self.tunnel_interface.as_deref()
+    }
+
+    /// Local SOCKS5 and HTTP proxy addresses in proxy mode.
+    pub fn get_proxy_addrs(&self) -> Option<(std::net::SocketAddr, std::net::SocketAddr)> {
+        self.proxy.as_ref().map(|p| (p.socks_addr(), p.http_addr()))
+    }
+
+    fn start_traffic_monitor(&mut self) {
+        self.traffic = Some(TrafficMonitor::start(&self.active_interface()));
+    }
+
+    /// (Re)starts per-application routing for the current tunnel.
+    fn start_app_router(&mut self) {
+        self.app_router = None;
+        if self.config.app_rules.is_empty() {
+            return;
+        }
+        match AppRouter::start(&self.config.app_rules, &self.active_interface()) {
+            Ok(router) => self.app_router = Some(router),
+            Err(e) => {
+                self.show_error(tr!("error.app_routing", error = e));
+            }
+        }
+    }
+
+    /// With domain rules for the server, only the resolved addresses of the
+    /// matched domains go through the tunnel: the backend installs no routes and
+    /// the system resolver is replaced by the local forwarder.
+    fn domain_routing(
+        &self,
+        server_id: u64,
+        config: &mut WireGuardConfig,
+        options: &mut TunnelOptions,
+    ) -> Option<DomainRouting> {
+        let patterns: Vec<DomainPattern> = self
+            .config
+            .domain_rules
+            .get(&server_id)
+            .map(|rules| rules.iter().filter_map(|r| r.parse().ok()).collect())
+            .unwrap_or_default();
+        if patterns.is_empty() || !domains::is_supported() {
+            return None;
+        }
+
+        // Read before the tunnel rewrites the system DNS configuration
+        let routing = DomainRouting {
+            patterns,
+            tunnel_dns: domains::parse_dns_list(&config.dns),
+            upstream: domains::system_resolvers(),
+        };
+        config.dns = domains::LOCAL_DNS.to_string();
+        options.manual_routes = true;
+        Some(routing)
+    }
+
+    fn start_domain_router(&mut self, routing: Option<DomainRouting>) {
+        self.domain_router = None;
+        let routing = match routing {
+            Some(r) => r,
+            None => return,
+        };
+        match DomainRouter::start(
+            routing.patterns,
+            routing.tunnel_dns,
+            routing.upstream,
+            &self.active_interface(),
+        ) {
+            Ok(router) => self.domain_router = Some(router),
+            Err(e) => {
+                self.show_error(tr!("error.domain_routing", error = e));
+            }
+        }
+    }
+
+    /// Host routes currently installed for domain rules, if active.
+    pub fn get_domain_route_count(&self) -> Option<usize> {
+        self.domain_router.as_ref().map(|r| r.active_routes())
+    }
+
+    pub fn get_ipv6_mode(&self) -> Option<Ipv6Mode> {
+        self.ipv6_mode
+    }
+
+    pub fn get_traffic(&self) -> Option<&TrafficMonitor> {
+        self.traffic.as_ref()
+    }
+
+    pub fn get_public_ip(&self) -> Option<String> {
+        self.public_ip.lock().ok().and_then(|ip| ip.clone())
+    }
+
+    pub fn handle_switch_server(&mut self, server_id: u64) {
+        let server_name = match self.servers.iter().find(|s| s.id == server_id) {
+            Some(server) => server.name.clone(),
+            None => return,
+        };
+
+        self.connection_status = ConnectionStatus::Switching(server_name.clone());
+
+        let session = match &mut self.session {
+            Some(s) => s,
+            None => return,
+        };
+
+        match session.switch_server(server_id) {
+            Ok(wg_config) => {
+                let wg_config = wg_config.clone();
+                self.domain_router = None;
+
+                if self.proxy.is_some() {
+                    match self.start_proxy(&wg_config) {
+                        Ok(()) => {
+                            self.remember_server(server_id, &server_name);
+                            self.selected_server = Some(server_id);
+                            self.connection_status =
+                                ConnectionStatus::ConnectedProxy(server_name.clone());
+                            self.record_session(server_id, &server_name);
+                            self.fetch_public_ip();
+                            self.notify(Notification::ServerSwitched {
+                                server: server_name.clone(),
+                            });
+                        }
+                        Err(e) => {
+                            self.notify(Notification::SwitchFailed {
+                                error: format!("{:#}", e),
+                            });
+                            self.abort_switch(ConnectionStatus::Failed);
+                            self.show_error(tr!("error.switch", error = format!("{:#}", e)));
+                        }
+                    }
+                    return;
+                }
+
+                // Built before the old tunnel goes away
+                let tunnel = match self.prepare_tunnel(server_id, &wg_config) {
+                    Ok(t) => t,
+                    Err(e) => {
+                        self.abort_switch(ConnectionStatus::Error);
+                        self.show_error(tr!("error.tunnel_config", error = e.localized()));
+                        return;
+                    }
+                };
+
+                let interface = self.active_interface();
+                let _ = crate::vpn::tunnel::stop_tunnel(&interface);
+
+                match crate::vpn::tunnel::apply_config(&tunnel.config, &tunnel.options, &interface)
+                {
+                    Ok(_) => {
+                        self.tunnel_interface = Some(interface);
+                        self.tunnel_config = Some((tunnel.config, tunnel.options));
+                        self.record_session(server_id, &server_name);
+                        self.remember_server(server_id, &server_name);
+                        self.selected_server = Some(server_id);
+                        self.connection_status = ConnectionStatus::Connected(server_name.clone());
+                        self.fetch_public_ip();
+                        self.start_traffic_monitor();
+                        self.ipv6_mode = Some(tunnel.ipv6);
+                        self.start_app_router();
+                        self.start_domain_router(tunnel.domain_routing);
+                        self.start_kill_switch();
+                        self.handshake_lost = false;
+                        self.notify(Notification::ServerSwitched {
+                            server: server_name.clone(),
+                        });
+                    }
+                    Err(e) => {
+                        self.notify(Notification::SwitchFailed {
+                            error: e.to_string(),
+                        });
+                        self.abort_switch(ConnectionStatus::Failed);
+                        self.show_error(tr!("error.switch", error = e));
+                    }
+                }
+            }
+            Err(e) => {
+                if !self.check_session_expired(&e) {
+                    self.notify(Notification::SwitchFailed {
+                        error: e.localized(),
+                    });
+                }
+                // The old peer was unregistered before the new one failed
+                if !self.session.as_ref().is_some_and(|s| s.is_connected()) {
+                    self.abort_switch(ConnectionStatus::Error);
+                } else if let Some(server) = self.get_selected_server() {
+                    let name = server.name.clone();
+                    self.connection_status = match self.proxy {
+                        Some(_) => ConnectionStatus::ConnectedProxy(name),
+                        None => ConnectionStatus::Connected(name),
+                    };
+                }
+                self.show_error(tr!("error.switch", error = e.localized()));
+            }
+        }
+    }
+
+    /// A switch that failed once the old connection was gone: tears down
+    /// what is left of it like a disconnection, leaving `status`.
+    fn abort_switch(&mut self, status: ConnectionStatus) {
+        self.handle_disconnect();
+        self.connection_status = status;
+    }
+
+    pub fn handle_logout(&mut self) {
+        if self.state == AppState::Connected {
+            self.handle_disconnect();
+        }
+
+        self.session = None;
+        // Or the next launch would log back in
+        self.config.saved_token = None;
+        self.save_config();
+        self.servers.clear();
+        self.selected_server = None;
+        self.server_filter = ServerFilter::default();
+        self.latencies.clear();
+        self.showing_profile = false;
+        self.showing_settings = false;
+        self.state = AppState::Login;
+        self.email.clear();
+        self.password.zeroize();
+
+        if let Some(saved_email) = &self.config.saved_email {
+            self.email = saved_email.clone();
+        }
+    }
+
+    pub fn handle_delete_account(&mut self) {
+        if let Some(session) = &mut self.session {
+            if let Err(e) = session.delete_account() {
+                self.profile_error = Some(tr!("error.generic", error = e.localized()));
+                return;
+            }
+        }
+        self.handle_logout();
+    }
+
+    pub fn handle_update_profile(&mut self) {
+        self.profile_error = None;
+        self.profile_success = None;
+
+        let username = self.profile_username.trim().to_string();
+        let email = self.profile_email.trim().to_string();
+
+        if username.len() < 3 {
+            self.profile_error = Some(tr!("profile.name_too_short").into());
+            return;
+        }
+        if !email.contains('@') {
+            self.profile_error = Some(tr!("profile.invalid_email").into());
+            return;
+        }
+        if self.profile_password.len() < 8 {
+            self.profile_error = Some(tr!("profile.password_too_short").into());
+            return;
+        }
+        let password = Zeroizing::new(std::mem::take(&mut self.profile_password));
+
+        match &mut self.session {
+            Some(session) => match session.update_profile(&username, &email, &password) {
+                Ok(()) => {
+                    self.profile_editing = false;
+                    self.profile_success = Some(tr!("profile.updated").into());
+                }
+                Err(e) => {
+                    self.profile_error = Some(tr!("error.generic", error = e.localized()));
+                }
+            },
+            None => {
+                self.profile_error = Some(tr!("profile.session_expired").into());
+            }
+        }
+    }
+
+    pub fn show_profile(&mut self) {
+        self.showing_profile = true;
+        self.profile_editing = false;
+        self.profile_error = None;
+        self.profile_success = None;
+    }
+
+    pub fn hide_profile(&mut self) {
+        self.showing_profile = false;
+        self.profile_editing = false;
+        self.profile_password.zeroize();
+        self.profile_error = None;
+        self.profile_success = None;
+    }
+
+    pub fn show_settings(&mut self) {
+        self.showing_settings = true;
+        self.settings_error = None;
+        self.new_excluded_route.clear();
+        self.reset_preference_fields();
+        if apps::is_supported() && self.installed_apps.is_empty() {
+            self.installed_apps = apps::installed_applications();
+        }
+    }
+
+    pub fn get_app_rules(&self) -> &[AppRule] {
+        &self.config.app_rules
+    }
+
+    pub fn get_installed_apps(&self) -> &[InstalledApp] {
+        &self.installed_apps
+    }
+
+    pub fn get_new_app_path(&mut self) -> &mut String {
+        &mut self.new_app_path
+    }
+
+    pub fn add_app_rule(&mut self, mode: AppMode) {
+        self.settings_error = None;
+        let path = match apps::resolve_executable(self.new_app_path.trim()) {
+            Some(p) => p.to_string_lossy().into_owned(),
+            None => {
+                self.settings_error = Some(tr!("settings.executable_not_found").into());
+                return;
+            }
+        };
+        self.config.app_rules.retain(|r| r.path != path);
+        self.config.app_rules.push(AppRule { path, mode });
+        self.new_app_path.clear();
+        self.app_rules_changed();
+    }
+
+    pub fn toggle_app_rule(&mut self, idx: usize) {
+        if let Some(rule) = self.config.app_rules.get_mut(idx) {
+            rule.mode = rule.mode.toggled();
+            self.app_rules_changed();
+        }
+    }
+
+    pub fn remove_app_rule(&mut self, idx: usize) {
+        if idx < self.config.app_rules.len() {
+            self.config.app_rules.remove(idx);
+            self.app_rules_changed();
+        }
+    }
+
+    fn app_rules_changed(&mut self) {
+        self.save_config();
+        if self.state == AppState::Connected {
+            self.start_app_router();
+        }
+    }
+
+    /// Starts the application of a rule inside its cgroup.
+    pub fn launch_app(&mut self, idx: usize) {
+        let rule = match self.config.app_rules.get(idx) {
+            Some(r) => r.clone(),
+            None => return,
+        };
+        match apps::launch(rule.mode, &rule.path, &[]) {
+            Ok(mut child) => {
+                // Reap the process when it exits
+                std::thread::spawn(move || child.wait());
+            }
+            Err(e) => {
+                self.settings_error = Some(tr!("settings.launch_error", error = e));
+            }
+        }
+    }
+
+    pub fn hide_settings(&mut self) {
+        self.showing_settings = false;
+        self.settings_error = None;
+        self.diagnostics_status = None;
+    }
+
+    pub fn show_logs(&mut self) {
+        self.showing_logs = true;
+    }
+
+    pub fn hide_logs(&mut self) {
+        self.showing_logs = false;
+    }
+
+    pub fn get_log_level(&self) -> tracing::Level {
+        self.log_level
+    }
+
+    pub fn set_log_level(&mut self, level: tracing::Level) {
+        self.log_level = level;
+    }
+
+    /// Writes the diagnostics bundle for a bug report.
+    pub fn export_diagnostics(&mut self) {
+        let report = crate::diagnostics::Report {
+            config: serde_json::to_value(&self.config).unwrap_or_default(),
+            wireguard: self
+                .session
+                .as_ref()
+                .and_then(|s| s.current_config())
+                .map(|c| c.to_ini()),
+            connection: self.connection_status.label(),
+            interface: Some(self.active_interface()),
+            proxy: self.config.proxy.enabled,
+        };
+        self.diagnostics_status = Some(match crate::diagnostics::export(&report) {
+            Ok(path) => Ok(tr!("diagnostics.saved", path = path.display())),
+            Err(e) => {
+                tracing::warn!(error = format!("{:#}", e), "diagnostics export failed");
+                Err(tr!("diagnostics.export_error", error = format!("{:#}", e)))
+            }
+        });
+    }
+
+    /// Opens the prerequisite checks and runs them in the background.
+    pub fn show_doctor(&mut self) {
+        self.showing_doctor = true;
+        self.run_doctor();
+    }
+
+    pub fn run_doctor(&mut self) {
+        let holder = Arc::clone(&self.doctor_checks);
+        if let Ok(mut checks) = holder.lock() {
+            *checks = None;
+        }
+        let api_url = self.config.preferences.api_url.clone();
+        let interface = self.interface_name().to_string();
+        std::thread::spawn(move || {
+            let results = crate::diagnostics::checks::run(&api_url, &interface);
+            if let Ok(mut checks) = holder.lock() {
+                *checks = Some(results);
+            }
+        });
+    }
+
+    /// Results of the last run, `None` while running.
+    pub fn get_doctor_checks(&self) -> Option<Vec<Check>> {
+        self.doctor_checks.lock().ok().and_then(|c| c.clone())
+    }
+
+    pub fn hide_doctor(&mut self) {
+        self.showing_doctor = false;
+        // The wizard is shown until the preferences exist
+        if !self.config_path.exists() {
+            self.save_config();
+        }
+    }
+
+    pub fn get_diagnostics_status(&self) -> Option<&Result<String, String>> {
+        self.diagnostics_status.as_ref()
+    }
+
+    pub fn is_bypass_local_network(&self) -> bool {
+        self.config.bypass_local_network
+    }
+
+    pub fn set_bypass_local_network(&mut self, bypass: bool) {
+        self.config.bypass_local_network = bypass;
+        self.save_config();
+    }
+
+    pub fn get_proxy_settings(&self) -> &ProxySettings {
+        &self.config.proxy
+    }
+
+    pub fn set_proxy_settings(&mut self, settings: ProxySettings) {
+        self.config.proxy = settings;
+        self.save_config();
+    }
+
+    pub fn get_preferences(&self) -> &Preferences {
+        &self.config.preferences
+    }
+
+    /// Fills the edited text fields from the saved preferences.
+    fn reset_preference_fields(&mut self) {
+        let preferences = &self.config.preferences;
+        self.pref_api_url = preferences.api_url.clone();
+        self.pref_dns = preferences
+            .dns_override
+            .iter()
+            .map(IpAddr::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.pref_mtu = preferences.mtu.map(|m| m.to_string()).unwrap_or_default();
+        self.pref_keepalive = preferences
+            .keepalive
+            .map(|k| k.to_string())
+            .unwrap_or_default();
+    }
+
+    pub fn get_pref_api_url(&mut self) -> &mut String {
+        &mut self.pref_api_url
+    }
+
+    pub fn get_pref_dns(&mut self) -> &mut String {
+        &mut self.pref_dns
+    }
+
+    pub fn get_pref_mtu(&mut self) -> &mut String {
+        &mut self.pref_mtu
+    }
+
+    pub fn get_pref_keepalive(&mut self) -> &mut String {
+        &mut self.pref_keepalive
+    }
+
+    /// Used by the next login; the current session keeps its server.
+    pub fn apply_api_url(&mut self) -> Result<(), String> {
+        let url = preferences::parse_api_url(&self.pref_api_url)?;
+        self.pref_api_url = url.clone();
+        self.config.preferences.api_url = url;
+        self.save_config();
+        Ok(())
+    }
+
+    pub fn apply_dns_override(&mut self) -> Result<(), String> {
+        self.config.preferences.dns_override = preferences::parse_dns_list(&self.pref_dns)?;
+        self.save_config();
+        Ok(())
+    }
+
+    pub fn apply_mtu(&mut self) -> Result<(), String> {
+        self.config.preferences.mtu = preferences::parse_mtu(&self.pref_mtu)?;
+        self.save_config();
+        Ok(())
+    }
+
+    pub fn apply_keepalive(&mut self) -> Result<(), String> {
+        self.config.preferences.keepalive = preferences::parse_keepalive(&self.pref_keepalive)?;
+        self.save_config();
+        Ok(())
+    }
+
+    pub fn set_connect_on_launch(&mut self, enabled: bool) {
+        self.config.preferences.connect_on_launch = enabled;
+        self.save_config();
+    }
+
+    pub fn set_close_to_tray(&mut self, enabled: bool) {
+        self.config.preferences.close_to_tray = enabled;
+        self.save_config();
+    }
+
+    pub fn is_notifier_available(&self) -> bool {
+        self.notifications.is_some()
+    }
+
+    pub fn is_notification_enabled(&self, kind: NotificationKind) -> bool {
+        !self.config.preferences.muted_notifications.contains(&kind)
+    }
+
+    pub fn set_notification_enabled(&mut self, kind: NotificationKind, enabled: bool) {
+        let muted = &mut self.config.preferences.muted_notifications;
+        muted.retain(|&k| k != kind);
+        if !enabled {
+            muted.push(kind);
+        }
+        self.save_config();
+    }
+
+    pub fn is_tray_available(&self) -> bool {
+        self.tray.is_some()
+    }
+
+    pub fn set_connect_on_untrusted(&mut self, enabled: bool) {
+        self.config.preferences.connect_on_untrusted = enabled;
+        self.save_config();
+        self.update_network_watcher();
+    }
+
+    pub fn set_auto_connect_server(&mut self, choice: ServerChoice) {
+        self.config.preferences.auto_connect_server = choice;
+        self.save_config();
+    }
+
+    /// `None` while offline or when not watched.
+    pub fn get_current_network(&self) -> Option<&NetworkInfo> {
+        self.network.as_ref()
+    }
+
+    /// False without NetworkManager.
+    pub fn is_network_watched(&self) -> bool {
+        self.network_watcher.is_some()
+    }
+
+    pub fn get_trusted_networks(&self) -> &[TrustRule] {
+        &self.config.preferences.trusted_networks
+    }
+
+    pub fn get_new_trust_kind(&self) -> TrustKind {
+        self.new_trust_kind
+    }
+
+    pub fn set_new_trust_kind(&mut self, kind: TrustKind) {
+        self.new_trust_kind = kind;
+    }
+
+    pub fn get_new_trust_value(&mut self) -> &mut String {
+        &mut self.new_trust_value
+    }
+
+    pub fn add_trusted_network(&mut self, rule: TrustRule) {
+        if !self.config.preferences.trusted_networks.contains(&rule) {
+            self.config.preferences.trusted_networks.push(rule);
+            self.save_config();
+        }
+    }
+
+    /// Adds the rule typed in the settings.
+    pub fn add_typed_trusted_network(&mut self) {
+        self.settings_error = None;
+        match self.new_trust_kind.rule(&self.new_trust_value) {
+            Ok(rule) => {
+                self.add_trusted_network(rule);
+                self.new_trust_value.clear();
+            }
+            Err(e) => self.settings_error = Some(e),
+        }
+    }
+
+    pub fn remove_trusted_network(&mut self, idx: usize) {
+        if idx < self.config.preferences.trusted_networks.len() {
+            self.config.preferences.trusted_networks.remove(idx);
+            self.save_config();
+        }
+    }
+
+    /// Takes effect right away on a running tunnel.
+    pub fn set_kill_switch(&mut self, enabled: bool) {
+        self.config.preferences.kill_switch = enabled;
+        self.save_config();
+        if self.state == AppState::Connected {
+            self.start_kill_switch();
+        }
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.config.preferences.language = language;
+        self.save_config();
+        crate::i18n::set_locale(Locale::from_preference(language));
+        self.ctx.request_repaint();
+    }
+
+    pub fn set_theme(&mut self, theme: ThemeMode) {
+        self.config.preferences.theme = theme;
+        self.save_config();
+        self.apply_theme();
+    }
+
+    pub fn set_power_saving(&mut self, power_saving: PowerSaving) {
+        self.config.preferences.power_saving = power_saving;
+        self.save_config();
+    }
+
+    /// Schedules the next frame of the globe and the connected screen, at
+    /// the pace allowed by the power saving.
+    pub fn request_animation_frame(&self, ctx: &egui::Context) {
+        self.pace.request_frame(ctx);
+    }
+
+    fn update_pace(&mut self, ctx: &egui::Context) {
+        let (focused, minimized) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (
+                viewport.focused.unwrap_or(true),
+                viewport.minimized.unwrap_or(false),
+            )
+        });
+        let visible = !self.window_hidden && !minimized;
+        let setting = self.config.preferences.power_saving;
+        // The power supplies are only read when they matter
+        let on_battery = setting == PowerSaving::Auto && self.power_source.on_battery();
+        self.pace = Pace::choose(setting, visible, focused, on_battery);
+    }
+
+    fn apply_theme(&self) {
+        let variant = Variant::resolve(self.config.preferences.theme, self.desktop_appearance);
+        theme::apply(&self.ctx, variant);
+    }
+
+    fn poll_appearance(&mut self) {
+        let appearance = match self.desktop_watcher.as_ref().and_then(|w| w.poll()) {
+            Some(a) => a,
+            None => return,
+        };
+        self.desktop_appearance = appearance;
+        if self.config.preferences.theme == ThemeMode::System {
+            self.apply_theme();
+        }
+    }
+
+    pub fn get_interface_name(&self) -> &str {
+        self.interface_name()
+    }
+
+    pub fn get_new_interface_name(&mut self) -> &mut String {
+        &mut self.new_interface_name
+    }
+
+    /// Saves the edited interface name; `Err` if the kernel would reject it.
+    pub fn apply_interface_name(&mut self) -> Result<(), String> {
+        let name = self.new_interface_name.trim().to_string();
+        crate::vpn::tunnel::validate_interface_name(&name)?;
+        self.config.preferences.interface_name =
+            Some(name.clone()).filter(|n| n != DEFAULT_INTERFACE);
+        self.new_interface_name = name;
+        self.save_config();
+        Ok(())
+    }
+
+    pub fn get_excluded_routes(&self) -> &[String] {
+        &self.config.excluded_routes
+    }
+
+    pub fn get_new_excluded_route(&mut self) -> &mut String {
+        &mut self.new_excluded_route
+    }
+
+    pub fn add_excluded_route(&mut self) {
+        self.settings_error = None;
+        match self.new_excluded_route.parse::<Cidr>() {
+            Ok(cidr) => {
+                let route = cidr.to_string();
+                if !self.config.excluded_routes.contains(&route) {
+                    self.config.excluded_routes.push(route);
+                    self.save_config();
+                }
+                self.new_excluded_route.clear();
+            }
+            Err(e) => {
+                self.settings_error = Some(tr!("settings.invalid_route", error = e.localized()));
+            }
+        }
+    }
+
+    pub fn remove_excluded_route(&mut self, idx: usize) {
+        if idx < self.config.excluded_routes.len() {
+            self.config.excluded_routes.remove(idx);
+            self.save_config();
+        }
+    }
+
+    /// Server whose domain rules are edited in the settings screen.
+    pub fn get_domain_rule_server(&self) -> Option<u64> {
+        self.domain_rule_server
+            .or_else(|| self.servers.first().map(|s| s.id))
+    }
+
+    pub fn set_domain_rule_server(&mut self, server_id: u64) {
+        self.domain_rule_server = Some(server_id);
+    }
+
+    pub fn get_domain_rules(&self, server_id: u64) -> &[String] {
+        self.config
+            .domain_rules
+            .get(&server_id)
+            .map(|r| r.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn get_new_domain_rule(&mut self) -> &mut String {
+        &mut self.new_domain_rule
+    }
+
+    pub fn add_domain_rule(&mut self) {
+        self.settings_error = None;
+        let server_id = match self.get_domain_rule_server() {
+            Some(id) => id,
+            None => return,
+        };
+        match self.new_domain_rule.parse::<DomainPattern>() {
+            Ok(pattern) => {
+                let rule = pattern.to_string();
+                let rules = self.config.domain_rules.entry(server_id).or_default();
+                if !rules.contains(&rule) {
+                    rules.push(rule);
+                    self.save_config();
+                }
+                self.new_domain_rule.clear();
+            }
+            Err(e) => {
+                self.settings_error = Some(tr!("settings.invalid_domain", error = e.localized()));
+            }
+        }
+    }
+
+    pub fn remove_domain_rule(&mut self, server_id: u64, idx: usize) {
+        if let Some(rules) = self.config.domain_rules.get_mut(&server_id) {
+            if idx < rules.len() {
+                rules.remove(idx);
+            }
+            if rules.is_empty() {
+                self.config.domain_rules.remove(&server_id);
+            }
+            self.save_config();
+        }
+    }
+
+    pub fn get_settings_error(&self) -> Option<&str> {
+        self.settings_error.as_deref()
+    }
+
+    pub fn start_profile_edit(&mut self) {
+        if let Some(session) = &self.session {
+            self.profile_username = session.user().username.clone();
+            self.profile_email = session.user().email.clone();
+        }
+        self.profile_password.zeroize();
+        self.profile_error = None;
+        self.profile_success = None;
+        self.profile_editing = true;
+    }
+
+    pub fn cancel_profile_edit(&mut self) {
+        self.profile_editing = false;
+        self.profile_error = None;
+        self.profile_password.zeroize();
+    }
+
+    pub fn is_profile_editing(&self) -> bool {
+        self.profile_editing
+    }
+
+    pub fn get_profile_username(&mut self) -> &mut String {
+        &mut self.profile_username
+    }
+
+    pub fn get_profile_email(&mut self) -> &mut String {
+        &mut self.profile_email
+    }
+
+    pub fn get_profile_password(&mut self) -> &mut String {
+        &mut self.profile_password
+    }
+
+    pub fn get_profile_error(&self) -> Option<&str> {
+        self.profile_error.as_deref()
+    }
+
+    pub fn get_profile_success(&self) -> Option<&str> {
+        self.profile_success.as_deref()
+    }
+
+    pub fn is_vpn_connected(&self) -> bool {
+        self.state == AppState::Connected
+    }
+
+    pub fn get_email(&mut self) -> &mut String {
+        &mut self.email
+    }
+
+    pub fn get_password(&mut self) -> &mut String {
+        &mut self.password
+    }
+
+    pub fn get_username(&mut self) -> &mut String {
+        &mut self.username
     }
 
     pub fn is_show_register(&self) -> bool {
@@ -464,16 +2140,117 @@ impl VpnApp {
         &self.servers
     }
 
-    pub fn get_selected_server(&self) -> Option<usize> {
-        self.selected_server
+    pub fn get_selected_server(&self) -> Option<&Server> {
+        let id = self.selected_server?;
+        self.servers.iter().find(|s| s.id == id)
+    }
+
+    pub fn set_selected_server(&mut self, server_id: u64) {
+        self.selected_server = Some(server_id);
+    }
+
+    /// `(latitude, longitude)` in degrees, `None` if unknown.
+    pub fn get_home_location(&self) -> Option<(f32, f32)> {
+        self.home_location
+    }
+
+    pub fn is_globe_shown(&self) -> bool {
+        self.show_globe
+    }
+
+    pub fn set_globe_shown(&mut self, shown: bool) {
+        self.show_globe = shown;
+    }
+
+    pub fn is_favourite(&self, server_id: u64) -> bool {
+        self.config.favourite_servers.contains(&server_id)
+    }
+
+    pub fn toggle_favourite(&mut self, server_id: u64) {
+        if !self.config.favourite_servers.remove(&server_id) {
+            self.config.favourite_servers.insert(server_id);
+        }
+        self.save_config();
+    }
+
+    /// Recent connections to servers still in the list, newest first.
+    pub fn get_recent_servers(&self) -> Vec<&RecentServer> {
+        self.config
+            .recent_servers
+            .iter()
+            .filter(|r| self.servers.iter().any(|s| s.id == r.id))
+            .collect()
+    }
+
+    /// One click on a recent server: connects, or switches when connected.
+    pub fn connect_to(&mut self, server_id: u64) {
+        match self.state {
+            AppState::ServerList if !self.is_connecting => {
+                self.selected_server = Some(server_id);
+                self.handle_connect();
+            }
+            AppState::Connected if self.selected_server != Some(server_id) => {
+                self.handle_switch_server(server_id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Servers shown in the list, filtered and sorted.
+    pub fn get_visible_servers(&self) -> Vec<&Server> {
+        server_list::arrange(
+            &self.servers,
+            &self.server_filter,
+            self.config.preferences.server_sort,
+            &self.latencies,
+        )
+    }
+
+    pub fn get_server_filter(&mut self) -> &mut ServerFilter {
+        &mut self.server_filter
     }
 
-    pub fn set_selected_server(&mut self, idx: usize) {
-        self.selected_server = Some(idx);
+    pub fn get_latency(&self, server_id: u64) -> Option<Duration> {
+        self.latencies.get(&server_id).copied()
     }
 
-    pub fn get_connection_status(&self) -> &str {
-        &self.connection_status
+    pub fn is_probing_latencies(&self) -> bool {
+        self.latency_probe.is_some()
+    }
+
+    pub fn get_server_sort(&self) -> ServerSort {
+        self.config.preferences.server_sort
+    }
+
+    pub fn set_server_sort(&mut self, sort: ServerSort) {
+        self.config.preferences.server_sort = sort;
+        self.save_config();
+        if sort == ServerSort::Latency && self.latencies.is_empty() {
+            self.refresh_latencies();
+        }
+    }
+
+    pub fn is_grouped_by_continent(&self) -> bool {
+        self.config.preferences.group_by_continent
+    }
+
+    pub fn set_grouped_by_continent(&mut self, grouped: bool) {
+        self.config.preferences.group_by_continent = grouped;
+        self.save_config();
+    }
+
+    pub fn is_continent_collapsed(&self, continent: Option<Continent>) -> bool {
+        self.collapsed_continents.contains(&continent)
+    }
+
+    pub fn toggle_continent(&mut self, continent: Option<Continent>) {
+        if !self.collapsed_continents.remove(&continent) {
+            self.collapsed_continents.insert(continent);
+        }
+    }
+
+    pub fn get_connection_status(&self) -> String {
+        self.connection_status.label()
     }
 
     pub fn is_connecting(&self) -> bool {
@@ -484,6 +2261,13 @@ impl VpnApp {
         self.error_message.as_deref()
     }
 
+    /// Shows an error on the current screen and keeps it in the log.
+    fn show_error(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::warn!("{}", message);
+        self.error_message = Some(message);
+    }
+
     pub fn clear_error(&mut self) {
         self.error_message = None;
     }
@@ -494,11 +2278,16 @@ impl VpnApp {
 }
 
 impl eframe::App for VpnApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        self.frame_stats.record(ctx, frame);
+        self.poll_background();
+        self.handle_close_request(ctx);
+        self.update_pace(ctx);
+
         egui::CentralPanel::default()
             .frame(
                 egui::Frame::none()
-                    .fill(egui::Color32::from_rgb(0, 0, 0))
+                    .fill(theme::current().bg)
                     .inner_margin(egui::Margin::symmetric(20.0, 12.0)),
             )
             .show(ctx, |ui| {
@@ -508,6 +2297,23 @@ impl eframe::App for VpnApp {
                     return;
                 }
 
+                // First-run wizard, or opened from the settings
+                if self.showing_doctor {
+                    crate::ui::doctor::render(ui, self);
+                    return;
+                }
+
+                // Opened from the settings, returns to them
+                if self.showing_logs {
+                    crate::ui::logs::render(ui, self);
+                    return;
+                }
+
+                if self.showing_settings {
+                    crate::ui::settings::render(ui, self);
+                    return;
+                }
+
                 match self.state {
                     AppState::Login => {
                         crate::ui::login::render(ui, self);
@@ -520,14 +2326,25 @@ impl eframe::App for VpnApp {
                     }
                 }
             });
+
+        self.frame_stats.show(ctx, self.pace);
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        self.app_router = None;
+        self.domain_router = None;
+        self.proxy = None;
+        if let Some(interface) = self.tunnel_interface.take() {
+            let _ = crate::vpn::tunnel::stop_tunnel(&interface);
+        }
+        self.kill_switch = None;
         if self.state == AppState::Connected {
             if let Some(session) = &mut self.session {
                 let _ = session.disconnect();
-                let _ = crate::vpn::tunnel::stop_tunnel();
             }
         }
+        recovery::end();
+        self.password.zeroize();
+        self.profile_password.zeroize();
     }
 }
@@ -0,0 +1,496 @@
+//! Command-line entry points, handled before the GUI starts.
+//!
+//! ```text
+//! vpn-desktop launch [--bypass | --tunnel] -- <command> [args...]
+//! vpn-desktop proxy [--server ID] [--socks PORT] [--http PORT] [--config FILE]
+//! vpn-desktop tunnel up|down|refresh|status --interface NAME [--manual-routes] [--block-ipv6] [--mtu N]
+//! vpn-desktop tunnel apps up --interface NAME [--inclusive]|down|move --mode bypass|tunnel PID...
+//! vpn-desktop tunnel dns up|down|route add|del --interface NAME ADDR
+//! vpn-desktop tunnel killswitch up --interface NAME [--allow CIDR]...|down
+//! vpn-desktop doctor [--interface NAME]
+//! ```
+//!
+//! `tunnel` is the privileged half of the kernel backend and of the routing
+//! features, run as root through sudo by the GUI; `up` and `refresh` read
+//! the WireGuard config on stdin. Every argument is checked before use.
+
+use crate::app::{AppConfig, VpnApp};
+use crate::i18n::{self, tr, Locale, Localize};
+use crate::vpn::apps::{self, AppMode};
+use crate::vpn::proxy::ProxyServer;
+use crate::vpn::recovery::{self, RuntimeState};
+use std::io::{BufRead, Write};
+use vpn_core::session::Session;
+use vpn_core::wireguard::WireGuardConfig;
+use zeroize::Zeroizing;
+
+/// Runs the subcommand given on the command line, if any, and returns its
+/// exit code. Returns `None` to start the GUI.
+pub fn run() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (command, rest) = args.split_first()?;
+    crate::logging::init_cli();
+
+    let code = match command.as_str() {
+        "launch" => launch(rest),
+        "proxy" => proxy(rest),
+        "tunnel" => tunnel(rest),
+        "doctor" => doctor(rest),
+        "help" | "--help" | "-h" => {
+            print_usage();
+            0
+        }
+        other => {
+            eprintln!("{}", tr!("cli.unknown_command", command = other));
+            print_usage();
+            2
+        }
+    };
+    Some(code)
+}
+
+fn print_usage() {
+    println!("{}", tr!("cli.usage"));
+}
+
+fn launch(args: &[String]) -> i32 {
+    let mut mode = AppMode::Bypass;
+    let mut rest = args;
+    while let Some((arg, tail)) = rest.split_first() {
+        match arg.as_str() {
+            "--bypass" => mode = AppMode::Bypass,
+            "--tunnel" => mode = AppMode::Tunnel,
+            "--" => {
+                rest = tail;
+                break;
+            }
+            _ => break,
+        }
+        rest = tail;
+    }
+
+    let (program, program_args) = match rest.split_first() {
+        Some(split) => split,
+        None => {
+            print_usage();
+            return 2;
+        }
+    };
+
+    match apps::launch(mode, program, program_args) {
+        Ok(mut child) => match child.wait() {
+            Ok(status) => status.code().unwrap_or(1),
+            Err(e) => {
+                eprintln!("{}", tr!("error.generic", error = e));
+                1
+            }
+        },
+        Err(e) => {
+            eprintln!("{}", tr!("error.generic", error = format!("{:#}", e)));
+            1
+        }
+    }
+}
+
+fn proxy(args: &[String]) -> i32 {
+    let config = VpnApp::load_saved_config();
+    i18n::set_locale(Locale::from_preference(config.preferences.language));
+    let mut settings = config.proxy.clone();
+    let mut server_id = config.last_server_id;
+    let mut config_file = None;
+
+    let mut rest = args;
+    while let Some((arg, tail)) = rest.split_first() {
+        let value = tail.first();
+        let parsed = match (arg.as_str(), value) {
+            ("--server", Some(v)) => v.parse().map(|id| server_id = Some(id)).is_ok(),
+            ("--socks", Some(v)) => v.parse().map(|p| settings.socks_port = p).is_ok(),
+            ("--http", Some(v)) => v.parse().map(|p| settings.http_port = p).is_ok(),
+            ("--config", Some(v)) => {
+                config_file = Some(v.clone());
+                true
+            }
+            _ => false,
+        };
+        if !parsed {
+            print_usage();
+            return 2;
+        }
+        rest = &tail[1..];
+    }
+
+    // Either a local config file, or a config fetched from the API
+    let (wg_config, mut session) = match config_file {
+        Some(path) => {
+            let parsed = std::fs::read_to_string(&path)
+                .map(Zeroizing::new)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Ok(WireGuardConfig::parse(&content)?));
+            match parsed {
+                Ok(c) => (c, None),
+                Err(e) => {
+                    eprintln!(
+                        "{}",
+                        tr!("error.generic", error = format!("{}: {:#}", path, e))
+                    );
+                    return 1;
+                }
+            }
+        }
+        None => {
+            let server_id = match server_id {
+                Some(id) => id,
+                None => {
+                    eprintln!("{}", tr!("cli.no_server"));
+                    return 2;
+                }
+            };
+            match login(&config).and_then(|mut session| {
+                let wg_config = session.connect(server_id)?.clone();
+                Ok((wg_config, Some(session)))
+            }) {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!("{}", tr!("error.generic", error = format!("{:#}", e)));
+                    return 1;
+                }
+            }
+        }
+    };
+
+    let code = match ProxyServer::start(&wg_config, &settings) {
+        Ok(server) => {
+            // Unregister the peer when interrupted or killed
+            if let Some(session) = &session {
+                if let Some(server) = session.current_server() {
+                    recovery::begin(
+                        RuntimeState::new(None, server.id, &server.name, session.api_base_url()),
+                        session.token(),
+                    );
+                }
+                recovery::install_signal_handlers();
+            }
+            println!("SOCKS5: {}", server.socks_addr());
+            println!("HTTP:   {}", server.http_addr());
+            println!("{}", tr!("cli.press_enter"));
+            let mut line = String::new();
+            if matches!(std::io::stdin().lock().read_line(&mut line), Ok(0)) {
+                // No terminal attached: run until killed
+                loop {
+                    std::thread::park();
+                }
+            }
+            server.stop();
+            recovery::end();
+            0
+        }
+        Err(e) => {
+            eprintln!("{}", tr!("error.generic", error = format!("{:#}", e)));
+            1
+        }
+    };
+
+    if let Some(session) = &mut session {
+        if let Err(e) = session.disconnect() {
+            eprintln!("{}", tr!("cli.disconnect_error", error = e.localized()));
+        }
+    }
+    code
+}
+
+#[cfg(target_os = "linux")]
+fn tunnel(args: &[String]) -> i32 {
+    use crate::vpn::{kernel, tunnel::TunnelOptions};
+    use std::io::Read;
+
+    let (action, mut rest) = match args.split_first() {
+        Some((action, rest)) => (action.as_str(), rest),
+        None => {
+            print_usage();
+            return 2;
+        }
+    };
+    match action {
+        "apps" => return tunnel_apps(rest),
+        "dns" => return tunnel_dns(rest),
+        "killswitch" => return tunnel_killswitch(rest),
+        _ => {}
+    }
+    let mut interface = None;
+    let mut options = TunnelOptions::default();
+    while let Some((arg, tail)) = rest.split_first() {
+        match (arg.as_str(), tail.first()) {
+            ("--interface", Some(name)) => {
+                interface = Some(name.clone());
+                rest = &tail[1..];
+                continue;
+            }
+            ("--mtu", Some(value)) => {
+                match crate::preferences::parse_mtu(value) {
+                    Ok(mtu) => options.mtu = mtu,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return 2;
+                    }
+                }
+                rest = &tail[1..];
+                continue;
+            }
+            ("--manual-routes", _) => options.manual_routes = true,
+            ("--block-ipv6", _) => options.block_ipv6 = true,
+            _ => {
+                print_usage();
+                return 2;
+            }
+        }
+        rest = tail;
+    }
+    let interface = match interface {
+        Some(name) => name,
+        None => {
+            print_usage();
+            return 2;
+        }
+    };
+    if let Err(e) = crate::vpn::tunnel::validate_interface_name(&interface) {
+        eprintln!("{}", tr!("cli.invalid_interface", error = e));
+        return 2;
+    }
+
+    let result = match action {
+        "up" | "refresh" => {
+            let mut ini = Zeroizing::new(String::new());
+            std::io::stdin()
+                .lock()
+                .read_to_string(&mut ini)
+                .map_err(anyhow::Error::from)
+                .and_then(|_| Ok(WireGuardConfig::parse(&ini)?))
+                .and_then(|config| match action {
+                    "up" => kernel::up(&config, &options, &interface),
+                    _ => kernel::refresh(&config, &options, &interface),
+                })
+        }
+        "down" => kernel::down(&interface),
+        "status" => kernel::last_handshake(&interface).map(|handshake| {
+            let secs = handshake
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            println!("{}", secs);
+        }),
+        _ => {
+            print_usage();
+            return 2;
+        }
+    };
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}", tr!("error.generic", error = format!("{:#}", e)));
+            1
+        }
+    }
+}
+
+/// `tunnel apps up --interface NAME [--inclusive]`, `tunnel apps down` and
+/// `tunnel apps move --mode bypass|tunnel PID...`: per-application routing.
+#[cfg(target_os = "linux")]
+fn tunnel_apps(args: &[String]) -> i32 {
+    let result = match args {
+        [action, flag, interface, rest @ ..] if action == "up" && flag == "--interface" => {
+            let inclusive = match rest {
+                [] => false,
+                [flag] if flag == "--inclusive" => true,
+                _ => {
+                    print_usage();
+                    return 2;
+                }
+            };
+            if let Err(e) = crate::vpn::tunnel::validate_interface_name(interface) {
+                eprintln!("{}", tr!("cli.invalid_interface", error = e));
+                return 2;
+            }
+            apps::setup(interface, inclusive)
+        }
+        [action] if action == "down" => {
+            apps::teardown();
+            Ok(())
+        }
+        [action, flag, mode, pids @ ..] if action == "move" && flag == "--mode" => {
+            let mode = AppMode::from_cgroup(mode);
+            let pids: Option<Vec<u32>> = pids.iter().map(|pid| pid.parse().ok()).collect();
+            match (mode, pids) {
+                (Some(mode), Some(pids)) if !pids.is_empty() => apps::move_processes(mode, &pids),
+                _ => {
+                    print_usage();
+                    return 2;
+                }
+            }
+        }
+        _ => {
+            print_usage();
+            return 2;
+        }
+    };
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}", tr!("error.generic", error = format!("{:#}", e)));
+            1
+        }
+    }
+}
+
+/// `tunnel dns up|down` and `tunnel dns route add|del --interface NAME ADDR`:
+/// domain-based routing.
+#[cfg(target_os = "linux")]
+fn tunnel_dns(args: &[String]) -> i32 {
+    use crate::vpn::domains;
+
+    let result = match args {
+        [action] if action == "up" => domains::redirect(),
+        [action] if action == "down" => {
+            domains::remove_redirect();
+            Ok(())
+        }
+        [route, action, flag, interface, addr]
+            if route == "route"
+                && (action == "add" || action == "del")
+                && flag == "--interface" =>
+        {
+            let addr = match addr.parse() {
+                Ok(addr) => addr,
+                Err(_) => {
+                    print_usage();
+                    return 2;
+                }
+            };
+            if let Err(e) = crate::vpn::tunnel::validate_interface_name(interface) {
+                eprintln!("{}", tr!("cli.invalid_interface", error = e));
+                return 2;
+            }
+            domains::host_route(action == "add", addr, interface)
+        }
+        _ => {
+            print_usage();
+            return 2;
+        }
+    };
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}", tr!("error.generic", error = format!("{:#}", e)));
+            1
+        }
+    }
+}
+
+/// `tunnel killswitch up --interface NAME [--allow CIDR]...` and
+/// `tunnel killswitch down`.
+#[cfg(target_os = "linux")]
+fn tunnel_killswitch(args: &[String]) -> i32 {
+    use crate::vpn::killswitch;
+    use vpn_core::cidr::Cidr;
+
+    let result = match args {
+        [action, flag, interface, rest @ ..] if action == "up" && flag == "--interface" => {
+            if let Err(e) = crate::vpn::tunnel::validate_interface_name(interface) {
+                eprintln!("{}", tr!("cli.invalid_interface", error = e));
+                return 2;
+            }
+            let mut allowed = Vec::new();
+            for pair in rest.chunks(2) {
+                match pair {
+                    [flag, cidr] if flag == "--allow" => match cidr.parse::<Cidr>() {
+                        Ok(cidr) => allowed.push(cidr),
+                        Err(e) => {
+                            eprintln!("{}", tr!("error.generic", error = e.localized()));
+                            return 2;
+                        }
+                    },
+                    _ => {
+                        print_usage();
+                        return 2;
+                    }
+                }
+            }
+            killswitch::apply(interface, &allowed)
+        }
+        [action] if action == "down" => {
+            killswitch::remove();
+            Ok(())
+        }
+        _ => {
+            print_usage();
+            return 2;
+        }
+    };
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}", tr!("error.generic", error = format!("{:#}", e)));
+            1
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn tunnel(_args: &[String]) -> i32 {
+    eprintln!("{}", tr!("cli.linux_only"));
+    1
+}
+
+fn doctor(args: &[String]) -> i32 {
+    use crate::diagnostics::checks::{self, Status};
+
+    let config = VpnApp::load_saved_config();
+    i18n::set_locale(Locale::from_preference(config.preferences.language));
+    let interface = match args {
+        [] => config.interface_name().to_string(),
+        [flag, name] if flag == "--interface" => name.clone(),
+        _ => {
+            print_usage();
+            return 2;
+        }
+    };
+
+    let results = checks::run(&config.preferences.api_url, &interface);
+    for check in &results {
+        let mark = match check.status {
+            Status::Ok => "ok",
+            Status::Warning => "!!",
+            Status::Error => "KO",
+        };
+        println!("[{}] {}: {}", mark, check.name, check.detail);
+        if let Some(hint) = &check.hint {
+            println!("     -> {}", hint);
+        }
+    }
+    if results.iter().any(|c| c.status == Status::Error) {
+        1
+    } else {
+        0
+    }
+}
+
+fn login(config: &AppConfig) -> anyhow::Result<Session> {
+    let email = match &config.saved_email {
+        Some(email) => email.clone(),
+        None => {
+            print!("Email: ");
+            std::io::stdout().flush()?;
+            let mut email = String::new();
+            std::io::stdin().lock().read_line(&mut email)?;
+            email.trim().to_string()
+        }
+    };
+    let password = Zeroizing::new(rpassword::prompt_password(tr!(
+        "cli.password_prompt",
+        email = email
+    ))?);
+    Ok(Session::login(
+        &config.preferences.api_url,
+        &email,
+        &password,
+    )?)
+}
@@ -0,0 +1,361 @@
+//! Storage of `config.json`.
+//!
+//! The file carries a `version`: older layouts are brought up to date by
+//! [`MIGRATIONS`] before deserializing, so a field changing shape no longer
+//! resets the saved email and last server. Each save keeps the previous file
+//! as `config.json.bak`. A file that cannot be read is moved aside instead of
+//! being overwritten, and the caller gets a message for the user. The
+//! command line only reads it and leaves it alone.
+
+use crate::i18n::tr;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Version written by this release.
+pub const CONFIG_VERSION: u64 = MIGRATIONS.len() as u64;
+
+type Migration = fn(&mut Map<String, Value>);
+
+/// `MIGRATIONS[n]` turns a version `n` file into version `n + 1`. Files
+/// written before versioning are version 0.
+const MIGRATIONS: &[Migration] = &[move_into_preferences, rename_auto_connect];
+
+/// Result of [`load`], defaults included when nothing could be read.
+pub struct Loaded<T> {
+    pub config: T,
+    /// Why the saved file was not used as is, to show to the user.
+    pub problem: Option<String>,
+}
+
+/// Loads the config for an app that will save it: an unusable file is
+/// renamed so that the next save does not destroy it.
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Loaded<T> {
+    load_with(path, true)
+}
+
+/// Loads the config without touching the files.
+pub fn read<T: DeserializeOwned + Default>(path: &Path) -> Loaded<T> {
+    load_with(path, false)
+}
+
+fn load_with<T: DeserializeOwned + Default>(path: &Path, move_aside: bool) -> Loaded<T> {
+    let bytes = match fs::read(path) {
+        Ok(b) => b,
+        // First run
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Loaded {
+                config: T::default(),
+                problem: None,
+            }
+        }
+        // Left in place: it may well be fine once readable again
+        Err(e) => {
+            return Loaded {
+                config: T::default(),
+                problem: Some(tr!("config.unreadable", path = path.display(), error = e)),
+            };
+        }
+    };
+
+    let error = match parse(&bytes) {
+        Ok(config) => {
+            return Loaded {
+                config,
+                problem: None,
+            }
+        }
+        Err(e) => e,
+    };
+
+    let kept = match move_aside.then(|| set_aside(path)) {
+        None => tr!("config.left_in_place").to_string(),
+        Some(Ok(p)) => tr!("config.kept_as", path = p.display()),
+        Some(Err(e)) => tr!("config.not_set_aside", error = e),
+    };
+    let backup = fs::read(backup_path(path))
+        .ok()
+        .and_then(|b| parse::<T>(&b).ok());
+    let (config, fallback) = match backup {
+        Some(config) => (config, tr!("config.backup_restored")),
+        None => (T::default(), tr!("config.defaults_used")),
+    };
+    Loaded {
+        config,
+        problem: Some(tr!(
+            "config.unusable",
+            error = error,
+            fallback = fallback,
+            kept = kept
+        )),
+    }
+}
+
+/// Writes the config atomically, keeping the previous file as a backup.
+pub fn save<T: Serialize>(path: &Path, config: &T) -> io::Result<()> {
+    let mut value = serde_json::to_value(config)?;
+    if let Value::Object(map) = &mut value {
+        map.insert("version".into(), CONFIG_VERSION.into());
+    }
+    let json = serde_json::to_string_pretty(&value)?;
+
+    if let Ok(previous) = fs::read(path) {
+        if previous != json.as_bytes() {
+            crate::private_file::write(&backup_path(path), &previous)?;
+        }
+    }
+    // Holds the session token
+    crate::private_file::write(path, json.as_bytes())
+}
+
+fn parse<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+    let mut value: Value = serde_json::from_slice(bytes).map_err(|e| e.to_string())?;
+    let map = value
+        .as_object_mut()
+        .ok_or_else(|| tr!("config.not_an_object").to_string())?;
+    let version = match map.get("version") {
+        None => 0,
+        Some(v) => v
+            .as_u64()
+            .ok_or_else(|| tr!("config.invalid_version", version = v))?,
+    };
+    if version > CONFIG_VERSION {
+        return Err(tr!("config.newer_version", version = version));
+    }
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(map);
+    }
+    if version < CONFIG_VERSION {
+        tracing::info!(from = version, to = CONFIG_VERSION, "config migrated");
+    }
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".bak");
+    PathBuf::from(name)
+}
+
+/// Renames an unusable file to `config.json.unusable-<secs>`, never over an
+/// earlier one.
+fn set_aside(path: &Path) -> io::Result<PathBuf> {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut n = 0;
+    let aside = loop {
+        let mut name = path.as_os_str().to_owned();
+        name.push(format!(".unusable-{}", secs));
+        if n > 0 {
+            name.push(format!("-{}", n));
+        }
+        let aside = PathBuf::from(name);
+        if !aside.exists() {
+            break aside;
+        }
+        n += 1;
+    };
+    fs::rename(path, &aside)?;
+    Ok(aside)
+}
+
+// ── Migrations ─────────────────────────────────────────────────────────────────
+
+/// 0 → 1: `api_url` and `interface_name` were top-level before the
+/// preferences existed. Files of the first preferences release carried their
+/// own version inside them.
+fn move_into_preferences(config: &mut Map<String, Value>) {
+    let mut preferences = match config.remove("preferences") {
+        Some(Value::Object(p)) => p,
+        _ => Map::new(),
+    };
+    preferences.remove("version");
+    for key in ["api_url", "interface_name"] {
+        if let Some(value) = config.remove(key) {
+            if !value.is_null() {
+                preferences.entry(key).or_insert(value);
+            }
+        }
+    }
+    config.insert("preferences".into(), Value::Object(preferences));
+}
+
+/// 1 → 2: `auto_connect` became `connect_on_launch` when connecting on
+/// untrusted networks was added.
+fn rename_auto_connect(config: &mut Map<String, Value>) {
+    if let Some(Value::Object(preferences)) = config.get_mut("preferences") {
+        if let Some(value) = preferences.remove("auto_connect") {
+            preferences.insert("connect_on_launch".into(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppConfig;
+    use serde_json::json;
+
+    /// Directory removed on drop, unique to each test.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "vpn-desktop-config-{}-{}",
+                std::process::id(),
+                name
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        fn config(&self) -> PathBuf {
+            self.0.join("config.json")
+        }
+
+        fn files(&self) -> Vec<String> {
+            let mut names: Vec<String> = fs::read_dir(&self.0)
+                .unwrap()
+                .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn parse_json(value: Value) -> Result<AppConfig, String> {
+        parse(value.to_string().as_bytes())
+    }
+
+    #[test]
+    fn version_0_moves_top_level_fields_into_preferences() {
+        let config = parse_json(json!({
+            "api_url": "https://api.example.com",
+            "interface_name": "wg7",
+            "saved_email": "user@example.com",
+            "last_server_id": 12,
+        }))
+        .unwrap();
+        assert_eq!(config.preferences.api_url, "https://api.example.com");
+        assert_eq!(config.preferences.interface_name.as_deref(), Some("wg7"));
+        assert_eq!(config.saved_email.as_deref(), Some("user@example.com"));
+        assert_eq!(config.last_server_id, Some(12));
+    }
+
+    #[test]
+    fn version_0_keeps_preferences_already_there() {
+        let config = parse_json(json!({
+            "api_url": "https://old.example.com",
+            "interface_name": null,
+            "preferences": {
+                "version": 1,
+                "api_url": "https://new.example.com",
+                "auto_connect": true,
+            },
+        }))
+        .unwrap();
+        assert_eq!(config.preferences.api_url, "https://new.example.com");
+        assert_eq!(config.preferences.interface_name, None);
+        assert!(config.preferences.connect_on_launch);
+    }
+
+    #[test]
+    fn version_1_renames_auto_connect() {
+        for auto_connect in [true, false] {
+            let config = parse_json(json!({
+                "version": 1,
+                "preferences": { "auto_connect": auto_connect },
+            }))
+            .unwrap();
+            assert_eq!(config.preferences.connect_on_launch, auto_connect);
+        }
+    }
+
+    #[test]
+    fn newer_version_is_refused() {
+        let newer = CONFIG_VERSION + 1;
+        assert!(parse_json(json!({ "version": newer })).is_err());
+        assert!(parse_json(json!({ "version": "2" })).is_err());
+        assert!(parse_json(json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn saved_config_round_trips() {
+        let dir = TempDir::new("round-trip");
+        let mut config = AppConfig::default();
+        config.saved_email = Some("user@example.com".to_string());
+        save(&dir.config(), &config).unwrap();
+
+        let saved: Value = serde_json::from_slice(&fs::read(dir.config()).unwrap()).unwrap();
+        assert_eq!(saved["version"], json!(CONFIG_VERSION));
+        let loaded: Loaded<AppConfig> = load(&dir.config());
+        assert!(loaded.problem.is_none());
+        assert_eq!(
+            loaded.config.saved_email.as_deref(),
+            Some("user@example.com")
+        );
+    }
+
+    #[test]
+    fn corrupt_file_falls_back_to_the_backup() {
+        let dir = TempDir::new("backup");
+        let mut config = AppConfig::default();
+        config.last_server_id = Some(3);
+        save(&dir.config(), &config).unwrap();
+        config.last_server_id = Some(4);
+        save(&dir.config(), &config).unwrap();
+        fs::write(dir.config(), b"{ not json").unwrap();
+
+        // Read only: nothing moves
+        let read_only: Loaded<AppConfig> = read(&dir.config());
+        assert_eq!(read_only.config.last_server_id, Some(3));
+        assert!(read_only.problem.is_some());
+        assert_eq!(dir.files(), ["config.json", "config.json.bak"]);
+
+        let loaded: Loaded<AppConfig> = load(&dir.config());
+        assert_eq!(loaded.config.last_server_id, Some(3));
+        assert!(loaded.problem.is_some());
+        assert!(!dir.config().exists());
+        let files = dir.files();
+        assert_eq!(files.len(), 2);
+        assert!(files[1].starts_with("config.json.unusable-"));
+    }
+
+    #[test]
+    fn corrupt_file_without_backup_uses_defaults() {
+        let dir = TempDir::new("defaults");
+        fs::write(dir.config(), b"[]").unwrap();
+        let loaded: Loaded<AppConfig> = load(&dir.config());
+        assert_eq!(loaded.config.last_server_id, None);
+        assert!(loaded.problem.is_some());
+    }
+
+    #[test]
+    fn set_aside_never_overwrites_an_earlier_file() {
+        let dir = TempDir::new("aside");
+        let mut aside = Vec::new();
+        for content in ["first", "second", "third"] {
+            fs::write(dir.config(), content).unwrap();
+            aside.push(set_aside(&dir.config()).unwrap());
+        }
+        assert!(!dir.config().exists());
+        let contents: Vec<String> = aside
+            .iter()
+            .map(|p| fs::read_to_string(p).unwrap())
+            .collect();
+        assert_eq!(contents, ["first", "second", "third"]);
+    }
+}
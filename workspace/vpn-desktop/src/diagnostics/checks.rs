@@ -0,0 +1,278 @@
+//! Prerequisites of the tunnel, checked by the first-run wizard and
+//! `vpn-desktop doctor`. A failed check says what to do about it, instead of
+//! leaving the user with the stderr of the first failed connection.
+
+use crate::i18n::tr;
+use crate::vpn::{apps, domains, tunnel};
+use std::time::Duration;
+
+const API_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Ok,
+    /// Some features will not work, connecting still does.
+    Warning,
+    /// Connecting will fail.
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    /// How to fix it, for warnings and errors.
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Ok,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warning(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Warning,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn error(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Error,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Runs every check; blocks up to [`API_TIMEOUT`] on the API.
+pub fn run(api_url: &str, interface: &str) -> Vec<Check> {
+    let mut checks = vec![api(api_url)];
+    checks.extend(platform(interface));
+    for check in &checks {
+        match check.status {
+            Status::Ok => tracing::debug!(check = check.name, detail = %check.detail, "check"),
+            _ => tracing::info!(check = check.name, detail = %check.detail, "check failed"),
+        }
+    }
+    checks
+}
+
+fn api(api_url: &str) -> Check {
+    const NAME: &str = "API";
+    let result = reqwest::blocking::Client::builder()
+        .timeout(API_TIMEOUT)
+        .build()
+        .and_then(|c| c.get(api_url).send());
+    match result {
+        // Any HTTP answer means the server is there
+        Ok(resp) => Check::ok(
+            NAME,
+            tr!(
+                "doctor.api.ok",
+                url = api_url,
+                status = resp.status().as_u16()
+            ),
+        ),
+        Err(e) => Check::error(
+            NAME,
+            tr!("doctor.api.unreachable", url = api_url, error = e),
+            tr!("doctor.api.hint"),
+        ),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn platform(interface: &str) -> Vec<Check> {
+    let mut checks = vec![
+        wireguard_module(),
+        privileges(interface),
+        dns(),
+        interface_name(interface),
+        default_routes(),
+    ];
+    if apps::is_supported() || domains::is_supported() {
+        checks.push(nftables());
+    }
+    checks
+}
+
+#[cfg(target_os = "macos")]
+fn platform(_interface: &str) -> Vec<Check> {
+    vec![program("wg-quick", tr!("doctor.program.hint_macos"))]
+}
+
+#[cfg(target_os = "windows")]
+fn platform(_interface: &str) -> Vec<Check> {
+    vec![program("wireguard.exe", tr!("doctor.program.hint_windows"))]
+}
+
+#[cfg(not(target_os = "linux"))]
+fn program(name: &'static str, hint: &str) -> Check {
+    match apps::resolve_executable(name) {
+        Some(path) => Check::ok(name, path.display().to_string()),
+        None => Check::error(name, tr!("doctor.program.missing"), hint),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn wireguard_module() -> Check {
+    let name = tr!("doctor.module.name");
+    // Also there when built into the kernel
+    if std::path::Path::new("/sys/module/wireguard").exists() {
+        return Check::ok(name, tr!("doctor.module.loaded"));
+    }
+    let installed = std::process::Command::new("modinfo")
+        .args(["-n", "wireguard"])
+        .output()
+        .is_ok_and(|o| o.status.success());
+    if installed {
+        Check::ok(name, tr!("doctor.module.installed"))
+    } else {
+        Check::error(
+            name,
+            tr!("doctor.module.missing"),
+            tr!("doctor.module.hint"),
+        )
+    }
+}
+
+/// The GUI brings the tunnel up through `sudo -n vpn-desktop tunnel`, which
+/// must not ask for a password. `tunnel status` is read-only.
+#[cfg(target_os = "linux")]
+fn privileges(interface: &str) -> Check {
+    let name = tr!("doctor.privileges.name");
+    if tunnel::is_root() {
+        return Check::ok(name, tr!("doctor.privileges.root"));
+    }
+    let exe = match std::env::current_exe() {
+        Ok(e) => e.to_string_lossy().into_owned(),
+        Err(e) => return Check::error(name, e.to_string(), tr!("doctor.privileges.restart")),
+    };
+    let user = std::env::var("USER").unwrap_or_else(|_| tr!("doctor.privileges.user").into());
+    let sudoers = tr!("doctor.privileges.sudoers", user = user, exe = exe);
+    let output = std::process::Command::new("sudo")
+        .args(["-n", &exe, "tunnel", "status", "--interface", interface])
+        .output();
+    match output {
+        Err(_) => Check::error(
+            name,
+            tr!("doctor.privileges.no_sudo"),
+            tr!("doctor.privileges.install_sudo", sudoers = sudoers),
+        ),
+        // Failures of sudo itself, not of the command it ran
+        Ok(o) if String::from_utf8_lossy(&o.stderr).starts_with("sudo:") => {
+            Check::error(name, tr!("doctor.privileges.password"), sudoers)
+        }
+        Ok(_) => Check::ok(name, tr!("doctor.privileges.ok")),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn dns() -> Check {
+    const NAME: &str = "DNS";
+    if !std::path::Path::new("/run/systemd/resolve/io.systemd.Resolve").exists() {
+        return Check::ok(NAME, tr!("doctor.dns.resolv_conf"));
+    }
+    match apps::resolve_executable("resolvectl") {
+        Some(_) => Check::ok(NAME, "systemd-resolved (resolvectl)"),
+        None => Check::error(
+            NAME,
+            tr!("doctor.dns.no_resolvectl"),
+            tr!("doctor.dns.hint"),
+        ),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn interface_name(interface: &str) -> Check {
+    const NAME: &str = "Interface";
+    let problem = tunnel::validate_interface_name(interface)
+        .err()
+        .or_else(|| tunnel::interface_conflict(interface));
+    match problem {
+        Some(problem) => Check::error(NAME, problem, tr!("doctor.interface.hint")),
+        None => Check::ok(NAME, tr!("doctor.interface.ok", interface = interface)),
+    }
+}
+
+/// Needs a default route, and warns about another VPN holding it: both
+/// tunnels would fight over the traffic.
+#[cfg(target_os = "linux")]
+fn default_routes() -> Check {
+    let name = tr!("doctor.route.name");
+    let mut devices = Vec::new();
+    for family in ["-4", "-6"] {
+        let output = match std::process::Command::new("ip")
+            .args([family, "route", "show", "table", "main"])
+            .output()
+        {
+            Ok(o) => o,
+            Err(e) => return Check::warning(name, e.to_string(), tr!("doctor.route.iproute2")),
+        };
+        for route in String::from_utf8_lossy(&output.stdout).lines() {
+            let fields: Vec<&str> = route.split_whitespace().collect();
+            // wg-quick and OpenVPN cover the default route with two /1
+            let default = matches!(
+                fields.first(),
+                Some(&"default") | Some(&"0.0.0.0/1") | Some(&"128.0.0.0/1")
+            );
+            let dev = fields
+                .windows(2)
+                .find(|w| w[0] == "dev")
+                .map(|w| w[1].to_string());
+            if let (true, Some(dev)) = (default, dev) {
+                if !devices.contains(&dev) {
+                    devices.push(dev);
+                }
+            }
+        }
+    }
+
+    if devices.is_empty() {
+        return Check::error(name, tr!("doctor.route.none"), tr!("doctor.route.hint"));
+    }
+    match devices.iter().find(|dev| is_foreign_tunnel(dev)) {
+        Some(dev) => Check::warning(
+            name,
+            tr!("doctor.route.other_vpn", device = dev),
+            tr!("doctor.route.other_vpn_hint"),
+        ),
+        None => Check::ok(name, tr!("doctor.route.ok", devices = devices.join(", "))),
+    }
+}
+
+/// Point-to-point interface (tun, WireGuard, PPP) not created by the app.
+#[cfg(target_os = "linux")]
+fn is_foreign_tunnel(dev: &str) -> bool {
+    const ARPHRD_PPP: &str = "512";
+    const ARPHRD_NONE: &str = "65534";
+    let dir = std::path::Path::new("/sys/class/net").join(dev);
+    let read = |file: &str| std::fs::read_to_string(dir.join(file)).unwrap_or_default();
+    let kind = read("type");
+    matches!(kind.trim(), ARPHRD_PPP | ARPHRD_NONE)
+        && read("ifalias").trim() != crate::vpn::kernel::LINK_ALIAS
+}
+
+#[cfg(target_os = "linux")]
+fn nftables() -> Check {
+    const NAME: &str = "nftables";
+    match apps::resolve_executable("nft") {
+        Some(_) => Check::ok(NAME, tr!("doctor.nftables.ok")),
+        None => Check::warning(
+            NAME,
+            tr!("doctor.nftables.missing"),
+            tr!("doctor.nftables.hint"),
+        ),
+    }
+}
@@ -0,0 +1,228 @@
+//! Troubleshooting: the prerequisite checks of [`checks`], and the bundle
+//! attached to bug reports, a zip of the log files, the preferences and
+//! WireGuard config with their secrets masked, and what is known about the
+//! system (kernel, WireGuard tooling, routes, DNS).
+
+pub mod checks;
+
+use crate::logging;
+use crate::vpn::apps;
+use anyhow::{Context, Result};
+use std::fmt::Write as _;
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use vpn_core::secret::Secret;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+const MASK: &str = "***";
+/// Keys of the preferences and WireGuard config that are never exported.
+const SECRET_KEYS: &[&str] = &["saved_token", "PrivateKey", "PresharedKey"];
+const TOOLS: &[&str] = &["wg-quick", "wg", "ip", "nft", "resolvectl", "sudo"];
+
+/// State of the application at export time.
+pub struct Report {
+    pub config: serde_json::Value,
+    /// Config of the active tunnel.
+    pub wireguard: Option<Secret>,
+    pub connection: String,
+    pub interface: Option<String>,
+    pub proxy: bool,
+}
+
+/// Writes the bundle to the downloads directory and returns its path.
+pub fn export(report: &Report) -> Result<PathBuf> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let files = logging::log_files();
+    for path in &files {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let content =
+            std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        zip.start_file(format!("logs/{}", name), options)?;
+        zip.write_all(&content)?;
+    }
+    if files.is_empty() {
+        // No log file could be created, the viewer's buffer is all there is
+        zip.start_file("logs/recent.log", options)?;
+        for line in logging::recent() {
+            writeln!(
+                zip,
+                "{} {:>5} {}: {}",
+                line.time, line.level, line.target, line.message
+            )?;
+        }
+    }
+
+    let mut config = report.config.clone();
+    redact_json(&mut config);
+    zip.start_file("config.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&config)?.as_bytes())?;
+
+    if let Some(wireguard) = &report.wireguard {
+        zip.start_file("wireguard.conf", options)?;
+        zip.write_all(redact_ini(wireguard.expose()).as_bytes())?;
+    }
+
+    zip.start_file("environment.txt", options)?;
+    zip.write_all(environment(report).as_bytes())?;
+
+    zip.start_file("network.txt", options)?;
+    zip.write_all(network().as_bytes())?;
+
+    let bytes = zip.finish()?.into_inner();
+    let path = output_dir().join(format!("fire-vpn-diagnostic-{}.zip", unix_time()));
+    // Logs may contain addresses and e-mails
+    crate::private_file::write(&path, &bytes)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    tracing::info!(path = %path.display(), "diagnostics exported");
+    Ok(path)
+}
+
+fn output_dir() -> PathBuf {
+    dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SECRET_KEYS.contains(&key.as_str()) && !value.is_null() {
+                    *value = serde_json::Value::String(MASK.into());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+fn redact_ini(ini: &str) -> String {
+    let mut out = String::new();
+    for line in ini.lines() {
+        match line.split_once('=') {
+            Some((key, _)) if SECRET_KEYS.contains(&key.trim()) => {
+                writeln!(out, "{}= {}", key, MASK)
+            }
+            _ => writeln!(out, "{}", line),
+        }
+        .ok();
+    }
+    out
+}
+
+fn environment(report: &Report) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "vpn-desktop {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        out,
+        "Platform: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    if let Some(name) = read("/etc/os-release").and_then(|s| {
+        s.lines()
+            .find_map(|l| l.strip_prefix("PRETTY_NAME="))
+            .map(|v| v.trim_matches('"').to_string())
+    }) {
+        let _ = writeln!(out, "Distribution: {}", name);
+    }
+    if let Some(kernel) = read("/proc/version") {
+        let _ = writeln!(out, "Kernel: {}", kernel.trim());
+    }
+    for var in ["XDG_SESSION_TYPE", "XDG_CURRENT_DESKTOP"] {
+        if let Ok(value) = std::env::var(var) {
+            let _ = writeln!(out, "{}: {}", var, value);
+        }
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "WireGuard kernel module: {}",
+        yes_no(Path::new("/sys/module/wireguard").exists())
+    );
+    let _ = writeln!(
+        out,
+        "systemd-resolved: {}",
+        yes_no(Path::new("/run/systemd/resolve/io.systemd.Resolve").exists())
+    );
+    for tool in TOOLS {
+        let found = apps::resolve_executable(tool);
+        let _ = writeln!(
+            out,
+            "{}: {}",
+            tool,
+            found
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "absent".into())
+        );
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "Connection: {}", report.connection);
+    let _ = writeln!(
+        out,
+        "Mode: {}",
+        if report.proxy { "proxy" } else { "kernel" }
+    );
+    if let Some(interface) = &report.interface {
+        let _ = writeln!(out, "Interface: {}", interface);
+    }
+    out
+}
+
+/// Routing state; read-only commands that need no privileges.
+fn network() -> String {
+    let mut out = String::new();
+    let commands: &[&[&str]] = &[
+        &["ip", "addr"],
+        &["ip", "-4", "rule"],
+        &["ip", "-6", "rule"],
+        &["ip", "-4", "route", "show", "table", "all"],
+        &["ip", "-6", "route", "show", "table", "all"],
+        &["resolvectl", "status"],
+    ];
+    for args in commands {
+        let _ = writeln!(out, "$ {}", args.join(" "));
+        match Command::new(args[0]).args(&args[1..]).output() {
+            Ok(output) => {
+                out.push_str(&String::from_utf8_lossy(&output.stdout));
+                out.push_str(&String::from_utf8_lossy(&output.stderr));
+            }
+            Err(e) => {
+                let _ = writeln!(out, "unavailable: {}", e);
+            }
+        }
+        let _ = writeln!(out);
+    }
+    let _ = writeln!(out, "$ cat /etc/resolv.conf");
+    out.push_str(&read("/etc/resolv.conf").unwrap_or_else(|| "unavailable\n".into()));
+    out
+}
+
+fn read(path: &str) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
@@ -0,0 +1,400 @@
+//! English messages, the reference: every key is here.
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // Units
+    ("unit.bytes", "B"),
+    ("unit.kilobytes", "KB"),
+    ("unit.megabytes", "MB"),
+    ("unit.gigabytes", "GB"),
+    ("unit.terabytes", "TB"),
+    ("unit.per_second", "{amount}/s"),
+    ("unit.milliseconds", "{value} ms"),
+
+    // Errors of vpn-core
+    ("error.request.timeout", "The server took too long to answer"),
+    ("error.request.unreachable", "Cannot reach the server, check the network and the API address"),
+    ("error.request.invalid_response", "Unexpected answer from the server"),
+    ("error.request.status", "The server answered with HTTP error {status}"),
+    ("error.request.other", "Request failed: {error}"),
+    ("error.api.refused", "Refused by the server: {message}"),
+    ("error.api.unauthorized", "Session expired, log in again"),
+    ("error.auth.refused", "{message}"),
+    ("error.session.not_connected", "Not connected to a server"),
+    ("error.wireguard.missing_field", "Incomplete WireGuard configuration: {field} missing"),
+    ("error.wireguard.invalid_format", "Invalid WireGuard configuration"),
+    ("error.cidr.invalid_address", "Invalid address: {address}"),
+    ("error.cidr.invalid_prefix", "Invalid prefix length: {prefix}"),
+    ("error.dns.malformed", "Malformed DNS packet"),
+    ("error.dns.invalid_pattern", "Invalid domain: {pattern}"),
+    ("error.country.unknown", "Unknown country: {country}"),
+
+    // Preferences
+    ("preferences.server.last", "Last server used"),
+    ("preferences.server.fastest", "Fastest server"),
+    ("preferences.language.system", "System language"),
+    ("preferences.theme.system", "System theme"),
+    ("preferences.theme.dark", "Dark"),
+    ("preferences.theme.light", "Light"),
+    ("preferences.theme.high_contrast", "High contrast"),
+    ("preferences.power.auto", "When unfocused or on battery"),
+    ("preferences.power.always", "Always"),
+    ("preferences.power.off", "Never"),
+    ("preferences.error.url", "Invalid URL: {error}"),
+    ("preferences.error.url_scheme", "The URL must start with http:// or https://"),
+    ("preferences.error.url_host", "The URL must contain a host name"),
+    ("preferences.error.dns", "Invalid DNS address: {address}"),
+    ("preferences.error.mtu", "The MTU must be between {min} and {max}"),
+    ("preferences.error.keepalive", "The keepalive must be a number of seconds (0 to 65535)"),
+
+    // Server list
+    ("servers.sort.name", "Name"),
+    ("servers.sort.latency", "Latency"),
+    ("servers.sort.load", "Load"),
+    ("servers.logout", "Log out"),
+    ("servers.profile", "Profile"),
+    ("servers.heading", "AVAILABLE SERVERS"),
+    ("servers.empty", "No server available"),
+    ("servers.empty_hint", "Check your connection"),
+    ("servers.no_match", "No matching server"),
+    ("servers.clear_filters", "Clear the filters"),
+    ("servers.favourites", "★ FAVOURITES"),
+    ("servers.others", "Others"),
+    ("servers.globe.pick", "Click a server to choose it"),
+    ("servers.globe.unplaced.one", "{count} server without a known position"),
+    ("servers.globe.unplaced.other", "{count} servers without a known position"),
+    ("servers.globe.online", "online"),
+    ("servers.globe.offline", "offline"),
+    ("servers.search", "Search for a server or a country (Ctrl+F)"),
+    ("servers.online_only", "Online"),
+    ("servers.all_regions", "All regions"),
+    ("servers.all_countries", "All countries"),
+    ("servers.sort_by", "Sort by"),
+    ("servers.measure_again", "Measure again"),
+    ("servers.by_continent", "By continent"),
+    ("servers.show_list", "List"),
+    ("servers.show_map", "Map"),
+    ("servers.recent", "Recent"),
+    ("servers.card.load", "load {load} %"),
+    ("servers.card.online", "ONLINE"),
+    ("servers.card.offline", "OFFLINE"),
+    ("servers.card.favourite", "Add to favourites"),
+    ("servers.card.unfavourite", "Remove from favourites"),
+
+    // Shared actions
+    ("action.connect", "Connect"),
+    ("action.connecting", "Connecting..."),
+    ("action.disconnect", "Disconnect"),
+
+    // Connection screen
+    ("connection.duration", "DURATION"),
+    ("connection.connected", "CONNECTED"),
+    ("connection.details", "CONNECTION DETAILS"),
+    ("connection.public_ip", "Public IP"),
+    ("connection.local_ip", "Local IP"),
+    ("connection.interface", "Interface"),
+    ("connection.domain_routes", "Domain routes"),
+    ("connection.servers", "SERVERS"),
+    ("connection.active", "ACTIVE"),
+    ("connection.switch", "Switch"),
+    ("connection.repair", "TUNNEL REPAIR"),
+    ("connection.received", "Received"),
+    ("connection.sent", "Sent"),
+    ("connection.packets", "Packets"),
+    ("connection.handshake_age", "{seconds} s ago"),
+    ("connection.last_handshake", "Last handshake"),
+
+    // Tunnel
+    ("ipv6.tunneled", "Through the tunnel"),
+    ("ipv6.blocked", "Blocked"),
+    ("ipv6.direct", "Outside the tunnel"),
+    ("interface.error.length", "Between 1 and {max} characters"),
+    ("interface.error.reserved", "Reserved name"),
+    ("interface.error.characters", "Letters, digits, '-', '_' and '.' only"),
+    ("interface.error.exists", "The interface {name} already exists on this system, choose another name"),
+    ("interface.error.exists_kind", "The interface {name} already exists ({kind}), choose another name"),
+    ("interface.system_kind", "system interface"),
+    ("interface.error.no_module", "WireGuard kernel module unavailable (modprobe wireguard)"),
+    ("interface.error.gone", "The interface {name} no longer exists"),
+
+    // Tunnel repair
+    ("repair.network_changed", "Network change ({what})"),
+    ("repair.suspending", "Going to sleep"),
+    ("repair.resumed", "Waking up"),
+    ("repair.link_up", "{name} connected"),
+    ("repair.link_down", "{name} disconnected"),
+    ("repair.default_route", "{family} default route"),
+    ("repair.events_lost", "notifications lost"),
+    ("repair.refreshing", "Resolving the server, updating routes and DNS..."),
+    ("repair.waiting_handshake", "Waiting for the handshake..."),
+    ("repair.restored", "Tunnel restored"),
+    ("repair.failed", "Failed: {reason}"),
+    ("repair.reconnecting", "Reconnecting to {server}"),
+    ("repair.no_answer", "the server no longer answers"),
+    ("repair.unsupported", "not supported"),
+
+    // Notifications
+    ("notification.connected", "VPN connected"),
+    ("notification.disconnected", "VPN disconnected"),
+    ("notification.reconnecting", "Reconnecting the VPN"),
+    ("notification.handshake_lost", "VPN connection lost"),
+    ("notification.server_switched", "Server switched"),
+    ("notification.switch_failed", "Could not switch servers"),
+    ("notification.token_expired", "Session expired"),
+    ("notification.connected_body", "Connected to {server}"),
+    ("notification.disconnected_body", "Traffic no longer goes through the VPN"),
+    ("notification.handshake_lost_body", "{server} no longer answers, traffic is blocked or lost"),
+    ("notification.token_expired_body", "Log in again to connect"),
+    ("notification.kind.connected", "Connection established"),
+    ("notification.kind.disconnected", "Disconnection"),
+    ("notification.kind.reconnecting", "Reconnection after a network change"),
+    ("notification.kind.handshake_lost", "Handshake lost"),
+    ("notification.kind.server_switched", "Server switch"),
+    ("notification.kind.token_expired", "Session expired"),
+
+    // Tray
+    ("tray.connect_to", "Connect to {server}"),
+    ("tray.connect_last", "Connect to the last server"),
+    ("tray.connect_fastest", "Connect to the fastest"),
+    ("tray.disconnect", "Disconnect"),
+    ("tray.show", "Open the window"),
+    ("tray.quit", "Quit"),
+
+    // Networks
+    ("network.wifi_on", "Wi-Fi “{ssid}” ({interface})"),
+    ("network.wifi", "Wi-Fi “{ssid}”"),
+    ("network.gateway", "Gateway {mac}"),
+    ("network.interface", "Interface {name}"),
+    ("network.kind.gateway", "Gateway MAC"),
+    ("network.kind.interface", "Interface"),
+    ("network.error.ssid", "The SSID must be 1 to 32 bytes long"),
+    ("network.error.mac", "Invalid MAC address (format aa:bb:cc:dd:ee:ff)"),
+
+    // Configuration file
+    ("config.unreadable", "Cannot read {path} ({error}): default settings used"),
+    ("config.left_in_place", "file left in place"),
+    ("config.kept_as", "file kept as {path}"),
+    ("config.not_set_aside", "file could not be set aside: {error}"),
+    ("config.backup_restored", "previous backup restored"),
+    ("config.defaults_used", "default settings used"),
+    ("config.unusable", "Unusable configuration ({error}): {fallback}, {kept}"),
+    ("config.not_an_object", "JSON object expected"),
+    ("config.invalid_version", "invalid version: {version}"),
+    ("config.newer_version", "written by a newer version of the application (format {version})"),
+
+    // Prerequisite checks
+    ("doctor.api.ok", "{url} answers (HTTP {status})"),
+    ("doctor.api.unreachable", "{url} unreachable: {error}"),
+    ("doctor.api.hint", "Check the network connection and the API address in Settings > Connection"),
+    ("doctor.program.hint_macos", "brew install wireguard-tools, or enable the proxy mode"),
+    ("doctor.program.hint_windows", "Install WireGuard for Windows (wireguard.com/install), or enable the proxy mode"),
+    ("doctor.program.missing", "not found in the PATH"),
+    ("doctor.module.name", "WireGuard module"),
+    ("doctor.module.loaded", "loaded"),
+    ("doctor.module.installed", "installed, loaded on the first connection"),
+    ("doctor.module.missing", "missing from this kernel"),
+    ("doctor.module.hint", "Install WireGuard (kernel 5.6 or newer, otherwise the wireguard-dkms package) then: sudo modprobe wireguard. The proxy mode does without it."),
+    ("doctor.privileges.name", "Administrator rights"),
+    ("doctor.privileges.root", "running as root"),
+    ("doctor.privileges.restart", "Restart vpn-desktop"),
+    ("doctor.privileges.user", "<user>"),
+    ("doctor.privileges.sudoers", "Add with visudo -f /etc/sudoers.d/fire-vpn: {user} ALL=(root) NOPASSWD: {exe} tunnel *"),
+    ("doctor.privileges.no_sudo", "sudo not found"),
+    ("doctor.privileges.install_sudo", "Install sudo. {sudoers}"),
+    ("doctor.privileges.password", "sudo asks for a password for vpn-desktop tunnel"),
+    ("doctor.privileges.ok", "sudo without a password for vpn-desktop tunnel"),
+    ("doctor.dns.resolv_conf", "/etc/resolv.conf replaced while connected"),
+    ("doctor.dns.no_resolvectl", "systemd-resolved running but resolvectl not found"),
+    ("doctor.dns.hint", "Install resolvectl (systemd-resolved package depending on the distribution)"),
+    ("doctor.interface.hint", "Choose another name in Settings > Interface"),
+    ("doctor.interface.ok", "{interface} available"),
+    ("doctor.route.name", "Default route"),
+    ("doctor.route.iproute2", "Install iproute2"),
+    ("doctor.route.none", "none"),
+    ("doctor.route.hint", "Check the network connection (cable, Wi-Fi)"),
+    ("doctor.route.other_vpn", "held by another VPN ({device})"),
+    ("doctor.route.other_vpn_hint", "Disconnect the other VPN before connecting"),
+    ("doctor.route.ok", "via {devices}"),
+    ("doctor.nftables.ok", "present"),
+    ("doctor.nftables.missing", "nft not found: per-application and domain routing unavailable"),
+    ("doctor.nftables.hint", "Install the nftables package"),
+    ("action.back", "← Back"),
+
+    // Log viewer
+    ("logs.level.error", "Errors"),
+    ("logs.level.warn", "Warnings"),
+    ("logs.level.info", "Info"),
+    ("logs.level.debug", "Debug"),
+    ("logs.title", "Log"),
+    ("logs.copy", "Copy"),
+    ("logs.export", "Export the diagnostics"),
+    ("logs.empty", "No messages"),
+
+    // Installation check
+    ("doctor.title", "Installation check"),
+    ("doctor.intro", "The tunnel needs the API, the WireGuard module and administrator rights. The proxy mode only needs the API."),
+    ("doctor.continue", "Continue"),
+    ("doctor.run_again", "Run again"),
+    ("doctor.running", "Checking…"),
+    ("doctor.ready", "Everything is ready to connect"),
+    ("doctor.problems.one", "{count} problem will prevent connecting"),
+    ("doctor.problems.other", "{count} problems will prevent connecting"),
+    ("action.save", "Save"),
+    ("action.cancel", "Cancel"),
+
+    // Profile
+    ("profile.account", "ACCOUNT"),
+    ("profile.user", "User"),
+    ("profile.email", "Email"),
+    ("profile.status", "Status"),
+    ("profile.connected", "Connected"),
+    ("profile.disconnected", "Disconnected"),
+    ("profile.server", "Server"),
+    ("profile.edit", "Edit the profile"),
+    ("profile.logout", "Log out"),
+    ("profile.delete", "Delete the account"),
+    ("profile.edit_heading", "EDIT THE PROFILE"),
+    ("profile.username", "Username"),
+    ("profile.new_password", "New password"),
+    ("profile.password_hint", "At least 8 characters"),
+
+    // Login
+    ("login.tagline", "SECURE CONNECTION"),
+    ("login.resuming", "Resuming the session…"),
+    ("login.password", "Password"),
+    ("login.register", "Create an account"),
+    ("login.submit", "Log in"),
+    ("login.to_login", "Already have an account? Log in"),
+    ("login.to_register", "No account? Sign up"),
+    ("login.settings", "⚙ Settings"),
+
+    // Unfinished session
+    ("recovery.title", "The previous session did not end properly"),
+    ("recovery.detail", "Server {server} — the interface {interface} may still be up."),
+    ("recovery.detail_proxy", "Server {server} (proxy mode)."),
+    ("recovery.resume", "Resume"),
+    ("recovery.clean_up", "Clean up"),
+
+    // Settings
+    ("settings.title", "Settings"),
+    ("settings.connection", "CONNECTION"),
+    ("settings.api_url", "API address"),
+    ("settings.api_url_hint", "Used at the next login."),
+    ("settings.connect_on_launch", "Connect at launch"),
+    ("settings.connect_on_launch_hint", "Resumes the saved session at startup, or connects after logging in"),
+    ("settings.auto_connect_server", "Server for automatic connections"),
+    ("settings.kill_switch", "Kill switch"),
+    ("settings.kill_switch_hint", "Blocks traffic outside the tunnel while the connection is up. No effect in proxy mode or with domain rules."),
+    ("settings.trusted_networks", "TRUSTED NETWORKS"),
+    ("settings.connect_on_untrusted", "Connect outside trusted networks"),
+    ("settings.connect_on_untrusted_hint", "On an unknown network the VPN connects by itself; back on a trusted network, an automatic connection is closed"),
+    ("settings.network_unknown", "NetworkManager unavailable: current network unknown"),
+    ("settings.trust_ssid", "This Wi-Fi"),
+    ("settings.trust_gateway", "This gateway"),
+    ("settings.trust_interface", "This interface"),
+    ("settings.offline", "Offline"),
+    ("settings.no_trusted_network", "No trusted network"),
+    ("action.add", "Add"),
+    ("settings.appearance", "APPEARANCE"),
+    ("settings.language", "Language"),
+    ("settings.theme", "Theme"),
+    ("settings.power_saving", "Power saving"),
+    ("settings.power_saving_hint", "Slows the globe animation down. F12 shows the frame times."),
+    ("settings.close_to_tray", "Close to the notification area"),
+    ("settings.close_to_tray_hint", "The window hides when closed, the VPN stays connected"),
+    ("settings.no_tray", "No notification area found (StatusNotifierItem)"),
+    ("settings.notifications", "NOTIFICATIONS"),
+    ("settings.no_notifier", "No notification service found"),
+    ("settings.diagnostics", "DIAGNOSTICS"),
+    ("settings.diagnostics_hint", "Tunnel prerequisites, application log and an archive to attach to a bug report (keys and the session token are masked)"),
+    ("settings.show_logs", "Show the log"),
+    ("settings.check_installation", "Check the installation"),
+    ("settings.export_diagnostics", "Export the diagnostics"),
+    ("settings.proxy", "PROXY MODE"),
+    ("settings.proxy_enabled", "Proxy mode (no administrator rights)"),
+    ("settings.proxy_hint", "Only the applications set up with the proxy go through the VPN"),
+    ("settings.socks_port", "SOCKS5 port"),
+    ("settings.http_port", "HTTP port"),
+    ("settings.next_connection", "Applied at the next connection."),
+    ("settings.tunnel", "TUNNEL"),
+    ("settings.interface_name", "WireGuard interface name"),
+    ("action.apply", "Apply"),
+    ("settings.dns", "DNS servers (empty: those of the server)"),
+    ("settings.keepalive", "Keepalive in seconds (empty: the server's value)"),
+    ("settings.split_tunnel", "SPLIT TUNNELING"),
+    ("settings.bypass_local", "Bypass the local network"),
+    ("settings.bypass_local_hint", "RFC1918 (10/8, 172.16/12, 192.168/16) and link-local"),
+    ("settings.excluded_routes", "Ranges excluded from the tunnel"),
+    ("settings.no_excluded_route", "No custom range"),
+    ("settings.apps", "APPLICATIONS"),
+    ("settings.apps_hint", "If an application is marked VPN, all the others go outside the tunnel."),
+    ("settings.no_app_rule", "No rule"),
+    ("settings.launch", "Launch"),
+    ("settings.pick_app", "Choose an application..."),
+    ("settings.app_bypass", "Outside VPN"),
+    ("settings.app_tunnel", "Through VPN"),
+    ("apps.mode.bypass", "OUTSIDE VPN"),
+    ("apps.mode.tunnel", "VPN"),
+    ("settings.apps_moved", "Applications already open are moved automatically; their existing connections stay unchanged."),
+    ("settings.domains", "DOMAIN ROUTING"),
+    ("settings.domains_hint", "Only these domains go through the server; the rest of the traffic stays direct."),
+    ("settings.no_server", "No server available"),
+    ("settings.no_domain_rule", "All the traffic goes through this server"),
+    ("settings.domains_syntax", "\"example.com\" includes its subdomains, \"*.example.com\" only the subdomains. Applied at the next connection."),
+    ("settings.current_network", "Current network: {network} ({trust})"),
+    ("settings.trusted", "trusted"),
+    ("settings.untrusted", "untrusted"),
+
+    // Application
+    ("status.disconnected", "Disconnected"),
+    ("status.connecting", "Connecting to {server}..."),
+    ("status.switching", "Switching to {server}..."),
+    ("status.connected", "Connected to {server}"),
+    ("status.connected_proxy", "Connected to {server} (proxy)"),
+    ("status.searching_fastest", "Looking for the fastest server..."),
+    ("status.no_server", "No server available"),
+    ("status.error", "Error"),
+    ("status.failed", "Connection error"),
+    ("status.logged_out", "Not logged in"),
+    ("error.connect", "Connection error: {error}"),
+    ("error.register", "Sign-up error: {error}"),
+    ("error.load_servers", "Could not load the servers: {error}"),
+    ("error.proxy", "Proxy error: {error}"),
+    ("error.tunnel_config", "Tunnel configuration error: {error}"),
+    ("error.tunnel", "Tunnel error: {error} (Settings > Check the installation)"),
+    ("error.cleanup", "Cleanup error: {error}"),
+    ("recovery.login_required", "log in to release the server"),
+    ("error.kill_switch", "Kill switch error: {error}"),
+    ("error.app_routing", "Per-application routing error: {error}"),
+    ("error.domain_routing", "Domain routing error: {error}"),
+    ("error.switch", "Server switch error: {error}"),
+    ("error.generic", "Error: {error}"),
+    ("profile.name_too_short", "The name must be at least 3 characters long."),
+    ("profile.invalid_email", "Invalid email."),
+    ("profile.password_too_short", "The password must be at least 8 characters long."),
+    ("profile.updated", "Profile updated."),
+    ("profile.session_expired", "Session expired."),
+    ("settings.executable_not_found", "Executable not found."),
+    ("settings.launch_error", "Launch error: {error}"),
+    ("diagnostics.saved", "Diagnostics saved: {path}"),
+    ("diagnostics.export_error", "Export error: {error}"),
+    ("settings.invalid_route", "Invalid range: {error}"),
+    ("settings.invalid_domain", "Invalid domain: {error}"),
+
+    // Command line
+    ("cli.usage", "Usage:\n  vpn-desktop                                   Starts the graphical interface\n  vpn-desktop launch [--bypass|--tunnel] -- <command> [args...]\n      Runs a command outside the VPN (--bypass, the default) or inside it\n  vpn-desktop proxy [--server ID] [--socks PORT] [--http PORT] [--config FILE]\n      Starts the VPN in proxy mode (SOCKS5 and HTTP CONNECT), without root rights\n  vpn-desktop tunnel up|down|refresh|status --interface NAME [--manual-routes] [--block-ipv6] [--mtu N]\n      Kernel WireGuard interface (root, run through sudo by the graphical interface)\n  vpn-desktop tunnel apps up --interface NAME [--inclusive]|down|move --mode bypass|tunnel PID...\n      Per-application routing (root, run through sudo by the graphical interface)\n  vpn-desktop tunnel dns up|down|route add|del --interface NAME ADDR\n      Domain-based routing (root, run through sudo by the graphical interface)\n  vpn-desktop tunnel killswitch up --interface NAME [--allow CIDR]...|down\n      Kill switch (root, run through sudo by the graphical interface)\n  vpn-desktop doctor [--interface NAME]\n      Checks the prerequisites (API, WireGuard module, sudo, DNS, routes)"),
+    ("cli.unknown_command", "Unknown command: {command}"),
+    ("cli.no_server", "No server: use --server ID"),
+    ("cli.press_enter", "Press Enter to stop."),
+    ("cli.disconnect_error", "API error while disconnecting: {error}"),
+    ("cli.invalid_interface", "Invalid interface name: {error}"),
+    ("cli.linux_only", "The kernel backend only exists on Linux"),
+    ("cli.password_prompt", "Password ({email}): "),
+
+    // Power saving and frame times
+    ("power.pace.full", "Full speed"),
+    ("power.pace.throttled", "Throttled"),
+    ("power.pace.paused", "Paused"),
+    ("power.pace.stopped", "Stopped"),
+    ("frame_stats.summary", "{fps} fps · CPU {average} ms, max {max} ms"),
+    ("frame_stats.pace", "Animations: {pace}"),
+];
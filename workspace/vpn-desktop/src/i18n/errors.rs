@@ -0,0 +1,111 @@
+//! Errors of vpn-core in the language of the interface.
+//!
+//! vpn-core's `Display` messages are English and meant for the logs; the
+//! screens and notifications use [`Localize::localized`] instead, which
+//! maps each error kind to a message of the catalog. Messages sent by the
+//! API itself are shown as they are.
+
+use super::tr;
+use vpn_core::api::ApiError;
+use vpn_core::auth::AuthError;
+use vpn_core::cidr::CidrError;
+use vpn_core::country::CountryError;
+use vpn_core::dns::DnsError;
+use vpn_core::session::SessionError;
+use vpn_core::wireguard::WireGuardError;
+
+pub trait Localize {
+    /// Message for the user, in the current language.
+    fn localized(&self) -> String;
+}
+
+/// Network failures, common to the API and authentication requests.
+fn request_error(error: &reqwest::Error) -> String {
+    if error.is_timeout() {
+        tr!("error.request.timeout").to_string()
+    } else if error.is_connect() {
+        tr!("error.request.unreachable").to_string()
+    } else if error.is_decode() {
+        tr!("error.request.invalid_response").to_string()
+    } else if let Some(status) = error.status() {
+        tr!("error.request.status", status = status.as_u16())
+    } else {
+        tr!("error.request.other", error = error)
+    }
+}
+
+impl Localize for ApiError {
+    fn localized(&self) -> String {
+        match self {
+            ApiError::Request(e) => request_error(e),
+            ApiError::Api(message) => tr!("error.api.refused", message = message),
+            ApiError::Unauthorized(_) => tr!("error.api.unauthorized").to_string(),
+        }
+    }
+}
+
+impl Localize for AuthError {
+    fn localized(&self) -> String {
+        match self {
+            AuthError::Request(e) => request_error(e),
+            AuthError::Api(message) => tr!("error.auth.refused", message = message),
+        }
+    }
+}
+
+impl Localize for SessionError {
+    fn localized(&self) -> String {
+        match self {
+            SessionError::Auth(e) => e.localized(),
+            SessionError::Api(e) => e.localized(),
+            SessionError::WireGuard(e) => e.localized(),
+            SessionError::NotConnected => tr!("error.session.not_connected").to_string(),
+        }
+    }
+}
+
+impl Localize for WireGuardError {
+    fn localized(&self) -> String {
+        match self {
+            WireGuardError::MissingField(field) => {
+                tr!("error.wireguard.missing_field", field = field)
+            }
+            WireGuardError::InvalidFormat => tr!("error.wireguard.invalid_format").to_string(),
+            WireGuardError::Cidr(e) => e.localized(),
+        }
+    }
+}
+
+impl Localize for CidrError {
+    fn localized(&self) -> String {
+        match self {
+            CidrError::InvalidAddress(address) => {
+                tr!("error.cidr.invalid_address", address = address)
+            }
+            CidrError::InvalidPrefix(prefix) => {
+                tr!("error.cidr.invalid_prefix", prefix = prefix)
+            }
+        }
+    }
+}
+
+impl Localize for DnsError {
+    fn localized(&self) -> String {
+        match self {
+            DnsError::Truncated | DnsError::Malformed => tr!("error.dns.malformed").to_string(),
+            DnsError::InvalidPattern(pattern) => {
+                tr!("error.dns.invalid_pattern", pattern = pattern)
+            }
+        }
+    }
+}
+
+impl Localize for CountryError {
+    fn localized(&self) -> String {
+        match self {
+            CountryError::Unknown(country) => {
+                tr!("error.country.unknown", country = country)
+            }
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//! French messages.
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // Units
+    ("unit.bytes", "o"),
+    ("unit.kilobytes", "Ko"),
+    ("unit.megabytes", "Mo"),
+    ("unit.gigabytes", "Go"),
+    ("unit.terabytes", "To"),
+    ("unit.per_second", "{amount}/s"),
+    ("unit.milliseconds", "{value} ms"),
+
+    // Errors of vpn-core
+    ("error.request.timeout", "Le serveur a mis trop de temps à répondre"),
+    ("error.request.unreachable", "Impossible de joindre le serveur, vérifiez le réseau et l'adresse de l'API"),
+    ("error.request.invalid_response", "Réponse inattendue du serveur"),
+    ("error.request.status", "Le serveur a répondu par l'erreur HTTP {status}"),
+    ("error.request.other", "La requête a échoué : {error}"),
+    ("error.api.refused", "Refusé par le serveur : {message}"),
+    ("error.api.unauthorized", "Session expirée, identifiez-vous à nouveau"),
+    ("error.auth.refused", "{message}"),
+    ("error.session.not_connected", "Aucun serveur connecté"),
+    ("error.wireguard.missing_field", "Configuration WireGuard incomplète : {field} manquant"),
+    ("error.wireguard.invalid_format", "Configuration WireGuard invalide"),
+    ("error.cidr.invalid_address", "Adresse invalide : {address}"),
+    ("error.cidr.invalid_prefix", "Longueur de préfixe invalide : {prefix}"),
+    ("error.dns.malformed", "Paquet DNS mal formé"),
+    ("error.dns.invalid_pattern", "Domaine invalide : {pattern}"),
+    ("error.country.unknown", "Pays inconnu : {country}"),
+
+    // Preferences
+    ("preferences.server.last", "Dernier serveur utilisé"),
+    ("preferences.server.fastest", "Serveur le plus rapide"),
+    ("preferences.language.system", "Langue du système"),
+    ("preferences.theme.system", "Thème du système"),
+    ("preferences.theme.dark", "Sombre"),
+    ("preferences.theme.light", "Clair"),
+    ("preferences.theme.high_contrast", "Contraste élevé"),
+    ("preferences.power.auto", "Hors focus ou sur batterie"),
+    ("preferences.power.always", "Toujours"),
+    ("preferences.power.off", "Jamais"),
+    ("preferences.error.url", "URL invalide: {error}"),
+    ("preferences.error.url_scheme", "L'URL doit commencer par http:// ou https://"),
+    ("preferences.error.url_host", "L'URL doit contenir un nom d'hôte"),
+    ("preferences.error.dns", "Adresse DNS invalide: {address}"),
+    ("preferences.error.mtu", "Le MTU doit être compris entre {min} et {max}"),
+    ("preferences.error.keepalive", "Le keepalive doit être un nombre de secondes (0 à 65535)"),
+
+    // Server list
+    ("servers.sort.name", "Nom"),
+    ("servers.sort.latency", "Latence"),
+    ("servers.sort.load", "Charge"),
+    ("servers.logout", "Deconnexion"),
+    ("servers.profile", "Profil"),
+    ("servers.heading", "SERVEURS DISPONIBLES"),
+    ("servers.empty", "Aucun serveur disponible"),
+    ("servers.empty_hint", "Verifiez votre connexion"),
+    ("servers.no_match", "Aucun serveur ne correspond"),
+    ("servers.clear_filters", "Effacer les filtres"),
+    ("servers.favourites", "★ FAVORIS"),
+    ("servers.others", "Autres"),
+    ("servers.globe.pick", "Cliquez sur un serveur pour le choisir"),
+    ("servers.globe.unplaced.one", "{count} serveur sans position connue"),
+    ("servers.globe.unplaced.other", "{count} serveurs sans position connue"),
+    ("servers.globe.online", "en ligne"),
+    ("servers.globe.offline", "hors ligne"),
+    ("servers.search", "Rechercher un serveur ou un pays (Ctrl+F)"),
+    ("servers.online_only", "En ligne"),
+    ("servers.all_regions", "Toutes les régions"),
+    ("servers.all_countries", "Tous les pays"),
+    ("servers.sort_by", "Trier par"),
+    ("servers.measure_again", "Mesurer à nouveau"),
+    ("servers.by_continent", "Par continent"),
+    ("servers.show_list", "Liste"),
+    ("servers.show_map", "Carte"),
+    ("servers.recent", "Récents"),
+    ("servers.card.load", "charge {load} %"),
+    ("servers.card.online", "EN LIGNE"),
+    ("servers.card.offline", "HORS LIGNE"),
+    ("servers.card.favourite", "Ajouter aux favoris"),
+    ("servers.card.unfavourite", "Retirer des favoris"),
+
+    // Shared actions
+    ("action.connect", "Se connecter"),
+    ("action.connecting", "Connexion en cours..."),
+    ("action.disconnect", "Se deconnecter"),
+
+    // Connection screen
+    ("connection.duration", "DUREE"),
+    ("connection.connected", "CONNECTE"),
+    ("connection.details", "DETAILS DE CONNEXION"),
+    ("connection.public_ip", "IP publique"),
+    ("connection.local_ip", "IP locale"),
+    ("connection.interface", "Interface"),
+    ("connection.domain_routes", "Routes par domaine"),
+    ("connection.servers", "SERVEURS"),
+    ("connection.active", "ACTIF"),
+    ("connection.switch", "Changer"),
+    ("connection.repair", "RÉPARATION DU TUNNEL"),
+    ("connection.received", "Reçu"),
+    ("connection.sent", "Envoyé"),
+    ("connection.packets", "Paquets"),
+    ("connection.handshake_age", "il y a {seconds} s"),
+    ("connection.last_handshake", "Dernier handshake"),
+
+    // Tunnel
+    ("ipv6.tunneled", "Via le tunnel"),
+    ("ipv6.blocked", "Bloqué"),
+    ("ipv6.direct", "Hors tunnel"),
+    ("interface.error.length", "Entre 1 et {max} caractères"),
+    ("interface.error.reserved", "Nom réservé"),
+    ("interface.error.characters", "Lettres, chiffres, '-', '_' et '.' uniquement"),
+    ("interface.error.exists", "L'interface {name} existe déjà sur ce système, choisissez un autre nom"),
+    ("interface.error.exists_kind", "L'interface {name} existe déjà ({kind}), choisissez un autre nom"),
+    ("interface.system_kind", "interface système"),
+    ("interface.error.no_module", "Module noyau WireGuard indisponible (modprobe wireguard)"),
+    ("interface.error.gone", "L'interface {name} n'existe plus"),
+
+    // Tunnel repair
+    ("repair.network_changed", "Changement de réseau ({what})"),
+    ("repair.suspending", "Mise en veille"),
+    ("repair.resumed", "Sortie de veille"),
+    ("repair.link_up", "{name} connectée"),
+    ("repair.link_down", "{name} déconnectée"),
+    ("repair.default_route", "route par défaut {family}"),
+    ("repair.events_lost", "notifications perdues"),
+    ("repair.refreshing", "Résolution du serveur, routes et DNS mis à jour..."),
+    ("repair.waiting_handshake", "Attente du handshake..."),
+    ("repair.restored", "Tunnel rétabli"),
+    ("repair.failed", "Échec : {reason}"),
+    ("repair.reconnecting", "Reconnexion à {server}"),
+    ("repair.no_answer", "le serveur ne répond plus"),
+    ("repair.unsupported", "non pris en charge"),
+
+    // Notifications
+    ("notification.connected", "VPN connecté"),
+    ("notification.disconnected", "VPN déconnecté"),
+    ("notification.reconnecting", "Reconnexion du VPN"),
+    ("notification.handshake_lost", "Connexion VPN perdue"),
+    ("notification.server_switched", "Serveur changé"),
+    ("notification.switch_failed", "Échec du changement de serveur"),
+    ("notification.token_expired", "Session expirée"),
+    ("notification.connected_body", "Connecté à {server}"),
+    ("notification.disconnected_body", "Le trafic ne passe plus par le VPN"),
+    ("notification.handshake_lost_body", "{server} ne répond plus, le trafic est bloqué ou perdu"),
+    ("notification.token_expired_body", "Identifiez-vous à nouveau pour vous connecter"),
+    ("notification.kind.connected", "Connexion établie"),
+    ("notification.kind.disconnected", "Déconnexion"),
+    ("notification.kind.reconnecting", "Reconnexion après un changement de réseau"),
+    ("notification.kind.handshake_lost", "Perte du handshake"),
+    ("notification.kind.server_switched", "Changement de serveur"),
+    ("notification.kind.token_expired", "Session expirée"),
+
+    // Tray
+    ("tray.connect_to", "Se connecter à {server}"),
+    ("tray.connect_last", "Se connecter au dernier serveur"),
+    ("tray.connect_fastest", "Se connecter au plus rapide"),
+    ("tray.disconnect", "Se déconnecter"),
+    ("tray.show", "Ouvrir la fenêtre"),
+    ("tray.quit", "Quitter"),
+
+    // Networks
+    ("network.wifi_on", "Wi-Fi « {ssid} » ({interface})"),
+    ("network.wifi", "Wi-Fi « {ssid} »"),
+    ("network.gateway", "Passerelle {mac}"),
+    ("network.interface", "Interface {name}"),
+    ("network.kind.gateway", "MAC de la passerelle"),
+    ("network.kind.interface", "Interface"),
+    ("network.error.ssid", "Le SSID doit faire de 1 à 32 octets"),
+    ("network.error.mac", "Adresse MAC invalide (format aa:bb:cc:dd:ee:ff)"),
+
+    // Configuration file
+    ("config.unreadable", "Impossible de lire {path} ({error}) : réglages par défaut utilisés"),
+    ("config.left_in_place", "fichier laissé en place"),
+    ("config.kept_as", "fichier conservé sous {path}"),
+    ("config.not_set_aside", "fichier impossible à mettre de côté : {error}"),
+    ("config.backup_restored", "sauvegarde précédente restaurée"),
+    ("config.defaults_used", "réglages par défaut utilisés"),
+    ("config.unusable", "Configuration inutilisable ({error}) : {fallback}, {kept}"),
+    ("config.not_an_object", "objet JSON attendu"),
+    ("config.invalid_version", "version invalide : {version}"),
+    ("config.newer_version", "écrite par une version plus récente de l'application (format {version})"),
+
+    // Prerequisite checks
+    ("doctor.api.ok", "{url} répond (HTTP {status})"),
+    ("doctor.api.unreachable", "{url} injoignable : {error}"),
+    ("doctor.api.hint", "Vérifiez la connexion réseau et l'adresse de l'API dans Réglages > Connexion"),
+    ("doctor.program.hint_macos", "brew install wireguard-tools, ou activez le mode proxy"),
+    ("doctor.program.hint_windows", "Installez WireGuard pour Windows (wireguard.com/install), ou activez le mode proxy"),
+    ("doctor.program.missing", "introuvable dans le PATH"),
+    ("doctor.module.name", "Module WireGuard"),
+    ("doctor.module.loaded", "chargé"),
+    ("doctor.module.installed", "installé, chargé à la première connexion"),
+    ("doctor.module.missing", "absent de ce noyau"),
+    ("doctor.module.hint", "Installez WireGuard (noyau 5.6 ou plus récent, sinon le paquet wireguard-dkms) puis : sudo modprobe wireguard. Le mode proxy s'en passe."),
+    ("doctor.privileges.name", "Droits administrateur"),
+    ("doctor.privileges.root", "exécuté en root"),
+    ("doctor.privileges.restart", "Relancez vpn-desktop"),
+    ("doctor.privileges.user", "<utilisateur>"),
+    ("doctor.privileges.sudoers", "Ajoutez avec visudo -f /etc/sudoers.d/fire-vpn : {user} ALL=(root) NOPASSWD: {exe} tunnel *"),
+    ("doctor.privileges.no_sudo", "sudo introuvable"),
+    ("doctor.privileges.install_sudo", "Installez sudo. {sudoers}"),
+    ("doctor.privileges.password", "sudo demande un mot de passe pour vpn-desktop tunnel"),
+    ("doctor.privileges.ok", "sudo sans mot de passe pour vpn-desktop tunnel"),
+    ("doctor.dns.resolv_conf", "/etc/resolv.conf remplacé pendant la connexion"),
+    ("doctor.dns.no_resolvectl", "systemd-resolved actif mais resolvectl introuvable"),
+    ("doctor.dns.hint", "Installez resolvectl (paquet systemd-resolved selon la distribution)"),
+    ("doctor.interface.hint", "Choisissez un autre nom dans Réglages > Interface"),
+    ("doctor.interface.ok", "{interface} disponible"),
+    ("doctor.route.name", "Route par défaut"),
+    ("doctor.route.iproute2", "Installez iproute2"),
+    ("doctor.route.none", "aucune"),
+    ("doctor.route.hint", "Vérifiez la connexion réseau (câble, Wi-Fi)"),
+    ("doctor.route.other_vpn", "tenue par un autre VPN ({device})"),
+    ("doctor.route.other_vpn_hint", "Déconnectez l'autre VPN avant de vous connecter"),
+    ("doctor.route.ok", "via {devices}"),
+    ("doctor.nftables.ok", "présent"),
+    ("doctor.nftables.missing", "nft introuvable : routage par application et par domaine indisponible"),
+    ("doctor.nftables.hint", "Installez le paquet nftables"),
+    ("action.back", "← Retour"),
+
+    // Log viewer
+    ("logs.level.error", "Erreurs"),
+    ("logs.level.warn", "Avertissements"),
+    ("logs.level.info", "Infos"),
+    ("logs.level.debug", "Débogage"),
+    ("logs.title", "Journal"),
+    ("logs.copy", "Copier"),
+    ("logs.export", "Exporter le diagnostic"),
+    ("logs.empty", "Aucun message"),
+
+    // Installation check
+    ("doctor.title", "Vérification de l'installation"),
+    ("doctor.intro", "Le tunnel a besoin de l'API, du module WireGuard et de droits administrateur. Le mode proxy ne demande que l'API."),
+    ("doctor.continue", "Continuer"),
+    ("doctor.run_again", "Relancer"),
+    ("doctor.running", "Vérification en cours…"),
+    ("doctor.ready", "Tout est prêt pour se connecter"),
+    ("doctor.problems.one", "{count} problème empêchera la connexion"),
+    ("doctor.problems.other", "{count} problèmes empêcheront la connexion"),
+    ("action.save", "Enregistrer"),
+    ("action.cancel", "Annuler"),
+
+    // Profile
+    ("profile.account", "COMPTE"),
+    ("profile.user", "Utilisateur"),
+    ("profile.email", "Email"),
+    ("profile.status", "Statut"),
+    ("profile.connected", "Connecte"),
+    ("profile.disconnected", "Deconnecte"),
+    ("profile.server", "Serveur"),
+    ("profile.edit", "Modifier le profil"),
+    ("profile.logout", "Se deconnecter"),
+    ("profile.delete", "Supprimer le compte"),
+    ("profile.edit_heading", "MODIFIER LE PROFIL"),
+    ("profile.username", "Nom d'utilisateur"),
+    ("profile.new_password", "Nouveau mot de passe"),
+    ("profile.password_hint", "Min. 8 caracteres"),
+
+    // Login
+    ("login.tagline", "CONNEXION SECURISEE"),
+    ("login.resuming", "Reprise de la session…"),
+    ("login.password", "Mot de passe"),
+    ("login.register", "Creer un compte"),
+    ("login.submit", "Se connecter"),
+    ("login.to_login", "Deja un compte ? Se connecter"),
+    ("login.to_register", "Pas de compte ? S'inscrire"),
+    ("login.settings", "⚙ Réglages"),
+
+    // Unfinished session
+    ("recovery.title", "La session précédente ne s'est pas terminée correctement"),
+    ("recovery.detail", "Serveur {server} — l'interface {interface} est peut-être encore active."),
+    ("recovery.detail_proxy", "Serveur {server} (mode proxy)."),
+    ("recovery.resume", "Reprendre"),
+    ("recovery.clean_up", "Nettoyer"),
+
+    // Settings
+    ("settings.title", "Réglages"),
+    ("settings.connection", "CONNEXION"),
+    ("settings.api_url", "Adresse de l'API"),
+    ("settings.api_url_hint", "Utilisée à la prochaine ouverture de session."),
+    ("settings.connect_on_launch", "Se connecter au lancement"),
+    ("settings.connect_on_launch_hint", "Reprend la session enregistrée au démarrage, ou se connecte après l'identification"),
+    ("settings.auto_connect_server", "Serveur des connexions automatiques"),
+    ("settings.kill_switch", "Kill switch"),
+    ("settings.kill_switch_hint", "Bloque le trafic hors du tunnel tant que la connexion est active. Sans effet en mode proxy ou avec des règles par domaine."),
+    ("settings.trusted_networks", "RÉSEAUX DE CONFIANCE"),
+    ("settings.connect_on_untrusted", "Se connecter hors des réseaux de confiance"),
+    ("settings.connect_on_untrusted_hint", "Sur un réseau inconnu le VPN se connecte tout seul ; de retour sur un réseau de confiance, une connexion automatique est coupée"),
+    ("settings.network_unknown", "NetworkManager indisponible : réseau actuel inconnu"),
+    ("settings.trust_ssid", "Ce Wi-Fi"),
+    ("settings.trust_gateway", "Cette passerelle"),
+    ("settings.trust_interface", "Cette interface"),
+    ("settings.offline", "Hors ligne"),
+    ("settings.no_trusted_network", "Aucun réseau de confiance"),
+    ("action.add", "Ajouter"),
+    ("settings.appearance", "APPARENCE"),
+    ("settings.language", "Langue"),
+    ("settings.theme", "Thème"),
+    ("settings.power_saving", "Économie d'énergie"),
+    ("settings.power_saving_hint", "Ralentit l'animation du globe. F12 affiche les temps d'image."),
+    ("settings.close_to_tray", "Fermer dans la zone de notification"),
+    ("settings.close_to_tray_hint", "La fenêtre se cache à la fermeture, le VPN reste connecté"),
+    ("settings.no_tray", "Aucune zone de notification détectée (StatusNotifierItem)"),
+    ("settings.notifications", "NOTIFICATIONS"),
+    ("settings.no_notifier", "Aucun service de notifications détecté"),
+    ("settings.diagnostics", "DIAGNOSTIC"),
+    ("settings.diagnostics_hint", "Prérequis du tunnel, journal de l'application et archive à joindre à un rapport de bug (les clés et le jeton de session sont masqués)"),
+    ("settings.show_logs", "Voir le journal"),
+    ("settings.check_installation", "Vérifier l'installation"),
+    ("settings.export_diagnostics", "Exporter le diagnostic"),
+    ("settings.proxy", "MODE PROXY"),
+    ("settings.proxy_enabled", "Mode proxy (sans droits administrateur)"),
+    ("settings.proxy_hint", "Seules les applications configurées avec le proxy passent par le VPN"),
+    ("settings.socks_port", "Port SOCKS5"),
+    ("settings.http_port", "Port HTTP"),
+    ("settings.next_connection", "Appliqué à la prochaine connexion."),
+    ("settings.tunnel", "TUNNEL"),
+    ("settings.interface_name", "Nom de l'interface WireGuard"),
+    ("action.apply", "Appliquer"),
+    ("settings.dns", "Serveurs DNS (vide : ceux du serveur)"),
+    ("settings.keepalive", "Keepalive en secondes (vide : valeur du serveur)"),
+    ("settings.split_tunnel", "TUNNEL FRACTIONNE"),
+    ("settings.bypass_local", "Contourner le réseau local"),
+    ("settings.bypass_local_hint", "RFC1918 (10/8, 172.16/12, 192.168/16) et link-local"),
+    ("settings.excluded_routes", "Plages exclues du tunnel"),
+    ("settings.no_excluded_route", "Aucune plage personnalisée"),
+    ("settings.apps", "APPLICATIONS"),
+    ("settings.apps_hint", "Si une application est marquée VPN, toutes les autres passent hors du tunnel."),
+    ("settings.no_app_rule", "Aucune règle"),
+    ("settings.launch", "Lancer"),
+    ("settings.pick_app", "Choisir une application..."),
+    ("settings.app_bypass", "Hors VPN"),
+    ("settings.app_tunnel", "Via VPN"),
+    ("apps.mode.bypass", "HORS VPN"),
+    ("apps.mode.tunnel", "VPN"),
+    ("settings.apps_moved", "Les applications déjà ouvertes sont déplacées automatiquement; leurs connexions existantes restent inchangées."),
+    ("settings.domains", "ROUTAGE PAR DOMAINE"),
+    ("settings.domains_hint", "Seuls ces domaines passent par le serveur; le reste du trafic reste direct."),
+    ("settings.no_server", "Aucun serveur disponible"),
+    ("settings.no_domain_rule", "Tout le trafic passe par ce serveur"),
+    ("settings.domains_syntax", "\"example.com\" inclut ses sous-domaines, \"*.example.com\" uniquement les sous-domaines. Appliqué à la prochaine connexion."),
+    ("settings.current_network", "Réseau actuel : {network} ({trust})"),
+    ("settings.trusted", "de confiance"),
+    ("settings.untrusted", "non fiable"),
+
+    // Application
+    ("status.disconnected", "Déconnecté"),
+    ("status.connecting", "Connexion à {server}..."),
+    ("status.switching", "Changement vers {server}..."),
+    ("status.connected", "Connecté à {server}"),
+    ("status.connected_proxy", "Connecté à {server} (proxy)"),
+    ("status.searching_fastest", "Recherche du serveur le plus rapide..."),
+    ("status.no_server", "Aucun serveur disponible"),
+    ("status.error", "Erreur"),
+    ("status.failed", "Erreur de connexion"),
+    ("status.logged_out", "Non identifié"),
+    ("error.connect", "Erreur de connexion: {error}"),
+    ("error.register", "Erreur d'inscription: {error}"),
+    ("error.load_servers", "Erreur de chargement des serveurs: {error}"),
+    ("error.proxy", "Erreur proxy: {error}"),
+    ("error.tunnel_config", "Erreur de configuration du tunnel: {error}"),
+    ("error.tunnel", "Erreur tunnel: {error} (Réglages > Vérifier l'installation)"),
+    ("error.cleanup", "Erreur de nettoyage: {error}"),
+    ("recovery.login_required", "connectez-vous pour libérer le serveur"),
+    ("error.kill_switch", "Erreur du kill switch: {error}"),
+    ("error.app_routing", "Erreur de routage par application: {error}"),
+    ("error.domain_routing", "Erreur de routage par domaine: {error}"),
+    ("error.switch", "Erreur de switch: {error}"),
+    ("error.generic", "Erreur: {error}"),
+    ("profile.name_too_short", "Le nom doit contenir au moins 3 caracteres."),
+    ("profile.invalid_email", "Email invalide."),
+    ("profile.password_too_short", "Le mot de passe doit contenir au moins 8 caracteres."),
+    ("profile.updated", "Profil mis a jour."),
+    ("profile.session_expired", "Session expirée."),
+    ("settings.executable_not_found", "Exécutable introuvable."),
+    ("settings.launch_error", "Erreur de lancement: {error}"),
+    ("diagnostics.saved", "Diagnostic enregistré : {path}"),
+    ("diagnostics.export_error", "Erreur d'export: {error}"),
+    ("settings.invalid_route", "Plage invalide: {error}"),
+    ("settings.invalid_domain", "Domaine invalide: {error}"),
+
+    // Command line
+    ("cli.usage", "Usage:\n  vpn-desktop                                   Lance l'interface graphique\n  vpn-desktop launch [--bypass|--tunnel] -- <commande> [args...]\n      Lance une commande hors du VPN (--bypass, par defaut) ou dans le VPN\n  vpn-desktop proxy [--server ID] [--socks PORT] [--http PORT] [--config FILE]\n      Demarre le VPN en mode proxy (SOCKS5 et HTTP CONNECT), sans droits root\n  vpn-desktop tunnel up|down|refresh|status --interface NOM [--manual-routes] [--block-ipv6] [--mtu N]\n      Interface WireGuard noyau (root, appele via sudo par l'interface graphique)\n  vpn-desktop tunnel apps up --interface NOM [--inclusive]|down|move --mode bypass|tunnel PID...\n      Routage par application (root, appele via sudo par l'interface graphique)\n  vpn-desktop tunnel dns up|down|route add|del --interface NOM ADRESSE\n      Routage par domaine (root, appele via sudo par l'interface graphique)\n  vpn-desktop tunnel killswitch up --interface NOM [--allow CIDR]...|down\n      Kill switch (root, appele via sudo par l'interface graphique)\n  vpn-desktop doctor [--interface NOM]\n      Verifie les prerequis (API, module WireGuard, sudo, DNS, routes)"),
+    ("cli.unknown_command", "Commande inconnue: {command}"),
+    ("cli.no_server", "Aucun serveur: utilisez --server ID"),
+    ("cli.press_enter", "Appuyez sur Entrée pour arrêter."),
+    ("cli.disconnect_error", "Erreur API lors de la déconnexion: {error}"),
+    ("cli.invalid_interface", "Nom d'interface invalide: {error}"),
+    ("cli.linux_only", "Le backend noyau n'existe que sous Linux"),
+    ("cli.password_prompt", "Mot de passe ({email}): "),
+
+    // Power saving and frame times
+    ("power.pace.full", "Pleine vitesse"),
+    ("power.pace.throttled", "Ralenti"),
+    ("power.pace.paused", "En pause"),
+    ("power.pace.stopped", "Arrêté"),
+    ("frame_stats.summary", "{fps} i/s · CPU {average} ms, max {max} ms"),
+    ("frame_stats.pace", "Animations : {pace}"),
+];
@@ -0,0 +1,255 @@
+//! Translated text of the interface, the tray, notifications and the
+//! command line.
+//!
+//! Messages are looked up by key in one catalog per language ([`en`],
+//! [`fr`]); a key missing from a catalog falls back to English, then to the
+//! key itself. Placeholders are written `{name}` and filled by [`tr!`].
+//! Messages that depend on a count have a `.one` and an `.other` form,
+//! picked by [`plural`] with the rules of the language.
+//!
+//! The locale is process-wide, so that the tray and notification threads
+//! follow the window: it is set once from the system, then from the
+//! language preference whenever it changes.
+
+mod en;
+mod errors;
+mod fr;
+
+pub use errors::Localize;
+
+use crate::preferences::Language;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+/// Language the text is shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    English,
+    French,
+}
+
+static LOCALE: AtomicU8 = AtomicU8::new(0);
+
+impl Locale {
+    /// Language of the desktop session, from the usual environment
+    /// variables in gettext's order: `LANGUAGE` (a list), then `LC_ALL`,
+    /// `LC_MESSAGES` and `LANG`. English when none is set or supported.
+    pub fn system() -> Locale {
+        let mut candidates: Vec<String> = Vec::new();
+        for var in ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"] {
+            if let Ok(value) = std::env::var(var) {
+                candidates.extend(
+                    value
+                        .split(':')
+                        .filter(|v| !v.is_empty())
+                        .map(str::to_string),
+                );
+            }
+        }
+        candidates
+            .iter()
+            // "C" and "POSIX" mean no translation
+            .take_while(|c| !matches!(c.as_str(), "C" | "POSIX") && !c.starts_with("C."))
+            .find_map(|c| Locale::from_tag(c))
+            .unwrap_or(Locale::English)
+    }
+
+    /// `fr_FR.UTF-8`, `fr-CA`, `en`…
+    fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.split(['_', '-', '.', '@']).next()?;
+        match language.to_ascii_lowercase().as_str() {
+            "fr" => Some(Locale::French),
+            "en" => Some(Locale::English),
+            _ => None,
+        }
+    }
+
+    pub fn from_preference(language: Language) -> Locale {
+        match language {
+            Language::System => Locale::system(),
+            Language::French => Locale::French,
+            Language::English => Locale::English,
+        }
+    }
+
+    pub fn current() -> Locale {
+        match LOCALE.load(Ordering::Relaxed) {
+            1 => Locale::French,
+            _ => Locale::English,
+        }
+    }
+
+    /// For the country and continent names of vpn-core.
+    pub fn country_language(self) -> vpn_core::country::Language {
+        match self {
+            Locale::English => vpn_core::country::Language::English,
+            Locale::French => vpn_core::country::Language::French,
+        }
+    }
+
+    fn messages(self) -> &'static HashMap<&'static str, &'static str> {
+        static ENGLISH: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        static FRENCH: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        let (cell, catalog) = match self {
+            Locale::English => (&ENGLISH, en::MESSAGES),
+            Locale::French => (&FRENCH, fr::MESSAGES),
+        };
+        cell.get_or_init(|| catalog.iter().copied().collect())
+    }
+
+    /// Singular for 1 in English, for 0 and 1 in French.
+    fn is_singular(self, count: u64) -> bool {
+        match self {
+            Locale::English => count == 1,
+            Locale::French => count <= 1,
+        }
+    }
+
+    fn decimal_separator(self) -> char {
+        match self {
+            Locale::English => '.',
+            Locale::French => ',',
+        }
+    }
+
+    fn group_separator(self) -> char {
+        match self {
+            Locale::English => ',',
+            // No-break space: the thin one is missing from egui's font
+            Locale::French => '\u{a0}',
+        }
+    }
+}
+
+pub fn set_locale(locale: Locale) {
+    let value = match locale {
+        Locale::English => 0,
+        Locale::French => 1,
+    };
+    LOCALE.store(value, Ordering::Relaxed);
+}
+
+/// Message for `key` in the current language.
+pub fn message(key: &'static str) -> &'static str {
+    Locale::current()
+        .messages()
+        .get(key)
+        .or_else(|| Locale::English.messages().get(key))
+        .copied()
+        .unwrap_or(key)
+}
+
+/// Message for `key` with its `{name}` placeholders filled.
+pub fn message_with(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    fill(message(key), args)
+}
+
+/// `key.one` or `key.other` depending on `count`, which fills `{count}`.
+pub fn plural(key: &'static str, count: u64, args: &[(&str, &dyn Display)]) -> String {
+    let locale = Locale::current();
+    let form = if locale.is_singular(count) {
+        "one"
+    } else {
+        "other"
+    };
+    let full_key = format!("{key}.{form}");
+    let template = [locale, Locale::English]
+        .into_iter()
+        .find_map(|l| l.messages().get(full_key.as_str()).copied())
+        .unwrap_or(key);
+    let count = integer(count);
+    let mut all: Vec<(&str, &dyn Display)> = vec![("count", &count)];
+    all.extend_from_slice(args);
+    fill(template, &all)
+}
+
+fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+    text
+}
+
+/// `tr!("key")` for a plain message, `tr!("key", name = value, ...)` to
+/// fill placeholders.
+macro_rules! tr {
+    ($key:literal) => {
+        $crate::i18n::message($key)
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::message_with(
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+pub(crate) use tr;
+
+/// Thousands grouped: "12,345" or "12 345".
+pub fn integer(value: u64) -> String {
+    let digits = value.to_string();
+    let separator = Locale::current().group_separator();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// `decimals` digits after the separator of the current language.
+pub fn decimal(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value.abs());
+    let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+    let mut result = String::new();
+    if value.is_sign_negative() && text.chars().any(|c| matches!(c, '1'..='9')) {
+        result.push('-');
+    }
+    result.push_str(&integer(whole.parse().unwrap_or(0)));
+    if !fraction.is_empty() {
+        result.push(Locale::current().decimal_separator());
+        result.push_str(fraction);
+    }
+    result
+}
+
+/// Human-readable byte count: "1.4 MB", "1,4 Mo".
+pub fn bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = [
+        "unit.bytes",
+        "unit.kilobytes",
+        "unit.megabytes",
+        "unit.gigabytes",
+        "unit.terabytes",
+    ];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    let number = if unit == 0 {
+        integer(bytes)
+    } else {
+        decimal(value, 1)
+    };
+    format!("{}\u{a0}{}", number, message(UNITS[unit]))
+}
+
+/// Bytes per second.
+pub fn rate(bytes_per_sec: f64) -> String {
+    tr!("unit.per_second", amount = bytes(bytes_per_sec as u64))
+}
+
+/// Round trip time: "42 ms".
+pub fn milliseconds(duration: std::time::Duration) -> String {
+    tr!(
+        "unit.milliseconds",
+        value = integer(duration.as_millis() as u64)
+    )
+}
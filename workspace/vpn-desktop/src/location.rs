@@ -0,0 +1,87 @@
+//! Rough location of this machine, where the globe's arc starts.
+//!
+//! No lookup service is asked: the system time zone names a city
+//! ("Europe/Paris") and tzdata's `zone.tab` gives its coordinates. Close
+//! enough for a drawing, and nothing leaves the machine.
+
+use std::path::Path;
+
+const ZONE_TABLES: [&str; 2] = [
+    "/usr/share/zoneinfo/zone1970.tab",
+    "/usr/share/zoneinfo/zone.tab",
+];
+
+/// `(latitude, longitude)` in degrees, `None` with UTC or an unknown zone.
+pub fn estimate() -> Option<(f32, f32)> {
+    let zone = time_zone()?;
+    let location = ZONE_TABLES
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .find_map(|table| find_zone(&table, &zone));
+    tracing::debug!(zone, location = ?location, "estimated location");
+    location
+}
+
+/// `TZ`, then `/etc/timezone`, then the `/etc/localtime` link.
+fn time_zone() -> Option<String> {
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.trim_start_matches(':');
+        if !tz.is_empty() {
+            return Some(tz.to_string());
+        }
+    }
+    if let Ok(tz) = std::fs::read_to_string("/etc/timezone") {
+        let tz = tz.trim();
+        if !tz.is_empty() {
+            return Some(tz.to_string());
+        }
+    }
+    let target = std::fs::read_link(Path::new("/etc/localtime")).ok()?;
+    let target = target.to_string_lossy();
+    let (_, zone) = target.split_once("zoneinfo/")?;
+    Some(zone.to_string())
+}
+
+/// Lines are `countries<TAB>coordinates<TAB>zone[<TAB>comment]`.
+fn find_zone(table: &str, zone: &str) -> Option<(f32, f32)> {
+    table
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| {
+            let mut fields = line.split('\t');
+            let coordinates = fields.nth(1)?;
+            if fields.next()? != zone {
+                return None;
+            }
+            parse_iso6709(coordinates)
+        })
+}
+
+/// `±DDMM[SS]±DDDMM[SS]`, as in `+4852+00220`.
+fn parse_iso6709(text: &str) -> Option<(f32, f32)> {
+    let split = text.get(1..)?.find(['+', '-'])? + 1;
+    let (lat, lon) = text.split_at(split);
+    Some((parse_angle(lat, 2)?, parse_angle(lon, 3)?))
+}
+
+fn parse_angle(text: &str, degree_digits: usize) -> Option<f32> {
+    let sign = match text.as_bytes().first()? {
+        b'+' => 1.0,
+        b'-' => -1.0,
+        _ => return None,
+    };
+    let digits = text.get(1..)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) || digits.len() < degree_digits + 2 {
+        return None;
+    }
+    let part = |range: std::ops::Range<usize>| -> f32 {
+        digits
+            .get(range)
+            .and_then(|d| d.parse::<f32>().ok())
+            .unwrap_or(0.0)
+    };
+    let degrees = part(0..degree_digits);
+    let minutes = part(degree_digits..degree_digits + 2);
+    let seconds = part(degree_digits + 2..degree_digits + 4);
+    Some(sign * (degrees + minutes / 60.0 + seconds / 3600.0))
+}
@@ -0,0 +1,221 @@
+//! Logging setup.
+//!
+//! The GUI logs to a file rotated daily (the last week is kept, and shipped
+//! in the diagnostics bundle), to stderr for warnings, and to an in-memory
+//! buffer read by the log viewer. Subcommands only log to stderr: `tunnel`
+//! runs as root and must not create files in the user's directories.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::path::PathBuf;
+use std::process::Output;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt as tracing_fmt, Layer};
+
+const FILE_PREFIX: &str = "vpn-desktop";
+const FILE_SUFFIX: &str = "log";
+const KEPT_FILES: usize = 7;
+/// Lines kept for the log viewer.
+const MEMORY_LINES: usize = 2000;
+
+/// A log event as shown by the log viewer.
+#[derive(Clone)]
+pub struct LogLine {
+    /// `HH:MM:SS`, UTC like the log file.
+    pub time: String,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+static RECENT: Mutex<VecDeque<LogLine>> = Mutex::new(VecDeque::new());
+
+/// Directory of the log files.
+pub fn log_dir() -> Option<PathBuf> {
+    let mut path = dirs::state_dir().or_else(dirs::data_local_dir)?;
+    path.push("vpn-client");
+    path.push("logs");
+    Some(path)
+}
+
+/// Log files, oldest first.
+pub fn log_files() -> Vec<PathBuf> {
+    let dir = match log_dir() {
+        Some(d) => d,
+        None => return Vec::new(),
+    };
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| {
+                    p.file_name()
+                        .map(|n| n.to_string_lossy().starts_with(FILE_PREFIX))
+                        .unwrap_or(false)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    // Dated names sort chronologically
+    files.sort();
+    files
+}
+
+/// Events logged since the start, oldest first.
+pub fn recent() -> Vec<LogLine> {
+    lock().iter().cloned().collect()
+}
+
+fn lock() -> std::sync::MutexGuard<'static, VecDeque<LogLine>> {
+    RECENT.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn targets() -> Targets {
+    Targets::new()
+        .with_target("vpn_core", Level::DEBUG)
+        .with_target("vpn_desktop", Level::DEBUG)
+        .with_default(Level::WARN)
+}
+
+pub fn init_gui() {
+    let file = log_dir().and_then(|dir| {
+        crate::private_file::create_dir(&dir).ok()?;
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(FILE_PREFIX)
+            .filename_suffix(FILE_SUFFIX)
+            .max_log_files(KEPT_FILES)
+            .build(dir)
+            .ok()
+    });
+
+    let _ = tracing_subscriber::registry()
+        .with(targets())
+        .with(file.map(|writer| tracing_fmt::layer().with_ansi(false).with_writer(writer)))
+        .with(MemoryLayer)
+        .with(stderr_layer())
+        .try_init();
+}
+
+pub fn init_cli() {
+    let _ = tracing_subscriber::registry()
+        .with(targets())
+        .with(stderr_layer())
+        .try_init();
+}
+
+fn stderr_layer<S>() -> impl Layer<S>
+where
+    S: Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    tracing_fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_filter(LevelFilter::WARN)
+}
+
+/// Logs an external command and what it printed. Its stdin is never
+/// logged: it carries the WireGuard private key.
+pub fn command(args: &[&str], output: &Output) {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let command = args.join(" ");
+    if output.status.success() {
+        tracing::debug!(
+            %command,
+            status = %output.status,
+            stdout = %stdout.trim(),
+            stderr = %stderr.trim(),
+            "command"
+        );
+    } else {
+        tracing::warn!(
+            %command,
+            status = %output.status,
+            stdout = %stdout.trim(),
+            stderr = %stderr.trim(),
+            "command failed"
+        );
+    }
+}
+
+/// Same as [`command`] for a read-only query polled while connected: only
+/// its failures reach the log viewer and the file, its successes are traced.
+pub fn query(args: &[&str], output: &Output) {
+    if !output.status.success() {
+        command(args, output);
+        return;
+    }
+    tracing::trace!(
+        command = %args.join(" "),
+        stdout = %String::from_utf8_lossy(&output.stdout).trim(),
+        "query"
+    );
+}
+
+/// Feeds the log viewer.
+struct MemoryLayer;
+
+impl<S: Subscriber> Layer<S> for MemoryLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = LineVisitor::default();
+        event.record(&mut visitor);
+        let line = LogLine {
+            time: time_of_day(SystemTime::now()),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.message + &visitor.fields,
+        };
+
+        let mut recent = lock();
+        if recent.len() == MEMORY_LINES {
+            recent.pop_front();
+        }
+        recent.push_back(line);
+    }
+}
+
+/// Message followed by the ` key=value` fields.
+#[derive(Default)]
+struct LineVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for LineVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else if !value.is_empty() {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.record_str(field, &format!("{:?}", value));
+        }
+    }
+}
+
+fn time_of_day(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
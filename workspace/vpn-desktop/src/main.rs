@@ -1,10 +1,36 @@
 mod app;
+mod cli;
+mod config;
+mod diagnostics;
+mod i18n;
+mod location;
+mod logging;
+mod network;
+mod notifications;
+mod power;
+mod preferences;
+mod private_file;
+mod server_list;
+mod tray;
 mod ui;
 mod vpn;
 
 use app::VpnApp;
 
 fn main() -> eframe::Result<()> {
+    // The command line has no preferences of its own
+    i18n::set_locale(i18n::Locale::system());
+    if let Some(code) = cli::run() {
+        std::process::exit(code);
+    }
+
+    logging::init_gui();
+    tracing::info!(version = env!("CARGO_PKG_VERSION"), "starting");
+
+    // Tear the tunnel down on SIGTERM/SIGINT and panics, not only on exit
+    vpn::recovery::install_panic_hook();
+    vpn::recovery::install_signal_handlers();
+
     // Force X11 on WSL2 — Wayland via WSLg often causes "Broken pipe" errors
     if std::path::Path::new("/proc/sys/fs/binfmt_misc/WSLInterop").exists()
         || std::env::var("WSL_DISTRO_NAME").is_ok()
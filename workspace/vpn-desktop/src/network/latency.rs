@@ -0,0 +1,51 @@
+//! Picks the server answering fastest.
+//!
+//! WireGuard stays silent to unauthenticated UDP, so the probe is a TCP
+//! connection to the server's WireGuard port: nothing listens there, and the
+//! refusal comes back after one round trip.
+
+use std::io::ErrorKind;
+use std::net::{IpAddr, SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+use vpn_core::api::Server;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Round trip to the server, `None` if it did not answer in time.
+pub fn probe(server: &Server) -> Option<Duration> {
+    let ip: IpAddr = server.ip.parse().ok()?;
+    let addr = SocketAddr::new(ip, server.listen_port);
+    let started = Instant::now();
+    match TcpStream::connect_timeout(&addr, PROBE_TIMEOUT) {
+        Ok(_) => Some(started.elapsed()),
+        Err(e) if e.kind() == ErrorKind::ConnectionRefused => Some(started.elapsed()),
+        Err(_) => None,
+    }
+}
+
+/// Probes the active servers in parallel; blocks up to [`PROBE_TIMEOUT`].
+/// Servers that did not answer are left out.
+pub fn probe_all(servers: &[Server]) -> Vec<(u64, Duration)> {
+    let probes: Vec<_> = servers
+        .iter()
+        .filter(|s| s.is_active)
+        .cloned()
+        .map(|server| std::thread::spawn(move || (server.id, probe(&server))))
+        .collect();
+    probes
+        .into_iter()
+        .filter_map(|p| p.join().ok())
+        .filter_map(|(id, rtt)| Some((id, rtt?)))
+        .collect()
+}
+
+/// Same probes as [`probe_all`].
+pub fn fastest(servers: &[Server]) -> Option<u64> {
+    let (id, rtt) = probe_all(servers).into_iter().min_by_key(|(_, rtt)| *rtt)?;
+    tracing::info!(
+        server_id = id,
+        rtt_ms = rtt.as_millis() as u64,
+        "fastest server"
+    );
+    Some(id)
+}
@@ -0,0 +1,348 @@
+//! Network the machine is on, to connect automatically outside trusted ones.
+//!
+//! The current network comes from a [`NetworkSource`], NetworkManager on
+//! Linux, or a scripted one in the tests. A [`NetworkWatcher`] polls it in
+//! the background and reports changes.
+
+pub mod latency;
+#[cfg(target_os = "linux")]
+mod networkmanager;
+
+use crate::i18n::tr;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Connection carrying the default route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkInfo {
+    pub interface: String,
+    /// Wi-Fi only.
+    pub ssid: Option<String>,
+    /// MAC address of the IPv4 gateway, lowercase, once it is in the
+    /// neighbour table.
+    pub gateway_mac: Option<String>,
+}
+
+impl NetworkInfo {
+    pub fn label(&self) -> String {
+        match &self.ssid {
+            Some(ssid) => tr!("network.wifi_on", ssid = ssid, interface = self.interface),
+            None => self.interface.clone(),
+        }
+    }
+}
+
+/// A network on which the VPN is not needed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum TrustRule {
+    Ssid(String),
+    GatewayMac(String),
+    Interface(String),
+}
+
+impl TrustRule {
+    pub fn matches(&self, network: &NetworkInfo) -> bool {
+        match self {
+            TrustRule::Ssid(ssid) => network.ssid.as_ref() == Some(ssid),
+            TrustRule::GatewayMac(mac) => network
+                .gateway_mac
+                .as_ref()
+                .is_some_and(|m| m.eq_ignore_ascii_case(mac)),
+            TrustRule::Interface(name) => &network.interface == name,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            TrustRule::Ssid(ssid) => tr!("network.wifi", ssid = ssid),
+            TrustRule::GatewayMac(mac) => tr!("network.gateway", mac = mac),
+            TrustRule::Interface(name) => tr!("network.interface", name = name),
+        }
+    }
+}
+
+/// Kind of rule added by hand in the settings.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TrustKind {
+    #[default]
+    Ssid,
+    GatewayMac,
+    Interface,
+}
+
+impl TrustKind {
+    pub const ALL: [TrustKind; 3] = [TrustKind::Ssid, TrustKind::GatewayMac, TrustKind::Interface];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TrustKind::Ssid => "Wi-Fi (SSID)",
+            TrustKind::GatewayMac => tr!("network.kind.gateway"),
+            TrustKind::Interface => tr!("network.kind.interface"),
+        }
+    }
+
+    pub fn rule(self, value: &str) -> Result<TrustRule, String> {
+        let value = value.trim();
+        match self {
+            // 32 bytes at most in 802.11
+            TrustKind::Ssid if value.is_empty() || value.len() > 32 => {
+                Err(tr!("network.error.ssid").into())
+            }
+            TrustKind::Ssid => Ok(TrustRule::Ssid(value.to_string())),
+            TrustKind::GatewayMac => parse_mac(value).map(TrustRule::GatewayMac),
+            TrustKind::Interface => crate::vpn::tunnel::validate_interface_name(value)
+                .map(|()| TrustRule::Interface(value.to_string())),
+        }
+    }
+}
+
+pub fn is_trusted(rules: &[TrustRule], network: &NetworkInfo) -> bool {
+    rules.iter().any(|r| r.matches(network))
+}
+
+/// Checks a gateway MAC typed by the user, normalized to lowercase.
+pub fn parse_mac(input: &str) -> Result<String, String> {
+    let mac = input.trim().to_ascii_lowercase().replace('-', ":");
+    let valid = mac.split(':').count() == 6
+        && mac
+            .split(':')
+            .all(|b| b.len() == 2 && b.chars().all(|c| c.is_ascii_hexdigit()));
+    if valid {
+        Ok(mac)
+    } else {
+        Err(tr!("network.error.mac").into())
+    }
+}
+
+/// Where the current network comes from.
+pub trait NetworkSource: Send {
+    /// `None` while offline.
+    fn current(&self) -> anyhow::Result<Option<NetworkInfo>>;
+}
+
+/// Trusted networks need NetworkManager, Linux only.
+pub fn is_supported() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// NetworkManager where available.
+pub fn system_source() -> Option<Box<dyn NetworkSource>> {
+    #[cfg(target_os = "linux")]
+    {
+        match networkmanager::NetworkManager::connect() {
+            Ok(nm) => return Some(Box::new(nm)),
+            Err(e) => tracing::info!(error = %e, "NetworkManager unavailable"),
+        }
+    }
+    None
+}
+
+/// Polls a source in the background; stops when dropped.
+pub struct NetworkWatcher {
+    changes: Receiver<Option<NetworkInfo>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl NetworkWatcher {
+    /// `on_change` runs on the watcher thread after each change, to wake the UI.
+    pub fn start(source: Box<dyn NetworkSource>, on_change: impl Fn() + Send + 'static) -> Self {
+        Self::start_every(source, POLL_INTERVAL, on_change)
+    }
+
+    fn start_every(
+        source: Box<dyn NetworkSource>,
+        interval: Duration,
+        on_change: impl Fn() + Send + 'static,
+    ) -> Self {
+        let (sender, changes) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::clone(&stop);
+        std::thread::spawn(move || {
+            let mut last = None;
+            while !stopped.load(Ordering::Relaxed) {
+                match source.current() {
+                    Ok(network) if last.as_ref() != Some(&network) => {
+                        tracing::info!(network = ?network, "network changed");
+                        last = Some(network.clone());
+                        if sender.send(network).is_err() {
+                            return;
+                        }
+                        on_change();
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::debug!(error = %e, "cannot read the current network"),
+                }
+                std::thread::sleep(interval);
+            }
+        });
+        Self { changes, stop }
+    }
+
+    /// Latest network if it changed since the previous call.
+    pub fn poll(&self) -> Option<Option<NetworkInfo>> {
+        self.changes.try_iter().last()
+    }
+}
+
+impl Drop for NetworkWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Mutex;
+
+    /// Returns the scripted readings in turn, then offline.
+    struct FakeSource(Mutex<VecDeque<anyhow::Result<Option<NetworkInfo>>>>);
+
+    impl FakeSource {
+        fn new(readings: Vec<anyhow::Result<Option<NetworkInfo>>>) -> Box<Self> {
+            Box::new(Self(Mutex::new(readings.into())))
+        }
+    }
+
+    impl NetworkSource for FakeSource {
+        fn current(&self) -> anyhow::Result<Option<NetworkInfo>> {
+            self.0.lock().unwrap().pop_front().unwrap_or(Ok(None))
+        }
+    }
+
+    fn wifi(ssid: &str) -> NetworkInfo {
+        NetworkInfo {
+            interface: "wlan0".to_string(),
+            ssid: Some(ssid.to_string()),
+            gateway_mac: Some("aa:bb:cc:dd:ee:ff".to_string()),
+        }
+    }
+
+    #[test]
+    fn watcher_reports_changes_only() {
+        let source = FakeSource::new(vec![
+            Ok(Some(wifi("home"))),
+            Ok(Some(wifi("home"))),
+            Err(anyhow::anyhow!("D-Bus unavailable")),
+            Ok(Some(wifi("home"))),
+            Ok(Some(wifi("office"))),
+            Ok(None),
+        ]);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = Arc::clone(&calls);
+        let watcher = NetworkWatcher::start_every(source, Duration::from_millis(1), move || {
+            counted.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let next = || {
+            watcher
+                .changes
+                .recv_timeout(Duration::from_secs(5))
+                .unwrap()
+        };
+        assert_eq!(next(), Some(wifi("home")));
+        assert_eq!(next(), Some(wifi("office")));
+        assert_eq!(next(), None);
+        // Offline from then on: nothing more
+        assert!(watcher
+            .changes
+            .recv_timeout(Duration::from_millis(50))
+            .is_err());
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn watcher_reports_offline_at_start() {
+        let watcher = NetworkWatcher::start_every(
+            FakeSource::new(Vec::new()),
+            Duration::from_millis(1),
+            || {},
+        );
+        let first = watcher.changes.recv_timeout(Duration::from_secs(5));
+        assert_eq!(first.unwrap(), None);
+    }
+
+    #[test]
+    fn rules_match_their_field() {
+        let network = wifi("home");
+        assert!(TrustRule::Ssid("home".to_string()).matches(&network));
+        assert!(!TrustRule::Ssid("Home".to_string()).matches(&network));
+        assert!(TrustRule::GatewayMac("AA:BB:CC:DD:EE:FF".to_string()).matches(&network));
+        assert!(!TrustRule::GatewayMac("aa:bb:cc:dd:ee:00".to_string()).matches(&network));
+        assert!(TrustRule::Interface("wlan0".to_string()).matches(&network));
+        assert!(!TrustRule::Interface("eth0".to_string()).matches(&network));
+
+        let wired = NetworkInfo {
+            interface: "eth0".to_string(),
+            ssid: None,
+            gateway_mac: None,
+        };
+        assert!(!TrustRule::Ssid("home".to_string()).matches(&wired));
+        assert!(!TrustRule::GatewayMac("aa:bb:cc:dd:ee:ff".to_string()).matches(&wired));
+        assert!(is_trusted(
+            &[
+                TrustRule::Ssid("home".to_string()),
+                TrustRule::Interface("eth0".to_string())
+            ],
+            &wired
+        ));
+    }
+
+    #[test]
+    fn mac_is_normalized() {
+        assert_eq!(
+            parse_mac(" AA-BB-CC-0D-ee-Ff ").unwrap(),
+            "aa:bb:cc:0d:ee:ff"
+        );
+        assert_eq!(parse_mac("aa:bb:cc:dd:ee:ff").unwrap(), "aa:bb:cc:dd:ee:ff");
+        for invalid in [
+            "",
+            "aa:bb:cc:dd:ee",
+            "aa:bb:cc:dd:ee:ff:00",
+            "aa:bb:cc:dd:ee:fg",
+            "aaa:bb:cc:dd:ee:f",
+            "aabbccddeeff",
+        ] {
+            assert!(parse_mac(invalid).is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn ssid_length_is_checked_in_bytes() {
+        assert_eq!(
+            TrustKind::Ssid.rule("  home ").unwrap(),
+            TrustRule::Ssid("home".to_string())
+        );
+        assert!(TrustKind::Ssid.rule(&"a".repeat(32)).is_ok());
+        assert!(TrustKind::Ssid.rule(&"a".repeat(33)).is_err());
+        // 16 x 2 bytes fits, 17 x 2 does not
+        assert!(TrustKind::Ssid.rule(&"é".repeat(16)).is_ok());
+        assert!(TrustKind::Ssid.rule(&"é".repeat(17)).is_err());
+        assert!(TrustKind::Ssid.rule("   ").is_err());
+    }
+
+    #[test]
+    fn other_kinds_build_their_rule() {
+        assert_eq!(
+            TrustKind::GatewayMac.rule("AA-BB-CC-DD-EE-FF").unwrap(),
+            TrustRule::GatewayMac("aa:bb:cc:dd:ee:ff".to_string())
+        );
+        assert!(TrustKind::GatewayMac.rule("gateway").is_err());
+        assert_eq!(
+            TrustKind::Interface.rule(" eth0 ").unwrap(),
+            TrustRule::Interface("eth0".to_string())
+        );
+        assert!(TrustKind::Interface.rule("eth0/1").is_err());
+        assert!(TrustKind::Interface
+            .rule("an-interface-name-too-long")
+            .is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Current network through NetworkManager's D-Bus API.
+
+use super::{NetworkInfo, NetworkSource};
+use anyhow::{Context, Result};
+use zbus::blocking::{Connection, Proxy, ProxyBuilder};
+use zbus::zvariant::{OwnedObjectPath, OwnedValue};
+use zbus::CacheProperties;
+
+const SERVICE: &str = "org.freedesktop.NetworkManager";
+const ROOT: &str = "/org/freedesktop/NetworkManager";
+const NM_DEVICE_TYPE_WIFI: u32 = 2;
+
+pub struct NetworkManager {
+    conn: Connection,
+}
+
+impl NetworkManager {
+    /// Fails without a system bus or when NetworkManager is not running.
+    pub fn connect() -> Result<Self> {
+        let conn = Connection::system().context("system bus")?;
+        let nm = Self { conn };
+        nm.property::<String>(ROOT, "org.freedesktop.NetworkManager", "Version")?;
+        Ok(nm)
+    }
+
+    fn property<T>(&self, path: &str, interface: &str, name: &str) -> Result<T>
+    where
+        T: TryFrom<OwnedValue>,
+        T::Error: Into<zbus::Error>,
+    {
+        // Read once per poll, caching would only add signal subscriptions
+        let proxy: Proxy = ProxyBuilder::new(&self.conn)
+            .destination(SERVICE)?
+            .path(path)?
+            .interface(interface)?
+            .cache_properties(CacheProperties::No)
+            .build()?;
+        proxy
+            .get_property(name)
+            .with_context(|| format!("{}.{} of {}", interface, name, path))
+    }
+
+    fn ssid(&self, device: &str) -> Result<Option<String>> {
+        let kind: u32 = self.property(
+            device,
+            "org.freedesktop.NetworkManager.Device",
+            "DeviceType",
+        )?;
+        if kind != NM_DEVICE_TYPE_WIFI {
+            return Ok(None);
+        }
+        let ap: OwnedObjectPath = self.property(
+            device,
+            "org.freedesktop.NetworkManager.Device.Wireless",
+            "ActiveAccessPoint",
+        )?;
+        if ap.as_str() == "/" {
+            return Ok(None);
+        }
+        let ssid: Vec<u8> = self.property(
+            ap.as_str(),
+            "org.freedesktop.NetworkManager.AccessPoint",
+            "Ssid",
+        )?;
+        Ok(Some(String::from_utf8_lossy(&ssid).into_owned()))
+    }
+
+    fn gateway_mac(&self, active: &str, interface: &str) -> Result<Option<String>> {
+        let config: OwnedObjectPath = self.property(
+            active,
+            "org.freedesktop.NetworkManager.Connection.Active",
+            "Ip4Config",
+        )?;
+        if config.as_str() == "/" {
+            return Ok(None);
+        }
+        let gateway: String = self.property(
+            config.as_str(),
+            "org.freedesktop.NetworkManager.IP4Config",
+            "Gateway",
+        )?;
+        if gateway.is_empty() {
+            return Ok(None);
+        }
+        Ok(neighbour_mac(&gateway, interface))
+    }
+}
+
+impl NetworkSource for NetworkManager {
+    fn current(&self) -> Result<Option<NetworkInfo>> {
+        let active: OwnedObjectPath =
+            self.property(ROOT, "org.freedesktop.NetworkManager", "PrimaryConnection")?;
+        if active.as_str() == "/" {
+            return Ok(None);
+        }
+        let devices: Vec<OwnedObjectPath> = self.property(
+            active.as_str(),
+            "org.freedesktop.NetworkManager.Connection.Active",
+            "Devices",
+        )?;
+        let device = match devices.first() {
+            Some(d) => d.as_str(),
+            None => return Ok(None),
+        };
+        let interface: String =
+            self.property(device, "org.freedesktop.NetworkManager.Device", "Interface")?;
+        Ok(Some(NetworkInfo {
+            ssid: self.ssid(device)?,
+            gateway_mac: self.gateway_mac(active.as_str(), &interface)?,
+            interface,
+        }))
+    }
+}
+
+/// Looks the gateway up in the kernel's ARP table.
+fn neighbour_mac(ip: &str, interface: &str) -> Option<String> {
+    // IP address, HW type, Flags, HW address, Mask, Device
+    let table = std::fs::read_to_string("/proc/net/arp").ok()?;
+    table.lines().skip(1).find_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            [addr, _, _, mac, _, dev] if *addr == ip && *dev == interface => {
+                Some(mac.to_ascii_lowercase()).filter(|m| m != "00:00:00:00:00:00")
+            }
+            _ => None,
+        }
+    })
+}
@@ -0,0 +1,69 @@
+//! `org.freedesktop.Notifications` on the session bus.
+
+use super::{Notification, Notifier};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use zbus::blocking::{Connection, Proxy, ProxyBuilder};
+use zbus::zvariant::Value;
+
+const URGENCY_NORMAL: u8 = 1;
+const URGENCY_CRITICAL: u8 = 2;
+
+pub struct Freedesktop {
+    proxy: Proxy<'static>,
+    /// Id of our last notification: the next one replaces it instead of
+    /// piling up.
+    last_id: Mutex<u32>,
+}
+
+impl Freedesktop {
+    /// Fails without a session bus or notification daemon.
+    pub fn connect() -> Result<Self> {
+        let conn = Connection::session().context("session bus")?;
+        let proxy: Proxy<'static> = ProxyBuilder::new(&conn)
+            .destination("org.freedesktop.Notifications")?
+            .path("/org/freedesktop/Notifications")?
+            .interface("org.freedesktop.Notifications")?
+            .build()?;
+        let (name, ..): (String, String, String, String) = proxy
+            .call("GetServerInformation", &())
+            .context("no notification daemon")?;
+        tracing::info!(server = name, "notification service");
+        Ok(Self {
+            proxy,
+            last_id: Mutex::new(0),
+        })
+    }
+}
+
+impl Notifier for Freedesktop {
+    fn notify(&self, notification: &Notification) -> Result<()> {
+        let urgency = if notification.is_critical() {
+            URGENCY_CRITICAL
+        } else {
+            URGENCY_NORMAL
+        };
+        let mut hints = HashMap::new();
+        hints.insert("urgency", Value::from(urgency));
+        let mut last_id = self.last_id.lock().unwrap_or_else(|e| e.into_inner());
+        let summary = notification.summary();
+        let body = notification.body();
+        let id: u32 = self.proxy.call(
+            "Notify",
+            &(
+                "FIRE VPN",
+                *last_id,
+                "network-vpn",
+                summary.as_str(),
+                body.as_str(),
+                Vec::<&str>::new(),
+                hints,
+                // Server default
+                -1i32,
+            ),
+        )?;
+        *last_id = id;
+        Ok(())
+    }
+}
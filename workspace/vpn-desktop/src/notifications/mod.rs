@@ -0,0 +1,282 @@
+//! Desktop notifications for connection events.
+//!
+//! Events go through a [`Notifier`], the freedesktop notification service
+//! on Linux or a recorder in the tests. [`Notifications`] drops the muted
+//! kinds and sends the others from a background thread so that a slow
+//! notification daemon never blocks the UI.
+
+#[cfg(target_os = "linux")]
+mod freedesktop;
+
+use crate::i18n::tr;
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{self, Sender};
+
+/// Something the user should hear about even with the window hidden.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Notification {
+    Connected {
+        server: String,
+    },
+    Disconnected,
+    /// The tunnel is being repaired or brought up again.
+    Reconnecting {
+        reason: String,
+    },
+    /// No handshake for longer than WireGuard keeps a session.
+    HandshakeLost {
+        server: String,
+    },
+    ServerSwitched {
+        server: String,
+    },
+    SwitchFailed {
+        error: String,
+    },
+    TokenExpired,
+}
+
+impl Notification {
+    pub fn kind(&self) -> NotificationKind {
+        match self {
+            Notification::Connected { .. } => NotificationKind::Connected,
+            Notification::Disconnected => NotificationKind::Disconnected,
+            Notification::Reconnecting { .. } => NotificationKind::Reconnecting,
+            Notification::HandshakeLost { .. } => NotificationKind::HandshakeLost,
+            Notification::ServerSwitched { .. } | Notification::SwitchFailed { .. } => {
+                NotificationKind::ServerSwitched
+            }
+            Notification::TokenExpired => NotificationKind::TokenExpired,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        match self {
+            Notification::Connected { .. } => tr!("notification.connected"),
+            Notification::Disconnected => tr!("notification.disconnected"),
+            Notification::Reconnecting { .. } => tr!("notification.reconnecting"),
+            Notification::HandshakeLost { .. } => tr!("notification.handshake_lost"),
+            Notification::ServerSwitched { .. } => tr!("notification.server_switched"),
+            Notification::SwitchFailed { .. } => tr!("notification.switch_failed"),
+            Notification::TokenExpired => tr!("notification.token_expired"),
+        }
+        .to_string()
+    }
+
+    pub fn body(&self) -> String {
+        match self {
+            Notification::Connected { server } | Notification::ServerSwitched { server } => {
+                tr!("notification.connected_body", server = server)
+            }
+            Notification::Disconnected => tr!("notification.disconnected_body").to_string(),
+            Notification::Reconnecting { reason } => reason.clone(),
+            Notification::HandshakeLost { server } => {
+                tr!("notification.handshake_lost_body", server = server)
+            }
+            Notification::SwitchFailed { error } => error.clone(),
+            Notification::TokenExpired => tr!("notification.token_expired_body").to_string(),
+        }
+    }
+
+    /// Stays on screen until dismissed.
+    pub fn is_critical(&self) -> bool {
+        matches!(
+            self,
+            Notification::HandshakeLost { .. } | Notification::TokenExpired
+        )
+    }
+}
+
+/// Toggled one by one in the settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    Connected,
+    Disconnected,
+    Reconnecting,
+    HandshakeLost,
+    ServerSwitched,
+    TokenExpired,
+}
+
+impl NotificationKind {
+    pub const ALL: [NotificationKind; 6] = [
+        NotificationKind::Connected,
+        NotificationKind::Disconnected,
+        NotificationKind::Reconnecting,
+        NotificationKind::HandshakeLost,
+        NotificationKind::ServerSwitched,
+        NotificationKind::TokenExpired,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NotificationKind::Connected => tr!("notification.kind.connected"),
+            NotificationKind::Disconnected => tr!("notification.kind.disconnected"),
+            NotificationKind::Reconnecting => tr!("notification.kind.reconnecting"),
+            NotificationKind::HandshakeLost => tr!("notification.kind.handshake_lost"),
+            NotificationKind::ServerSwitched => tr!("notification.kind.server_switched"),
+            NotificationKind::TokenExpired => tr!("notification.kind.token_expired"),
+        }
+    }
+}
+
+/// Where notifications are shown.
+pub trait Notifier: Send {
+    fn notify(&self, notification: &Notification) -> anyhow::Result<()>;
+}
+
+/// The desktop's notification service where available.
+pub fn system_notifier() -> Option<Box<dyn Notifier>> {
+    #[cfg(target_os = "linux")]
+    {
+        match freedesktop::Freedesktop::connect() {
+            Ok(service) => return Some(Box::new(service)),
+            Err(e) => tracing::info!(error = %e, "notification service unavailable"),
+        }
+    }
+    None
+}
+
+/// Hands notifications to a [`Notifier`] on its own thread.
+pub struct Notifications {
+    sender: Sender<Notification>,
+}
+
+impl Notifications {
+    pub fn start(notifier: Box<dyn Notifier>) -> Self {
+        let (sender, receiver) = mpsc::channel::<Notification>();
+        std::thread::spawn(move || {
+            for notification in receiver {
+                if let Err(e) = notifier.notify(&notification) {
+                    tracing::debug!(error = %e, "cannot show a notification");
+                }
+            }
+        });
+        Self { sender }
+    }
+
+    /// Unless its kind is in `muted`.
+    pub fn send(&self, notification: Notification, muted: &[NotificationKind]) {
+        if muted.contains(&notification.kind()) {
+            return;
+        }
+        tracing::debug!(notification = ?notification, "notification");
+        let _ = self.sender.send(notification);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::Receiver;
+    use std::time::Duration;
+
+    /// Hands every notification it receives back to the test.
+    struct Recorder(Sender<Notification>);
+
+    impl Notifier for Recorder {
+        fn notify(&self, notification: &Notification) -> anyhow::Result<()> {
+            self.0.send(notification.clone())?;
+            Ok(())
+        }
+    }
+
+    fn recorded() -> (Notifications, Receiver<Notification>) {
+        let (sender, received) = mpsc::channel();
+        (Notifications::start(Box::new(Recorder(sender))), received)
+    }
+
+    fn next(received: &Receiver<Notification>) -> Notification {
+        received.recv_timeout(Duration::from_secs(5)).unwrap()
+    }
+
+    #[test]
+    fn delivers_in_order() {
+        let (notifications, received) = recorded();
+        let sent = [
+            Notification::Connected {
+                server: "Paris".to_string(),
+            },
+            Notification::Reconnecting {
+                reason: "network changed".to_string(),
+            },
+            Notification::Disconnected,
+        ];
+        for notification in sent.clone() {
+            notifications.send(notification, &[]);
+        }
+        for expected in sent {
+            assert_eq!(next(&received), expected);
+        }
+    }
+
+    #[test]
+    fn muted_kinds_are_dropped() {
+        let (notifications, received) = recorded();
+        let muted = [
+            NotificationKind::ServerSwitched,
+            NotificationKind::Disconnected,
+        ];
+        notifications.send(Notification::Disconnected, &muted);
+        notifications.send(
+            Notification::SwitchFailed {
+                error: "timeout".to_string(),
+            },
+            &muted,
+        );
+        notifications.send(Notification::TokenExpired, &muted);
+        assert_eq!(next(&received), Notification::TokenExpired);
+        assert!(received.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn kinds_and_criticality() {
+        let server = || "Paris".to_string();
+        let cases = [
+            (
+                Notification::Connected { server: server() },
+                NotificationKind::Connected,
+                false,
+            ),
+            (
+                Notification::Disconnected,
+                NotificationKind::Disconnected,
+                false,
+            ),
+            (
+                Notification::Reconnecting {
+                    reason: String::new(),
+                },
+                NotificationKind::Reconnecting,
+                false,
+            ),
+            (
+                Notification::HandshakeLost { server: server() },
+                NotificationKind::HandshakeLost,
+                true,
+            ),
+            (
+                Notification::ServerSwitched { server: server() },
+                NotificationKind::ServerSwitched,
+                false,
+            ),
+            (
+                Notification::SwitchFailed {
+                    error: String::new(),
+                },
+                NotificationKind::ServerSwitched,
+                false,
+            ),
+            (
+                Notification::TokenExpired,
+                NotificationKind::TokenExpired,
+                true,
+            ),
+        ];
+        for (notification, kind, critical) in cases {
+            assert_eq!(notification.kind(), kind, "{:?}", notification);
+            assert_eq!(notification.is_critical(), critical, "{:?}", notification);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Power saving: how often the animated screens (globe, connected screen)
+//! are redrawn.
+//!
+//! Without saving they are redrawn every frame, which keeps a core busy
+//! even when nobody looks. The pace drops when the window loses the focus or
+//! the machine runs on battery, and drawing stops while the window is
+//! hidden. Other screens only repaint on input or background events.
+
+use crate::i18n::tr;
+use crate::preferences::PowerSaving;
+use std::time::{Duration, Instant};
+
+/// About 15 frames per second.
+const THROTTLED_FRAME: Duration = Duration::from_millis(66);
+/// Once a second, for the connection timer.
+const PAUSED_FRAME: Duration = Duration::from_secs(1);
+/// Between two readings of the power supplies.
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Redraw rate of the animations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pace {
+    Full,
+    Throttled,
+    Paused,
+    /// Window hidden: nothing is drawn.
+    Stopped,
+}
+
+impl Pace {
+    pub fn choose(setting: PowerSaving, visible: bool, focused: bool, on_battery: bool) -> Pace {
+        if !visible {
+            return Pace::Stopped;
+        }
+        match setting {
+            PowerSaving::Off => Pace::Full,
+            PowerSaving::Always if focused => Pace::Throttled,
+            PowerSaving::Always => Pace::Paused,
+            PowerSaving::Auto => match (focused, on_battery) {
+                (true, false) => Pace::Full,
+                (false, true) => Pace::Paused,
+                _ => Pace::Throttled,
+            },
+        }
+    }
+
+    /// Schedules the next frame of an animation.
+    pub fn request_frame(self, ctx: &egui::Context) {
+        match self {
+            Pace::Full => ctx.request_repaint(),
+            Pace::Throttled => ctx.request_repaint_after(THROTTLED_FRAME),
+            Pace::Paused => ctx.request_repaint_after(PAUSED_FRAME),
+            Pace::Stopped => {}
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Pace::Full => tr!("power.pace.full"),
+            Pace::Throttled => tr!("power.pace.throttled"),
+            Pace::Paused => tr!("power.pace.paused"),
+            Pace::Stopped => tr!("power.pace.stopped"),
+        }
+    }
+}
+
+/// Whether the machine runs on battery, read again every
+/// [`CHECK_INTERVAL`] rather than each frame.
+#[derive(Default)]
+pub struct PowerSource {
+    on_battery: bool,
+    checked: Option<Instant>,
+}
+
+impl PowerSource {
+    pub fn on_battery(&mut self) -> bool {
+        if self.checked.is_none_or(|at| at.elapsed() >= CHECK_INTERVAL) {
+            self.on_battery = read_on_battery();
+            self.checked = Some(Instant::now());
+        }
+        self.on_battery
+    }
+}
+
+/// A system battery discharging. Batteries of peripherals (mouse, headset)
+/// have the `Device` scope and are left out.
+#[cfg(target_os = "linux")]
+fn read_on_battery() -> bool {
+    let entries = match std::fs::read_dir("/sys/class/power_supply") {
+        Ok(entries) => entries,
+        Err(_) => return false,
+    };
+    entries.flatten().any(|entry| {
+        let read = |name: &str| {
+            std::fs::read_to_string(entry.path().join(name))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default()
+        };
+        read("type") == "Battery" && read("scope") != "Device" && read("status") == "Discharging"
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_on_battery() -> bool {
+    false
+}
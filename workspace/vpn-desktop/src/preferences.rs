@@ -0,0 +1,227 @@
+//! User preferences, edited in the settings screen.
+//!
+//! Stored in `config.json` under `preferences`, versioned with the rest of
+//! the file. Every field has a default so that adding one needs no migration.
+
+use crate::i18n::tr;
+use crate::network::TrustRule;
+use crate::notifications::NotificationKind;
+use crate::server_list::ServerSort;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::ops::RangeInclusive;
+
+pub const DEFAULT_API_URL: &str = "http://localhost:8080";
+/// Same default as wg-quick.
+pub const DEFAULT_MTU: u16 = 1420;
+/// IPv6 needs at least 1280; above 1500 the encrypted packets no longer fit
+/// an Ethernet frame.
+const MTU_RANGE: RangeInclusive<u16> = 1280..=1500;
+
+/// Server used by automatic connections.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerChoice {
+    /// `last_server_id`, or the fastest one if unknown.
+    #[default]
+    Last,
+    Fastest,
+}
+
+impl ServerChoice {
+    pub const ALL: [ServerChoice; 2] = [ServerChoice::Last, ServerChoice::Fastest];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ServerChoice::Last => tr!("preferences.server.last"),
+            ServerChoice::Fastest => tr!("preferences.server.fastest"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// Language of the desktop session.
+    #[default]
+    System,
+    French,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::System, Language::French, Language::English];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::System => tr!("preferences.language.system"),
+            // Each language under its own name
+            Language::French => "Français",
+            Language::English => "English",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    /// Follows the desktop's light or dark preference, and its contrast.
+    #[default]
+    System,
+    Dark,
+    Light,
+    #[serde(rename = "high-contrast")]
+    HighContrast,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 4] = [
+        ThemeMode::System,
+        ThemeMode::Dark,
+        ThemeMode::Light,
+        ThemeMode::HighContrast,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeMode::System => tr!("preferences.theme.system"),
+            ThemeMode::Dark => tr!("preferences.theme.dark"),
+            ThemeMode::Light => tr!("preferences.theme.light"),
+            ThemeMode::HighContrast => tr!("preferences.theme.high_contrast"),
+        }
+    }
+}
+
+/// When the animations slow down, see [`crate::power`].
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerSaving {
+    /// Window unfocused or running on battery.
+    #[default]
+    Auto,
+    Always,
+    Off,
+}
+
+impl PowerSaving {
+    pub const ALL: [PowerSaving; 3] = [PowerSaving::Auto, PowerSaving::Always, PowerSaving::Off];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerSaving::Auto => tr!("preferences.power.auto"),
+            PowerSaving::Always => tr!("preferences.power.always"),
+            PowerSaving::Off => tr!("preferences.power.off"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub api_url: String,
+    /// Connects once logged in, and resumes the saved session at launch.
+    pub connect_on_launch: bool,
+    /// Connects when the network is not trusted, disconnects on a trusted
+    /// one if the connection was automatic (Linux, NetworkManager).
+    pub connect_on_untrusted: bool,
+    pub trusted_networks: Vec<TrustRule>,
+    pub auto_connect_server: ServerChoice,
+    /// Blocks the traffic outside the tunnel while connected (Linux).
+    pub kill_switch: bool,
+    /// Replaces the DNS servers of the server's config when not empty.
+    pub dns_override: Vec<IpAddr>,
+    /// Tunnel MTU, [`DEFAULT_MTU`] if unset.
+    pub mtu: Option<u16>,
+    /// Replaces the server's `PersistentKeepalive`, in seconds (0 disables it).
+    pub keepalive: Option<u16>,
+    /// Name of the tunnel interface, `tunnel::DEFAULT_INTERFACE` if unset.
+    pub interface_name: Option<String>,
+    pub language: Language,
+    pub theme: ThemeMode,
+    pub power_saving: PowerSaving,
+    /// Closing the window hides it in the tray, the tunnel stays up.
+    pub close_to_tray: bool,
+    /// Desktop notifications turned off in the settings.
+    pub muted_notifications: Vec<NotificationKind>,
+    pub server_sort: ServerSort,
+    /// Server list split in collapsible continent groups.
+    pub group_by_continent: bool,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            api_url: DEFAULT_API_URL.to_string(),
+            connect_on_launch: false,
+            connect_on_untrusted: false,
+            trusted_networks: Vec::new(),
+            auto_connect_server: ServerChoice::default(),
+            kill_switch: false,
+            dns_override: Vec::new(),
+            mtu: None,
+            keepalive: None,
+            interface_name: None,
+            language: Language::default(),
+            theme: ThemeMode::default(),
+            power_saving: PowerSaving::default(),
+            close_to_tray: false,
+            muted_notifications: Vec::new(),
+            server_sort: ServerSort::default(),
+            group_by_continent: true,
+        }
+    }
+}
+
+/// Checks an API base URL; the trailing slash is dropped since the client
+/// appends absolute paths.
+pub fn parse_api_url(input: &str) -> Result<String, String> {
+    let input = input.trim().trim_end_matches('/');
+    let url = reqwest::Url::parse(input).map_err(|e| tr!("preferences.error.url", error = e))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(tr!("preferences.error.url_scheme").into());
+    }
+    if url.host_str().is_none() {
+        return Err(tr!("preferences.error.url_host").into());
+    }
+    Ok(input.to_string())
+}
+
+/// Addresses separated by commas or spaces; empty keeps the server's DNS.
+pub fn parse_dns_list(input: &str) -> Result<Vec<IpAddr>, String> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<IpAddr>()
+                .map_err(|_| tr!("preferences.error.dns", address = s))
+        })
+        .collect()
+}
+
+/// Empty for the default.
+pub fn parse_mtu(input: &str) -> Result<Option<u16>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    match input.parse::<u16>() {
+        Ok(mtu) if MTU_RANGE.contains(&mtu) => Ok(Some(mtu)),
+        _ => Err(tr!(
+            "preferences.error.mtu",
+            min = MTU_RANGE.start(),
+            max = MTU_RANGE.end()
+        )),
+    }
+}
+
+/// Empty for the server's value.
+pub fn parse_keepalive(input: &str) -> Result<Option<u16>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    input
+        .parse::<u16>()
+        .map(Some)
+        .map_err(|_| tr!("preferences.error.keepalive").into())
+}
@@ -0,0 +1,170 @@
+//! Searching, filtering, sorting and grouping the server list.
+//!
+//! The API gives `Server.country` as free text ("fr", "France",
+//! "Allemagne"); [`country`] maps it to an ISO country, which gives the
+//! code, the localized name and the continent. Unknown countries are kept,
+//! under "Others".
+
+use crate::i18n::{tr, Locale};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use vpn_core::api::Server;
+use vpn_core::country::{Continent, Country};
+
+/// Recent connections kept in `config.json`.
+const MAX_RECENT: usize = 8;
+
+/// A past connection, kept with its name for servers gone from the list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentServer {
+    pub id: u64,
+    pub name: String,
+    /// Seconds since the Unix epoch.
+    pub connected_at: u64,
+}
+
+/// Moves the server to the front of `recent`, newest first.
+pub fn push_recent(recent: &mut Vec<RecentServer>, id: u64, name: &str) {
+    let connected_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    recent.retain(|r| r.id != id);
+    recent.insert(
+        0,
+        RecentServer {
+            id,
+            name: name.to_string(),
+            connected_at,
+        },
+    );
+    recent.truncate(MAX_RECENT);
+}
+
+/// `None` for a country missing from the ISO table.
+pub fn country(text: &str) -> Option<Country> {
+    text.parse().ok()
+}
+
+/// Name in the interface's language, or the API's text for an unknown
+/// country.
+pub fn country_name(text: &str) -> String {
+    country(text)
+        .map(|c| c.name(Locale::current().country_language()).to_string())
+        .unwrap_or_else(|| text.to_string())
+}
+
+pub fn continent_label(continent: Continent) -> &'static str {
+    continent.name(Locale::current().country_language())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerSort {
+    #[default]
+    Name,
+    /// Measured round trip; servers not probed yet come last.
+    Latency,
+    /// As reported by the API; servers without a load come last.
+    Load,
+}
+
+impl ServerSort {
+    pub const ALL: [ServerSort; 3] = [ServerSort::Name, ServerSort::Latency, ServerSort::Load];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ServerSort::Name => tr!("servers.sort.name"),
+            ServerSort::Latency => tr!("servers.sort.latency"),
+            ServerSort::Load => tr!("servers.sort.load"),
+        }
+    }
+}
+
+/// What the search box and the filters let through.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServerFilter {
+    /// Matched against the server name and the country as given by the API,
+    /// ignoring case, and against the country's names in any language.
+    pub query: String,
+    pub online_only: bool,
+    /// Country code, or the API's text for an unknown country.
+    pub country: Option<String>,
+    pub continent: Option<Continent>,
+}
+
+impl ServerFilter {
+    pub fn matches(&self, server: &Server) -> bool {
+        if self.online_only && !server.is_active {
+            return false;
+        }
+        let known = country(&server.country);
+        if let Some(wanted) = &self.country {
+            if country_key(server) != *wanted {
+                return false;
+            }
+        }
+        if let Some(continent) = self.continent {
+            if known.map(|c| c.continent()) != Some(continent) {
+                return false;
+            }
+        }
+        let query = self.query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+        [server.name.as_str(), server.country.as_str()]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+            || known.is_some_and(|c| c.matches(&query))
+    }
+}
+
+/// Value of [`ServerFilter::country`] selecting this server's country.
+pub fn country_key(server: &Server) -> String {
+    country(&server.country)
+        .map(|c| c.code().to_string())
+        .unwrap_or_else(|| server.country.clone())
+}
+
+/// Countries present in `servers`, as `(key, name)` sorted by name.
+pub fn countries(servers: &[Server]) -> Vec<(String, String)> {
+    let mut countries: Vec<(String, String)> = servers
+        .iter()
+        .map(|s| (country_key(s), country_name(&s.country)))
+        .collect();
+    countries.sort_by(|a, b| a.1.cmp(&b.1));
+    countries.dedup();
+    countries
+}
+
+/// Servers let through by `filter`, in `sort` order.
+pub fn arrange<'a>(
+    servers: &'a [Server],
+    filter: &ServerFilter,
+    sort: ServerSort,
+    latencies: &HashMap<u64, Duration>,
+) -> Vec<&'a Server> {
+    let mut list: Vec<&Server> = servers.iter().filter(|s| filter.matches(s)).collect();
+    let by_name = |a: &&Server, b: &&Server| a.name.to_lowercase().cmp(&b.name.to_lowercase());
+    list.sort_by(|a, b| {
+        let order = match sort {
+            ServerSort::Name => Ordering::Equal,
+            ServerSort::Latency => missing_last(latencies.get(&a.id), latencies.get(&b.id)),
+            ServerSort::Load => missing_last(a.load.as_ref(), b.load.as_ref()),
+        };
+        order.then_with(|| by_name(a, b))
+    });
+    list
+}
+
+fn missing_last<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
@@ -0,0 +1,90 @@
+//! Icon in the system tray, with a quick connect menu.
+//!
+//! On Linux the icon is a StatusNotifierItem served over the session bus
+//! ([`sni`]), shown by KDE, GNOME with the AppIndicator extension, and most
+//! panels. The app pushes a [`TrayState`] each frame and reads back the
+//! [`TrayCommand`]s chosen in the menu.
+
+#[cfg(target_os = "linux")]
+mod sni;
+
+use std::sync::mpsc::Receiver;
+
+/// What the icon and menu show.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrayState {
+    pub connected: bool,
+    /// Line shown at the top of the menu and in the tooltip.
+    pub status: String,
+    /// Servers can be connected to (logged in, not connected).
+    pub can_connect: bool,
+    /// Name of the last server, if still in the list.
+    pub last_server: Option<String>,
+    /// Favourite servers as `(id, name)`, connected to or switched to in
+    /// one click.
+    pub favourites: Vec<(u64, String)>,
+}
+
+/// Chosen in the tray menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrayCommand {
+    ConnectLast,
+    ConnectFastest,
+    /// Connects to a favourite, or switches to it when connected.
+    Connect(u64),
+    Disconnect,
+    /// Shows the window, or hides it when it is visible (click on the icon).
+    ToggleWindow,
+    ShowWindow,
+    Quit,
+}
+
+pub struct Tray {
+    #[cfg(target_os = "linux")]
+    item: sni::StatusNotifier,
+    commands: Receiver<TrayCommand>,
+    state: TrayState,
+}
+
+impl Tray {
+    /// `None` without a tray host (no session bus, no StatusNotifierWatcher).
+    /// `on_command` runs on the bus thread after each command, to wake the UI.
+    #[cfg(target_os = "linux")]
+    pub fn start(on_command: impl Fn() + Send + Sync + 'static) -> Option<Self> {
+        let (sender, commands) = std::sync::mpsc::channel();
+        let state = TrayState::default();
+        match sni::StatusNotifier::start(&state, sender, on_command) {
+            Ok(item) => Some(Self {
+                item,
+                commands,
+                state,
+            }),
+            Err(e) => {
+                tracing::info!(error = %e, "no system tray");
+                None
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn start(_on_command: impl Fn() + Send + Sync + 'static) -> Option<Self> {
+        None
+    }
+
+    /// Cheap when nothing changed: the bus is only told about differences.
+    pub fn update(&mut self, state: TrayState) {
+        if state == self.state {
+            return;
+        }
+        #[cfg(target_os = "linux")]
+        if let Err(e) = self.item.update(&state) {
+            tracing::debug!(error = %e, "cannot update the tray icon");
+        }
+        self.state = state;
+    }
+
+    /// Commands since the previous call.
+    pub fn poll(&self) -> Vec<TrayCommand> {
+        self.commands.try_iter().collect()
+    }
+}
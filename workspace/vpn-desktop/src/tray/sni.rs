@@ -0,0 +1,419 @@
+//! StatusNotifierItem and its `com.canonical.dbusmenu` menu, served on the
+//! session bus and registered with the `org.kde.StatusNotifierWatcher`.
+
+use super::{TrayCommand, TrayState};
+use crate::i18n::tr;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use zbus::blocking::{Connection, ProxyBuilder};
+use zbus::interface;
+use zbus::zvariant::{OwnedValue, StructureBuilder, Type, Value};
+
+const ITEM_PATH: &str = "/StatusNotifierItem";
+const MENU_PATH: &str = "/MenuBar";
+const ITEM_INTERFACE: &str = "org.kde.StatusNotifierItem";
+const MENU_INTERFACE: &str = "com.canonical.dbusmenu";
+const ICON_SIZE: i32 = 22;
+
+/// Menu entry ids; 0 is the root.
+const STATUS: i32 = 1;
+const CONNECT_LAST: i32 = 2;
+const CONNECT_FASTEST: i32 = 3;
+const DISCONNECT: i32 = 4;
+const SHOW: i32 = 5;
+const QUIT: i32 = 6;
+const SEPARATORS: [i32; 3] = [10, 11, 12];
+/// Favourite `n` is `FAVOURITES + n`.
+const FAVOURITES: i32 = 100;
+
+type Wake = Arc<dyn Fn() + Send + Sync>;
+
+/// Shared between the app and the interfaces served on the bus thread.
+struct Shared {
+    state: TrayState,
+    revision: u32,
+}
+
+pub struct StatusNotifier {
+    conn: Connection,
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl StatusNotifier {
+    pub fn start(
+        state: &TrayState,
+        commands: Sender<TrayCommand>,
+        on_command: impl Fn() + Send + Sync + 'static,
+    ) -> Result<Self> {
+        let shared = Arc::new(Mutex::new(Shared {
+            state: state.clone(),
+            revision: 1,
+        }));
+        let wake: Wake = Arc::new(on_command);
+        let name = format!("org.kde.StatusNotifierItem-{}-1", std::process::id());
+        let item = Item {
+            shared: Arc::clone(&shared),
+            commands: commands.clone(),
+            wake: Arc::clone(&wake),
+        };
+        let menu = Menu {
+            shared: Arc::clone(&shared),
+            commands,
+            wake,
+        };
+        let conn = zbus::blocking::connection::Builder::session()
+            .context("session bus")?
+            .name(name.as_str())?
+            .serve_at(ITEM_PATH, item)?
+            .serve_at(MENU_PATH, menu)?
+            .build()
+            .context("session bus")?;
+
+        let watcher: zbus::blocking::Proxy = ProxyBuilder::new(&conn)
+            .destination("org.kde.StatusNotifierWatcher")?
+            .path("/StatusNotifierWatcher")?
+            .interface("org.kde.StatusNotifierWatcher")?
+            .build()?;
+        watcher
+            .call_method("RegisterStatusNotifierItem", &(name.as_str(),))
+            .context("no StatusNotifierWatcher")?;
+        tracing::info!(name, "tray icon registered");
+        Ok(Self { conn, shared })
+    }
+
+    pub fn update(&self, state: &TrayState) -> Result<()> {
+        let revision = {
+            let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            shared.state = state.clone();
+            shared.revision += 1;
+            shared.revision
+        };
+        for signal in ["NewIcon", "NewToolTip", "NewTitle"] {
+            self.conn
+                .emit_signal(None::<&str>, ITEM_PATH, ITEM_INTERFACE, signal, &())?;
+        }
+        self.conn.emit_signal(
+            None::<&str>,
+            MENU_PATH,
+            MENU_INTERFACE,
+            "LayoutUpdated",
+            &(revision, 0i32),
+        )?;
+        Ok(())
+    }
+}
+
+fn send(commands: &Sender<TrayCommand>, wake: &Wake, command: TrayCommand) {
+    tracing::debug!(command = ?command, "tray command");
+    if commands.send(command).is_ok() {
+        wake();
+    }
+}
+
+fn state(shared: &Mutex<Shared>) -> TrayState {
+    shared
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .state
+        .clone()
+}
+
+// ── Item ───────────────────────────────────────────────────────────────────────
+
+struct Item {
+    shared: Arc<Mutex<Shared>>,
+    commands: Sender<TrayCommand>,
+    wake: Wake,
+}
+
+/// `(width, height, ARGB32 in network byte order)`.
+type Pixmap = (i32, i32, Vec<u8>);
+
+#[interface(name = "org.kde.StatusNotifierItem")]
+impl Item {
+    fn activate(&self, _x: i32, _y: i32) {
+        send(&self.commands, &self.wake, TrayCommand::ToggleWindow);
+    }
+
+    fn secondary_activate(&self, _x: i32, _y: i32) {
+        send(&self.commands, &self.wake, TrayCommand::ShowWindow);
+    }
+
+    fn context_menu(&self, _x: i32, _y: i32) {}
+
+    fn scroll(&self, _delta: i32, _orientation: &str) {}
+
+    #[zbus(property)]
+    fn category(&self) -> &str {
+        "ApplicationStatus"
+    }
+
+    #[zbus(property)]
+    fn id(&self) -> &str {
+        "fire-vpn"
+    }
+
+    #[zbus(property)]
+    fn title(&self) -> String {
+        format!("FIRE VPN — {}", state(&self.shared).status)
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> &str {
+        "Active"
+    }
+
+    #[zbus(property)]
+    fn window_id(&self) -> i32 {
+        0
+    }
+
+    #[zbus(property)]
+    fn icon_name(&self) -> &str {
+        ""
+    }
+
+    #[zbus(property)]
+    fn icon_pixmap(&self) -> Vec<Pixmap> {
+        vec![icon(state(&self.shared).connected)]
+    }
+
+    #[zbus(property)]
+    fn tool_tip(&self) -> (String, Vec<Pixmap>, String, String) {
+        let status = state(&self.shared).status;
+        (String::new(), Vec::new(), "FIRE VPN".to_string(), status)
+    }
+
+    #[zbus(property)]
+    fn item_is_menu(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn menu(&self) -> zbus::zvariant::OwnedObjectPath {
+        zbus::zvariant::OwnedObjectPath::try_from(MENU_PATH).expect("valid object path")
+    }
+}
+
+/// Same disc as the window icon: blue when connected, grey otherwise.
+fn icon(connected: bool) -> Pixmap {
+    let (r, g, b) = if connected {
+        (30, 144, 255)
+    } else {
+        (110, 118, 129)
+    };
+    let size = ICON_SIZE as usize;
+    let mut argb = vec![0u8; size * size * 4];
+    let radius = size as f32 / 2.0;
+    for y in 0..size {
+        for x in 0..size {
+            let dx = x as f32 + 0.5 - radius;
+            let dy = y as f32 + 0.5 - radius;
+            if (dx * dx + dy * dy).sqrt() < radius {
+                let idx = (y * size + x) * 4;
+                argb[idx..idx + 4].copy_from_slice(&[255, r, g, b]);
+            }
+        }
+    }
+    (ICON_SIZE, ICON_SIZE, argb)
+}
+
+// ── Menu ───────────────────────────────────────────────────────────────────────
+
+struct Menu {
+    shared: Arc<Mutex<Shared>>,
+    commands: Sender<TrayCommand>,
+    wake: Wake,
+}
+
+/// `(ia{sv}av)`: id, properties and children, each a variant of the same.
+#[derive(serde::Serialize, Type)]
+struct Layout {
+    id: i32,
+    properties: HashMap<String, OwnedValue>,
+    children: Vec<OwnedValue>,
+}
+
+fn favourite(id: i32, state: &TrayState) -> Option<&(u64, String)> {
+    let index = usize::try_from(id.checked_sub(FAVOURITES)?).ok()?;
+    state.favourites.get(index)
+}
+
+fn entry_properties(id: i32, state: &TrayState) -> HashMap<String, OwnedValue> {
+    let mut properties = HashMap::new();
+    let mut set = |key: &str, value: Value<'_>| {
+        if let Ok(value) = value.try_to_owned() {
+            properties.insert(key.to_string(), value);
+        }
+    };
+    let label = match id {
+        STATUS => state.status.clone(),
+        CONNECT_LAST => match &state.last_server {
+            Some(name) => tr!("tray.connect_to", server = name),
+            None => tr!("tray.connect_last").to_string(),
+        },
+        CONNECT_FASTEST => tr!("tray.connect_fastest").to_string(),
+        DISCONNECT => tr!("tray.disconnect").to_string(),
+        SHOW => tr!("tray.show").to_string(),
+        QUIT => tr!("tray.quit").to_string(),
+        _ => match favourite(id, state) {
+            Some((_, name)) => format!("★ {}", name),
+            None => String::new(),
+        },
+    };
+    if SEPARATORS.contains(&id) {
+        set("type", Value::from("separator"));
+        return properties;
+    }
+    set("label", Value::from(label));
+    let (visible, enabled) = match id {
+        STATUS => (true, false),
+        CONNECT_LAST => (
+            !state.connected,
+            state.can_connect && state.last_server.is_some(),
+        ),
+        CONNECT_FASTEST => (!state.connected, state.can_connect),
+        DISCONNECT => (state.connected, true),
+        _ if id >= FAVOURITES => (true, state.can_connect || state.connected),
+        _ => (true, true),
+    };
+    set("visible", Value::from(visible));
+    set("enabled", Value::from(enabled));
+    properties
+}
+
+/// Favourites go between the connection entries and the window ones.
+fn entries(state: &TrayState) -> Vec<i32> {
+    let mut entries = vec![
+        STATUS,
+        SEPARATORS[0],
+        CONNECT_LAST,
+        CONNECT_FASTEST,
+        DISCONNECT,
+    ];
+    if !state.favourites.is_empty() {
+        entries.push(SEPARATORS[1]);
+        entries.extend((0..state.favourites.len() as i32).map(|n| FAVOURITES + n));
+    }
+    entries.extend([SEPARATORS[2], SHOW, QUIT]);
+    entries
+}
+
+fn entry(id: i32, state: &TrayState) -> Option<OwnedValue> {
+    let structure = StructureBuilder::new()
+        .add_field(id)
+        .add_field(entry_properties(id, state))
+        .add_field(Vec::<OwnedValue>::new())
+        .build();
+    Value::from(structure).try_to_owned().ok()
+}
+
+#[interface(name = "com.canonical.dbusmenu")]
+impl Menu {
+    fn get_layout(
+        &self,
+        parent_id: i32,
+        _recursion_depth: i32,
+        _property_names: Vec<String>,
+    ) -> (u32, Layout) {
+        let (state, revision) = {
+            let shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            (shared.state.clone(), shared.revision)
+        };
+        // Flat menu: only the root has children
+        let layout = if parent_id == 0 {
+            let mut properties = HashMap::new();
+            if let Ok(value) = Value::from("submenu").try_to_owned() {
+                properties.insert("children-display".to_string(), value);
+            }
+            Layout {
+                id: 0,
+                properties,
+                children: entries(&state)
+                    .into_iter()
+                    .filter_map(|id| entry(id, &state))
+                    .collect(),
+            }
+        } else {
+            Layout {
+                id: parent_id,
+                properties: entry_properties(parent_id, &state),
+                children: Vec::new(),
+            }
+        };
+        (revision, layout)
+    }
+
+    fn get_group_properties(
+        &self,
+        ids: Vec<i32>,
+        _property_names: Vec<String>,
+    ) -> Vec<(i32, HashMap<String, OwnedValue>)> {
+        let state = state(&self.shared);
+        let ids = if ids.is_empty() { entries(&state) } else { ids };
+        ids.into_iter()
+            .map(|id| (id, entry_properties(id, &state)))
+            .collect()
+    }
+
+    fn get_property(&self, id: i32, name: &str) -> zbus::fdo::Result<OwnedValue> {
+        entry_properties(id, &state(&self.shared))
+            .remove(name)
+            .ok_or_else(|| zbus::fdo::Error::InvalidArgs(format!("{} of {}", name, id)))
+    }
+
+    fn event(&self, id: i32, event_id: &str, _data: OwnedValue, _timestamp: u32) {
+        if event_id != "clicked" {
+            return;
+        }
+        let command = match id {
+            CONNECT_LAST => TrayCommand::ConnectLast,
+            CONNECT_FASTEST => TrayCommand::ConnectFastest,
+            DISCONNECT => TrayCommand::Disconnect,
+            SHOW => TrayCommand::ShowWindow,
+            QUIT => TrayCommand::Quit,
+            _ => match favourite(id, &state(&self.shared)) {
+                Some((server_id, _)) => TrayCommand::Connect(*server_id),
+                None => return,
+            },
+        };
+        send(&self.commands, &self.wake, command);
+    }
+
+    fn event_group(&self, events: Vec<(i32, String, OwnedValue, u32)>) -> Vec<i32> {
+        for (id, event_id, data, timestamp) in events {
+            self.event(id, &event_id, data, timestamp);
+        }
+        Vec::new()
+    }
+
+    fn about_to_show(&self, _id: i32) -> bool {
+        false
+    }
+
+    fn about_to_show_group(&self, _ids: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+        (Vec::new(), Vec::new())
+    }
+
+    #[zbus(property)]
+    fn version(&self) -> u32 {
+        3
+    }
+
+    #[zbus(property)]
+    fn text_direction(&self) -> &str {
+        "ltr"
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> &str {
+        "normal"
+    }
+
+    #[zbus(property)]
+    fn icon_theme_path(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,59 @@
+//! Pictures bundled in the binary, decoded on first use and kept as
+//! textures for the rest of the run.
+
+use egui::{ColorImage, TextureHandle, TextureOptions};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Asset {
+    /// Ghost behind the server list.
+    Backdrop,
+}
+
+impl Asset {
+    fn name(self) -> &'static str {
+        match self {
+            Asset::Backdrop => "bg-ghost",
+        }
+    }
+
+    fn bytes(self) -> &'static [u8] {
+        match self {
+            Asset::Backdrop => include_bytes!("../../assets/bg-ghost.jpg"),
+        }
+    }
+}
+
+/// Asset textures. A picture that cannot be decoded is not tried again.
+pub struct AssetStore {
+    textures: RefCell<HashMap<Asset, Option<TextureHandle>>>,
+}
+
+impl AssetStore {
+    pub fn new() -> Self {
+        Self {
+            textures: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, ctx: &egui::Context, asset: Asset) -> Option<TextureHandle> {
+        self.textures
+            .borrow_mut()
+            .entry(asset)
+            .or_insert_with(|| {
+                let image = match image::load_from_memory(asset.bytes()) {
+                    Ok(image) => image.to_rgba8(),
+                    Err(e) => {
+                        tracing::warn!(asset = asset.name(), error = %e, "cannot decode asset");
+                        return None;
+                    }
+                };
+                let size = [image.width() as usize, image.height() as usize];
+                let pixels = image.as_flat_samples();
+                let image = ColorImage::from_rgba_unmultiplied(size, pixels.as_slice());
+                Some(ctx.load_texture(asset.name(), image, TextureOptions::LINEAR))
+            })
+            .clone()
+    }
+}
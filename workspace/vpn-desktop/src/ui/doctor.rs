@@ -0,0 +1,128 @@
+use crate::app::VpnApp;
+use crate::diagnostics::checks::{Check, Status};
+use crate::i18n::{self, tr};
+use crate::ui::theme;
+use egui::{Rounding, Stroke};
+use std::time::Duration;
+
+/// Prerequisite checks: first-run wizard, also reachable from the settings.
+pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::draw_top_accent(ui);
+
+    ui.add_space(8.0);
+
+    ui.label(
+        egui::RichText::new(tr!("doctor.title"))
+            .size(18.0)
+            .color(theme::current().text_primary)
+            .strong(),
+    );
+    ui.label(
+        egui::RichText::new(tr!("doctor.intro"))
+            .size(11.0)
+            .color(theme::current().text_muted),
+    );
+
+    ui.add_space(12.0);
+
+    let checks = app.get_doctor_checks();
+
+    ui.horizontal(|ui| {
+        if theme::small_button(
+            ui,
+            tr!("doctor.continue"),
+            theme::current().accent,
+            theme::current().text_on_accent,
+        ) {
+            app.hide_doctor();
+        }
+        ui.add_space(4.0);
+        if checks.is_some()
+            && theme::small_button(
+                ui,
+                tr!("doctor.run_again"),
+                theme::current().bg_card,
+                theme::current().text_primary,
+            )
+        {
+            app.run_doctor();
+        }
+    });
+
+    ui.add_space(12.0);
+
+    let checks = match checks {
+        Some(c) => c,
+        None => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(
+                    egui::RichText::new(tr!("doctor.running"))
+                        .size(12.0)
+                        .color(theme::current().text_secondary),
+                );
+            });
+            // The checks run in the background
+            ui.ctx().request_repaint_after(Duration::from_millis(200));
+            return;
+        }
+    };
+
+    let failed = checks.iter().filter(|c| c.status == Status::Error).count();
+    let (summary, color) = if failed == 0 {
+        (tr!("doctor.ready").to_string(), theme::current().success)
+    } else {
+        (
+            i18n::plural("doctor.problems", failed as u64, &[]),
+            theme::current().error,
+        )
+    };
+    ui.label(egui::RichText::new(summary).size(13.0).color(color));
+
+    ui.add_space(8.0);
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        for check in &checks {
+            render_check(ui, check);
+            ui.add_space(6.0);
+        }
+    });
+}
+
+fn render_check(ui: &mut egui::Ui, check: &Check) {
+    let (mark, color) = match check.status {
+        Status::Ok => ("✔", theme::current().success),
+        Status::Warning => ("!", theme::current().warning),
+        Status::Error => ("✖", theme::current().error),
+    };
+
+    egui::Frame::none()
+        .fill(theme::current().bg_card)
+        .rounding(Rounding::same(8.0))
+        .stroke(Stroke::new(1.0, theme::current().border))
+        .inner_margin(egui::Margin::symmetric(12.0, 8.0))
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(mark).size(13.0).color(color).strong());
+                ui.label(
+                    egui::RichText::new(check.name)
+                        .size(13.0)
+                        .color(theme::current().text_primary),
+                );
+            });
+            ui.label(
+                egui::RichText::new(&check.detail)
+                    .size(11.0)
+                    .color(theme::current().text_secondary),
+            );
+            if let Some(hint) = &check.hint {
+                ui.add_space(2.0);
+                // Selectable so the sudoers line can be copied
+                ui.add(
+                    egui::Label::new(egui::RichText::new(hint).size(11.0).color(color))
+                        .selectable(true),
+                );
+            }
+        });
+}
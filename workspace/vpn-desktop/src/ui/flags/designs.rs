@@ -0,0 +1,2277 @@
+//! Designs of every ISO 3166-1 flag, simplified to what reads at list size:
+//! stripes and crosses are exact, coats of arms become a disc or a rough
+//! outline in their main colour.
+
+use super::shape::Shape::{self, *};
+use egui::Color32;
+
+const WHITE: Color32 = Color32::WHITE;
+const BLACK: Color32 = Color32::BLACK;
+
+const fn rgb(r: u8, g: u8, b: u8) -> Color32 {
+    Color32::from_rgb(r, g, b)
+}
+
+/// Shown for countries that are not in the ISO table.
+pub const GLOBE: &[Shape] = &[
+    HStripes(&[rgb(40, 50, 60)]),
+    Disc(0.5, 0.5, 0.42, rgb(70, 130, 180)),
+    Ring(0.5, 0.5, 0.25, 0.04, rgb(40, 50, 60)),
+    Rect(0.495, 0.08, 0.505, 0.92, rgb(40, 50, 60)),
+];
+
+/// Design for an alpha-2 code in upper case.
+pub fn design(code: &str) -> Option<&'static [Shape]> {
+    FLAGS
+        .binary_search_by(|(c, _)| c.cmp(&code))
+        .ok()
+        .map(|i| FLAGS[i].1)
+}
+
+const AD: &[Shape] = &[
+    VBands(&[
+        (1.0, rgb(16, 6, 159)),
+        (1.1, rgb(254, 223, 0)),
+        (1.0, rgb(213, 0, 50)),
+    ]),
+    Disc(0.5, 0.5, 0.15, rgb(192, 160, 60)),
+];
+
+const AE: &[Shape] = &[
+    HStripes(&[rgb(0, 115, 47), WHITE, BLACK]),
+    Rect(0.0, 0.0, 0.25, 1.0, rgb(255, 0, 0)),
+];
+
+const AF: &[Shape] = &[
+    VStripes(&[BLACK, rgb(190, 0, 0), rgb(0, 122, 54)]),
+    Ring(0.5, 0.5, 0.17, 0.04, WHITE),
+];
+
+const AG: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.5, 1.0)], BLACK),
+    Disc(0.5, 0.4, 0.18, rgb(252, 209, 22)),
+    Polygon(
+        &[(0.2, 0.4), (0.8, 0.4), (0.7, 0.6), (0.3, 0.6)],
+        rgb(0, 114, 198),
+    ),
+    Polygon(&[(0.3, 0.6), (0.7, 0.6), (0.5, 1.0)], WHITE),
+];
+
+const AI: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, WHITE),
+];
+
+const AL: &[Shape] = &[
+    HStripes(&[rgb(228, 30, 32)]),
+    Polygon(
+        &[
+            (0.5, 0.18),
+            (0.62, 0.3),
+            (0.72, 0.25),
+            (0.66, 0.5),
+            (0.55, 0.6),
+            (0.5, 0.82),
+            (0.45, 0.6),
+            (0.34, 0.5),
+            (0.28, 0.25),
+            (0.38, 0.3),
+        ],
+        BLACK,
+    ),
+];
+
+const AM: &[Shape] = &[HStripes(&[
+    rgb(217, 0, 18),
+    rgb(0, 51, 160),
+    rgb(242, 168, 0),
+])];
+
+const AO: &[Shape] = &[
+    HStripes(&[rgb(204, 9, 47), BLACK]),
+    Ring(0.5, 0.5, 0.2, 0.05, rgb(255, 203, 0)),
+    Star(0.5, 0.45, 0.08, 5, rgb(255, 203, 0)),
+];
+
+const AQ: &[Shape] = &[HStripes(&[rgb(7, 45, 133)]), Disc(0.5, 0.5, 0.3, WHITE)];
+
+const AR: &[Shape] = &[
+    HStripes(&[rgb(116, 172, 223), WHITE, rgb(116, 172, 223)]),
+    Disc(0.5, 0.5, 0.1, rgb(246, 180, 14)),
+];
+
+const AS: &[Shape] = &[
+    HStripes(&[rgb(0, 0, 102)]),
+    Polygon(&[(1.0, 0.0), (0.0, 0.5), (1.0, 1.0)], rgb(191, 10, 48)),
+    Polygon(&[(1.0, 0.08), (0.12, 0.5), (1.0, 0.92)], WHITE),
+    Disc(0.7, 0.5, 0.15, rgb(139, 90, 43)),
+];
+
+const AT: &[Shape] = &[HStripes(&[rgb(200, 16, 46), WHITE, rgb(200, 16, 46)])];
+
+const AU: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Star(0.25, 0.75, 0.15, 7, WHITE),
+    Star(0.75, 0.2, 0.07, 7, WHITE),
+    Star(0.6, 0.45, 0.07, 7, WHITE),
+    Star(0.88, 0.4, 0.07, 7, WHITE),
+    Star(0.75, 0.83, 0.07, 7, WHITE),
+    Star(0.8, 0.56, 0.035, 5, WHITE),
+];
+
+const AW: &[Shape] = &[
+    HStripes(&[rgb(65, 143, 222)]),
+    Rect(0.0, 0.66, 1.0, 0.72, rgb(249, 209, 0)),
+    Rect(0.0, 0.78, 1.0, 0.84, rgb(249, 209, 0)),
+    Star(0.15, 0.2, 0.15, 4, WHITE),
+    Star(0.15, 0.2, 0.11, 4, rgb(239, 51, 64)),
+];
+
+const AX: &[Shape] = &[
+    HStripes(&[rgb(0, 100, 173)]),
+    Cross(0.36, 0.5, 0.13, rgb(255, 211, 0)),
+    Cross(0.36, 0.5, 0.06, rgb(218, 14, 21)),
+];
+
+const AZ: &[Shape] = &[
+    HStripes(&[rgb(0, 181, 226), rgb(239, 51, 64), rgb(80, 158, 47)]),
+    Crescent(0.47, 0.5, 0.12, 0.03, 0.0, 0.1, WHITE),
+    Star(0.56, 0.5, 0.06, 8, WHITE),
+];
+
+const BA: &[Shape] = &[
+    HStripes(&[rgb(0, 35, 149)]),
+    Polygon(&[(0.27, 0.0), (0.77, 0.0), (0.77, 1.0)], rgb(254, 203, 0)),
+    Star(0.225, 0.05, 0.055, 5, WHITE),
+    Star(0.3, 0.2, 0.055, 5, WHITE),
+    Star(0.375, 0.35, 0.055, 5, WHITE),
+    Star(0.45, 0.5, 0.055, 5, WHITE),
+    Star(0.525, 0.65, 0.055, 5, WHITE),
+    Star(0.6, 0.8, 0.055, 5, WHITE),
+    Star(0.675, 0.95, 0.055, 5, WHITE),
+];
+
+const BB: &[Shape] = &[
+    VStripes(&[rgb(0, 38, 127), rgb(255, 199, 38), rgb(0, 38, 127)]),
+    Rect(0.49, 0.25, 0.51, 0.75, BLACK),
+    Rect(0.43, 0.28, 0.45, 0.5, BLACK),
+    Rect(0.55, 0.28, 0.57, 0.5, BLACK),
+    Rect(0.43, 0.48, 0.57, 0.52, BLACK),
+];
+
+const BD: &[Shape] = &[
+    HStripes(&[rgb(0, 106, 78)]),
+    Disc(0.45, 0.5, 0.3, rgb(244, 42, 65)),
+];
+
+const BE: &[Shape] = &[VStripes(&[BLACK, rgb(253, 218, 36), rgb(239, 51, 64)])];
+
+const BF: &[Shape] = &[
+    HStripes(&[rgb(239, 43, 45), rgb(0, 158, 73)]),
+    Star(0.5, 0.5, 0.17, 5, rgb(252, 209, 22)),
+];
+
+const BG: &[Shape] = &[HStripes(&[WHITE, rgb(0, 150, 110), rgb(214, 38, 18)])];
+
+const BH: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38)]),
+    Polygon(
+        &[
+            (0.0, 0.0),
+            (0.25, 0.0),
+            (0.38, 0.1),
+            (0.25, 0.2),
+            (0.38, 0.3),
+            (0.25, 0.4),
+            (0.38, 0.5),
+            (0.25, 0.6),
+            (0.38, 0.7),
+            (0.25, 0.8),
+            (0.38, 0.9),
+            (0.25, 1.0),
+            (0.0, 1.0),
+        ],
+        WHITE,
+    ),
+];
+
+const BI: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.5, 0.5)], rgb(30, 181, 58)),
+    Polygon(&[(0.0, 1.0), (1.0, 1.0), (0.5, 0.5)], rgb(30, 181, 58)),
+    Saltire(0.08, WHITE),
+    Disc(0.5, 0.5, 0.25, WHITE),
+    Star(0.5, 0.38, 0.06, 6, rgb(206, 17, 38)),
+    Star(0.44, 0.58, 0.06, 6, rgb(206, 17, 38)),
+    Star(0.56, 0.58, 0.06, 6, rgb(206, 17, 38)),
+];
+
+const BJ: &[Shape] = &[
+    HStripes(&[rgb(252, 209, 22), rgb(232, 17, 45)]),
+    Rect(0.0, 0.0, 0.4, 1.0, rgb(0, 135, 81)),
+];
+
+const BM: &[Shape] = &[
+    HStripes(&[rgb(200, 16, 46)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, WHITE),
+];
+
+const BN: &[Shape] = &[
+    HStripes(&[rgb(247, 224, 23)]),
+    Polygon(&[(0.0, 0.1), (0.0, 0.3), (1.0, 0.8), (1.0, 0.6)], WHITE),
+    Polygon(&[(0.0, 0.3), (0.0, 0.5), (1.0, 1.0), (1.0, 0.8)], BLACK),
+    Disc(0.5, 0.5, 0.15, rgb(207, 16, 32)),
+];
+
+const BO: &[Shape] = &[HStripes(&[
+    rgb(213, 43, 30),
+    rgb(249, 228, 0),
+    rgb(0, 122, 51),
+])];
+
+const BQ: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Polygon(&[(0.0, 0.0), (0.55, 0.0), (0.0, 0.7)], rgb(248, 200, 0)),
+    Polygon(&[(1.0, 0.3), (1.0, 1.0), (0.45, 1.0)], rgb(18, 32, 103)),
+    Ring(0.3, 0.42, 0.1, 0.02, BLACK),
+    Star(0.3, 0.42, 0.06, 6, rgb(206, 17, 38)),
+];
+
+const BR: &[Shape] = &[
+    HStripes(&[rgb(0, 156, 59)]),
+    Polygon(
+        &[(0.09, 0.5), (0.5, 0.085), (0.91, 0.5), (0.5, 0.915)],
+        rgb(255, 223, 0),
+    ),
+    Disc(0.5, 0.5, 0.25, rgb(0, 39, 118)),
+    Polygon(
+        &[(0.35, 0.43), (0.65, 0.52), (0.65, 0.56), (0.35, 0.47)],
+        WHITE,
+    ),
+];
+
+const BS: &[Shape] = &[
+    HStripes(&[rgb(0, 171, 201), rgb(250, 224, 66), rgb(0, 171, 201)]),
+    Polygon(&[(0.0, 0.0), (0.4, 0.5), (0.0, 1.0)], BLACK),
+];
+
+const BT: &[Shape] = &[
+    HStripes(&[rgb(255, 78, 18)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], rgb(255, 213, 32)),
+    Polygon(
+        &[(0.3, 0.62), (0.7, 0.25), (0.72, 0.38), (0.35, 0.72)],
+        WHITE,
+    ),
+];
+
+const BW: &[Shape] = &[
+    HStripes(&[rgb(117, 170, 219)]),
+    Rect(0.0, 0.375, 1.0, 0.625, WHITE),
+    Rect(0.0, 0.4167, 1.0, 0.5833, BLACK),
+];
+
+const BY: &[Shape] = &[
+    HBands(&[(2.0, rgb(200, 49, 62)), (1.0, rgb(0, 153, 40))]),
+    Rect(0.0, 0.0, 0.11, 1.0, WHITE),
+    Rect(0.045, 0.0, 0.065, 1.0, rgb(200, 49, 62)),
+];
+
+const BZ: &[Shape] = &[
+    HStripes(&[rgb(0, 56, 147)]),
+    Rect(0.0, 0.0, 1.0, 0.1, rgb(206, 17, 38)),
+    Rect(0.0, 0.9, 1.0, 1.0, rgb(206, 17, 38)),
+    Disc(0.5, 0.5, 0.3, WHITE),
+    Ring(0.5, 0.5, 0.27, 0.03, rgb(0, 113, 52)),
+];
+
+const CA: &[Shape] = &[
+    VBands(&[(1.0, rgb(216, 6, 33)), (2.0, WHITE), (1.0, rgb(216, 6, 33))]),
+    Polygon(
+        &[
+            (0.5, 0.14),
+            (0.47, 0.25),
+            (0.44, 0.23),
+            (0.45, 0.38),
+            (0.41, 0.31),
+            (0.39, 0.35),
+            (0.36, 0.33),
+            (0.38, 0.45),
+            (0.36, 0.47),
+            (0.44, 0.56),
+            (0.43, 0.6),
+            (0.495, 0.58),
+            (0.495, 0.78),
+            (0.505, 0.78),
+            (0.505, 0.58),
+            (0.57, 0.6),
+            (0.56, 0.56),
+            (0.64, 0.47),
+            (0.62, 0.45),
+            (0.64, 0.33),
+            (0.61, 0.35),
+            (0.59, 0.31),
+            (0.55, 0.38),
+            (0.56, 0.23),
+            (0.53, 0.25),
+        ],
+        rgb(216, 6, 33),
+    ),
+];
+
+const CC: &[Shape] = &[
+    HStripes(&[rgb(0, 128, 0)]),
+    Disc(0.2, 0.3, 0.15, rgb(255, 230, 0)),
+    Crescent(0.45, 0.5, 0.12, 0.04, 0.0, 0.1, rgb(255, 230, 0)),
+    Star(0.78, 0.2, 0.07, 7, rgb(255, 230, 0)),
+    Star(0.68, 0.45, 0.07, 7, rgb(255, 230, 0)),
+    Star(0.88, 0.42, 0.07, 7, rgb(255, 230, 0)),
+    Star(0.78, 0.78, 0.07, 7, rgb(255, 230, 0)),
+];
+
+const CD: &[Shape] = &[
+    HStripes(&[rgb(0, 127, 255)]),
+    Rising(0.2, rgb(247, 216, 23)),
+    Rising(0.14, rgb(206, 16, 33)),
+    Star(0.17, 0.2, 0.12, 5, rgb(247, 216, 23)),
+];
+
+const CF: &[Shape] = &[
+    HStripes(&[rgb(0, 48, 130), WHITE, rgb(40, 150, 40), rgb(255, 206, 0)]),
+    Rect(0.44, 0.0, 0.56, 1.0, rgb(210, 16, 52)),
+    Star(0.15, 0.12, 0.08, 5, rgb(255, 206, 0)),
+];
+
+const CG: &[Shape] = &[
+    HStripes(&[rgb(251, 222, 74)]),
+    Polygon(&[(0.0, 0.0), (0.6, 0.0), (0.0, 0.9)], rgb(0, 149, 67)),
+    Polygon(&[(1.0, 0.1), (1.0, 1.0), (0.4, 1.0)], rgb(220, 36, 31)),
+];
+
+const CH: &[Shape] = &[
+    HStripes(&[rgb(218, 41, 28)]),
+    Rect(0.4375, 0.2, 0.5625, 0.8, WHITE),
+    Rect(0.3125, 0.4, 0.6875, 0.6, WHITE),
+];
+
+const CI: &[Shape] = &[VStripes(&[rgb(247, 127, 0), WHITE, rgb(0, 158, 96)])];
+
+const CK: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Ring(0.75, 0.5, 0.22, 0.045, WHITE),
+];
+
+const CL: &[Shape] = &[
+    HStripes(&[WHITE, rgb(213, 43, 30)]),
+    Rect(0.0, 0.0, 0.3125, 0.5, rgb(0, 57, 166)),
+    Star(0.156, 0.25, 0.15, 5, WHITE),
+];
+
+const CM: &[Shape] = &[
+    VStripes(&[rgb(0, 122, 94), rgb(206, 17, 38), rgb(252, 209, 22)]),
+    Star(0.5, 0.5, 0.15, 5, rgb(252, 209, 22)),
+];
+
+const CN: &[Shape] = &[
+    HStripes(&[rgb(238, 28, 37)]),
+    Star(0.166, 0.25, 0.15, 5, rgb(255, 255, 0)),
+    Star(0.333, 0.1, 0.05, 5, rgb(255, 255, 0)),
+    Star(0.4, 0.2, 0.05, 5, rgb(255, 255, 0)),
+    Star(0.4, 0.35, 0.05, 5, rgb(255, 255, 0)),
+    Star(0.333, 0.45, 0.05, 5, rgb(255, 255, 0)),
+];
+
+const CO: &[Shape] = &[HBands(&[
+    (2.0, rgb(252, 209, 22)),
+    (1.0, rgb(0, 56, 147)),
+    (1.0, rgb(206, 17, 38)),
+])];
+
+const CR: &[Shape] = &[HBands(&[
+    (1.0, rgb(0, 43, 127)),
+    (1.0, WHITE),
+    (2.0, rgb(206, 17, 38)),
+    (1.0, WHITE),
+    (1.0, rgb(0, 43, 127)),
+])];
+
+const CU: &[Shape] = &[
+    HStripes(&[
+        rgb(0, 42, 143),
+        WHITE,
+        rgb(0, 42, 143),
+        WHITE,
+        rgb(0, 42, 143),
+    ]),
+    Polygon(&[(0.0, 0.0), (0.43, 0.5), (0.0, 1.0)], rgb(207, 20, 43)),
+    Star(0.15, 0.5, 0.13, 5, WHITE),
+];
+
+const CV: &[Shape] = &[
+    HBands(&[
+        (6.0, rgb(0, 56, 147)),
+        (1.0, WHITE),
+        (1.0, rgb(207, 32, 39)),
+        (1.0, WHITE),
+        (3.0, rgb(0, 56, 147)),
+    ]),
+    Ring(0.38, 0.625, 0.24, 0.035, rgb(247, 215, 0)),
+];
+
+const CW: &[Shape] = &[
+    HStripes(&[rgb(0, 43, 127)]),
+    Rect(0.0, 0.625, 1.0, 0.75, rgb(249, 232, 20)),
+    Star(0.1, 0.125, 0.07, 5, WHITE),
+    Star(0.2, 0.27, 0.09, 5, WHITE),
+];
+
+const CX: &[Shape] = &[
+    HStripes(&[rgb(28, 138, 66)]),
+    Polygon(&[(0.0, 0.0), (1.0, 1.0), (0.0, 1.0)], rgb(0, 33, 165)),
+    Disc(0.5, 0.5, 0.2, rgb(255, 206, 0)),
+    Star(0.12, 0.45, 0.06, 7, WHITE),
+    Star(0.25, 0.75, 0.06, 7, WHITE),
+    Star(0.1, 0.8, 0.06, 7, WHITE),
+    Star(0.3, 0.6, 0.06, 7, WHITE),
+];
+
+const CY: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Polygon(
+        &[
+            (0.3, 0.35),
+            (0.5, 0.28),
+            (0.7, 0.3),
+            (0.62, 0.45),
+            (0.38, 0.5),
+        ],
+        rgb(212, 121, 0),
+    ),
+    Polygon(
+        &[(0.4, 0.62), (0.5, 0.7), (0.6, 0.62), (0.5, 0.66)],
+        rgb(75, 86, 33),
+    ),
+];
+
+const CZ: &[Shape] = &[
+    HStripes(&[WHITE, rgb(215, 20, 26)]),
+    Polygon(&[(0.0, 0.0), (0.5, 0.5), (0.0, 1.0)], rgb(17, 69, 126)),
+];
+
+const DE: &[Shape] = &[HStripes(&[BLACK, rgb(221, 0, 0), rgb(255, 206, 0)])];
+
+const DJ: &[Shape] = &[
+    HStripes(&[rgb(106, 178, 231), rgb(18, 173, 43)]),
+    Polygon(&[(0.0, 0.0), (0.5, 0.5), (0.0, 1.0)], WHITE),
+    Star(0.16, 0.5, 0.1, 5, rgb(215, 20, 26)),
+];
+
+const DK: &[Shape] = &[HStripes(&[rgb(200, 16, 46)]), Cross(0.36, 0.5, 0.07, WHITE)];
+
+const DM: &[Shape] = &[
+    HStripes(&[rgb(0, 106, 78)]),
+    Cross(0.5, 0.5, 0.1, rgb(252, 209, 22)),
+    Cross(0.5, 0.5, 0.065, BLACK),
+    Cross(0.5, 0.5, 0.03, WHITE),
+    Disc(0.5, 0.5, 0.22, rgb(210, 16, 52)),
+    Disc(0.5, 0.5, 0.08, rgb(100, 40, 120)),
+];
+
+const DO: &[Shape] = &[
+    Rect(0.0, 0.0, 0.5, 0.5, rgb(0, 45, 98)),
+    Rect(0.5, 0.0, 1.0, 0.5, rgb(206, 17, 38)),
+    Rect(0.0, 0.5, 0.5, 1.0, rgb(206, 17, 38)),
+    Rect(0.5, 0.5, 1.0, 1.0, rgb(0, 45, 98)),
+    Cross(0.5, 0.5, 0.08, WHITE),
+    Disc(0.5, 0.5, 0.07, rgb(0, 100, 0)),
+];
+
+const DZ: &[Shape] = &[
+    VStripes(&[rgb(0, 98, 51), WHITE]),
+    Crescent(0.5, 0.5, 0.25, 0.06, 0.0, 0.21, rgb(210, 16, 52)),
+    Star(0.56, 0.5, 0.1, 5, rgb(210, 16, 52)),
+];
+
+const EC: &[Shape] = &[
+    HBands(&[
+        (2.0, rgb(255, 221, 0)),
+        (1.0, rgb(3, 78, 162)),
+        (1.0, rgb(237, 28, 36)),
+    ]),
+    Disc(0.5, 0.5, 0.12, rgb(150, 120, 60)),
+];
+
+const EE: &[Shape] = &[HStripes(&[rgb(0, 114, 206), BLACK, WHITE])];
+
+const EG: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38), WHITE, BLACK]),
+    Disc(0.5, 0.5, 0.1, rgb(192, 147, 0)),
+];
+
+const EH: &[Shape] = &[
+    HStripes(&[BLACK, WHITE, rgb(0, 122, 61)]),
+    Polygon(&[(0.0, 0.0), (0.33, 0.5), (0.0, 1.0)], rgb(198, 12, 48)),
+    Crescent(0.5, 0.5, 0.12, 0.04, 0.0, 0.1, rgb(198, 12, 48)),
+    Star(0.53, 0.5, 0.06, 5, rgb(198, 12, 48)),
+];
+
+const ER: &[Shape] = &[
+    HStripes(&[rgb(18, 173, 43), rgb(65, 137, 221)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.5), (0.0, 1.0)], rgb(234, 4, 14)),
+    Ring(0.22, 0.5, 0.15, 0.03, rgb(255, 199, 44)),
+];
+
+const ES: &[Shape] = &[
+    HBands(&[
+        (1.0, rgb(170, 21, 27)),
+        (2.0, rgb(241, 191, 0)),
+        (1.0, rgb(170, 21, 27)),
+    ]),
+    Rect(0.25, 0.38, 0.33, 0.62, rgb(170, 21, 27)),
+];
+
+const ET: &[Shape] = &[
+    HStripes(&[rgb(7, 137, 48), rgb(252, 221, 9), rgb(218, 18, 26)]),
+    Disc(0.5, 0.5, 0.22, rgb(15, 71, 175)),
+    Star(0.5, 0.5, 0.15, 5, rgb(252, 221, 9)),
+];
+
+const FI: &[Shape] = &[HStripes(&[WHITE]), Cross(0.36, 0.5, 0.09, rgb(0, 47, 108))];
+
+const FJ: &[Shape] = &[
+    HStripes(&[rgb(104, 191, 229)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Rect(0.68, 0.3, 0.82, 0.7, WHITE),
+    Rect(0.68, 0.3, 0.82, 0.4, rgb(206, 17, 38)),
+];
+
+const FK: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, rgb(97, 176, 227)),
+];
+
+const FM: &[Shape] = &[
+    HStripes(&[rgb(117, 178, 221)]),
+    Star(0.5, 0.2, 0.1, 5, WHITE),
+    Star(0.5, 0.8, 0.1, 5, WHITE),
+    Star(0.33, 0.5, 0.1, 5, WHITE),
+    Star(0.67, 0.5, 0.1, 5, WHITE),
+];
+
+const FO: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Cross(0.36, 0.5, 0.12, rgb(0, 101, 189)),
+    Cross(0.36, 0.5, 0.06, rgb(239, 48, 62)),
+];
+
+const FR: &[Shape] = &[VStripes(&[rgb(0, 85, 164), WHITE, rgb(239, 65, 53)])];
+
+const GA: &[Shape] = &[HStripes(&[
+    rgb(0, 158, 96),
+    rgb(252, 209, 22),
+    rgb(58, 117, 196),
+])];
+
+const GB: &[Shape] = &[UnionFlag(0.0, 0.0, 1.0, 1.0)];
+
+const GD: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38)]),
+    Rect(0.09, 0.14, 0.91, 0.86, rgb(0, 122, 94)),
+    Polygon(&[(0.09, 0.14), (0.91, 0.14), (0.5, 0.5)], rgb(252, 209, 22)),
+    Polygon(&[(0.09, 0.86), (0.91, 0.86), (0.5, 0.5)], rgb(252, 209, 22)),
+    Disc(0.5, 0.5, 0.12, rgb(206, 17, 38)),
+    Star(0.5, 0.5, 0.08, 5, rgb(252, 209, 22)),
+    Star(0.3, 0.07, 0.05, 5, rgb(252, 209, 22)),
+    Star(0.5, 0.07, 0.05, 5, rgb(252, 209, 22)),
+    Star(0.7, 0.07, 0.05, 5, rgb(252, 209, 22)),
+    Star(0.3, 0.93, 0.05, 5, rgb(252, 209, 22)),
+    Star(0.5, 0.93, 0.05, 5, rgb(252, 209, 22)),
+    Star(0.7, 0.93, 0.05, 5, rgb(252, 209, 22)),
+    Disc(0.2, 0.5, 0.06, rgb(252, 209, 22)),
+];
+
+const GE: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Cross(0.5, 0.5, 0.1, rgb(255, 0, 0)),
+    Rect(0.2344, 0.15, 0.2656, 0.35, rgb(255, 0, 0)),
+    Rect(0.1875, 0.225, 0.3125, 0.275, rgb(255, 0, 0)),
+    Rect(0.7344, 0.15, 0.7656, 0.35, rgb(255, 0, 0)),
+    Rect(0.6875, 0.225, 0.8125, 0.275, rgb(255, 0, 0)),
+    Rect(0.2344, 0.65, 0.2656, 0.85, rgb(255, 0, 0)),
+    Rect(0.1875, 0.725, 0.3125, 0.775, rgb(255, 0, 0)),
+    Rect(0.7344, 0.65, 0.7656, 0.85, rgb(255, 0, 0)),
+    Rect(0.6875, 0.725, 0.8125, 0.775, rgb(255, 0, 0)),
+];
+
+const GG: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Cross(0.5, 0.5, 0.12, rgb(232, 17, 45)),
+    Rect(0.4844, 0.4, 0.5156, 0.6, rgb(249, 221, 22)),
+    Rect(0.4375, 0.475, 0.5625, 0.525, rgb(249, 221, 22)),
+];
+
+const GH: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38), rgb(252, 209, 22), rgb(0, 107, 63)]),
+    Star(0.5, 0.5, 0.16, 5, BLACK),
+];
+
+const GI: &[Shape] = &[
+    HBands(&[(2.0, WHITE), (1.0, rgb(218, 0, 12))]),
+    Rect(0.38, 0.25, 0.62, 0.6, rgb(218, 0, 12)),
+    Rect(0.48, 0.7, 0.52, 0.9, rgb(248, 215, 0)),
+];
+
+const GL: &[Shape] = &[
+    HStripes(&[WHITE, rgb(208, 12, 51)]),
+    HalfDisc(0.39, 0.5, 0.33, true, rgb(208, 12, 51)),
+    HalfDisc(0.39, 0.5, 0.33, false, WHITE),
+];
+
+const GM: &[Shape] = &[HBands(&[
+    (6.0, rgb(206, 17, 38)),
+    (1.0, WHITE),
+    (4.0, rgb(12, 28, 140)),
+    (1.0, WHITE),
+    (6.0, rgb(58, 119, 40)),
+])];
+
+const GN: &[Shape] = &[VStripes(&[
+    rgb(206, 17, 38),
+    rgb(252, 209, 22),
+    rgb(0, 148, 96),
+])];
+
+const GQ: &[Shape] = &[
+    HStripes(&[rgb(62, 154, 0), WHITE, rgb(227, 32, 17)]),
+    Polygon(&[(0.0, 0.0), (0.25, 0.5), (0.0, 1.0)], rgb(0, 115, 206)),
+    Disc(0.5, 0.5, 0.08, rgb(150, 150, 150)),
+];
+
+const GR: &[Shape] = &[
+    HStripes(&[
+        rgb(13, 94, 175),
+        WHITE,
+        rgb(13, 94, 175),
+        WHITE,
+        rgb(13, 94, 175),
+        WHITE,
+        rgb(13, 94, 175),
+        WHITE,
+        rgb(13, 94, 175),
+    ]),
+    Rect(0.0, 0.0, 0.3472, 0.5556, rgb(13, 94, 175)),
+    Rect(0.0, 0.2222, 0.3472, 0.3333, WHITE),
+    Rect(0.1389, 0.0, 0.2083, 0.5556, WHITE),
+];
+
+const GS: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, rgb(200, 200, 200)),
+];
+
+const GT: &[Shape] = &[
+    VStripes(&[rgb(73, 151, 208), WHITE, rgb(73, 151, 208)]),
+    Disc(0.5, 0.5, 0.14, rgb(110, 160, 90)),
+];
+
+const GU: &[Shape] = &[
+    HStripes(&[rgb(191, 10, 48)]),
+    Rect(0.025, 0.04, 0.975, 0.96, rgb(0, 40, 104)),
+    Disc(0.5, 0.5, 0.28, rgb(191, 10, 48)),
+    Disc(0.5, 0.5, 0.25, rgb(104, 170, 230)),
+];
+
+const GW: &[Shape] = &[
+    HStripes(&[rgb(252, 209, 22), rgb(0, 148, 96)]),
+    Rect(0.0, 0.0, 0.333, 1.0, rgb(206, 17, 38)),
+    Star(0.167, 0.5, 0.14, 5, BLACK),
+];
+
+const GY: &[Shape] = &[
+    HStripes(&[rgb(0, 158, 73)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.5), (0.0, 1.0)], WHITE),
+    Polygon(&[(0.0, 0.04), (0.95, 0.5), (0.0, 0.96)], rgb(252, 209, 22)),
+    Polygon(&[(0.0, 0.0), (0.5, 0.5), (0.0, 1.0)], BLACK),
+    Polygon(&[(0.0, 0.05), (0.45, 0.5), (0.0, 0.95)], rgb(206, 17, 38)),
+];
+
+const HK: &[Shape] = &[
+    HStripes(&[rgb(222, 41, 16)]),
+    Star(0.5, 0.5, 0.3, 5, WHITE),
+    Disc(0.5, 0.5, 0.04, rgb(222, 41, 16)),
+];
+
+const HN: &[Shape] = &[
+    HStripes(&[rgb(0, 115, 207), WHITE, rgb(0, 115, 207)]),
+    Star(0.5, 0.5, 0.05, 5, rgb(0, 115, 207)),
+    Star(0.4, 0.42, 0.05, 5, rgb(0, 115, 207)),
+    Star(0.4, 0.58, 0.05, 5, rgb(0, 115, 207)),
+    Star(0.6, 0.42, 0.05, 5, rgb(0, 115, 207)),
+    Star(0.6, 0.58, 0.05, 5, rgb(0, 115, 207)),
+];
+
+const HR: &[Shape] = &[
+    HStripes(&[rgb(255, 0, 0), WHITE, rgb(23, 23, 150)]),
+    Rect(0.42, 0.28, 0.58, 0.62, rgb(255, 0, 0)),
+    Rect(0.4733, 0.28, 0.5267, 0.3933, WHITE),
+    Rect(0.42, 0.3933, 0.4733, 0.5067, WHITE),
+    Rect(0.5267, 0.3933, 0.58, 0.5067, WHITE),
+    Rect(0.4733, 0.5067, 0.5267, 0.62, WHITE),
+];
+
+const HT: &[Shape] = &[
+    HStripes(&[rgb(0, 32, 159), rgb(210, 16, 52)]),
+    Rect(0.4, 0.35, 0.6, 0.65, WHITE),
+    Disc(0.5, 0.5, 0.08, rgb(0, 122, 61)),
+];
+
+const HU: &[Shape] = &[HStripes(&[rgb(206, 41, 57), WHITE, rgb(71, 112, 80)])];
+
+const ID: &[Shape] = &[HStripes(&[rgb(255, 0, 0), WHITE])];
+
+const IE: &[Shape] = &[VStripes(&[rgb(22, 155, 98), WHITE, rgb(255, 136, 62)])];
+
+const IL: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Rect(0.0, 0.1, 1.0, 0.25, rgb(0, 56, 184)),
+    Rect(0.0, 0.75, 1.0, 0.9, rgb(0, 56, 184)),
+    Star(0.5, 0.5, 0.17, 6, rgb(0, 56, 184)),
+    Star(0.5, 0.5, 0.12, 6, WHITE),
+];
+
+const IM: &[Shape] = &[
+    HStripes(&[rgb(207, 20, 43)]),
+    Star(0.5, 0.5, 0.22, 3, WHITE),
+];
+
+const IN: &[Shape] = &[
+    HStripes(&[rgb(255, 153, 51), WHITE, rgb(19, 136, 8)]),
+    Ring(0.5, 0.5, 0.13, 0.025, rgb(0, 0, 128)),
+    Disc(0.5, 0.5, 0.03, rgb(0, 0, 128)),
+];
+
+const IO: &[Shape] = &[
+    HStripes(&[
+        WHITE,
+        rgb(0, 33, 105),
+        WHITE,
+        rgb(0, 33, 105),
+        WHITE,
+        rgb(0, 33, 105),
+        WHITE,
+        rgb(0, 33, 105),
+        WHITE,
+        rgb(0, 33, 105),
+    ]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Rect(0.74, 0.35, 0.76, 0.8, rgb(100, 70, 40)),
+    Disc(0.75, 0.35, 0.1, rgb(0, 130, 60)),
+];
+
+const IQ: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38), WHITE, BLACK]),
+    Rect(0.33, 0.46, 0.67, 0.54, rgb(0, 122, 61)),
+];
+
+const IR: &[Shape] = &[
+    HStripes(&[rgb(35, 159, 64), WHITE, rgb(218, 0, 0)]),
+    Disc(0.5, 0.5, 0.1, rgb(218, 0, 0)),
+];
+
+const IS: &[Shape] = &[
+    HStripes(&[rgb(2, 82, 156)]),
+    Cross(0.36, 0.5, 0.11, WHITE),
+    Cross(0.36, 0.5, 0.055, rgb(220, 30, 53)),
+];
+
+const IT: &[Shape] = &[VStripes(&[rgb(0, 146, 70), WHITE, rgb(206, 43, 55)])];
+
+const JE: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Saltire(0.07, rgb(223, 17, 43)),
+    Rect(0.47, 0.1, 0.53, 0.28, rgb(223, 17, 43)),
+    Rect(0.47, 0.05, 0.53, 0.1, rgb(255, 206, 0)),
+];
+
+const JM: &[Shape] = &[
+    HStripes(&[BLACK]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.5, 0.5)], rgb(0, 155, 58)),
+    Polygon(&[(0.0, 1.0), (1.0, 1.0), (0.5, 0.5)], rgb(0, 155, 58)),
+    Saltire(0.09, rgb(254, 209, 0)),
+];
+
+const JO: &[Shape] = &[
+    HStripes(&[BLACK, WHITE, rgb(0, 122, 61)]),
+    Polygon(&[(0.0, 0.0), (0.5, 0.5), (0.0, 1.0)], rgb(206, 17, 38)),
+    Star(0.16, 0.5, 0.07, 7, WHITE),
+];
+
+const JP: &[Shape] = &[HStripes(&[WHITE]), Disc(0.5, 0.5, 0.3, rgb(188, 0, 45))];
+
+const KE: &[Shape] = &[
+    HBands(&[
+        (6.0, BLACK),
+        (1.0, WHITE),
+        (6.0, rgb(187, 0, 0)),
+        (1.0, WHITE),
+        (6.0, rgb(0, 102, 0)),
+    ]),
+    Polygon(
+        &[
+            (0.5, 0.15),
+            (0.56, 0.3),
+            (0.57, 0.5),
+            (0.56, 0.7),
+            (0.5, 0.85),
+            (0.44, 0.7),
+            (0.43, 0.5),
+            (0.44, 0.3),
+        ],
+        rgb(187, 0, 0),
+    ),
+    Rect(0.44, 0.42, 0.56, 0.44, WHITE),
+    Rect(0.44, 0.56, 0.56, 0.58, WHITE),
+];
+
+const KG: &[Shape] = &[
+    HStripes(&[rgb(232, 17, 45)]),
+    Star(0.5, 0.5, 0.3, 20, rgb(255, 239, 0)),
+    Disc(0.5, 0.5, 0.15, rgb(232, 17, 45)),
+    Disc(0.5, 0.5, 0.12, rgb(255, 239, 0)),
+];
+
+const KH: &[Shape] = &[
+    HBands(&[
+        (1.0, rgb(3, 46, 161)),
+        (2.0, rgb(224, 0, 37)),
+        (1.0, rgb(3, 46, 161)),
+    ]),
+    Polygon(
+        &[
+            (0.35, 0.62),
+            (0.38, 0.45),
+            (0.42, 0.45),
+            (0.44, 0.35),
+            (0.48, 0.35),
+            (0.5, 0.28),
+            (0.52, 0.35),
+            (0.56, 0.35),
+            (0.58, 0.45),
+            (0.62, 0.45),
+            (0.65, 0.62),
+        ],
+        WHITE,
+    ),
+];
+
+const KI: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38), rgb(0, 56, 147)]),
+    Rect(0.0, 0.6, 1.0, 0.65, WHITE),
+    Rect(0.0, 0.75, 1.0, 0.8, WHITE),
+    Rect(0.0, 0.9, 1.0, 0.95, WHITE),
+    Star(0.5, 0.5, 0.3, 17, rgb(252, 209, 22)),
+    HalfDisc(0.5, 0.5, 0.18, true, rgb(252, 209, 22)),
+    Polygon(
+        &[(0.38, 0.22), (0.62, 0.15), (0.55, 0.26), (0.45, 0.28)],
+        rgb(252, 209, 22),
+    ),
+];
+
+const KM: &[Shape] = &[
+    HStripes(&[rgb(255, 196, 0), WHITE, rgb(206, 17, 38), rgb(58, 117, 196)]),
+    Polygon(&[(0.0, 0.0), (0.5, 0.5), (0.0, 1.0)], rgb(61, 142, 51)),
+    Crescent(0.15, 0.5, 0.18, 0.06, 0.0, 0.16, WHITE),
+    Star(0.25, 0.35, 0.03, 5, WHITE),
+    Star(0.25, 0.45, 0.03, 5, WHITE),
+    Star(0.25, 0.55, 0.03, 5, WHITE),
+    Star(0.25, 0.65, 0.03, 5, WHITE),
+];
+
+const KN: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], rgb(0, 158, 73)),
+    Rising(0.2, rgb(252, 209, 22)),
+    Rising(0.14, BLACK),
+    Star(0.35, 0.65, 0.07, 5, WHITE),
+    Star(0.65, 0.35, 0.07, 5, WHITE),
+];
+
+const KP: &[Shape] = &[
+    HBands(&[
+        (6.0, rgb(2, 79, 162)),
+        (1.0, WHITE),
+        (17.0, rgb(237, 28, 39)),
+        (1.0, WHITE),
+        (6.0, rgb(2, 79, 162)),
+    ]),
+    Disc(0.33, 0.5, 0.18, WHITE),
+    Star(0.33, 0.5, 0.17, 5, rgb(237, 28, 39)),
+];
+
+const KR: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Disc(0.5, 0.5, 0.25, rgb(205, 46, 58)),
+    HalfDisc(0.5, 0.5, 0.25, false, rgb(0, 71, 160)),
+    Disc(0.422, 0.5, 0.125, rgb(205, 46, 58)),
+    Disc(0.578, 0.5, 0.125, rgb(0, 71, 160)),
+    Rect(0.15, 0.15, 0.23, 0.32, BLACK),
+    Rect(0.77, 0.15, 0.85, 0.32, BLACK),
+    Rect(0.15, 0.68, 0.23, 0.85, BLACK),
+    Rect(0.77, 0.68, 0.85, 0.85, BLACK),
+];
+
+const KW: &[Shape] = &[
+    HStripes(&[rgb(0, 122, 61), WHITE, rgb(206, 17, 38)]),
+    Polygon(
+        &[(0.0, 0.0), (0.25, 0.333), (0.25, 0.667), (0.0, 1.0)],
+        BLACK,
+    ),
+];
+
+const KY: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, WHITE),
+];
+
+const KZ: &[Shape] = &[
+    HStripes(&[rgb(0, 175, 202)]),
+    Star(0.5, 0.45, 0.25, 24, rgb(254, 197, 14)),
+    Disc(0.5, 0.45, 0.15, rgb(254, 197, 14)),
+    Rect(0.04, 0.05, 0.08, 0.95, rgb(254, 197, 14)),
+    Polygon(
+        &[(0.35, 0.68), (0.65, 0.68), (0.5, 0.76)],
+        rgb(254, 197, 14),
+    ),
+];
+
+const LA: &[Shape] = &[
+    HBands(&[
+        (1.0, rgb(206, 17, 38)),
+        (2.0, rgb(0, 40, 104)),
+        (1.0, rgb(206, 17, 38)),
+    ]),
+    Disc(0.5, 0.5, 0.2, WHITE),
+];
+
+const LB: &[Shape] = &[
+    HBands(&[
+        (1.0, rgb(237, 28, 36)),
+        (2.0, WHITE),
+        (1.0, rgb(237, 28, 36)),
+    ]),
+    Polygon(&[(0.5, 0.3), (0.62, 0.65), (0.38, 0.65)], rgb(0, 166, 81)),
+    Rect(0.49, 0.65, 0.51, 0.7, rgb(0, 166, 81)),
+];
+
+const LC: &[Shape] = &[
+    HStripes(&[rgb(102, 204, 255)]),
+    Polygon(&[(0.5, 0.15), (0.68, 0.85), (0.32, 0.85)], WHITE),
+    Polygon(&[(0.5, 0.22), (0.65, 0.85), (0.35, 0.85)], BLACK),
+    Polygon(&[(0.5, 0.5), (0.68, 0.85), (0.32, 0.85)], rgb(252, 209, 22)),
+];
+
+const LI: &[Shape] = &[
+    HStripes(&[rgb(0, 43, 127), rgb(206, 17, 38)]),
+    Rect(0.15, 0.15, 0.3, 0.35, rgb(255, 215, 0)),
+];
+
+const LK: &[Shape] = &[
+    HStripes(&[rgb(255, 190, 41)]),
+    Rect(0.04, 0.07, 0.14, 0.93, rgb(0, 83, 78)),
+    Rect(0.14, 0.07, 0.24, 0.93, rgb(255, 90, 0)),
+    Rect(0.28, 0.07, 0.96, 0.93, rgb(141, 32, 41)),
+    Polygon(
+        &[
+            (0.52, 0.7),
+            (0.55, 0.4),
+            (0.6, 0.3),
+            (0.7, 0.32),
+            (0.72, 0.45),
+            (0.78, 0.5),
+            (0.75, 0.7),
+        ],
+        rgb(255, 190, 41),
+    ),
+];
+
+const LR: &[Shape] = &[
+    HStripes(&[
+        rgb(191, 10, 48),
+        WHITE,
+        rgb(191, 10, 48),
+        WHITE,
+        rgb(191, 10, 48),
+        WHITE,
+        rgb(191, 10, 48),
+        WHITE,
+        rgb(191, 10, 48),
+        WHITE,
+        rgb(191, 10, 48),
+    ]),
+    Rect(0.0, 0.0, 0.284, 0.4545, rgb(0, 40, 104)),
+    Star(0.142, 0.227, 0.15, 5, WHITE),
+];
+
+const LS: &[Shape] = &[
+    HBands(&[(3.0, rgb(0, 32, 159)), (4.0, WHITE), (3.0, rgb(0, 155, 72))]),
+    Polygon(&[(0.42, 0.55), (0.5, 0.35), (0.58, 0.55)], BLACK),
+];
+
+const LT: &[Shape] = &[HStripes(&[
+    rgb(253, 185, 19),
+    rgb(0, 106, 68),
+    rgb(193, 39, 45),
+])];
+
+const LU: &[Shape] = &[HStripes(&[rgb(237, 41, 57), WHITE, rgb(0, 161, 222)])];
+
+const LV: &[Shape] = &[HBands(&[
+    (2.0, rgb(158, 48, 57)),
+    (1.0, WHITE),
+    (2.0, rgb(158, 48, 57)),
+])];
+
+const LY: &[Shape] = &[
+    HBands(&[
+        (1.0, rgb(231, 0, 19)),
+        (2.0, BLACK),
+        (1.0, rgb(35, 158, 70)),
+    ]),
+    Crescent(0.48, 0.5, 0.14, 0.04, 0.0, 0.115, WHITE),
+    Star(0.55, 0.5, 0.06, 5, WHITE),
+];
+
+const MA: &[Shape] = &[
+    HStripes(&[rgb(193, 39, 45)]),
+    Star(0.5, 0.5, 0.22, 5, rgb(0, 98, 51)),
+    Star(0.5, 0.5, 0.15, 5, rgb(193, 39, 45)),
+];
+
+const MC: &[Shape] = &[HStripes(&[rgb(206, 17, 38), WHITE])];
+
+const MD: &[Shape] = &[
+    VStripes(&[rgb(0, 70, 174), rgb(255, 210, 0), rgb(204, 9, 47)]),
+    Disc(0.5, 0.5, 0.13, rgb(176, 125, 43)),
+];
+
+const ME: &[Shape] = &[
+    HStripes(&[rgb(212, 175, 55)]),
+    Rect(0.04, 0.06, 0.96, 0.94, rgb(197, 0, 0)),
+    Polygon(
+        &[
+            (0.5, 0.25),
+            (0.58, 0.33),
+            (0.64, 0.3),
+            (0.6, 0.48),
+            (0.54, 0.55),
+            (0.5, 0.72),
+            (0.46, 0.55),
+            (0.4, 0.48),
+            (0.36, 0.3),
+            (0.42, 0.33),
+        ],
+        rgb(212, 175, 55),
+    ),
+];
+
+const MG: &[Shape] = &[
+    HStripes(&[rgb(252, 61, 50), rgb(0, 126, 58)]),
+    Rect(0.0, 0.0, 0.333, 1.0, WHITE),
+];
+
+const MH: &[Shape] = &[
+    HStripes(&[rgb(0, 56, 147)]),
+    Polygon(&[(0.0, 0.95), (1.0, 0.0), (1.0, 0.15)], WHITE),
+    Polygon(&[(0.0, 0.97), (1.0, 0.17), (1.0, 0.33)], rgb(221, 117, 0)),
+    Star(0.2, 0.25, 0.15, 24, WHITE),
+];
+
+const MK: &[Shape] = &[
+    HStripes(&[rgb(206, 32, 40)]),
+    Polygon(&[(0.0, 0.4), (0.5, 0.5), (0.0, 0.6)], rgb(248, 233, 49)),
+    Polygon(&[(1.0, 0.4), (0.5, 0.5), (1.0, 0.6)], rgb(248, 233, 49)),
+    Polygon(&[(0.45, 0.0), (0.5, 0.5), (0.55, 0.0)], rgb(248, 233, 49)),
+    Polygon(&[(0.45, 1.0), (0.5, 0.5), (0.55, 1.0)], rgb(248, 233, 49)),
+    Polygon(
+        &[(0.0, 0.0), (0.1, 0.0), (0.5, 0.5), (0.0, 0.12)],
+        rgb(248, 233, 49),
+    ),
+    Polygon(
+        &[(1.0, 0.0), (0.9, 0.0), (0.5, 0.5), (1.0, 0.12)],
+        rgb(248, 233, 49),
+    ),
+    Polygon(
+        &[(0.0, 1.0), (0.1, 1.0), (0.5, 0.5), (0.0, 0.88)],
+        rgb(248, 233, 49),
+    ),
+    Polygon(
+        &[(1.0, 1.0), (0.9, 1.0), (0.5, 0.5), (1.0, 0.88)],
+        rgb(248, 233, 49),
+    ),
+    Disc(0.5, 0.5, 0.17, rgb(206, 32, 40)),
+    Disc(0.5, 0.5, 0.14, rgb(248, 233, 49)),
+];
+
+const ML: &[Shape] = &[VStripes(&[
+    rgb(20, 181, 58),
+    rgb(252, 209, 22),
+    rgb(206, 17, 38),
+])];
+
+const MM: &[Shape] = &[
+    HStripes(&[rgb(254, 203, 0), rgb(52, 178, 51), rgb(234, 40, 57)]),
+    Star(0.5, 0.55, 0.32, 5, WHITE),
+];
+
+const MN: &[Shape] = &[
+    VStripes(&[rgb(196, 39, 44), rgb(1, 81, 148), rgb(196, 39, 44)]),
+    Disc(0.167, 0.3, 0.06, rgb(249, 207, 2)),
+    Rect(0.14, 0.4, 0.195, 0.85, rgb(249, 207, 2)),
+];
+
+const MO: &[Shape] = &[
+    HStripes(&[rgb(0, 120, 94)]),
+    Polygon(&[(0.4, 0.52), (0.5, 0.35), (0.6, 0.52)], WHITE),
+    Rect(0.38, 0.57, 0.62, 0.62, WHITE),
+    Star(0.5, 0.2, 0.04, 5, rgb(255, 222, 0)),
+    Star(0.4, 0.25, 0.04, 5, rgb(255, 222, 0)),
+    Star(0.6, 0.25, 0.04, 5, rgb(255, 222, 0)),
+    Star(0.33, 0.33, 0.04, 5, rgb(255, 222, 0)),
+    Star(0.67, 0.33, 0.04, 5, rgb(255, 222, 0)),
+];
+
+const MP: &[Shape] = &[
+    HStripes(&[rgb(0, 114, 206)]),
+    Ring(0.5, 0.5, 0.28, 0.04, rgb(255, 153, 0)),
+    Rect(0.47, 0.35, 0.53, 0.7, rgb(150, 150, 150)),
+    Star(0.5, 0.5, 0.15, 5, WHITE),
+];
+
+const MR: &[Shape] = &[
+    HBands(&[
+        (3.0, rgb(210, 16, 52)),
+        (14.0, rgb(0, 98, 51)),
+        (3.0, rgb(210, 16, 52)),
+    ]),
+    Crescent(0.5, 0.5, 0.22, 0.0, -0.07, 0.2, rgb(255, 214, 0)),
+    Star(0.5, 0.38, 0.08, 5, rgb(255, 214, 0)),
+];
+
+const MS: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, rgb(102, 204, 255)),
+];
+
+const MT: &[Shape] = &[
+    VStripes(&[WHITE, rgb(207, 20, 43)]),
+    Rect(0.05, 0.05, 0.15, 0.25, rgb(160, 160, 160)),
+];
+
+const MU: &[Shape] = &[HStripes(&[
+    rgb(234, 40, 57),
+    rgb(26, 32, 110),
+    rgb(255, 214, 0),
+    rgb(0, 165, 81),
+])];
+
+const MV: &[Shape] = &[
+    HStripes(&[rgb(210, 16, 52)]),
+    Rect(0.25, 0.25, 0.75, 0.75, rgb(0, 126, 58)),
+    Crescent(0.52, 0.5, 0.15, 0.05, 0.0, 0.13, WHITE),
+];
+
+const MW: &[Shape] = &[
+    HStripes(&[BLACK, rgb(206, 17, 38), rgb(51, 156, 0)]),
+    Star(0.5, 0.33, 0.2, 31, rgb(206, 17, 38)),
+    Disc(0.5, 0.33, 0.11, rgb(206, 17, 38)),
+];
+
+const MX: &[Shape] = &[
+    VStripes(&[rgb(0, 104, 71), WHITE, rgb(206, 17, 38)]),
+    Disc(0.5, 0.5, 0.12, rgb(139, 90, 43)),
+];
+
+const MY: &[Shape] = &[
+    HStripes(&[
+        rgb(204, 0, 1),
+        WHITE,
+        rgb(204, 0, 1),
+        WHITE,
+        rgb(204, 0, 1),
+        WHITE,
+        rgb(204, 0, 1),
+        WHITE,
+        rgb(204, 0, 1),
+        WHITE,
+        rgb(204, 0, 1),
+        WHITE,
+        rgb(204, 0, 1),
+        WHITE,
+    ]),
+    Rect(0.0, 0.0, 0.5, 0.5714, rgb(1, 0, 102)),
+    Crescent(0.18, 0.285, 0.18, 0.06, 0.0, 0.15, rgb(255, 204, 0)),
+    Star(0.31, 0.285, 0.1, 14, rgb(255, 204, 0)),
+];
+
+const MZ: &[Shape] = &[
+    HBands(&[
+        (6.0, rgb(0, 150, 57)),
+        (1.0, WHITE),
+        (6.0, BLACK),
+        (1.0, WHITE),
+        (6.0, rgb(252, 225, 0)),
+    ]),
+    Polygon(&[(0.0, 0.0), (0.45, 0.5), (0.0, 1.0)], rgb(210, 16, 52)),
+    Star(0.16, 0.5, 0.12, 5, rgb(252, 225, 0)),
+];
+
+const NA: &[Shape] = &[
+    HStripes(&[rgb(0, 155, 58)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], rgb(0, 53, 128)),
+    Rising(0.2, WHITE),
+    Rising(0.15, rgb(210, 16, 52)),
+    Star(0.2, 0.25, 0.12, 12, rgb(255, 206, 0)),
+];
+
+const NC: &[Shape] = &[
+    HStripes(&[rgb(0, 66, 156), rgb(237, 41, 57), rgb(0, 146, 63)]),
+    Disc(0.375, 0.5, 0.3, rgb(255, 206, 0)),
+    Rect(0.365, 0.28, 0.385, 0.72, BLACK),
+];
+
+const NE: &[Shape] = &[
+    HStripes(&[rgb(224, 82, 6), WHITE, rgb(13, 176, 43)]),
+    Disc(0.5, 0.5, 0.12, rgb(224, 82, 6)),
+];
+
+const NF: &[Shape] = &[
+    VBands(&[(7.0, rgb(0, 122, 61)), (9.0, WHITE), (7.0, rgb(0, 122, 61))]),
+    Polygon(&[(0.5, 0.15), (0.58, 0.8), (0.42, 0.8)], rgb(0, 122, 61)),
+];
+
+const NG: &[Shape] = &[VStripes(&[rgb(0, 135, 81), WHITE, rgb(0, 135, 81)])];
+
+const NI: &[Shape] = &[
+    HStripes(&[rgb(0, 103, 198), WHITE, rgb(0, 103, 198)]),
+    Ring(0.5, 0.5, 0.1, 0.02, rgb(190, 170, 60)),
+    Polygon(&[(0.5, 0.43), (0.54, 0.55), (0.46, 0.55)], rgb(0, 150, 200)),
+];
+
+const NL: &[Shape] = &[HStripes(&[rgb(174, 28, 40), WHITE, rgb(33, 70, 139)])];
+
+const NO: &[Shape] = &[
+    HStripes(&[rgb(186, 12, 47)]),
+    Cross(0.36, 0.5, 0.125, WHITE),
+    Cross(0.36, 0.5, 0.0625, rgb(0, 32, 91)),
+];
+
+const NP: &[Shape] = &[
+    Polygon(
+        &[(0.2, 0.0), (0.8, 0.45), (0.4, 0.45), (0.8, 1.0), (0.2, 1.0)],
+        rgb(0, 56, 147),
+    ),
+    Polygon(
+        &[
+            (0.23, 0.05),
+            (0.7, 0.42),
+            (0.33, 0.42),
+            (0.7, 0.97),
+            (0.23, 0.97),
+        ],
+        rgb(220, 20, 60),
+    ),
+    Disc(0.35, 0.3, 0.08, WHITE),
+    Star(0.35, 0.75, 0.1, 12, WHITE),
+];
+
+const NR: &[Shape] = &[
+    HStripes(&[rgb(0, 43, 127)]),
+    Rect(0.0, 0.46, 1.0, 0.54, rgb(255, 196, 0)),
+    Star(0.25, 0.72, 0.12, 12, WHITE),
+];
+
+const NU: &[Shape] = &[
+    HStripes(&[rgb(254, 221, 0)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.25, 0.25, 0.07, rgb(0, 36, 125)),
+    Star(0.25, 0.25, 0.06, 5, rgb(254, 221, 0)),
+];
+
+const NZ: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Star(0.75, 0.2, 0.09, 5, WHITE),
+    Star(0.75, 0.2, 0.065, 5, rgb(200, 16, 46)),
+    Star(0.66, 0.45, 0.09, 5, WHITE),
+    Star(0.66, 0.45, 0.065, 5, rgb(200, 16, 46)),
+    Star(0.86, 0.4, 0.09, 5, WHITE),
+    Star(0.86, 0.4, 0.065, 5, rgb(200, 16, 46)),
+    Star(0.75, 0.8, 0.09, 5, WHITE),
+    Star(0.75, 0.8, 0.065, 5, rgb(200, 16, 46)),
+];
+
+const OM: &[Shape] = &[
+    HStripes(&[WHITE, rgb(219, 22, 29), rgb(0, 129, 0)]),
+    Rect(0.0, 0.0, 0.25, 1.0, rgb(219, 22, 29)),
+    Disc(0.12, 0.15, 0.07, WHITE),
+];
+
+const PA: &[Shape] = &[
+    Rect(0.0, 0.0, 0.5, 0.5, WHITE),
+    Rect(0.5, 0.0, 1.0, 0.5, rgb(218, 18, 26)),
+    Rect(0.0, 0.5, 0.5, 1.0, rgb(0, 82, 147)),
+    Rect(0.5, 0.5, 1.0, 1.0, WHITE),
+    Star(0.25, 0.25, 0.12, 5, rgb(0, 82, 147)),
+    Star(0.75, 0.75, 0.12, 5, rgb(218, 18, 26)),
+];
+
+const PE: &[Shape] = &[VStripes(&[rgb(217, 16, 35), WHITE, rgb(217, 16, 35)])];
+
+const PF: &[Shape] = &[
+    HBands(&[
+        (1.0, rgb(206, 17, 38)),
+        (2.0, WHITE),
+        (1.0, rgb(206, 17, 38)),
+    ]),
+    Disc(0.5, 0.5, 0.18, rgb(255, 206, 0)),
+    Rect(0.44, 0.55, 0.56, 0.65, rgb(0, 56, 168)),
+];
+
+const PG: &[Shape] = &[
+    HStripes(&[BLACK]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)], rgb(206, 17, 38)),
+    Polygon(
+        &[(0.55, 0.2), (0.85, 0.3), (0.7, 0.45), (0.6, 0.35)],
+        rgb(252, 209, 22),
+    ),
+    Star(0.25, 0.45, 0.05, 5, WHITE),
+    Star(0.15, 0.6, 0.05, 5, WHITE),
+    Star(0.33, 0.62, 0.05, 5, WHITE),
+    Star(0.25, 0.8, 0.05, 5, WHITE),
+    Star(0.21, 0.68, 0.05, 5, WHITE),
+];
+
+const PH: &[Shape] = &[
+    HStripes(&[rgb(0, 56, 168), rgb(206, 17, 38)]),
+    Polygon(&[(0.0, 0.0), (0.54, 0.5), (0.0, 1.0)], WHITE),
+    Star(0.18, 0.5, 0.14, 8, rgb(252, 209, 22)),
+    Disc(0.18, 0.5, 0.08, rgb(252, 209, 22)),
+    Star(0.05, 0.1, 0.04, 5, rgb(252, 209, 22)),
+    Star(0.05, 0.9, 0.04, 5, rgb(252, 209, 22)),
+    Star(0.42, 0.5, 0.04, 5, rgb(252, 209, 22)),
+];
+
+const PK: &[Shape] = &[
+    VBands(&[(1.0, WHITE), (3.0, rgb(1, 65, 28))]),
+    Crescent(0.625, 0.5, 0.3, 0.07, -0.05, 0.26, WHITE),
+    Star(0.7, 0.38, 0.08, 5, WHITE),
+];
+
+const PL: &[Shape] = &[HStripes(&[WHITE, rgb(220, 20, 60)])];
+
+const PN: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, rgb(0, 150, 0)),
+];
+
+const PR: &[Shape] = &[
+    HStripes(&[rgb(237, 0, 0), WHITE, rgb(237, 0, 0), WHITE, rgb(237, 0, 0)]),
+    Polygon(&[(0.0, 0.0), (0.45, 0.5), (0.0, 1.0)], rgb(0, 80, 240)),
+    Star(0.15, 0.5, 0.12, 5, WHITE),
+];
+
+const PS: &[Shape] = &[
+    HStripes(&[BLACK, WHITE, rgb(0, 150, 57)]),
+    Polygon(&[(0.0, 0.0), (0.4, 0.5), (0.0, 1.0)], rgb(238, 42, 53)),
+];
+
+const PT: &[Shape] = &[
+    VBands(&[(2.0, rgb(0, 102, 0)), (3.0, rgb(255, 0, 0))]),
+    Disc(0.4, 0.5, 0.22, rgb(255, 255, 0)),
+    Rect(0.36, 0.35, 0.44, 0.62, WHITE),
+    Rect(0.37, 0.37, 0.43, 0.58, rgb(255, 0, 0)),
+];
+
+const PW: &[Shape] = &[
+    HStripes(&[rgb(74, 173, 214)]),
+    Disc(0.44, 0.5, 0.3, rgb(255, 222, 0)),
+];
+
+const PY: &[Shape] = &[
+    HStripes(&[rgb(213, 43, 30), WHITE, rgb(0, 56, 168)]),
+    Ring(0.5, 0.5, 0.09, 0.02, rgb(0, 100, 0)),
+];
+
+const QA: &[Shape] = &[
+    HStripes(&[rgb(138, 21, 56)]),
+    Polygon(
+        &[
+            (0.0, 0.0),
+            (0.3, 0.0),
+            (0.38, 0.0556),
+            (0.3, 0.1111),
+            (0.38, 0.1667),
+            (0.3, 0.2222),
+            (0.38, 0.2778),
+            (0.3, 0.3333),
+            (0.38, 0.3889),
+            (0.3, 0.4444),
+            (0.38, 0.5),
+            (0.3, 0.5556),
+            (0.38, 0.6111),
+            (0.3, 0.6667),
+            (0.38, 0.7222),
+            (0.3, 0.7778),
+            (0.38, 0.8333),
+            (0.3, 0.8889),
+            (0.38, 0.9444),
+            (0.3, 1.0),
+            (0.0, 1.0),
+        ],
+        WHITE,
+    ),
+];
+
+const RO: &[Shape] = &[VStripes(&[
+    rgb(0, 43, 127),
+    rgb(252, 209, 22),
+    rgb(206, 17, 38),
+])];
+
+const RS: &[Shape] = &[
+    HStripes(&[rgb(198, 54, 60), rgb(12, 64, 118), WHITE]),
+    Rect(0.27, 0.25, 0.4, 0.7, rgb(198, 54, 60)),
+    Rect(0.3, 0.32, 0.37, 0.6, WHITE),
+];
+
+const RU: &[Shape] = &[HStripes(&[WHITE, rgb(0, 57, 166), rgb(213, 43, 30)])];
+
+const RW: &[Shape] = &[
+    HBands(&[
+        (2.0, rgb(0, 161, 222)),
+        (1.0, rgb(250, 210, 1)),
+        (1.0, rgb(32, 96, 61)),
+    ]),
+    Star(0.8, 0.28, 0.14, 24, rgb(250, 210, 1)),
+    Disc(0.8, 0.28, 0.075, rgb(0, 161, 222)),
+    Disc(0.8, 0.28, 0.06, rgb(250, 210, 1)),
+];
+
+const SA: &[Shape] = &[
+    HStripes(&[rgb(0, 84, 48)]),
+    Rect(0.28, 0.3, 0.72, 0.42, WHITE),
+    Rect(0.3, 0.6, 0.7, 0.64, WHITE),
+];
+
+const SB: &[Shape] = &[
+    HStripes(&[rgb(33, 89, 40)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], rgb(0, 81, 186)),
+    Rising(0.05, rgb(252, 209, 22)),
+    Star(0.12, 0.15, 0.06, 5, WHITE),
+    Star(0.28, 0.15, 0.06, 5, WHITE),
+    Star(0.2, 0.28, 0.06, 5, WHITE),
+    Star(0.12, 0.4, 0.06, 5, WHITE),
+    Star(0.28, 0.4, 0.06, 5, WHITE),
+];
+
+const SC: &[Shape] = &[
+    HStripes(&[rgb(0, 122, 61)]),
+    Polygon(&[(0.0, 1.0), (0.0, 0.0), (0.33, 0.0)], rgb(0, 61, 136)),
+    Polygon(&[(0.0, 1.0), (0.33, 0.0), (0.67, 0.0)], rgb(252, 216, 86)),
+    Polygon(
+        &[(0.0, 1.0), (0.67, 0.0), (1.0, 0.0), (1.0, 0.33)],
+        rgb(214, 40, 40),
+    ),
+    Polygon(&[(0.0, 1.0), (1.0, 0.33), (1.0, 0.67)], WHITE),
+];
+
+const SD: &[Shape] = &[
+    HStripes(&[rgb(210, 16, 52), WHITE, BLACK]),
+    Polygon(&[(0.0, 0.0), (0.35, 0.5), (0.0, 1.0)], rgb(0, 114, 41)),
+];
+
+const SE: &[Shape] = &[
+    HStripes(&[rgb(0, 106, 167)]),
+    Cross(0.36, 0.5, 0.1, rgb(254, 204, 2)),
+];
+
+const SG: &[Shape] = &[
+    HStripes(&[rgb(239, 51, 64), WHITE]),
+    Crescent(0.16, 0.25, 0.17, 0.06, 0.0, 0.15, WHITE),
+    Star(0.27, 0.15, 0.035, 5, WHITE),
+    Star(0.31, 0.22, 0.035, 5, WHITE),
+    Star(0.295, 0.33, 0.035, 5, WHITE),
+    Star(0.245, 0.33, 0.035, 5, WHITE),
+    Star(0.23, 0.22, 0.035, 5, WHITE),
+];
+
+const SH: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, WHITE),
+];
+
+const SI: &[Shape] = &[
+    HStripes(&[WHITE, rgb(0, 93, 164), rgb(237, 28, 36)]),
+    Rect(0.2, 0.18, 0.32, 0.5, rgb(237, 28, 36)),
+    Rect(0.21, 0.2, 0.31, 0.48, rgb(0, 93, 164)),
+];
+
+const SK: &[Shape] = &[
+    HStripes(&[WHITE, rgb(11, 78, 162), rgb(238, 28, 37)]),
+    Rect(0.22, 0.2, 0.44, 0.75, WHITE),
+    Rect(0.23, 0.22, 0.43, 0.73, rgb(238, 28, 37)),
+    Rect(0.31, 0.3, 0.35, 0.62, WHITE),
+    Rect(0.27, 0.38, 0.39, 0.42, WHITE),
+    Rect(0.28, 0.47, 0.38, 0.51, WHITE),
+    Rect(0.23, 0.62, 0.43, 0.73, rgb(11, 78, 162)),
+];
+
+const SL: &[Shape] = &[HStripes(&[rgb(30, 181, 58), WHITE, rgb(0, 114, 198)])];
+
+const SM: &[Shape] = &[
+    HStripes(&[WHITE, rgb(94, 182, 228)]),
+    Disc(0.5, 0.5, 0.12, rgb(200, 170, 80)),
+];
+
+const SN: &[Shape] = &[
+    VStripes(&[rgb(0, 133, 63), rgb(253, 239, 66), rgb(227, 27, 35)]),
+    Star(0.5, 0.5, 0.14, 5, rgb(0, 133, 63)),
+];
+
+const SO: &[Shape] = &[
+    HStripes(&[rgb(65, 137, 221)]),
+    Star(0.5, 0.5, 0.25, 5, WHITE),
+];
+
+const SR: &[Shape] = &[
+    HBands(&[
+        (2.0, rgb(55, 126, 63)),
+        (1.0, WHITE),
+        (4.0, rgb(180, 10, 45)),
+        (1.0, WHITE),
+        (2.0, rgb(55, 126, 63)),
+    ]),
+    Star(0.5, 0.5, 0.2, 5, rgb(236, 200, 70)),
+];
+
+const SS: &[Shape] = &[
+    HBands(&[
+        (6.0, BLACK),
+        (1.0, WHITE),
+        (6.0, rgb(218, 18, 26)),
+        (1.0, WHITE),
+        (6.0, rgb(7, 137, 48)),
+    ]),
+    Polygon(&[(0.0, 0.0), (0.4, 0.5), (0.0, 1.0)], rgb(15, 71, 175)),
+    Star(0.12, 0.5, 0.1, 5, rgb(252, 221, 9)),
+];
+
+const ST: &[Shape] = &[
+    HBands(&[
+        (2.0, rgb(18, 173, 43)),
+        (3.0, rgb(255, 206, 0)),
+        (2.0, rgb(18, 173, 43)),
+    ]),
+    Polygon(&[(0.0, 0.0), (0.25, 0.5), (0.0, 1.0)], rgb(210, 16, 52)),
+    Star(0.45, 0.5, 0.12, 5, BLACK),
+    Star(0.7, 0.5, 0.12, 5, BLACK),
+];
+
+const SV: &[Shape] = &[
+    HStripes(&[rgb(15, 71, 175), WHITE, rgb(15, 71, 175)]),
+    Ring(0.5, 0.5, 0.1, 0.02, rgb(200, 170, 60)),
+];
+
+const SX: &[Shape] = &[
+    HStripes(&[rgb(237, 41, 57), rgb(0, 38, 100)]),
+    Polygon(&[(0.0, 0.0), (0.45, 0.5), (0.0, 1.0)], WHITE),
+    Disc(0.15, 0.5, 0.1, rgb(250, 200, 0)),
+];
+
+const SY: &[Shape] = &[
+    HStripes(&[rgb(0, 122, 61), WHITE, BLACK]),
+    Star(0.35, 0.5, 0.08, 5, rgb(206, 17, 38)),
+    Star(0.5, 0.5, 0.08, 5, rgb(206, 17, 38)),
+    Star(0.65, 0.5, 0.08, 5, rgb(206, 17, 38)),
+];
+
+const SZ: &[Shape] = &[
+    HBands(&[
+        (3.0, rgb(62, 94, 198)),
+        (1.0, rgb(255, 213, 32)),
+        (8.0, rgb(177, 0, 0)),
+        (1.0, rgb(255, 213, 32)),
+        (3.0, rgb(62, 94, 198)),
+    ]),
+    Polygon(&[(0.3, 0.5), (0.5, 0.38), (0.7, 0.5), (0.5, 0.62)], WHITE),
+    Polygon(&[(0.5, 0.38), (0.7, 0.5), (0.5, 0.62)], BLACK),
+];
+
+const TC: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, rgb(255, 206, 0)),
+];
+
+const TD: &[Shape] = &[VStripes(&[
+    rgb(0, 38, 100),
+    rgb(254, 203, 0),
+    rgb(198, 12, 48),
+])];
+
+const TF: &[Shape] = &[
+    HStripes(&[rgb(0, 38, 84)]),
+    Rect(0.0, 0.0, 0.13, 0.4, rgb(0, 85, 164)),
+    Rect(0.13, 0.0, 0.26, 0.4, WHITE),
+    Rect(0.26, 0.0, 0.39, 0.4, rgb(239, 65, 53)),
+    Rect(0.6, 0.4, 0.7, 0.6, WHITE),
+    Star(0.55, 0.75, 0.05, 5, WHITE),
+    Star(0.65, 0.82, 0.05, 5, WHITE),
+    Star(0.75, 0.75, 0.05, 5, WHITE),
+];
+
+const TG: &[Shape] = &[
+    HStripes(&[
+        rgb(0, 106, 78),
+        rgb(255, 206, 0),
+        rgb(0, 106, 78),
+        rgb(255, 206, 0),
+        rgb(0, 106, 78),
+    ]),
+    Rect(0.0, 0.0, 0.375, 0.6, rgb(210, 16, 52)),
+    Star(0.1875, 0.3, 0.15, 5, WHITE),
+];
+
+const TH: &[Shape] = &[HBands(&[
+    (1.0, rgb(165, 25, 49)),
+    (1.0, WHITE),
+    (2.0, rgb(45, 42, 74)),
+    (1.0, WHITE),
+    (1.0, rgb(165, 25, 49)),
+])];
+
+const TJ: &[Shape] = &[
+    HBands(&[(2.0, rgb(204, 0, 0)), (3.0, WHITE), (2.0, rgb(0, 102, 0))]),
+    Polygon(
+        &[(0.45, 0.5), (0.55, 0.5), (0.53, 0.58), (0.47, 0.58)],
+        rgb(248, 198, 0),
+    ),
+    Star(0.5, 0.4, 0.035, 5, rgb(248, 198, 0)),
+    Star(0.42, 0.43, 0.035, 5, rgb(248, 198, 0)),
+    Star(0.58, 0.43, 0.035, 5, rgb(248, 198, 0)),
+    Star(0.36, 0.49, 0.035, 5, rgb(248, 198, 0)),
+    Star(0.64, 0.49, 0.035, 5, rgb(248, 198, 0)),
+];
+
+const TK: &[Shape] = &[
+    HStripes(&[rgb(0, 36, 125)]),
+    Polygon(
+        &[(0.2, 0.75), (0.8, 0.75), (0.75, 0.82), (0.25, 0.82)],
+        rgb(255, 206, 0),
+    ),
+    Star(0.15, 0.3, 0.05, 5, WHITE),
+    Star(0.25, 0.2, 0.05, 5, WHITE),
+    Star(0.25, 0.42, 0.05, 5, WHITE),
+    Star(0.32, 0.3, 0.05, 5, WHITE),
+];
+
+const TL: &[Shape] = &[
+    HStripes(&[rgb(220, 36, 31)]),
+    Polygon(&[(0.0, 0.0), (0.55, 0.5), (0.0, 1.0)], rgb(255, 199, 38)),
+    Polygon(&[(0.0, 0.0), (0.36, 0.5), (0.0, 1.0)], BLACK),
+    Star(0.13, 0.5, 0.13, 5, WHITE),
+];
+
+const TM: &[Shape] = &[
+    HStripes(&[rgb(0, 132, 61)]),
+    Rect(0.15, 0.0, 0.3, 1.0, rgb(209, 36, 36)),
+    Crescent(0.42, 0.25, 0.1, 0.04, 0.0, 0.085, WHITE),
+    Star(0.5, 0.15, 0.03, 5, WHITE),
+    Star(0.52, 0.25, 0.03, 5, WHITE),
+    Star(0.5, 0.35, 0.03, 5, WHITE),
+    Star(0.56, 0.2, 0.03, 5, WHITE),
+    Star(0.56, 0.3, 0.03, 5, WHITE),
+];
+
+const TN: &[Shape] = &[
+    HStripes(&[rgb(231, 0, 19)]),
+    Disc(0.5, 0.5, 0.25, WHITE),
+    Crescent(0.5, 0.5, 0.19, 0.05, 0.0, 0.15, rgb(231, 0, 19)),
+    Star(0.53, 0.5, 0.1, 5, rgb(231, 0, 19)),
+];
+
+const TO: &[Shape] = &[
+    HStripes(&[rgb(193, 0, 0)]),
+    Rect(0.0, 0.0, 0.42, 0.5, WHITE),
+    Rect(0.18, 0.08, 0.24, 0.42, rgb(193, 0, 0)),
+    Rect(0.1, 0.19, 0.32, 0.31, rgb(193, 0, 0)),
+];
+
+const TR: &[Shape] = &[
+    HStripes(&[rgb(227, 10, 23)]),
+    Crescent(0.4, 0.5, 0.25, 0.0625, 0.0, 0.2, WHITE),
+    Star(0.55, 0.5, 0.125, 5, WHITE),
+];
+
+const TT: &[Shape] = &[
+    HStripes(&[rgb(218, 26, 53)]),
+    Falling(0.22, WHITE),
+    Falling(0.16, BLACK),
+];
+
+const TV: &[Shape] = &[
+    HStripes(&[rgb(0, 157, 224)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Star(0.6, 0.8, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.7, 0.6, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.65, 0.35, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.8, 0.75, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.85, 0.5, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.9, 0.25, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.75, 0.15, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.55, 0.55, 0.05, 5, rgb(255, 206, 0)),
+    Star(0.95, 0.85, 0.05, 5, rgb(255, 206, 0)),
+];
+
+const TW: &[Shape] = &[
+    HStripes(&[rgb(254, 0, 0)]),
+    Rect(0.0, 0.0, 0.5, 0.5, rgb(0, 0, 149)),
+    Star(0.25, 0.25, 0.17, 12, WHITE),
+    Disc(0.25, 0.25, 0.085, rgb(0, 0, 149)),
+    Disc(0.25, 0.25, 0.07, WHITE),
+];
+
+const TZ: &[Shape] = &[
+    HStripes(&[rgb(0, 163, 221)]),
+    Polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], rgb(30, 181, 58)),
+    Rising(0.2, rgb(252, 209, 22)),
+    Rising(0.15, BLACK),
+];
+
+const UA: &[Shape] = &[HStripes(&[rgb(0, 87, 183), rgb(255, 215, 0)])];
+
+const UG: &[Shape] = &[
+    HStripes(&[
+        BLACK,
+        rgb(252, 220, 4),
+        rgb(217, 0, 0),
+        BLACK,
+        rgb(252, 220, 4),
+        rgb(217, 0, 0),
+    ]),
+    Disc(0.5, 0.5, 0.2, WHITE),
+    Disc(0.5, 0.5, 0.08, rgb(150, 150, 150)),
+];
+
+const US: &[Shape] = &[
+    HStripes(&[
+        rgb(178, 34, 52),
+        WHITE,
+        rgb(178, 34, 52),
+        WHITE,
+        rgb(178, 34, 52),
+        WHITE,
+        rgb(178, 34, 52),
+        WHITE,
+        rgb(178, 34, 52),
+        WHITE,
+        rgb(178, 34, 52),
+        WHITE,
+        rgb(178, 34, 52),
+    ]),
+    Rect(0.0, 0.0, 0.4, 0.5385, rgb(60, 59, 110)),
+    Star(0.0333, 0.0538, 0.028, 5, WHITE),
+    Star(0.1, 0.0538, 0.028, 5, WHITE),
+    Star(0.1667, 0.0538, 0.028, 5, WHITE),
+    Star(0.2333, 0.0538, 0.028, 5, WHITE),
+    Star(0.3, 0.0538, 0.028, 5, WHITE),
+    Star(0.3667, 0.0538, 0.028, 5, WHITE),
+    Star(0.0667, 0.1077, 0.028, 5, WHITE),
+    Star(0.1333, 0.1077, 0.028, 5, WHITE),
+    Star(0.2, 0.1077, 0.028, 5, WHITE),
+    Star(0.2667, 0.1077, 0.028, 5, WHITE),
+    Star(0.3333, 0.1077, 0.028, 5, WHITE),
+    Star(0.0333, 0.1615, 0.028, 5, WHITE),
+    Star(0.1, 0.1615, 0.028, 5, WHITE),
+    Star(0.1667, 0.1615, 0.028, 5, WHITE),
+    Star(0.2333, 0.1615, 0.028, 5, WHITE),
+    Star(0.3, 0.1615, 0.028, 5, WHITE),
+    Star(0.3667, 0.1615, 0.028, 5, WHITE),
+    Star(0.0667, 0.2154, 0.028, 5, WHITE),
+    Star(0.1333, 0.2154, 0.028, 5, WHITE),
+    Star(0.2, 0.2154, 0.028, 5, WHITE),
+    Star(0.2667, 0.2154, 0.028, 5, WHITE),
+    Star(0.3333, 0.2154, 0.028, 5, WHITE),
+    Star(0.0333, 0.2692, 0.028, 5, WHITE),
+    Star(0.1, 0.2692, 0.028, 5, WHITE),
+    Star(0.1667, 0.2692, 0.028, 5, WHITE),
+    Star(0.2333, 0.2692, 0.028, 5, WHITE),
+    Star(0.3, 0.2692, 0.028, 5, WHITE),
+    Star(0.3667, 0.2692, 0.028, 5, WHITE),
+    Star(0.0667, 0.3231, 0.028, 5, WHITE),
+    Star(0.1333, 0.3231, 0.028, 5, WHITE),
+    Star(0.2, 0.3231, 0.028, 5, WHITE),
+    Star(0.2667, 0.3231, 0.028, 5, WHITE),
+    Star(0.3333, 0.3231, 0.028, 5, WHITE),
+    Star(0.0333, 0.377, 0.028, 5, WHITE),
+    Star(0.1, 0.377, 0.028, 5, WHITE),
+    Star(0.1667, 0.377, 0.028, 5, WHITE),
+    Star(0.2333, 0.377, 0.028, 5, WHITE),
+    Star(0.3, 0.377, 0.028, 5, WHITE),
+    Star(0.3667, 0.377, 0.028, 5, WHITE),
+    Star(0.0667, 0.4308, 0.028, 5, WHITE),
+    Star(0.1333, 0.4308, 0.028, 5, WHITE),
+    Star(0.2, 0.4308, 0.028, 5, WHITE),
+    Star(0.2667, 0.4308, 0.028, 5, WHITE),
+    Star(0.3333, 0.4308, 0.028, 5, WHITE),
+    Star(0.0333, 0.4846, 0.028, 5, WHITE),
+    Star(0.1, 0.4846, 0.028, 5, WHITE),
+    Star(0.1667, 0.4846, 0.028, 5, WHITE),
+    Star(0.2333, 0.4846, 0.028, 5, WHITE),
+    Star(0.3, 0.4846, 0.028, 5, WHITE),
+    Star(0.3667, 0.4846, 0.028, 5, WHITE),
+];
+
+const UY: &[Shape] = &[
+    HStripes(&[
+        WHITE,
+        rgb(0, 56, 168),
+        WHITE,
+        rgb(0, 56, 168),
+        WHITE,
+        rgb(0, 56, 168),
+        WHITE,
+        rgb(0, 56, 168),
+        WHITE,
+    ]),
+    Rect(0.0, 0.0, 0.3472, 0.5556, WHITE),
+    Star(0.1736, 0.2778, 0.2, 16, rgb(252, 209, 22)),
+    Disc(0.1736, 0.2778, 0.09, rgb(252, 209, 22)),
+];
+
+const UZ: &[Shape] = &[
+    HBands(&[
+        (10.0, rgb(0, 153, 181)),
+        (1.0, rgb(206, 17, 38)),
+        (9.0, WHITE),
+        (1.0, rgb(206, 17, 38)),
+        (10.0, rgb(30, 181, 58)),
+    ]),
+    Crescent(0.12, 0.17, 0.11, 0.04, 0.0, 0.1, WHITE),
+    Star(0.28, 0.08, 0.025, 5, WHITE),
+    Star(0.34, 0.08, 0.025, 5, WHITE),
+    Star(0.4, 0.08, 0.025, 5, WHITE),
+    Star(0.22, 0.17, 0.025, 5, WHITE),
+    Star(0.28, 0.17, 0.025, 5, WHITE),
+    Star(0.34, 0.17, 0.025, 5, WHITE),
+    Star(0.4, 0.17, 0.025, 5, WHITE),
+    Star(0.16, 0.26, 0.025, 5, WHITE),
+    Star(0.22, 0.26, 0.025, 5, WHITE),
+    Star(0.28, 0.26, 0.025, 5, WHITE),
+    Star(0.34, 0.26, 0.025, 5, WHITE),
+    Star(0.4, 0.26, 0.025, 5, WHITE),
+];
+
+const VA: &[Shape] = &[
+    VStripes(&[rgb(255, 224, 0), WHITE]),
+    Disc(0.75, 0.5, 0.1, rgb(200, 170, 80)),
+];
+
+const VC: &[Shape] = &[
+    VBands(&[
+        (1.0, rgb(0, 114, 198)),
+        (2.0, rgb(252, 209, 22)),
+        (1.0, rgb(0, 158, 96)),
+    ]),
+    Polygon(
+        &[(0.42, 0.3), (0.46, 0.4), (0.42, 0.5), (0.38, 0.4)],
+        rgb(0, 158, 96),
+    ),
+    Polygon(
+        &[(0.58, 0.3), (0.62, 0.4), (0.58, 0.5), (0.54, 0.4)],
+        rgb(0, 158, 96),
+    ),
+    Polygon(
+        &[(0.5, 0.52), (0.54, 0.62), (0.5, 0.72), (0.46, 0.62)],
+        rgb(0, 158, 96),
+    ),
+];
+
+const VE: &[Shape] = &[
+    HStripes(&[rgb(255, 204, 0), rgb(0, 36, 125), rgb(207, 20, 43)]),
+    Star(0.3356, 0.5242, 0.045, 5, WHITE),
+    Star(0.3659, 0.44, 0.045, 5, WHITE),
+    Star(0.4125, 0.3775, 0.045, 5, WHITE),
+    Star(0.4696, 0.3443, 0.045, 5, WHITE),
+    Star(0.5304, 0.3443, 0.045, 5, WHITE),
+    Star(0.5875, 0.3775, 0.045, 5, WHITE),
+    Star(0.6341, 0.44, 0.045, 5, WHITE),
+    Star(0.6644, 0.5242, 0.045, 5, WHITE),
+];
+
+const VG: &[Shape] = &[
+    HStripes(&[rgb(1, 33, 105)]),
+    UnionFlag(0.0, 0.0, 0.5, 0.5),
+    Disc(0.75, 0.5, 0.17, rgb(0, 102, 51)),
+];
+
+const VI: &[Shape] = &[
+    HStripes(&[WHITE]),
+    Disc(0.5, 0.5, 0.18, rgb(255, 206, 0)),
+    Rect(0.46, 0.45, 0.54, 0.65, rgb(0, 56, 168)),
+];
+
+const VN: &[Shape] = &[
+    HStripes(&[rgb(218, 37, 29)]),
+    Star(0.5, 0.5, 0.3, 5, rgb(255, 255, 0)),
+];
+
+const VU: &[Shape] = &[
+    HStripes(&[rgb(210, 16, 52), rgb(0, 154, 68)]),
+    Rect(0.0, 0.42, 1.0, 0.58, BLACK),
+    Rect(0.0, 0.46, 1.0, 0.54, rgb(253, 206, 18)),
+    Polygon(&[(0.0, 0.0), (0.52, 0.5), (0.0, 1.0)], rgb(253, 206, 18)),
+    Polygon(&[(0.0, 0.08), (0.43, 0.5), (0.0, 0.92)], BLACK),
+    Ring(0.15, 0.5, 0.1, 0.03, rgb(253, 206, 18)),
+];
+
+const WF: &[Shape] = &[
+    HStripes(&[rgb(237, 41, 57)]),
+    Rect(0.0, 0.0, 0.42, 0.52, WHITE),
+    Rect(0.0, 0.0, 0.13, 0.5, rgb(0, 85, 164)),
+    Rect(0.13, 0.0, 0.27, 0.5, WHITE),
+    Rect(0.27, 0.0, 0.4, 0.5, rgb(239, 65, 53)),
+    Star(0.7, 0.55, 0.2, 4, WHITE),
+];
+
+const WS: &[Shape] = &[
+    HStripes(&[rgb(206, 17, 38)]),
+    Rect(0.0, 0.0, 0.5, 0.5, rgb(0, 43, 127)),
+    Star(0.25, 0.1, 0.06, 5, WHITE),
+    Star(0.35, 0.2, 0.06, 5, WHITE),
+    Star(0.15, 0.25, 0.06, 5, WHITE),
+    Star(0.25, 0.4, 0.06, 5, WHITE),
+    Star(0.3, 0.27, 0.03, 5, WHITE),
+];
+
+const YE: &[Shape] = &[HStripes(&[rgb(206, 17, 38), WHITE, BLACK])];
+
+const ZA: &[Shape] = &[
+    HStripes(&[rgb(224, 60, 49), rgb(0, 35, 149)]),
+    Polygon(
+        &[
+            (0.0, 0.0),
+            (0.2, 0.0),
+            (0.53, 0.333),
+            (1.0, 0.333),
+            (1.0, 0.667),
+            (0.53, 0.667),
+            (0.2, 1.0),
+            (0.0, 1.0),
+        ],
+        WHITE,
+    ),
+    Polygon(
+        &[
+            (0.0, 0.0),
+            (0.1, 0.0),
+            (0.48, 0.4),
+            (1.0, 0.4),
+            (1.0, 0.6),
+            (0.48, 0.6),
+            (0.1, 1.0),
+            (0.0, 1.0),
+        ],
+        rgb(0, 122, 77),
+    ),
+    Polygon(&[(0.0, 0.12), (0.4, 0.5), (0.0, 0.88)], rgb(255, 182, 18)),
+    Polygon(&[(0.0, 0.2), (0.33, 0.5), (0.0, 0.8)], BLACK),
+];
+
+const ZM: &[Shape] = &[
+    HStripes(&[rgb(25, 138, 0)]),
+    Rect(0.7, 0.4, 0.8, 1.0, rgb(222, 32, 16)),
+    Rect(0.8, 0.4, 0.9, 1.0, BLACK),
+    Rect(0.9, 0.4, 1.0, 1.0, rgb(239, 125, 0)),
+    Polygon(&[(0.77, 0.2), (0.93, 0.2), (0.85, 0.3)], rgb(239, 125, 0)),
+];
+
+const ZW: &[Shape] = &[
+    HStripes(&[
+        rgb(0, 115, 47),
+        rgb(255, 210, 0),
+        rgb(210, 16, 52),
+        BLACK,
+        rgb(210, 16, 52),
+        rgb(255, 210, 0),
+        rgb(0, 115, 47),
+    ]),
+    Polygon(&[(0.0, 0.0), (0.45, 0.5), (0.0, 1.0)], BLACK),
+    Polygon(&[(0.0, 0.03), (0.42, 0.5), (0.0, 0.97)], WHITE),
+    Star(0.15, 0.5, 0.12, 5, rgb(210, 16, 52)),
+    Disc(0.15, 0.5, 0.05, rgb(255, 210, 0)),
+];
+
+/// Every ISO 3166-1 code, sorted, with its design. Territories that fly
+/// their sovereign's flag share it.
+const FLAGS: &[(&str, &[Shape])] = &[
+    ("AD", AD),
+    ("AE", AE),
+    ("AF", AF),
+    ("AG", AG),
+    ("AI", AI),
+    ("AL", AL),
+    ("AM", AM),
+    ("AO", AO),
+    ("AQ", AQ),
+    ("AR", AR),
+    ("AS", AS),
+    ("AT", AT),
+    ("AU", AU),
+    ("AW", AW),
+    ("AX", AX),
+    ("AZ", AZ),
+    ("BA", BA),
+    ("BB", BB),
+    ("BD", BD),
+    ("BE", BE),
+    ("BF", BF),
+    ("BG", BG),
+    ("BH", BH),
+    ("BI", BI),
+    ("BJ", BJ),
+    ("BL", FR),
+    ("BM", BM),
+    ("BN", BN),
+    ("BO", BO),
+    ("BQ", BQ),
+    ("BR", BR),
+    ("BS", BS),
+    ("BT", BT),
+    ("BV", NO),
+    ("BW", BW),
+    ("BY", BY),
+    ("BZ", BZ),
+    ("CA", CA),
+    ("CC", CC),
+    ("CD", CD),
+    ("CF", CF),
+    ("CG", CG),
+    ("CH", CH),
+    ("CI", CI),
+    ("CK", CK),
+    ("CL", CL),
+    ("CM", CM),
+    ("CN", CN),
+    ("CO", CO),
+    ("CR", CR),
+    ("CU", CU),
+    ("CV", CV),
+    ("CW", CW),
+    ("CX", CX),
+    ("CY", CY),
+    ("CZ", CZ),
+    ("DE", DE),
+    ("DJ", DJ),
+    ("DK", DK),
+    ("DM", DM),
+    ("DO", DO),
+    ("DZ", DZ),
+    ("EC", EC),
+    ("EE", EE),
+    ("EG", EG),
+    ("EH", EH),
+    ("ER", ER),
+    ("ES", ES),
+    ("ET", ET),
+    ("FI", FI),
+    ("FJ", FJ),
+    ("FK", FK),
+    ("FM", FM),
+    ("FO", FO),
+    ("FR", FR),
+    ("GA", GA),
+    ("GB", GB),
+    ("GD", GD),
+    ("GE", GE),
+    ("GF", FR),
+    ("GG", GG),
+    ("GH", GH),
+    ("GI", GI),
+    ("GL", GL),
+    ("GM", GM),
+    ("GN", GN),
+    ("GP", FR),
+    ("GQ", GQ),
+    ("GR", GR),
+    ("GS", GS),
+    ("GT", GT),
+    ("GU", GU),
+    ("GW", GW),
+    ("GY", GY),
+    ("HK", HK),
+    ("HM", AU),
+    ("HN", HN),
+    ("HR", HR),
+    ("HT", HT),
+    ("HU", HU),
+    ("ID", ID),
+    ("IE", IE),
+    ("IL", IL),
+    ("IM", IM),
+    ("IN", IN),
+    ("IO", IO),
+    ("IQ", IQ),
+    ("IR", IR),
+    ("IS", IS),
+    ("IT", IT),
+    ("JE", JE),
+    ("JM", JM),
+    ("JO", JO),
+    ("JP", JP),
+    ("KE", KE),
+    ("KG", KG),
+    ("KH", KH),
+    ("KI", KI),
+    ("KM", KM),
+    ("KN", KN),
+    ("KP", KP),
+    ("KR", KR),
+    ("KW", KW),
+    ("KY", KY),
+    ("KZ", KZ),
+    ("LA", LA),
+    ("LB", LB),
+    ("LC", LC),
+    ("LI", LI),
+    ("LK", LK),
+    ("LR", LR),
+    ("LS", LS),
+    ("LT", LT),
+    ("LU", LU),
+    ("LV", LV),
+    ("LY", LY),
+    ("MA", MA),
+    ("MC", MC),
+    ("MD", MD),
+    ("ME", ME),
+    ("MF", FR),
+    ("MG", MG),
+    ("MH", MH),
+    ("MK", MK),
+    ("ML", ML),
+    ("MM", MM),
+    ("MN", MN),
+    ("MO", MO),
+    ("MP", MP),
+    ("MQ", FR),
+    ("MR", MR),
+    ("MS", MS),
+    ("MT", MT),
+    ("MU", MU),
+    ("MV", MV),
+    ("MW", MW),
+    ("MX", MX),
+    ("MY", MY),
+    ("MZ", MZ),
+    ("NA", NA),
+    ("NC", NC),
+    ("NE", NE),
+    ("NF", NF),
+    ("NG", NG),
+    ("NI", NI),
+    ("NL", NL),
+    ("NO", NO),
+    ("NP", NP),
+    ("NR", NR),
+    ("NU", NU),
+    ("NZ", NZ),
+    ("OM", OM),
+    ("PA", PA),
+    ("PE", PE),
+    ("PF", PF),
+    ("PG", PG),
+    ("PH", PH),
+    ("PK", PK),
+    ("PL", PL),
+    ("PM", FR),
+    ("PN", PN),
+    ("PR", PR),
+    ("PS", PS),
+    ("PT", PT),
+    ("PW", PW),
+    ("PY", PY),
+    ("QA", QA),
+    ("RE", FR),
+    ("RO", RO),
+    ("RS", RS),
+    ("RU", RU),
+    ("RW", RW),
+    ("SA", SA),
+    ("SB", SB),
+    ("SC", SC),
+    ("SD", SD),
+    ("SE", SE),
+    ("SG", SG),
+    ("SH", SH),
+    ("SI", SI),
+    ("SJ", NO),
+    ("SK", SK),
+    ("SL", SL),
+    ("SM", SM),
+    ("SN", SN),
+    ("SO", SO),
+    ("SR", SR),
+    ("SS", SS),
+    ("ST", ST),
+    ("SV", SV),
+    ("SX", SX),
+    ("SY", SY),
+    ("SZ", SZ),
+    ("TC", TC),
+    ("TD", TD),
+    ("TF", TF),
+    ("TG", TG),
+    ("TH", TH),
+    ("TJ", TJ),
+    ("TK", TK),
+    ("TL", TL),
+    ("TM", TM),
+    ("TN", TN),
+    ("TO", TO),
+    ("TR", TR),
+    ("TT", TT),
+    ("TV", TV),
+    ("TW", TW),
+    ("TZ", TZ),
+    ("UA", UA),
+    ("UG", UG),
+    ("UM", US),
+    ("US", US),
+    ("UY", UY),
+    ("UZ", UZ),
+    ("VA", VA),
+    ("VC", VC),
+    ("VE", VE),
+    ("VG", VG),
+    ("VI", VI),
+    ("VN", VN),
+    ("VU", VU),
+    ("WF", WF),
+    ("WS", WS),
+    ("YE", YE),
+    ("YT", FR),
+    ("ZA", ZA),
+    ("ZM", ZM),
+    ("ZW", ZW),
+];
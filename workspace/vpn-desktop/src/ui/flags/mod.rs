@@ -0,0 +1,58 @@
+//! Country flags, drawn from vector designs at the size they are shown.
+//!
+//! [`designs`] describes the flag of every ISO 3166-1 country with a few
+//! shapes. A flag is rasterized the first time it is asked for at a given
+//! pixel height and kept as a texture; heights are rounded up to a few steps
+//! so that zooming or moving to a HiDPI screen does not fill the GPU.
+
+mod designs;
+mod shape;
+
+use egui::{TextureHandle, TextureOptions};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use vpn_core::country::Country;
+
+/// Texture heights in pixels.
+const HEIGHTS: [u32; 7] = [16, 24, 32, 48, 64, 96, 128];
+
+/// Key of the texture shown for unknown countries.
+const GLOBE: &str = "globe";
+
+/// Flag textures, keyed by country code and pixel height.
+pub struct FlagStore {
+    textures: RefCell<HashMap<(&'static str, u32), TextureHandle>>,
+}
+
+impl FlagStore {
+    pub fn new() -> Self {
+        Self {
+            textures: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Texture for a country name/code, sharp at `height` points. Returns
+    /// a globe for countries missing from the ISO table.
+    pub fn get(&self, ctx: &egui::Context, country: &str, height: f32) -> TextureHandle {
+        let code = country.parse::<Country>().map_or(GLOBE, |c| c.code());
+        let pixels = height * ctx.pixels_per_point();
+        let height = HEIGHTS
+            .into_iter()
+            .find(|&h| h as f32 >= pixels)
+            .unwrap_or(HEIGHTS[HEIGHTS.len() - 1]);
+        self.textures
+            .borrow_mut()
+            .entry((code, height))
+            .or_insert_with(|| {
+                let shapes = designs::design(code).unwrap_or(designs::GLOBE);
+                let width = (height as f32 * shape::ASPECT).round() as usize;
+                let image = shape::render(shapes, width, height as usize);
+                ctx.load_texture(
+                    format!("flag_{code}_{height}"),
+                    image,
+                    TextureOptions::LINEAR,
+                )
+            })
+            .clone()
+    }
+}
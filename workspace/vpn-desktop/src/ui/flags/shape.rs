@@ -0,0 +1,240 @@
+//! The few shapes flags are made of, and their rasterizer.
+//!
+//! Positions are fractions of the flag: `x` from hoist (0) to fly (1), `y`
+//! from top (0) to bottom (1). Lengths (radius, stripe width) are fractions
+//! of the height, so that discs stay round. Every flag is drawn at
+//! [`ASPECT`], the shape of the slot it is shown in.
+
+use egui::{Color32, ColorImage};
+use std::f32::consts::TAU;
+
+/// Width over height.
+pub const ASPECT: f32 = 1.6;
+
+/// Samples per pixel along each axis.
+const SUPERSAMPLE: usize = 4;
+
+pub enum Shape {
+    /// Equal horizontal stripes, top to bottom.
+    HStripes(&'static [Color32]),
+    /// Equal vertical stripes, hoist to fly.
+    VStripes(&'static [Color32]),
+    /// Horizontal stripes with relative heights.
+    HBands(&'static [(f32, Color32)]),
+    /// Vertical stripes with relative widths.
+    VBands(&'static [(f32, Color32)]),
+    /// `x0, y0, x1, y1`.
+    Rect(f32, f32, f32, f32, Color32),
+    /// Any simple polygon, even-odd filled.
+    Polygon(&'static [(f32, f32)], Color32),
+    /// Centre `x, y`, radius.
+    Disc(f32, f32, f32, Color32),
+    /// Centre `x, y`, radius, upper half (`true`) or lower half.
+    HalfDisc(f32, f32, f32, bool, Color32),
+    /// Centre `x, y`, radius, line width.
+    Ring(f32, f32, f32, f32, Color32),
+    /// Centre `x, y`, outer radius, number of points; one points up.
+    Star(f32, f32, f32, u8, Color32),
+    /// A disc (`x, y`, radius) minus another one offset by `dx, dy` with
+    /// its own radius.
+    Crescent(f32, f32, f32, f32, f32, f32, Color32),
+    /// Horizontal and vertical bars through `x, y`, across the whole flag,
+    /// with the given half width.
+    Cross(f32, f32, f32, Color32),
+    /// Both diagonals, with the given half width.
+    Saltire(f32, Color32),
+    /// The diagonal from the lower hoist to the upper fly.
+    Rising(f32, Color32),
+    /// The diagonal from the upper hoist to the lower fly.
+    Falling(f32, Color32),
+    /// The Union Flag fitted in `x0, y0, x1, y1`, as in the canton of
+    /// Australia or the British overseas territories.
+    UnionFlag(f32, f32, f32, f32),
+}
+
+const UNION_BLUE: Color32 = Color32::from_rgb(1, 33, 105);
+const UNION_RED: Color32 = Color32::from_rgb(200, 16, 46);
+
+impl Shape {
+    /// `x0, y0, x1, y1`, to skip the shape quickly.
+    fn bounds(&self) -> [f32; 4] {
+        let round = |x: f32, y: f32, r: f32| [x - r / ASPECT, y - r, x + r / ASPECT, y + r];
+        match *self {
+            Shape::Rect(x0, y0, x1, y1, _) | Shape::UnionFlag(x0, y0, x1, y1) => [x0, y0, x1, y1],
+            Shape::Polygon(points, _) => points.iter().fold(
+                [f32::MAX, f32::MAX, f32::MIN, f32::MIN],
+                |[x0, y0, x1, y1], &(x, y)| [x0.min(x), y0.min(y), x1.max(x), y1.max(y)],
+            ),
+            Shape::Disc(x, y, r, _)
+            | Shape::HalfDisc(x, y, r, _, _)
+            | Shape::Star(x, y, r, _, _)
+            | Shape::Crescent(x, y, r, _, _, _, _) => round(x, y, r),
+            Shape::Ring(x, y, r, w, _) => round(x, y, r + w / 2.0),
+            _ => [0.0, 0.0, 1.0, 1.0],
+        }
+    }
+
+    /// Colour of the shape at `x, y`, `None` outside it.
+    fn color_at(&self, x: f32, y: f32) -> Option<Color32> {
+        // Height units, where distances can be compared
+        let (px, py) = (x * ASPECT, y);
+        let distance = |cx: f32, cy: f32| (px - cx * ASPECT).hypot(py - cy);
+        let inside = |hit: bool, color: Color32| hit.then_some(color);
+        match *self {
+            Shape::HStripes(colors) => colors.get(band(y, colors.len())).copied(),
+            Shape::VStripes(colors) => colors.get(band(x, colors.len())).copied(),
+            Shape::HBands(bands) => weighted_band(y, bands),
+            Shape::VBands(bands) => weighted_band(x, bands),
+            Shape::Rect(x0, y0, x1, y1, color) => {
+                inside((x0..x1).contains(&x) && (y0..y1).contains(&y), color)
+            }
+            Shape::Polygon(points, color) => inside(in_polygon(points, x, y), color),
+            Shape::Disc(cx, cy, r, color) => inside(distance(cx, cy) <= r, color),
+            Shape::HalfDisc(cx, cy, r, upper, color) => {
+                inside(distance(cx, cy) <= r && (py <= cy) == upper, color)
+            }
+            Shape::Ring(cx, cy, r, w, color) => {
+                inside((distance(cx, cy) - r).abs() <= w / 2.0, color)
+            }
+            Shape::Star(cx, cy, r, points, color) => {
+                inside(in_star(px - cx * ASPECT, py - cy, r, points.max(3)), color)
+            }
+            Shape::Crescent(cx, cy, r, dx, dy, inner, color) => inside(
+                distance(cx, cy) <= r && (px - cx * ASPECT - dx).hypot(py - cy - dy) > inner,
+                color,
+            ),
+            Shape::Cross(cx, cy, w, color) => {
+                inside((px - cx * ASPECT).abs() <= w || (py - cy).abs() <= w, color)
+            }
+            Shape::Saltire(w, color) => inside(
+                falling_distance(px, py, ASPECT) <= w || rising_distance(px, py, ASPECT) <= w,
+                color,
+            ),
+            Shape::Rising(w, color) => inside(rising_distance(px, py, ASPECT) <= w, color),
+            Shape::Falling(w, color) => inside(falling_distance(px, py, ASPECT) <= w, color),
+            Shape::UnionFlag(x0, y0, x1, y1) => {
+                let height = y1 - y0;
+                let aspect = (x1 - x0) * ASPECT / height;
+                let u = (x - x0) / (x1 - x0) * aspect;
+                let v = (y - y0) / height;
+                Some(union_flag(u, v, aspect))
+            }
+        }
+    }
+}
+
+/// Draws `shapes` at `width` x `height` pixels, later shapes on top.
+pub fn render(shapes: &[Shape], width: usize, height: usize) -> ColorImage {
+    let bounds: Vec<[f32; 4]> = shapes.iter().map(Shape::bounds).collect();
+    let samples = (SUPERSAMPLE * SUPERSAMPLE) as u32;
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in 0..height {
+        for column in 0..width {
+            let mut sum = [0u32; 4];
+            for sy in 0..SUPERSAMPLE {
+                for sx in 0..SUPERSAMPLE {
+                    let x = (column as f32 + (sx as f32 + 0.5) / SUPERSAMPLE as f32) / width as f32;
+                    let y = (row as f32 + (sy as f32 + 0.5) / SUPERSAMPLE as f32) / height as f32;
+                    let color = shapes
+                        .iter()
+                        .zip(&bounds)
+                        .rev()
+                        .filter(|(_, [x0, y0, x1, y1])| {
+                            (*x0..=*x1).contains(&x) && (*y0..=*y1).contains(&y)
+                        })
+                        .find_map(|(shape, _)| shape.color_at(x, y))
+                        .unwrap_or(Color32::TRANSPARENT);
+                    for (total, channel) in sum.iter_mut().zip(color.to_array()) {
+                        *total += u32::from(channel);
+                    }
+                }
+            }
+            let [r, g, b, a] = sum.map(|total| (total / samples) as u8);
+            pixels.push(Color32::from_rgba_premultiplied(r, g, b, a));
+        }
+    }
+    ColorImage {
+        size: [width, height],
+        pixels,
+    }
+}
+
+fn band(position: f32, count: usize) -> usize {
+    ((position * count as f32) as usize).min(count.saturating_sub(1))
+}
+
+fn weighted_band(position: f32, bands: &[(f32, Color32)]) -> Option<Color32> {
+    let total: f32 = bands.iter().map(|(weight, _)| weight).sum();
+    let mut end = 0.0;
+    for &(weight, color) in bands {
+        end += weight / total;
+        if position < end {
+            return Some(color);
+        }
+    }
+    bands.last().map(|&(_, color)| color)
+}
+
+fn in_polygon(points: &[(f32, f32)], x: f32, y: f32) -> bool {
+    let mut inside = false;
+    let mut previous = points.len().wrapping_sub(1);
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        let (xj, yj) = points[previous];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        previous = i;
+    }
+    inside
+}
+
+/// `dx, dy` from the centre, in height units.
+fn in_star(dx: f32, dy: f32, r: f32, points: u8) -> bool {
+    let d = dx.hypot(dy);
+    if d > r {
+        return false;
+    }
+    let sector = TAU / f32::from(points);
+    let half = sector / 2.0;
+    // Angle from the nearest point, clockwise from straight up
+    let angle = dx.atan2(-dy).rem_euclid(sector);
+    let angle = if angle > half { sector - angle } else { angle };
+    // Inner vertices of a regular star, kept between a thin cross and a
+    // round sun
+    let inner = r * ((sector).cos() / half.cos()).clamp(0.3, 0.7);
+    let (ox, oy) = (r, 0.0);
+    let (ix, iy) = (inner * half.cos(), inner * half.sin());
+    let (qx, qy) = (d * angle.cos(), d * angle.sin());
+    // Same side of the outer edge as the centre
+    let edge = |x: f32, y: f32| (ix - ox) * (y - oy) - (iy - oy) * (x - ox);
+    edge(qx, qy) * edge(0.0, 0.0) >= 0.0
+}
+
+/// From the diagonal running from `(0, 0)` to `(aspect, 1)`.
+fn falling_distance(px: f32, py: f32, aspect: f32) -> f32 {
+    (px - py * aspect).abs() / aspect.hypot(1.0)
+}
+
+/// From the diagonal running from `(0, 1)` to `(aspect, 0)`.
+fn rising_distance(px: f32, py: f32, aspect: f32) -> f32 {
+    (px + py * aspect - aspect).abs() / aspect.hypot(1.0)
+}
+
+/// `u, v` in units of the canton's height, `u` up to `aspect`.
+fn union_flag(u: f32, v: f32, aspect: f32) -> Color32 {
+    let centre = ((u - aspect / 2.0).abs(), (v - 0.5).abs());
+    if centre.0 <= 3.0 / 30.0 || centre.1 <= 3.0 / 30.0 {
+        return UNION_RED;
+    }
+    if centre.0 <= 5.0 / 30.0 || centre.1 <= 5.0 / 30.0 {
+        return Color32::WHITE;
+    }
+    let diagonal = falling_distance(u, v, aspect).min(rising_distance(u, v, aspect));
+    if diagonal <= 1.0 / 30.0 {
+        UNION_RED
+    } else if diagonal <= 3.0 / 30.0 {
+        Color32::WHITE
+    } else {
+        UNION_BLUE
+    }
+}
@@ -0,0 +1,86 @@
+//! Frame-time overlay, toggled with F12, to measure the cost of a screen
+//! and check the power-saving pace.
+
+use crate::i18n::{self, tr};
+use crate::power::Pace;
+use crate::ui::theme;
+use std::collections::VecDeque;
+
+/// Frames averaged, in seconds.
+const WINDOW: f64 = 2.0;
+
+#[derive(Default)]
+pub struct FrameStats {
+    shown: bool,
+    /// Start time and CPU time in seconds of the recent frames.
+    frames: VecDeque<(f64, f32)>,
+}
+
+impl FrameStats {
+    /// Called at the start of each frame: `frame` holds the CPU time of the
+    /// previous one.
+    pub fn record(&mut self, ctx: &egui::Context, frame: &eframe::Frame) {
+        if ctx.input(|i| i.key_pressed(egui::Key::F12)) {
+            self.shown = !self.shown;
+            self.frames.clear();
+        }
+        if !self.shown {
+            return;
+        }
+        let now = ctx.input(|i| i.time);
+        let cpu = frame.info().cpu_usage.unwrap_or(0.0);
+        self.frames.push_back((now, cpu));
+        while self
+            .frames
+            .front()
+            .is_some_and(|&(at, _)| now - at > WINDOW)
+        {
+            self.frames.pop_front();
+        }
+    }
+
+    pub fn show(&self, ctx: &egui::Context, pace: Pace) {
+        if !self.shown {
+            return;
+        }
+        let (first, last) = match (self.frames.front(), self.frames.back()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => return,
+        };
+        let fps = match last - first {
+            span if span > 0.0 => (self.frames.len() - 1) as f64 / span,
+            _ => 0.0,
+        };
+        let cpu = self.frames.iter().map(|&(_, cpu)| cpu);
+        let average = cpu.clone().sum::<f32>() / self.frames.len() as f32;
+        let max = cpu.fold(0.0_f32, f32::max);
+
+        let summary = tr!(
+            "frame_stats.summary",
+            fps = i18n::decimal(fps, 0),
+            average = i18n::decimal(average as f64 * 1000.0, 1),
+            max = i18n::decimal(max as f64 * 1000.0, 1)
+        );
+        egui::Area::new(egui::Id::new("frame_stats"))
+            .order(egui::Order::Foreground)
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-8.0, -8.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::none()
+                    .fill(theme::current().bg_card)
+                    .stroke(egui::Stroke::new(1.0, theme::current().border))
+                    .rounding(egui::Rounding::same(6.0))
+                    .inner_margin(egui::Margin::symmetric(8.0, 4.0))
+                    .show(ui, |ui| {
+                        for line in [summary, tr!("frame_stats.pace", pace = pace.label())] {
+                            ui.label(
+                                egui::RichText::new(line)
+                                    .size(11.0)
+                                    .family(egui::FontFamily::Monospace)
+                                    .color(theme::current().text_secondary),
+                            );
+                        }
+                    });
+            });
+    }
+}
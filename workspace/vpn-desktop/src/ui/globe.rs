@@ -0,0 +1,535 @@
+//! Holographic globe showing the servers where they are.
+//!
+//! Markers sit on their country's centroid ([`vpn_core::country`]) and a
+//! click on one picks that server. An arc runs from the machine's estimated
+//! location ([`crate::location`]) to the connected server. Dragging turns
+//! the globe; it starts spinning again a few seconds later.
+
+use crate::i18n::tr;
+use crate::server_list;
+use crate::ui::continents;
+use crate::ui::theme;
+use egui::{Pos2, Rect, Stroke, Vec2};
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+use std::sync::OnceLock;
+use vpn_core::api::Server;
+
+/// Radians per second while spinning on its own.
+const SPIN_SPEED: f32 = 0.3;
+/// Seconds after a drag before spinning again.
+const SPIN_RESUME: f64 = 4.0;
+/// Tilt limits, so that a pole never faces the viewer.
+const MAX_TILT: f32 = 1.2;
+/// Pointer distance, in points, at which a marker counts as hovered.
+const MARKER_HIT: f32 = 9.0;
+/// Spread of the markers of one country, in degrees.
+const MARKER_SPREAD: f32 = 1.5;
+
+/// Orientation kept between frames.
+pub struct GlobeView {
+    rotation: f32,
+    tilt: f32,
+    last_frame: Option<f64>,
+    last_drag: f64,
+}
+
+impl Default for GlobeView {
+    fn default() -> Self {
+        Self {
+            rotation: 0.0,
+            // 20 degrees: the northern hemisphere, where most servers are
+            tilt: 0.35,
+            last_frame: None,
+            last_drag: f64::NEG_INFINITY,
+        }
+    }
+}
+
+/// A server that can be placed on the globe.
+pub struct Marker {
+    pub id: u64,
+    pub name: String,
+    pub country: String,
+    pub is_active: bool,
+    /// Radians.
+    lat: f32,
+    lon: f32,
+}
+
+/// Servers of unknown countries are left out. Servers of one country are
+/// spread around its centroid so that each can be clicked.
+pub fn markers(servers: &[&Server]) -> Vec<Marker> {
+    let mut markers = Vec::new();
+    for server in servers {
+        let Some(country) = server_list::country(&server.country) else {
+            continue;
+        };
+        let same: Vec<u64> = servers
+            .iter()
+            .filter(|s| server_list::country(&s.country) == Some(country))
+            .map(|s| s.id)
+            .collect();
+        let (lat, lon) = country.centroid();
+        let (mut lat, mut lon) = (lat as f32, lon as f32);
+        if same.len() > 1 {
+            let index = same.iter().position(|&id| id == server.id).unwrap_or(0);
+            let angle = TAU * index as f32 / same.len() as f32;
+            lat += MARKER_SPREAD * angle.cos();
+            lon += MARKER_SPREAD * angle.sin() / lat.to_radians().cos().max(0.2);
+        }
+        markers.push(Marker {
+            id: server.id,
+            name: server.name.clone(),
+            country: server.country.clone(),
+            is_active: server.is_active,
+            lat: lat.to_radians(),
+            lon: lon.to_radians(),
+        });
+    }
+    markers
+}
+
+/// What the globe highlights.
+#[derive(Default)]
+pub struct Highlight {
+    pub connected: Option<u64>,
+    pub selected: Option<u64>,
+    /// Start of the arc to the connected server, in degrees.
+    pub home: Option<(f32, f32)>,
+}
+
+pub struct GlobeResponse {
+    pub rect: Rect,
+    /// Marker clicked this frame.
+    pub clicked: Option<u64>,
+}
+
+/// Unit vector of a point on the globe, before rotation.
+fn to_vector(lat: f32, lon: f32) -> [f32; 3] {
+    [lat.cos() * lon.sin(), lat.sin(), lat.cos() * lon.cos()]
+}
+
+/// Orientation of one frame, shared by every point drawn in it.
+struct Projection {
+    sin_r: f32,
+    cos_r: f32,
+    sin_t: f32,
+    cos_t: f32,
+    center: Pos2,
+    radius: f32,
+}
+
+impl Projection {
+    fn new(view: &GlobeView, center: Pos2, radius: f32) -> Self {
+        let (sin_r, cos_r) = view.rotation.sin_cos();
+        let (sin_t, cos_t) = view.tilt.sin_cos();
+        Self {
+            sin_r,
+            cos_r,
+            sin_t,
+            cos_t,
+            center,
+            radius,
+        }
+    }
+
+    /// Turns the globe by `rotation` around its axis, tilts it towards the
+    /// viewer and projects it. Returns the screen position and the depth,
+    /// which is positive on the visible side.
+    fn project(&self, p: [f32; 3]) -> (Pos2, f32) {
+        let x = p[0] * self.cos_r + p[2] * self.sin_r;
+        let z = -p[0] * self.sin_r + p[2] * self.cos_r;
+        let y_tilted = p[1] * self.cos_t - z * self.sin_t;
+        let z_tilted = p[1] * self.sin_t + z * self.cos_t;
+        (
+            Pos2::new(
+                self.center.x + x * self.radius,
+                self.center.y - y_tilted * self.radius,
+            ),
+            z_tilted,
+        )
+    }
+
+    fn project_sphere(&self, lat: f32, lon: f32) -> (Pos2, f32) {
+        self.project(to_vector(lat, lon))
+    }
+}
+
+/// Continents and grid lines as unit vectors. They never change: only the
+/// projection is done each frame.
+struct Geometry {
+    continents: Vec<Vec<[f32; 3]>>,
+    latitudes: Vec<Vec<[f32; 3]>>,
+    meridians: Vec<Vec<[f32; 3]>>,
+}
+
+fn geometry() -> &'static Geometry {
+    static GEOMETRY: OnceLock<Geometry> = OnceLock::new();
+    GEOMETRY.get_or_init(|| {
+        let continents = continents::continent_outlines()
+            .into_iter()
+            .map(|polygon| {
+                polygon
+                    .into_iter()
+                    .map(|(lat, lon)| to_vector(lat, lon))
+                    .collect()
+            })
+            .collect();
+        let latitudes = [-60.0_f32, -30.0, 0.0, 30.0, 60.0]
+            .into_iter()
+            .map(|lat_deg| {
+                let lat = lat_deg.to_radians();
+                (0..=48)
+                    .map(|s| to_vector(lat, s as f32 / 48.0 * TAU))
+                    .collect()
+            })
+            .collect();
+        let meridians = (0..12)
+            .map(|i| {
+                let lon = (i as f32 / 12.0) * TAU;
+                (0..=40)
+                    .map(|s| to_vector(-FRAC_PI_2 + s as f32 / 40.0 * PI, lon))
+                    .collect()
+            })
+            .collect();
+        Geometry {
+            continents,
+            latitudes,
+            meridians,
+        }
+    })
+}
+
+pub fn show(
+    ui: &mut egui::Ui,
+    view: &mut GlobeView,
+    markers: &[Marker],
+    highlight: &Highlight,
+    size: f32,
+) -> GlobeResponse {
+    let time = ui.input(|i| i.time);
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), egui::Sense::click_and_drag());
+    let center = rect.center();
+    let radius = size * 0.42;
+
+    // ── Orientation ────────────────────────────────────────────────────────
+    let dt = view.last_frame.map_or(0.0, |last| (time - last) as f32);
+    view.last_frame = Some(time);
+    if response.dragged() {
+        let delta = response.drag_delta();
+        view.rotation += delta.x / radius;
+        view.tilt = (view.tilt + delta.y / radius).clamp(-MAX_TILT, MAX_TILT);
+        view.last_drag = time;
+    } else if time - view.last_drag > SPIN_RESUME {
+        // A long pause (window hidden) is not caught up
+        view.rotation += dt.min(0.1) * SPIN_SPEED;
+    }
+    view.rotation %= TAU;
+
+    // ── Hovered marker ─────────────────────────────────────────────────────
+    let projection = Projection::new(view, center, radius);
+    let projected: Vec<(Pos2, f32)> = markers
+        .iter()
+        .map(|m| projection.project_sphere(m.lat, m.lon))
+        .collect();
+    let hovered = response.hover_pos().and_then(|pointer| {
+        projected
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, z))| *z > 0.0)
+            .map(|(i, (pos, _))| (i, pos.distance(pointer)))
+            .filter(|(_, distance)| *distance < MARKER_HIT)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    });
+    let clicked = if response.clicked() {
+        hovered.map(|i| markers[i].id)
+    } else {
+        None
+    };
+    match hovered {
+        Some(i) => {
+            let marker = &markers[i];
+            let status = if marker.is_active {
+                tr!("servers.globe.online")
+            } else {
+                tr!("servers.globe.offline")
+            };
+            response
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .on_hover_text_at_pointer(format!(
+                    "{} · {} ({})",
+                    marker.name,
+                    server_list::country_name(&marker.country),
+                    status
+                ));
+        }
+        None if response.dragged() => {
+            response.on_hover_cursor(egui::CursorIcon::Grabbing);
+        }
+        None => {
+            response.on_hover_cursor(egui::CursorIcon::Grab);
+        }
+    }
+
+    if ui.is_rect_visible(rect) {
+        let time = time as f32;
+        let painter = ui.painter();
+        let theme = theme::current();
+        draw_sphere(painter, &projection, time);
+
+        // ── Arc from here to the connected server ──────────────────────────
+        let connected = highlight
+            .connected
+            .and_then(|id| markers.iter().find(|m| m.id == id));
+        if let (Some((lat, lon)), Some(server)) = (highlight.home, connected) {
+            let home = to_vector(lat.to_radians(), lon.to_radians());
+            let target = to_vector(server.lat, server.lon);
+            draw_arc(painter, &projection, home, target, time);
+        }
+
+        // ── Server markers ─────────────────────────────────────────────────
+        for (i, (marker, &(pos, z))) in markers.iter().zip(&projected).enumerate() {
+            if z <= -0.05 {
+                continue;
+            }
+            let depth = ((z + 0.05) * 1.2).clamp(0.0, 1.0);
+            let is_connected = highlight.connected == Some(marker.id);
+            let is_selected = highlight.selected == Some(marker.id);
+            let color = if is_connected {
+                theme.holo
+            } else if is_selected {
+                theme.accent
+            } else if marker.is_active {
+                theme.success
+            } else {
+                theme.text_muted
+            };
+            let pulse = ((time * 1.5 + i as f32 * 0.7).sin() + 1.0) / 2.0;
+            let emphasis = if is_connected || is_selected || hovered == Some(i) {
+                1.6
+            } else {
+                1.0
+            };
+
+            // Glow, ring for the connected one, core
+            painter.circle_filled(
+                pos,
+                (3.5 + pulse * 2.0) * emphasis,
+                theme.faint(color, depth * (25.0 + pulse * 30.0)),
+            );
+            if is_connected {
+                let ring = (time * 0.8) % 1.0;
+                painter.circle_stroke(
+                    pos,
+                    4.0 + ring * 10.0,
+                    Stroke::new(1.0, theme.faint(color, depth * (1.0 - ring) * 160.0)),
+                );
+            }
+            painter.circle_filled(
+                pos,
+                (1.8 + depth * 0.8) * emphasis,
+                theme.faint(color, depth * 240.0),
+            );
+        }
+
+        // ── Where we are ───────────────────────────────────────────────────
+        if let Some((lat, lon)) = highlight.home {
+            let (pos, z) = projection.project_sphere(lat.to_radians(), lon.to_radians());
+            if z > -0.05 {
+                let depth = ((z + 0.05) * 1.2).clamp(0.0, 1.0);
+                painter.circle_filled(pos, 2.2, theme.faint(theme.home, depth * 220.0));
+            }
+        }
+
+        draw_scanline(painter, center, radius, time);
+    }
+
+    GlobeResponse { rect, clicked }
+}
+
+/// Glow, outline, continents and grid.
+fn draw_sphere(painter: &egui::Painter, projection: &Projection, time: f32) {
+    let theme = theme::current();
+    let (center, radius) = (projection.center, projection.radius);
+    // ── Outer holographic glow rings ───────────────────────────────────────
+    for i in 0..3 {
+        let pulse = ((time * 0.8 + i as f32 * 0.7).sin() + 1.0) / 2.0;
+        let r = radius + 8.0 + i as f32 * 6.0 + pulse * 3.0;
+        let alpha = 15.0 + pulse * 20.0;
+        painter.circle_stroke(center, r, Stroke::new(0.5, theme.faint(theme.holo, alpha)));
+    }
+
+    // ── Globe outline (holographic cyan) ───────────────────────────────────
+    let outline_pulse = ((time * 1.2).sin() + 1.0) / 2.0;
+    let outline_alpha = 40.0 + outline_pulse * 30.0;
+    painter.circle_stroke(
+        center,
+        radius,
+        Stroke::new(1.5, theme.faint(theme.holo, outline_alpha)),
+    );
+
+    // ── Globe fill (very subtle dark glow) ─────────────────────────────────
+    let fill_alpha = 8.0 + outline_pulse * 6.0;
+    painter.circle_filled(center, radius - 1.0, theme.faint(theme.ocean, fill_alpha));
+
+    // ── Continent outlines (holographic landmasses) ────────────────────────
+    // Buffers reused from one polygon to the next
+    let mut projected_poly: Vec<(Pos2, f32)> = Vec::new();
+    let mut visible: Vec<(Pos2, f32)> = Vec::new();
+    for polygon in &geometry().continents {
+        if polygon.len() < 2 {
+            continue;
+        }
+        projected_poly.clear();
+        projected_poly.extend(polygon.iter().map(|&p| projection.project(p)));
+
+        // Fan-filled from the first visible vertex, front-facing part only
+        visible.clear();
+        visible.extend(projected_poly.iter().copied().filter(|&(_, z)| z > -0.05));
+        if visible.len() >= 3 {
+            let avg_z: f32 = visible.iter().map(|&(_, z)| z).sum::<f32>() / visible.len() as f32;
+            let depth_factor = ((avg_z + 0.05) * 1.1).clamp(0.0, 1.0);
+            let fill_a = (depth_factor * (18.0 + outline_pulse * 12.0)).min(40.0);
+
+            let mesh_color = theme.faint(theme.land, fill_a);
+            let mut mesh = egui::Mesh::default();
+            for &(pos, _) in &visible {
+                mesh.vertices.push(egui::epaint::Vertex {
+                    pos,
+                    uv: egui::epaint::WHITE_UV,
+                    color: mesh_color,
+                });
+            }
+            for i in 1..(visible.len() as u32 - 1) {
+                mesh.indices.extend([0, i, i + 1]);
+            }
+            painter.add(egui::Shape::mesh(mesh));
+        }
+
+        for i in 0..projected_poly.len() {
+            let j = (i + 1) % projected_poly.len();
+            let (a, az) = projected_poly[i];
+            let (b, bz) = projected_poly[j];
+            if az > -0.05 && bz > -0.05 {
+                let depth_factor = ((az.min(bz) + 0.05) * 1.1).clamp(0.0, 1.0);
+                let alpha = (depth_factor * (35.0 + outline_pulse * 30.0)).min(80.0);
+                if alpha > 2.0 {
+                    painter.line_segment([a, b], Stroke::new(0.8, theme.faint(theme.coast, alpha)));
+                }
+            }
+        }
+    }
+
+    // ── Latitude lines (holographic grid) ──────────────────────────────────
+    let grid_alpha = 12.0 + outline_pulse * 8.0;
+    for line in &geometry().latitudes {
+        grid_line(painter, projection, line, grid_alpha);
+    }
+
+    // ── Longitude lines (meridians) ────────────────────────────────────────
+    for line in &geometry().meridians {
+        grid_line(painter, projection, line, 15.0);
+    }
+}
+
+/// Polyline through `points`, faded with depth.
+fn grid_line(painter: &egui::Painter, projection: &Projection, points: &[[f32; 3]], alpha: f32) {
+    let theme = theme::current();
+    let mut points = points.iter().map(|&p| projection.project(p));
+    let mut prev = match points.next() {
+        Some(p) => p,
+        None => return,
+    };
+    for next in points {
+        let z = prev.1.min(next.1);
+        if z > -0.1 {
+            let a = ((z + 0.1) * 0.9).min(1.0) * alpha;
+            if a > 1.0 {
+                painter.line_segment(
+                    [prev.0, next.0],
+                    Stroke::new(0.4, theme.faint(theme.holo_dim, a)),
+                );
+            }
+        }
+        prev = next;
+    }
+}
+
+/// Great circle from `from` to `to`, raised above the surface, with a dot
+/// travelling along it.
+fn draw_arc(
+    painter: &egui::Painter,
+    projection: &Projection,
+    from: [f32; 3],
+    to: [f32; 3],
+    time: f32,
+) {
+    const SEGMENTS: usize = 48;
+    let theme = theme::current();
+    let dot = (from[0] * to[0] + from[1] * to[1] + from[2] * to[2]).clamp(-1.0, 1.0);
+    let angle = dot.acos();
+    if angle < 1e-3 {
+        return;
+    }
+    let height = 0.08 + 0.12 * (angle / PI);
+    let point = |t: f32| -> (Pos2, bool) {
+        let a = ((1.0 - t) * angle).sin() / angle.sin();
+        let b = (t * angle).sin() / angle.sin();
+        let lift = 1.0 + height * (PI * t).sin();
+        let p = [
+            (a * from[0] + b * to[0]) * lift,
+            (a * from[1] + b * to[1]) * lift,
+            (a * from[2] + b * to[2]) * lift,
+        ];
+        let (pos, z) = projection.project(p);
+        // In front of the sphere, or beside it on screen
+        let visible = z > 0.0 || pos.distance(projection.center) > projection.radius;
+        (pos, visible)
+    };
+
+    let mut prev = point(0.0);
+    for s in 1..=SEGMENTS {
+        let t = s as f32 / SEGMENTS as f32;
+        let next = point(t);
+        if prev.1 && next.1 {
+            let flow = ((time * 3.0 - t * 8.0).sin() + 1.0) / 2.0;
+            painter.line_segment(
+                [prev.0, next.0],
+                Stroke::new(1.2, theme.faint(theme.holo, 90.0 + flow * 100.0)),
+            );
+        }
+        prev = next;
+    }
+
+    let (pos, visible) = point((time * 0.4) % 1.0);
+    if visible {
+        painter.circle_filled(pos, 2.5, theme.holo_bright);
+    }
+}
+
+/// Holographic sweep.
+fn draw_scanline(painter: &egui::Painter, center: Pos2, radius: f32, time: f32) {
+    let theme = theme::current();
+    let scan_angle = (time * 0.6) % TAU;
+    let scan = Pos2::new(
+        center.x + scan_angle.cos() * radius,
+        center.y + scan_angle.sin() * radius,
+    );
+    painter.line_segment(
+        [center, scan],
+        Stroke::new(0.5, theme.faint(theme.scan, 20.0)),
+    );
+
+    let sweep_segments = 20;
+    for s in 0..sweep_segments {
+        let t = s as f32 / sweep_segments as f32;
+        let a = scan_angle - t * 0.4;
+        let alpha = (1.0 - t) * 12.0;
+        painter.circle_filled(
+            Pos2::new(center.x + a.cos() * radius, center.y + a.sin() * radius),
+            1.0,
+            theme.faint(theme.scan, alpha),
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::app::VpnApp;
+use crate::i18n::tr;
 use crate::ui::theme;
 
 pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
@@ -16,14 +17,14 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
         ui.label(
             egui::RichText::new("SilentGhostVPN")
                 .size(28.0)
-                .color(theme::ACCENT)
+                .color(theme::current().accent)
                 .strong(),
         );
         ui.add_space(4.0);
         ui.label(
-            egui::RichText::new("CONNEXION SECURISEE")
+            egui::RichText::new(tr!("login.tagline"))
                 .size(11.0)
-                .color(theme::TEXT_MUTED),
+                .color(theme::current().text_muted),
         );
 
         ui.add_space(32.0);
@@ -34,40 +35,59 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
             theme::card_frame(ui, |ui| {
                 ui.set_min_width(ui.available_width());
 
+                crate::ui::recovery::render_banner(ui, app);
+
+                if app.is_resuming_session() {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(
+                            egui::RichText::new(tr!("login.resuming"))
+                                .size(12.0)
+                                .color(theme::current().text_secondary),
+                        );
+                    });
+                    ui.add_space(12.0);
+                }
+
                 // Error message
                 if let Some(error) = app.get_error() {
                     let error = error.to_string();
                     egui::Frame::none()
-                        .fill(egui::Color32::from_rgba_premultiplied(248, 81, 73, 15))
+                        .fill(theme::faint(theme::current().error, 15.0))
                         .rounding(egui::Rounding::same(6.0))
                         .stroke(egui::Stroke::new(
                             1.0,
-                            egui::Color32::from_rgba_premultiplied(248, 81, 73, 40),
+                            theme::faint(theme::current().error, 40.0),
                         ))
                         .inner_margin(egui::Margin::same(12.0))
                         .show(ui, |ui| {
-                            ui.label(egui::RichText::new(&error).size(13.0).color(theme::ERROR));
+                            ui.label(
+                                egui::RichText::new(&error)
+                                    .size(13.0)
+                                    .color(theme::current().error),
+                            );
                         });
                     ui.add_space(16.0);
                 }
 
                 // Form fields
                 if app.is_show_register() {
-                    theme::text_field(ui, "Nom d'utilisateur", app.get_username());
+                    theme::text_field(ui, tr!("profile.username"), app.get_username());
                     ui.add_space(16.0);
                 }
 
-                theme::text_field(ui, "Email", app.get_email());
+                theme::text_field(ui, tr!("profile.email"), app.get_email());
                 ui.add_space(16.0);
 
-                let pw_response = theme::password_field(ui, "Mot de passe", app.get_password());
+                let pw_response =
+                    theme::password_field(ui, tr!("login.password"), app.get_password());
                 ui.add_space(20.0);
 
                 // Submit button
                 let button_text = if app.is_show_register() {
-                    "Creer un compte"
+                    tr!("login.register")
                 } else {
-                    "Se connecter"
+                    tr!("login.submit")
                 };
 
                 let clicked = theme::primary_button(ui, button_text, true);
@@ -87,9 +107,9 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
                 // Toggle register/login (centered)
                 ui.vertical_centered(|ui| {
                     let toggle_text = if app.is_show_register() {
-                        "Deja un compte ? Se connecter"
+                        tr!("login.to_login")
                     } else {
-                        "Pas de compte ? S'inscrire"
+                        tr!("login.to_register")
                     };
 
                     if ui
@@ -97,7 +117,7 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
                             egui::Label::new(
                                 egui::RichText::new(toggle_text)
                                     .size(13.0)
-                                    .color(theme::ACCENT),
+                                    .color(theme::current().accent),
                             )
                             .sense(egui::Sense::click()),
                         )
@@ -109,5 +129,23 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
                 });
             });
         });
+
+        ui.add_space(16.0);
+
+        // The API address has to be set before the first login
+        if ui
+            .add(
+                egui::Label::new(
+                    egui::RichText::new(tr!("login.settings"))
+                        .size(12.0)
+                        .color(theme::current().text_muted),
+                )
+                .sense(egui::Sense::click()),
+            )
+            .on_hover_cursor(egui::CursorIcon::PointingHand)
+            .clicked()
+        {
+            app.show_settings();
+        }
     });
 }
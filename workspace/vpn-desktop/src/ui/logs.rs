@@ -0,0 +1,154 @@
+use crate::app::VpnApp;
+use crate::i18n::{self, tr};
+use crate::logging::{self, LogLine};
+use crate::ui::theme;
+use egui::{Color32, Rounding, Stroke};
+use std::time::Duration;
+use tracing::Level;
+
+/// Levels and the catalog keys of their names.
+const LEVELS: [(Level, &str); 4] = [
+    (Level::ERROR, "logs.level.error"),
+    (Level::WARN, "logs.level.warn"),
+    (Level::INFO, "logs.level.info"),
+    (Level::DEBUG, "logs.level.debug"),
+];
+
+pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::draw_top_accent(ui);
+    // New events do not wake egui up
+    ui.ctx().request_repaint_after(Duration::from_secs(1));
+
+    // ── Header ─────────────────────────────────────────────────────────────
+    ui.horizontal(|ui| {
+        if ui
+            .add(
+                egui::Label::new(
+                    egui::RichText::new(tr!("action.back"))
+                        .size(13.0)
+                        .color(theme::current().accent),
+                )
+                .sense(egui::Sense::click()),
+            )
+            .on_hover_cursor(egui::CursorIcon::PointingHand)
+            .clicked()
+        {
+            app.hide_logs();
+        }
+    });
+
+    ui.add_space(8.0);
+
+    ui.label(
+        egui::RichText::new(tr!("logs.title"))
+            .size(18.0)
+            .color(theme::current().text_primary)
+            .strong(),
+    );
+
+    ui.add_space(8.0);
+
+    // ── Level filter ───────────────────────────────────────────────────────
+    ui.horizontal_wrapped(|ui| {
+        for (level, label) in LEVELS {
+            let selected = app.get_log_level() == level;
+            let text = egui::RichText::new(i18n::message(label))
+                .size(12.0)
+                .color(if selected {
+                    theme::current().text_primary
+                } else {
+                    theme::current().text_secondary
+                });
+            if ui.selectable_label(selected, text).clicked() {
+                app.set_log_level(level);
+            }
+        }
+    });
+
+    // More verbose levels compare greater
+    let lines: Vec<LogLine> = logging::recent()
+        .into_iter()
+        .filter(|l| l.level <= app.get_log_level())
+        .collect();
+
+    ui.add_space(6.0);
+    ui.horizontal(|ui| {
+        if theme::small_button(
+            ui,
+            tr!("logs.copy"),
+            theme::current().bg_card,
+            theme::current().text_primary,
+        ) {
+            let text: String = lines.iter().map(|l| format_line(l) + "\n").collect();
+            ui.ctx().copy_text(text);
+        }
+        ui.add_space(4.0);
+        if theme::small_button(
+            ui,
+            tr!("logs.export"),
+            theme::current().accent,
+            theme::current().text_on_accent,
+        ) {
+            app.export_diagnostics();
+        }
+    });
+    render_diagnostics_status(ui, app);
+
+    ui.add_space(8.0);
+
+    egui::Frame::none()
+        .fill(theme::current().bg_card)
+        .rounding(Rounding::same(8.0))
+        .stroke(Stroke::new(1.0, theme::current().border))
+        .inner_margin(egui::Margin::same(8.0))
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            egui::ScrollArea::both()
+                .auto_shrink([false, false])
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    if lines.is_empty() {
+                        ui.label(
+                            egui::RichText::new(tr!("logs.empty"))
+                                .size(12.0)
+                                .color(theme::current().text_muted),
+                        );
+                    }
+                    for line in &lines {
+                        ui.label(
+                            egui::RichText::new(format_line(line))
+                                .monospace()
+                                .size(11.0)
+                                .color(level_color(line.level)),
+                        );
+                    }
+                });
+        });
+}
+
+/// Where the last export went, or why it failed. Also shown in the settings.
+pub fn render_diagnostics_status(ui: &mut egui::Ui, app: &VpnApp) {
+    let (text, color) = match app.get_diagnostics_status() {
+        Some(Ok(message)) => (message, theme::current().success),
+        Some(Err(error)) => (error, theme::current().error),
+        None => return,
+    };
+    ui.add_space(4.0);
+    ui.label(egui::RichText::new(text).size(11.0).color(color));
+}
+
+fn format_line(line: &LogLine) -> String {
+    format!(
+        "{} {:>5} {}: {}",
+        line.time, line.level, line.target, line.message
+    )
+}
+
+fn level_color(level: Level) -> Color32 {
+    match level {
+        Level::ERROR => theme::current().error,
+        Level::WARN => theme::current().warning,
+        Level::INFO => theme::current().text_primary,
+        _ => theme::current().text_secondary,
+    }
+}
@@ -1,7 +1,14 @@
+pub mod assets;
 pub mod connection;
 pub mod continents;
+pub mod doctor;
 pub mod flags;
+pub mod frame_stats;
+pub mod globe;
 pub mod login;
+pub mod logs;
 pub mod profile;
+pub mod recovery;
 pub mod servers;
+pub mod settings;
 pub mod theme;
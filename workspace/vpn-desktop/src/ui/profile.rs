@@ -1,4 +1,5 @@
 use crate::app::VpnApp;
+use crate::i18n::tr;
 use crate::ui::theme;
 use egui::{Rounding, Stroke, Vec2};
 
@@ -12,9 +13,9 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
         if ui
             .add(
                 egui::Label::new(
-                    egui::RichText::new("← Retour")
+                    egui::RichText::new(tr!("action.back"))
                         .size(13.0)
-                        .color(theme::ACCENT),
+                        .color(theme::current().accent),
                 )
                 .sense(egui::Sense::click()),
             )
@@ -53,13 +54,13 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
                         ui.label(
                             egui::RichText::new(&session.user().username)
                                 .size(15.0)
-                                .color(theme::TEXT_PRIMARY)
+                                .color(theme::current().text_primary)
                                 .strong(),
                         );
                         ui.label(
                             egui::RichText::new(&session.user().email)
                                 .size(11.0)
-                                .color(theme::TEXT_SECONDARY),
+                                .color(theme::current().text_secondary),
                         );
                     }
                 });
@@ -89,13 +90,13 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
 
 fn render_view_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
     // Account info
-    section_label(ui, "COMPTE");
+    section_label(ui, tr!("profile.account"));
 
     compact_card(ui, |ui| {
         if let Some(session) = app.get_session() {
             row(ui, "ID", &format!("#{}", session.user().id), true);
-            row(ui, "Utilisateur", &session.user().username, true);
-            row(ui, "Email", &session.user().email, false);
+            row(ui, tr!("profile.user"), &session.user().username, true);
+            row(ui, tr!("profile.email"), &session.user().email, false);
         }
     });
 
@@ -106,9 +107,7 @@ fn render_view_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
 
     let is_connected = app.is_vpn_connected();
     let server_name = if is_connected {
-        app.get_selected_server()
-            .and_then(|idx| app.get_servers().get(idx))
-            .map(|s| s.name.clone())
+        app.get_selected_server().map(|s| s.name.clone())
     } else {
         None
     };
@@ -116,15 +115,15 @@ fn render_view_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
     compact_card(ui, |ui| {
         ui.horizontal(|ui| {
             ui.label(
-                egui::RichText::new("Statut")
+                egui::RichText::new(tr!("profile.status"))
                     .size(12.0)
-                    .color(theme::TEXT_MUTED),
+                    .color(theme::current().text_muted),
             );
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 let (label, color) = if is_connected {
-                    ("Connecte", theme::SUCCESS)
+                    (tr!("profile.connected"), theme::current().success)
                 } else {
-                    ("Deconnecte", theme::TEXT_MUTED)
+                    (tr!("profile.disconnected"), theme::current().text_muted)
                 };
                 let (dot, _) = ui.allocate_exact_size(Vec2::splat(7.0), egui::Sense::hover());
                 ui.painter().circle_filled(dot.center(), 3.0, color);
@@ -134,7 +133,7 @@ fn render_view_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
 
         if let Some(name) = &server_name {
             separator(ui);
-            row(ui, "Serveur", name, false);
+            row(ui, tr!("profile.server"), name, false);
         }
     });
 
@@ -144,7 +143,7 @@ fn render_view_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
     let btn_width = 200.0_f32.min(content_width);
     ui.vertical_centered(|ui| {
         ui.allocate_ui(Vec2::new(btn_width, 0.0), |ui| {
-            if outline_button(ui, "Modifier le profil", theme::ACCENT) {
+            if outline_button(ui, tr!("profile.edit"), theme::current().accent) {
                 app.start_profile_edit();
             }
         });
@@ -152,7 +151,7 @@ fn render_view_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
         ui.add_space(8.0);
 
         ui.allocate_ui(Vec2::new(btn_width, 0.0), |ui| {
-            if outline_button(ui, "Se deconnecter", theme::TEXT_PRIMARY) {
+            if outline_button(ui, tr!("profile.logout"), theme::current().text_primary) {
                 app.handle_logout();
             }
         });
@@ -162,9 +161,9 @@ fn render_view_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
         if ui
             .add(
                 egui::Label::new(
-                    egui::RichText::new("Supprimer le compte")
+                    egui::RichText::new(tr!("profile.delete"))
                         .size(12.0)
-                        .color(theme::DANGER),
+                        .color(theme::current().danger),
                 )
                 .sense(egui::Sense::click()),
             )
@@ -186,14 +185,14 @@ fn render_edit_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
         ui.add_space(8.0);
     }
 
-    section_label(ui, "MODIFIER LE PROFIL");
+    section_label(ui, tr!("profile.edit_heading"));
 
     compact_card(ui, |ui| {
         // Username
         ui.label(
-            egui::RichText::new("Nom d'utilisateur")
+            egui::RichText::new(tr!("profile.username"))
                 .size(11.0)
-                .color(theme::TEXT_SECONDARY),
+                .color(theme::current().text_secondary),
         );
         ui.add_space(2.0);
         ui.add(
@@ -207,9 +206,9 @@ fn render_edit_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
 
         // Email
         ui.label(
-            egui::RichText::new("Email")
+            egui::RichText::new(tr!("profile.email"))
                 .size(11.0)
-                .color(theme::TEXT_SECONDARY),
+                .color(theme::current().text_secondary),
         );
         ui.add_space(2.0);
         ui.add(
@@ -223,9 +222,9 @@ fn render_edit_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
 
         // Password
         ui.label(
-            egui::RichText::new("Nouveau mot de passe")
+            egui::RichText::new(tr!("profile.new_password"))
                 .size(11.0)
-                .color(theme::TEXT_SECONDARY),
+                .color(theme::current().text_secondary),
         );
         ui.add_space(2.0);
         ui.add(
@@ -234,7 +233,7 @@ fn render_edit_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
                 .desired_width(ui.available_width())
                 .margin(egui::Margin::symmetric(10.0, 8.0))
                 .font(egui::FontId::new(13.0, egui::FontFamily::Proportional))
-                .hint_text("Min. 8 caracteres"),
+                .hint_text(tr!("profile.password_hint")),
         );
     });
 
@@ -244,7 +243,7 @@ fn render_edit_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
     let btn_width = 200.0_f32.min(content_width);
     ui.vertical_centered(|ui| {
         ui.allocate_ui(Vec2::new(btn_width, 0.0), |ui| {
-            if accent_button(ui, "Enregistrer") {
+            if accent_button(ui, tr!("action.save")) {
                 app.handle_update_profile();
             }
         });
@@ -252,7 +251,7 @@ fn render_edit_mode(ui: &mut egui::Ui, app: &mut VpnApp, content_width: f32) {
         ui.add_space(6.0);
 
         ui.allocate_ui(Vec2::new(btn_width, 0.0), |ui| {
-            if outline_button(ui, "Annuler", theme::TEXT_MUTED) {
+            if outline_button(ui, tr!("action.cancel"), theme::current().text_muted) {
                 app.cancel_profile_edit();
             }
         });
@@ -265,7 +264,7 @@ fn section_label(ui: &mut egui::Ui, text: &str) {
     ui.label(
         egui::RichText::new(text)
             .size(10.0)
-            .color(theme::TEXT_MUTED)
+            .color(theme::current().text_muted)
             .strong(),
     );
     ui.add_space(4.0);
@@ -273,9 +272,9 @@ fn section_label(ui: &mut egui::Ui, text: &str) {
 
 fn compact_card(ui: &mut egui::Ui, content: impl FnOnce(&mut egui::Ui)) {
     egui::Frame::none()
-        .fill(theme::BG_CARD)
+        .fill(theme::current().bg_card)
         .rounding(Rounding::same(8.0))
-        .stroke(Stroke::new(1.0, theme::BORDER))
+        .stroke(Stroke::new(1.0, theme::current().border))
         .inner_margin(egui::Margin::symmetric(12.0, 8.0))
         .show(ui, |ui| {
             ui.set_min_width(ui.available_width());
@@ -288,13 +287,13 @@ fn row(ui: &mut egui::Ui, label: &str, value: &str, show_separator: bool) {
         ui.label(
             egui::RichText::new(label)
                 .size(12.0)
-                .color(theme::TEXT_MUTED),
+                .color(theme::current().text_muted),
         );
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
             ui.label(
                 egui::RichText::new(value)
                     .size(12.0)
-                    .color(theme::TEXT_PRIMARY),
+                    .color(theme::current().text_primary),
             );
         });
     });
@@ -307,11 +306,8 @@ fn separator(ui: &mut egui::Ui) {
     ui.add_space(2.0);
     let rect = ui.available_rect_before_wrap();
     let line = egui::Rect::from_min_size(rect.min, Vec2::new(rect.width(), 1.0));
-    ui.painter().rect_filled(
-        line,
-        Rounding::ZERO,
-        egui::Color32::from_rgba_premultiplied(48, 60, 61, 25),
-    );
+    ui.painter()
+        .rect_filled(line, Rounding::ZERO, theme::current().border);
     ui.add_space(4.0);
 }
 
@@ -321,13 +317,17 @@ fn outline_button(ui: &mut egui::Ui, text: &str, text_color: egui::Color32) -> b
 
     if ui.is_rect_visible(rect) {
         let bg = if response.hovered() {
-            theme::BG_CARD_HOVER
+            theme::current().bg_card_hover
         } else {
-            theme::BG_CARD
+            theme::current().bg_card
         };
         let painter = ui.painter();
         painter.rect_filled(rect, Rounding::same(6.0), bg);
-        painter.rect_stroke(rect, Rounding::same(6.0), Stroke::new(1.0, theme::BORDER));
+        painter.rect_stroke(
+            rect,
+            Rounding::same(6.0),
+            Stroke::new(1.0, theme::current().border),
+        );
         painter.text(
             rect.center(),
             egui::Align2::CENTER_CENTER,
@@ -348,9 +348,9 @@ fn accent_button(ui: &mut egui::Ui, text: &str) -> bool {
 
     if ui.is_rect_visible(rect) {
         let bg = if response.hovered() {
-            theme::ACCENT_HOVER
+            theme::current().accent_hover
         } else {
-            theme::ACCENT
+            theme::current().accent
         };
         let painter = ui.painter();
         painter.rect_filled(rect, Rounding::same(6.0), bg);
@@ -359,7 +359,7 @@ fn accent_button(ui: &mut egui::Ui, text: &str) -> bool {
             egui::Align2::CENTER_CENTER,
             text,
             egui::FontId::new(13.0, egui::FontFamily::Proportional),
-            theme::TEXT_PRIMARY,
+            theme::current().text_on_accent,
         );
     }
 
@@ -370,28 +370,33 @@ fn accent_button(ui: &mut egui::Ui, text: &str) -> bool {
 
 fn error_banner(ui: &mut egui::Ui, text: &str) {
     egui::Frame::none()
-        .fill(egui::Color32::from_rgba_premultiplied(248, 81, 73, 15))
+        .fill(theme::faint(theme::current().error, 15.0))
         .rounding(Rounding::same(6.0))
-        .stroke(Stroke::new(
-            1.0,
-            egui::Color32::from_rgba_premultiplied(248, 81, 73, 40),
-        ))
+        .stroke(Stroke::new(1.0, theme::faint(theme::current().error, 40.0)))
         .inner_margin(egui::Margin::symmetric(10.0, 6.0))
         .show(ui, |ui| {
-            ui.label(egui::RichText::new(text).size(12.0).color(theme::ERROR));
+            ui.label(
+                egui::RichText::new(text)
+                    .size(12.0)
+                    .color(theme::current().error),
+            );
         });
 }
 
 fn success_banner(ui: &mut egui::Ui, text: &str) {
     egui::Frame::none()
-        .fill(egui::Color32::from_rgba_premultiplied(63, 185, 80, 15))
+        .fill(theme::faint(theme::current().success, 15.0))
         .rounding(Rounding::same(6.0))
         .stroke(Stroke::new(
             1.0,
-            egui::Color32::from_rgba_premultiplied(63, 185, 80, 40),
+            theme::faint(theme::current().success, 40.0),
         ))
         .inner_margin(egui::Margin::symmetric(10.0, 6.0))
         .show(ui, |ui| {
-            ui.label(egui::RichText::new(text).size(12.0).color(theme::SUCCESS));
+            ui.label(
+                egui::RichText::new(text)
+                    .size(12.0)
+                    .color(theme::current().success),
+            );
         });
 }
@@ -0,0 +1,63 @@
+use crate::app::VpnApp;
+use crate::i18n::tr;
+use crate::ui::theme;
+use egui::{Rounding, Stroke};
+
+/// Banner offering to resume or clean up a session that was not shut down.
+pub fn render_banner(ui: &mut egui::Ui, app: &mut VpnApp) {
+    let leftover = match app.get_leftover() {
+        Some(l) => l.clone(),
+        None => return,
+    };
+    let detail = match &leftover.interface {
+        Some(interface) => tr!(
+            "recovery.detail",
+            server = leftover.server_name,
+            interface = interface
+        ),
+        None => tr!("recovery.detail_proxy", server = leftover.server_name),
+    };
+
+    egui::Frame::none()
+        .fill(theme::faint(theme::current().accent, 15.0))
+        .rounding(Rounding::same(6.0))
+        .stroke(Stroke::new(
+            1.0,
+            theme::faint(theme::current().accent, 40.0),
+        ))
+        .inner_margin(egui::Margin::same(10.0))
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            ui.label(
+                egui::RichText::new(tr!("recovery.title"))
+                    .size(13.0)
+                    .color(theme::current().text_primary),
+            );
+            ui.label(
+                egui::RichText::new(detail)
+                    .size(12.0)
+                    .color(theme::current().text_secondary),
+            );
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                if theme::small_button(
+                    ui,
+                    tr!("recovery.resume"),
+                    theme::current().accent,
+                    theme::current().text_on_accent,
+                ) {
+                    app.resume_leftover();
+                }
+                ui.add_space(4.0);
+                if theme::small_button(
+                    ui,
+                    tr!("recovery.clean_up"),
+                    theme::current().bg_card,
+                    theme::current().text_primary,
+                ) {
+                    app.clean_up_leftover();
+                }
+            });
+        });
+    ui.add_space(8.0);
+}
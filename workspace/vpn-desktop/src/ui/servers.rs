@@ -1,28 +1,24 @@
 use crate::app::VpnApp;
-use crate::ui::theme;
-use egui::{ColorImage, Rounding, Stroke};
-
-const BG_GHOST: &[u8] = include_bytes!("../../assets/bg-ghost.jpg");
+use crate::i18n::{self, tr};
+use crate::server_list::{self, ServerFilter, ServerSort};
+use crate::ui::assets::Asset;
+use crate::ui::{globe, theme};
+use egui::{Rounding, Stroke};
+use std::time::Duration;
+use vpn_core::country::Continent;
 
 pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
     // ── Background ghost image with low opacity ────────────────────────────
     let panel_rect = ui.available_rect_before_wrap();
 
-    // Charger l'image depuis les bytes
-    if let Ok(image) = image::load_from_memory(BG_GHOST) {
-        let size = [image.width() as usize, image.height() as usize];
-        let image_buffer = image.to_rgba8();
-        let pixels = image_buffer.as_flat_samples();
-        let color_image = ColorImage::from_rgba_unmultiplied(size, pixels.as_slice());
-
-        let texture = ui
-            .ctx()
-            .load_texture("bg-ghost", color_image, egui::TextureOptions::LINEAR);
-
-        let bg_image = egui::Image::new(&texture)
-            .tint(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 30))
-            .fit_to_exact_size(panel_rect.size());
-        bg_image.paint_at(ui, panel_rect);
+    let alpha = theme::current().backdrop_alpha;
+    if alpha > 0 {
+        if let Some(texture) = app.assets.get(ui.ctx(), Asset::Backdrop) {
+            egui::Image::new(&texture)
+                .tint(egui::Color32::from_rgba_unmultiplied(255, 255, 255, alpha))
+                .fit_to_exact_size(panel_rect.size())
+                .paint_at(ui, panel_rect);
+        }
     }
 
     theme::draw_top_accent(ui);
@@ -34,7 +30,7 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
         ui.label(
             egui::RichText::new("SilentGhostVPN")
                 .size(16.0)
-                .color(theme::ACCENT)
+                .color(theme::current().accent)
                 .strong(),
         );
 
@@ -43,9 +39,9 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
             if ui
                 .add(
                     egui::Label::new(
-                        egui::RichText::new("Deconnexion")
+                        egui::RichText::new(tr!("servers.logout"))
                             .size(13.0)
-                            .color(theme::TEXT_MUTED),
+                            .color(theme::current().text_muted),
                     )
                     .sense(egui::Sense::click()),
                 )
@@ -58,9 +54,23 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
             ui.add_space(8.0);
 
             // Profile button
-            if theme::small_button(ui, "Profil", theme::ACCENT, theme::TEXT_PRIMARY) {
+            if theme::small_button(
+                ui,
+                tr!("servers.profile"),
+                theme::current().accent,
+                theme::current().text_on_accent,
+            ) {
                 app.show_profile();
             }
+            ui.add_space(4.0);
+            if theme::small_button(
+                ui,
+                "⚙",
+                theme::current().bg_card,
+                theme::current().text_primary,
+            ) {
+                app.show_settings();
+            }
         });
     });
 
@@ -70,21 +80,24 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
     if let Some(error) = app.get_error() {
         let error = error.to_string();
         egui::Frame::none()
-            .fill(egui::Color32::from_rgba_premultiplied(248, 81, 73, 15))
+            .fill(theme::faint(theme::current().error, 15.0))
             .rounding(Rounding::same(6.0))
-            .stroke(Stroke::new(
-                1.0,
-                egui::Color32::from_rgba_premultiplied(248, 81, 73, 40),
-            ))
+            .stroke(Stroke::new(1.0, theme::faint(theme::current().error, 40.0)))
             .inner_margin(egui::Margin::same(10.0))
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new(&error).size(13.0).color(theme::ERROR));
+                    ui.label(
+                        egui::RichText::new(&error)
+                            .size(13.0)
+                            .color(theme::current().error),
+                    );
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         if ui
                             .add(
                                 egui::Label::new(
-                                    egui::RichText::new("✕").size(12.0).color(theme::ERROR),
+                                    egui::RichText::new("✕")
+                                        .size(12.0)
+                                        .color(theme::current().error),
                                 )
                                 .sense(egui::Sense::click()),
                             )
@@ -99,55 +112,138 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
         ui.add_space(8.0);
     }
 
+    crate::ui::recovery::render_banner(ui, app);
+
     // ── Section heading ────────────────────────────────────────────────────
-    theme::section_heading(ui, "SERVEURS DISPONIBLES");
+    theme::section_heading(ui, tr!("servers.heading"));
+
+    render_toolbar(ui, app);
+    ui.add_space(8.0);
+    render_recent(ui, app);
 
     // ── Server list ────────────────────────────────────────────────────────
-    let servers: Vec<_> = app
-        .get_servers()
+    let rows: Vec<Row> = app
+        .get_visible_servers()
+        .into_iter()
+        .map(|s| Row {
+            id: s.id,
+            country: s.country.clone(),
+            name: s.name.clone(),
+            is_active: s.is_active,
+            latency: app.get_latency(s.id),
+            load: s.load,
+            continent: server_list::country(&s.country).map(|c| c.continent()),
+            favourite: app.is_favourite(s.id),
+        })
+        .collect();
+    let (favourites, rows): (Vec<Row>, Vec<Row>) = rows.into_iter().partition(|r| r.favourite);
+    let grouped = app.is_grouped_by_continent();
+    let groups = if grouped {
+        group_rows(rows)
+    } else {
+        vec![(None, rows)]
+    };
+
+    // Keyboard navigation follows what is on screen, folded groups excepted
+    let order: Vec<u64> = favourites
         .iter()
-        .map(|s| (s.id, s.country.clone(), s.name.clone(), s.is_active))
+        .map(|r| r.id)
+        .chain(
+            groups
+                .iter()
+                .filter(|(continent, _)| !grouped || !app.is_continent_collapsed(*continent))
+                .flat_map(|(_, rows)| rows.iter().map(|r| r.id)),
+        )
         .collect();
-    let selected = app.get_selected_server();
+    let scroll_to = handle_keys(ui, app, &order);
+    let selected = app.get_selected_server().map(|s| s.id);
+    let has_servers = !app.get_servers().is_empty();
 
     let bottom_space = 80.0;
     let scroll_height = ui.available_height() - bottom_space;
 
-    egui::ScrollArea::vertical()
-        .max_height(scroll_height)
-        .show(ui, |ui| {
-            if servers.is_empty() {
-                ui.add_space(40.0);
-                ui.vertical_centered(|ui| {
-                    ui.label(
-                        egui::RichText::new("Aucun serveur disponible")
-                            .size(14.0)
-                            .color(theme::TEXT_MUTED),
-                    );
-                    ui.add_space(4.0);
-                    ui.label(
-                        egui::RichText::new("Verifiez votre connexion")
-                            .size(12.0)
-                            .color(theme::TEXT_MUTED),
-                    );
-                });
-            } else {
-                for (idx, (_id, country, name, is_active)) in servers.iter().enumerate() {
-                    let is_selected = selected == Some(idx);
-                    server_card(ui, idx, country, name, *is_active, is_selected, app);
-                    ui.add_space(8.0);
+    if has_servers && app.is_globe_shown() {
+        render_globe(ui, app, scroll_height);
+    } else {
+        egui::ScrollArea::vertical()
+            .max_height(scroll_height)
+            .show(ui, |ui| {
+                if !has_servers {
+                    ui.add_space(40.0);
+                    ui.vertical_centered(|ui| {
+                        ui.label(
+                            egui::RichText::new(tr!("servers.empty"))
+                                .size(14.0)
+                                .color(theme::current().text_muted),
+                        );
+                        ui.add_space(4.0);
+                        ui.label(
+                            egui::RichText::new(tr!("servers.empty_hint"))
+                                .size(12.0)
+                                .color(theme::current().text_muted),
+                        );
+                    });
+                } else if favourites.is_empty() && groups.iter().all(|(_, rows)| rows.is_empty()) {
+                    ui.add_space(40.0);
+                    ui.vertical_centered(|ui| {
+                        ui.label(
+                            egui::RichText::new(tr!("servers.no_match"))
+                                .size(14.0)
+                                .color(theme::current().text_muted),
+                        );
+                        ui.add_space(4.0);
+                        if ui
+                            .add(
+                                egui::Label::new(
+                                    egui::RichText::new(tr!("servers.clear_filters"))
+                                        .size(12.0)
+                                        .color(theme::current().accent),
+                                )
+                                .sense(egui::Sense::click()),
+                            )
+                            .on_hover_cursor(egui::CursorIcon::PointingHand)
+                            .clicked()
+                        {
+                            *app.get_server_filter() = ServerFilter::default();
+                        }
+                    });
+                } else {
+                    if !favourites.is_empty() {
+                        group_header(ui, tr!("servers.favourites"), favourites.len(), None);
+                        ui.add_space(6.0);
+                        server_cards(ui, &favourites, selected, scroll_to, app);
+                    }
+                    for (continent, rows) in &groups {
+                        if rows.is_empty() {
+                            continue;
+                        }
+                        if grouped {
+                            let collapsed = app.is_continent_collapsed(*continent);
+                            let name = continent
+                                .map_or(tr!("servers.others"), server_list::continent_label)
+                                .to_uppercase();
+                            if group_header(ui, &name, rows.len(), Some(collapsed)) {
+                                app.toggle_continent(*continent);
+                            }
+                            ui.add_space(6.0);
+                            if collapsed {
+                                continue;
+                            }
+                        }
+                        server_cards(ui, rows, selected, scroll_to, app);
+                    }
                 }
-            }
-        });
+            });
+    }
 
     // ── Footer pinned to bottom ────────────────────────────────────────────
     ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
         ui.add_space(4.0);
         let can_connect = app.get_selected_server().is_some() && !app.is_connecting();
         let btn_text = if app.is_connecting() {
-            "Connexion en cours..."
+            tr!("action.connecting")
         } else {
-            "Se connecter"
+            tr!("action.connect")
         };
         if theme::primary_button(ui, btn_text, can_connect) {
             app.handle_connect();
@@ -160,30 +256,323 @@ pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
             egui::Vec2::new(separator_rect.width(), 1.0),
         );
         ui.painter()
-            .rect_filled(line, Rounding::ZERO, theme::BORDER);
+            .rect_filled(line, Rounding::ZERO, theme::current().border);
+    });
+}
+
+/// A server as shown in the list, copied out of the app for the frame.
+struct Row {
+    id: u64,
+    country: String,
+    name: String,
+    is_active: bool,
+    latency: Option<Duration>,
+    load: Option<u8>,
+    continent: Option<Continent>,
+    favourite: bool,
+}
+
+/// The filtered servers on the globe; a click on a marker selects it.
+fn render_globe(ui: &mut egui::Ui, app: &mut VpnApp, height: f32) {
+    let visible = app.get_visible_servers();
+    let markers = globe::markers(&visible);
+    let unplaced = visible.len() - markers.len();
+    let selected = app.get_selected_server();
+    let highlight = globe::Highlight {
+        connected: None,
+        selected: selected.map(|s| s.id),
+        home: app.get_home_location(),
+    };
+    let caption = match selected {
+        Some(server) => format!(
+            "{} · {}",
+            server.name,
+            server_list::country_name(&server.country)
+        ),
+        None => tr!("servers.globe.pick").to_string(),
+    };
+
+    let size = ui.available_width().min(height - 40.0).clamp(160.0, 320.0);
+    ui.vertical_centered(|ui| {
+        let response = globe::show(ui, &mut app.globe, &markers, &highlight, size);
+        if let Some(id) = response.clicked {
+            app.set_selected_server(id);
+        }
+        ui.add_space(6.0);
+        ui.label(
+            egui::RichText::new(caption)
+                .size(13.0)
+                .color(theme::current().text_primary),
+        );
+        if unplaced > 0 {
+            ui.label(
+                egui::RichText::new(i18n::plural("servers.globe.unplaced", unplaced as u64, &[]))
+                    .size(11.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+    });
+    app.request_animation_frame(ui.ctx());
+}
+
+/// Continent order, unknown countries last; rows keep their sort order.
+fn group_rows(rows: Vec<Row>) -> Vec<(Option<Continent>, Vec<Row>)> {
+    let mut groups: Vec<(Option<Continent>, Vec<Row>)> = Vec::new();
+    for row in rows {
+        match groups.iter_mut().find(|(c, _)| *c == row.continent) {
+            Some((_, rows)) => rows.push(row),
+            None => groups.push((row.continent, vec![row])),
+        }
+    }
+    groups.sort_by_key(|(continent, _)| (continent.is_none(), *continent));
+    groups
+}
+
+// ── Search, filters and sorting ────────────────────────────────────────────────
+
+fn render_toolbar(ui: &mut egui::Ui, app: &mut VpnApp) {
+    let search_id = egui::Id::new("server_search");
+    if ui.input_mut(|i| i.consume_shortcut(&SEARCH_SHORTCUT)) {
+        ui.memory_mut(|m| m.request_focus(search_id));
+    }
+
+    let response = ui.add(
+        egui::TextEdit::singleline(&mut app.get_server_filter().query)
+            .id(search_id)
+            .hint_text(tr!("servers.search"))
+            .desired_width(f32::INFINITY)
+            .margin(egui::Margin::symmetric(10.0, 8.0)),
+    );
+    if response.has_focus() && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+        app.get_server_filter().query.clear();
+        response.surrender_focus();
+    }
+
+    ui.add_space(6.0);
+
+    let countries = server_list::countries(app.get_servers());
+    ui.horizontal_wrapped(|ui| {
+        let filter = app.get_server_filter();
+        ui.checkbox(
+            &mut filter.online_only,
+            egui::RichText::new(tr!("servers.online_only")).size(12.0),
+        );
+
+        let region = filter
+            .continent
+            .map_or(tr!("servers.all_regions"), server_list::continent_label);
+        egui::ComboBox::from_id_salt("server_region")
+            .selected_text(egui::RichText::new(region).size(12.0))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut filter.continent, None, tr!("servers.all_regions"));
+                for continent in Continent::ALL {
+                    ui.selectable_value(
+                        &mut filter.continent,
+                        Some(continent),
+                        server_list::continent_label(continent),
+                    );
+                }
+            });
+
+        let country = filter
+            .country
+            .as_ref()
+            .and_then(|key| countries.iter().find(|(k, _)| k == key))
+            .map_or(tr!("servers.all_countries"), |(_, name)| name.as_str());
+        egui::ComboBox::from_id_salt("server_country")
+            .selected_text(egui::RichText::new(country).size(12.0))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut filter.country, None, tr!("servers.all_countries"));
+                for (key, name) in &countries {
+                    ui.selectable_value(&mut filter.country, Some(key.clone()), name);
+                }
+            });
     });
+
+    ui.horizontal(|ui| {
+        let sort = app.get_server_sort();
+        ui.label(
+            egui::RichText::new(tr!("servers.sort_by"))
+                .size(12.0)
+                .color(theme::current().text_muted),
+        );
+        egui::ComboBox::from_id_salt("server_sort")
+            .selected_text(egui::RichText::new(sort.label()).size(12.0))
+            .show_ui(ui, |ui| {
+                for option in ServerSort::ALL {
+                    if ui
+                        .selectable_label(sort == option, option.label())
+                        .clicked()
+                    {
+                        app.set_server_sort(option);
+                    }
+                }
+            });
+        if sort == ServerSort::Latency {
+            if app.is_probing_latencies() {
+                ui.spinner();
+            } else if ui
+                .small_button("↻")
+                .on_hover_text(tr!("servers.measure_again"))
+                .clicked()
+            {
+                app.refresh_latencies();
+            }
+        }
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let shown = app.is_globe_shown();
+            if !shown {
+                let mut grouped = app.is_grouped_by_continent();
+                if ui
+                    .checkbox(
+                        &mut grouped,
+                        egui::RichText::new(tr!("servers.by_continent")).size(12.0),
+                    )
+                    .changed()
+                {
+                    app.set_grouped_by_continent(grouped);
+                }
+            }
+            let toggle = if shown {
+                tr!("servers.show_list")
+            } else {
+                tr!("servers.show_map")
+            };
+            if ui
+                .selectable_label(shown, egui::RichText::new(toggle).size(12.0))
+                .clicked()
+            {
+                app.set_globe_shown(!shown);
+            }
+        });
+    });
+}
+
+const SEARCH_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::F);
+
+/// Up and down move the selection through `order`, Enter connects. Returns
+/// the server to scroll to.
+fn handle_keys(ui: &egui::Ui, app: &mut VpnApp, order: &[u64]) -> Option<u64> {
+    if order.is_empty() || ui.memory(|m| m.any_popup_open()) {
+        return None;
+    }
+    let (down, up, enter) = ui.input(|i| {
+        (
+            i.key_pressed(egui::Key::ArrowDown),
+            i.key_pressed(egui::Key::ArrowUp),
+            i.key_pressed(egui::Key::Enter),
+        )
+    });
+    let current = app
+        .get_selected_server()
+        .and_then(|s| order.iter().position(|&id| id == s.id));
+    let next = match (down, up, current) {
+        (true, _, Some(i)) => Some((i + 1).min(order.len() - 1)),
+        (_, true, Some(i)) => Some(i.saturating_sub(1)),
+        (true, _, None) => Some(0),
+        (_, true, None) => Some(order.len() - 1),
+        _ => None,
+    };
+    if let Some(i) = next {
+        app.set_selected_server(order[i]);
+        return Some(order[i]);
+    }
+    if enter && current.is_some() && !app.is_connecting() {
+        app.handle_connect();
+    }
+    None
+}
+
+/// Clicked when `collapsed` is set: the group folds. Without it the
+/// header is a plain title.
+fn group_header(ui: &mut egui::Ui, name: &str, count: usize, collapsed: Option<bool>) -> bool {
+    let text = match collapsed {
+        Some(true) => format!("⏵ {}  {}", name, count),
+        Some(false) => format!("⏷ {}  {}", name, count),
+        None => format!("{}  {}", name, count),
+    };
+    let label = egui::RichText::new(text)
+        .size(11.0)
+        .color(theme::current().text_secondary)
+        .strong();
+    if collapsed.is_none() {
+        ui.label(label);
+        return false;
+    }
+    ui.add(egui::Label::new(label).sense(egui::Sense::click()))
+        .on_hover_cursor(egui::CursorIcon::PointingHand)
+        .clicked()
+}
+
+// ── Recent servers ─────────────────────────────────────────────────────────────
+
+/// One click reconnects to a recent server.
+fn render_recent(ui: &mut egui::Ui, app: &mut VpnApp) {
+    let recent: Vec<(u64, String)> = app
+        .get_recent_servers()
+        .iter()
+        .take(3)
+        .map(|r| (r.id, r.name.clone()))
+        .collect();
+    if recent.is_empty() {
+        return;
+    }
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(tr!("servers.recent"))
+                .size(12.0)
+                .color(theme::current().text_muted),
+        );
+        for (id, name) in &recent {
+            if theme::small_button(
+                ui,
+                name,
+                theme::current().bg_card,
+                theme::current().text_primary,
+            ) {
+                app.connect_to(*id);
+            }
+        }
+    });
+    ui.add_space(8.0);
+}
+
+fn server_cards(
+    ui: &mut egui::Ui,
+    rows: &[Row],
+    selected: Option<u64>,
+    scroll_to: Option<u64>,
+    app: &mut VpnApp,
+) {
+    for row in rows {
+        let response = server_card(ui, row, selected == Some(row.id), app);
+        if scroll_to == Some(row.id) {
+            response.scroll_to_me(Some(egui::Align::Center));
+        }
+        ui.add_space(8.0);
+    }
 }
 
 fn server_card(
     ui: &mut egui::Ui,
-    idx: usize,
-    country: &str,
-    name: &str,
-    is_active: bool,
+    row: &Row,
     is_selected: bool,
     app: &mut VpnApp,
-) {
+) -> egui::Response {
     let fill = if is_selected {
-        theme::BG_CARD_HOVER
+        theme::current().bg_card_hover
     } else {
-        theme::BG_CARD
+        theme::current().bg_card
     };
     let border = if is_selected {
-        Stroke::new(1.0, theme::ACCENT)
+        Stroke::new(1.0, theme::current().accent)
     } else {
-        Stroke::new(1.0, theme::BORDER)
+        Stroke::new(1.0, theme::current().border)
     };
 
+    let mut star_rect = egui::Rect::NOTHING;
     let response = egui::Frame::none()
         .fill(fill)
         .rounding(Rounding::same(12.0))
@@ -194,44 +583,76 @@ fn server_card(
 
             ui.horizontal(|ui| {
                 // Country flag image
-                if let Some(tex) = app.flag_store.get(country) {
-                    let size = egui::vec2(24.0, 15.0);
-                    ui.add(egui::Image::new(tex).fit_to_exact_size(size).rounding(2.0));
-                }
+                let size = egui::vec2(24.0, 15.0);
+                let tex = app.flag_store.get(ui.ctx(), &row.country, size.y);
+                ui.add(egui::Image::new(&tex).fit_to_exact_size(size).rounding(2.0));
 
                 ui.add_space(4.0);
 
                 ui.vertical(|ui| {
                     ui.label(
-                        egui::RichText::new(name)
+                        egui::RichText::new(&row.name)
                             .size(14.0)
-                            .color(theme::TEXT_PRIMARY)
+                            .color(theme::current().text_primary)
                             .strong(),
                     );
+                    let mut details = vec![server_list::country_name(&row.country)];
+                    if let Some(latency) = row.latency {
+                        details.push(i18n::milliseconds(latency));
+                    }
+                    if let Some(load) = row.load {
+                        details.push(tr!("servers.card.load", load = load));
+                    }
                     ui.label(
-                        egui::RichText::new(country)
+                        egui::RichText::new(details.join(" · "))
                             .size(11.0)
-                            .color(theme::TEXT_MUTED),
+                            .color(theme::current().text_muted),
                     );
                 });
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    let (status_text, status_color) = if is_active {
-                        ("EN LIGNE", theme::SUCCESS)
+                    let (status_text, status_color) = if row.is_active {
+                        (tr!("servers.card.online"), theme::current().success)
                     } else {
-                        ("HORS LIGNE", theme::TEXT_MUTED)
+                        (tr!("servers.card.offline"), theme::current().text_muted)
                     };
                     theme::status_pill(ui, status_text, status_color);
+                    ui.add_space(6.0);
+                    star_rect = star(ui, row.favourite);
                 });
             });
         })
         .response;
 
-    if response
+    // The card takes the clicks; the star is told apart by position
+    let response = response
         .interact(egui::Sense::click())
-        .on_hover_cursor(egui::CursorIcon::PointingHand)
-        .clicked()
-    {
-        app.set_selected_server(idx);
+        .on_hover_cursor(egui::CursorIcon::PointingHand);
+    if response.clicked() {
+        let on_star = response
+            .interact_pointer_pos()
+            .is_some_and(|pos| star_rect.contains(pos));
+        if on_star {
+            app.toggle_favourite(row.id);
+        } else {
+            app.set_selected_server(row.id);
+        }
     }
+    response
+}
+
+/// Favourite toggle drawn on a card; returns where it is.
+fn star(ui: &mut egui::Ui, favourite: bool) -> egui::Rect {
+    let (text, color) = if favourite {
+        ("★", theme::current().warning)
+    } else {
+        ("☆", theme::current().text_muted)
+    };
+    ui.label(egui::RichText::new(text).size(16.0).color(color))
+        .on_hover_text(if favourite {
+            tr!("servers.card.unfavourite")
+        } else {
+            tr!("servers.card.favourite")
+        })
+        .rect
 }
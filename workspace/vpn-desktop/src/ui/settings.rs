@@ -0,0 +1,1122 @@
+use crate::app::VpnApp;
+use crate::i18n::tr;
+use crate::network::{self, TrustKind, TrustRule};
+use crate::notifications::NotificationKind;
+use crate::preferences::{
+    self, Language, PowerSaving, ServerChoice, ThemeMode, DEFAULT_API_URL, DEFAULT_MTU,
+};
+use crate::ui::theme;
+use crate::vpn::apps::{self, AppMode};
+use crate::vpn::tunnel::{self, DEFAULT_INTERFACE};
+use crate::vpn::{domains, killswitch};
+use egui::{Rounding, Stroke, Vec2};
+
+pub fn render(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::draw_top_accent(ui);
+
+    // ── Header ─────────────────────────────────────────────────────────────
+    ui.horizontal(|ui| {
+        if ui
+            .add(
+                egui::Label::new(
+                    egui::RichText::new(tr!("action.back"))
+                        .size(13.0)
+                        .color(theme::current().accent),
+                )
+                .sense(egui::Sense::click()),
+            )
+            .on_hover_cursor(egui::CursorIcon::PointingHand)
+            .clicked()
+        {
+            app.hide_settings();
+        }
+    });
+
+    ui.add_space(8.0);
+
+    ui.label(
+        egui::RichText::new(tr!("settings.title"))
+            .size(18.0)
+            .color(theme::current().text_primary)
+            .strong(),
+    );
+
+    ui.add_space(12.0);
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        render_connection(ui, app);
+        if network::is_supported() {
+            ui.add_space(16.0);
+            render_trusted_networks(ui, app);
+        }
+        ui.add_space(16.0);
+        render_proxy_mode(ui, app);
+        ui.add_space(16.0);
+        render_tunnel(ui, app);
+        ui.add_space(16.0);
+        render_split_tunnel(ui, app);
+        if apps::is_supported() {
+            ui.add_space(16.0);
+            render_app_rules(ui, app);
+        }
+        if domains::is_supported() {
+            ui.add_space(16.0);
+            render_domain_rules(ui, app);
+        }
+        ui.add_space(16.0);
+        render_notifications(ui, app);
+        ui.add_space(16.0);
+        render_appearance(ui, app);
+        ui.add_space(16.0);
+        render_diagnostics(ui, app);
+        ui.add_space(16.0);
+    });
+}
+
+// ── Connection ─────────────────────────────────────────────────────────────────
+
+fn render_connection(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.connection"));
+
+    compact_card(ui, |ui| {
+        preference_field(
+            ui,
+            app,
+            tr!("settings.api_url"),
+            DEFAULT_API_URL,
+            VpnApp::get_pref_api_url,
+            VpnApp::apply_api_url,
+            |s| preferences::parse_api_url(s).err(),
+        );
+        ui.label(
+            egui::RichText::new(tr!("settings.api_url_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+
+        ui.add_space(8.0);
+
+        let mut connect_on_launch = app.get_preferences().connect_on_launch;
+        if ui
+            .checkbox(
+                &mut connect_on_launch,
+                egui::RichText::new(tr!("settings.connect_on_launch"))
+                    .size(13.0)
+                    .color(theme::current().text_primary),
+            )
+            .changed()
+        {
+            app.set_connect_on_launch(connect_on_launch);
+        }
+        ui.label(
+            egui::RichText::new(tr!("settings.connect_on_launch_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+
+        ui.add_space(4.0);
+
+        let choice = app.get_preferences().auto_connect_server;
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(tr!("settings.auto_connect_server"))
+                    .size(12.0)
+                    .color(theme::current().text_secondary),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                egui::ComboBox::from_id_salt("auto_connect_server")
+                    .selected_text(choice.label())
+                    .show_ui(ui, |ui| {
+                        for option in ServerChoice::ALL {
+                            if ui
+                                .selectable_label(choice == option, option.label())
+                                .clicked()
+                            {
+                                app.set_auto_connect_server(option);
+                            }
+                        }
+                    });
+            });
+        });
+
+        ui.add_space(4.0);
+
+        if killswitch::is_supported() {
+            let mut kill_switch = app.get_preferences().kill_switch;
+            if ui
+                .checkbox(
+                    &mut kill_switch,
+                    egui::RichText::new(tr!("settings.kill_switch"))
+                        .size(13.0)
+                        .color(theme::current().text_primary),
+                )
+                .changed()
+            {
+                app.set_kill_switch(kill_switch);
+            }
+            ui.label(
+                egui::RichText::new(tr!("settings.kill_switch_hint"))
+                    .size(11.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+    });
+}
+
+// ── Trusted networks ───────────────────────────────────────────────────────────
+
+fn render_trusted_networks(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.trusted_networks"));
+
+    compact_card(ui, |ui| {
+        let mut enabled = app.get_preferences().connect_on_untrusted;
+        if ui
+            .checkbox(
+                &mut enabled,
+                egui::RichText::new(tr!("settings.connect_on_untrusted"))
+                    .size(13.0)
+                    .color(theme::current().text_primary),
+            )
+            .changed()
+        {
+            app.set_connect_on_untrusted(enabled);
+        }
+        ui.label(
+            egui::RichText::new(tr!("settings.connect_on_untrusted_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+
+        if !enabled {
+            return;
+        }
+        ui.add_space(8.0);
+
+        // Shortcuts to trust the network the machine is on
+        if !app.is_network_watched() {
+            ui.label(
+                egui::RichText::new(tr!("settings.network_unknown"))
+                    .size(12.0)
+                    .color(theme::current().warning),
+            );
+        } else if let Some(current) = app.get_current_network().cloned() {
+            let trusted = network::is_trusted(app.get_trusted_networks(), &current);
+            ui.label(
+                egui::RichText::new(tr!(
+                    "settings.current_network",
+                    network = current.label(),
+                    trust = if trusted {
+                        tr!("settings.trusted")
+                    } else {
+                        tr!("settings.untrusted")
+                    }
+                ))
+                .size(12.0)
+                .color(if trusted {
+                    theme::current().success
+                } else {
+                    theme::current().text_secondary
+                }),
+            );
+            ui.horizontal_wrapped(|ui| {
+                let mut candidates = Vec::new();
+                if let Some(ssid) = &current.ssid {
+                    candidates.push((tr!("settings.trust_ssid"), TrustRule::Ssid(ssid.clone())));
+                }
+                if let Some(mac) = &current.gateway_mac {
+                    candidates.push((
+                        tr!("settings.trust_gateway"),
+                        TrustRule::GatewayMac(mac.clone()),
+                    ));
+                }
+                candidates.push((
+                    tr!("settings.trust_interface"),
+                    TrustRule::Interface(current.interface.clone()),
+                ));
+                for (label, rule) in candidates {
+                    if !app.get_trusted_networks().contains(&rule)
+                        && theme::small_button(
+                            ui,
+                            label,
+                            theme::current().bg_card,
+                            theme::current().text_primary,
+                        )
+                    {
+                        app.add_trusted_network(rule);
+                    }
+                }
+            });
+        } else {
+            ui.label(
+                egui::RichText::new(tr!("settings.offline"))
+                    .size(12.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+
+        ui.add_space(8.0);
+
+        let rules = app.get_trusted_networks().to_vec();
+        if rules.is_empty() {
+            ui.label(
+                egui::RichText::new(tr!("settings.no_trusted_network"))
+                    .size(12.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+        let mut remove = None;
+        for (idx, rule) in rules.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(rule.label())
+                        .size(12.0)
+                        .color(theme::current().text_primary),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add(
+                            egui::Label::new(
+                                egui::RichText::new("✕")
+                                    .size(12.0)
+                                    .color(theme::current().danger),
+                            )
+                            .sense(egui::Sense::click()),
+                        )
+                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        remove = Some(idx);
+                    }
+                });
+            });
+        }
+        if let Some(idx) = remove {
+            app.remove_trusted_network(idx);
+        }
+
+        ui.add_space(8.0);
+
+        let kind = app.get_new_trust_kind();
+        let mut submit = false;
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_salt("trust_kind")
+                .selected_text(kind.label())
+                .show_ui(ui, |ui| {
+                    for option in TrustKind::ALL {
+                        if ui
+                            .selectable_label(kind == option, option.label())
+                            .clicked()
+                        {
+                            app.set_new_trust_kind(option);
+                        }
+                    }
+                });
+            let response = ui.add(
+                egui::TextEdit::singleline(app.get_new_trust_value())
+                    .desired_width(ui.available_width() - 70.0)
+                    .margin(egui::Margin::symmetric(10.0, 6.0))
+                    .font(egui::FontId::new(13.0, egui::FontFamily::Monospace)),
+            );
+            submit = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if theme::small_button(
+                ui,
+                tr!("action.add"),
+                theme::current().accent,
+                theme::current().text_on_accent,
+            ) {
+                submit = true;
+            }
+        });
+        if submit {
+            app.add_typed_trusted_network();
+        }
+        if let Some(err) = app.get_settings_error() {
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(err)
+                    .size(12.0)
+                    .color(theme::current().error),
+            );
+        }
+    });
+}
+
+// ── Appearance ─────────────────────────────────────────────────────────────────
+
+fn render_appearance(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.appearance"));
+
+    compact_card(ui, |ui| {
+        let current = app.get_preferences().clone();
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(tr!("settings.language"))
+                    .size(12.0)
+                    .color(theme::current().text_secondary),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                egui::ComboBox::from_id_salt("language")
+                    .selected_text(current.language.label())
+                    .show_ui(ui, |ui| {
+                        for language in Language::ALL {
+                            if ui
+                                .selectable_label(current.language == language, language.label())
+                                .clicked()
+                            {
+                                app.set_language(language);
+                            }
+                        }
+                    });
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(tr!("settings.theme"))
+                    .size(12.0)
+                    .color(theme::current().text_secondary),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                egui::ComboBox::from_id_salt("theme")
+                    .selected_text(current.theme.label())
+                    .show_ui(ui, |ui| {
+                        for mode in ThemeMode::ALL {
+                            if ui
+                                .selectable_label(current.theme == mode, mode.label())
+                                .clicked()
+                            {
+                                app.set_theme(mode);
+                            }
+                        }
+                    });
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(tr!("settings.power_saving"))
+                    .size(12.0)
+                    .color(theme::current().text_secondary),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                egui::ComboBox::from_id_salt("power_saving")
+                    .selected_text(current.power_saving.label())
+                    .show_ui(ui, |ui| {
+                        for power_saving in PowerSaving::ALL {
+                            if ui
+                                .selectable_label(
+                                    current.power_saving == power_saving,
+                                    power_saving.label(),
+                                )
+                                .clicked()
+                            {
+                                app.set_power_saving(power_saving);
+                            }
+                        }
+                    });
+            });
+        });
+        ui.label(
+            egui::RichText::new(tr!("settings.power_saving_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+
+        ui.add_space(8.0);
+        let tray = app.is_tray_available();
+        let mut close_to_tray = current.close_to_tray;
+        let response = ui.add_enabled(
+            tray,
+            egui::Checkbox::new(
+                &mut close_to_tray,
+                egui::RichText::new(tr!("settings.close_to_tray"))
+                    .size(13.0)
+                    .color(theme::current().text_primary),
+            ),
+        );
+        if response.changed() {
+            app.set_close_to_tray(close_to_tray);
+        }
+        let hint = if tray {
+            tr!("settings.close_to_tray_hint")
+        } else {
+            tr!("settings.no_tray")
+        };
+        ui.label(
+            egui::RichText::new(hint)
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+    });
+}
+
+// ── Notifications ──────────────────────────────────────────────────────────────
+
+fn render_notifications(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.notifications"));
+
+    compact_card(ui, |ui| {
+        let available = app.is_notifier_available();
+        for kind in NotificationKind::ALL {
+            let mut enabled = app.is_notification_enabled(kind);
+            let response = ui.add_enabled(
+                available,
+                egui::Checkbox::new(
+                    &mut enabled,
+                    egui::RichText::new(kind.label())
+                        .size(13.0)
+                        .color(theme::current().text_primary),
+                ),
+            );
+            if response.changed() {
+                app.set_notification_enabled(kind, enabled);
+            }
+        }
+        if !available {
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(tr!("settings.no_notifier"))
+                    .size(11.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+    });
+}
+
+// ── Diagnostics ────────────────────────────────────────────────────────────────
+
+fn render_diagnostics(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.diagnostics"));
+
+    compact_card(ui, |ui| {
+        ui.label(
+            egui::RichText::new(tr!("settings.diagnostics_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            if theme::small_button(
+                ui,
+                tr!("settings.show_logs"),
+                theme::current().bg_card,
+                theme::current().text_primary,
+            ) {
+                app.show_logs();
+            }
+            ui.add_space(4.0);
+            if theme::small_button(
+                ui,
+                tr!("settings.check_installation"),
+                theme::current().bg_card,
+                theme::current().text_primary,
+            ) {
+                app.show_doctor();
+            }
+            ui.add_space(4.0);
+            if theme::small_button(
+                ui,
+                tr!("settings.export_diagnostics"),
+                theme::current().accent,
+                theme::current().text_on_accent,
+            ) {
+                app.export_diagnostics();
+            }
+        });
+        crate::ui::logs::render_diagnostics_status(ui, app);
+    });
+}
+
+// ── Proxy mode ─────────────────────────────────────────────────────────────────
+
+fn render_proxy_mode(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.proxy"));
+
+    compact_card(ui, |ui| {
+        let mut settings = app.get_proxy_settings().clone();
+        let mut changed = ui
+            .checkbox(
+                &mut settings.enabled,
+                egui::RichText::new(tr!("settings.proxy_enabled"))
+                    .size(13.0)
+                    .color(theme::current().text_primary),
+            )
+            .changed();
+        ui.label(
+            egui::RichText::new(tr!("settings.proxy_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+
+        ui.add_space(8.0);
+
+        ui.add_enabled_ui(settings.enabled, |ui| {
+            for (label, port) in [
+                (tr!("settings.socks_port"), &mut settings.socks_port),
+                (tr!("settings.http_port"), &mut settings.http_port),
+            ] {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(label)
+                            .size(12.0)
+                            .color(theme::current().text_secondary),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        changed |= ui
+                            .add(egui::DragValue::new(port).range(1024..=65535))
+                            .changed();
+                    });
+                });
+            }
+        });
+
+        if changed {
+            app.set_proxy_settings(settings);
+        }
+
+        ui.add_space(4.0);
+        ui.label(
+            egui::RichText::new(tr!("settings.next_connection"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+    });
+}
+
+// ── Tunnel ─────────────────────────────────────────────────────────────────────
+
+fn render_tunnel(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.tunnel"));
+
+    compact_card(ui, |ui| {
+        ui.label(
+            egui::RichText::new(tr!("settings.interface_name"))
+                .size(11.0)
+                .color(theme::current().text_secondary),
+        );
+        ui.add_space(4.0);
+
+        let mut submit = false;
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(app.get_new_interface_name())
+                    .desired_width(ui.available_width() - 80.0)
+                    .margin(egui::Margin::symmetric(10.0, 6.0))
+                    .font(egui::FontId::new(13.0, egui::FontFamily::Monospace))
+                    .char_limit(15)
+                    .hint_text(DEFAULT_INTERFACE),
+            );
+            submit = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if theme::small_button(
+                ui,
+                tr!("action.apply"),
+                theme::current().accent,
+                theme::current().text_on_accent,
+            ) {
+                submit = true;
+            }
+        });
+
+        let edited = app.get_new_interface_name().trim().to_string();
+        let problem = match tunnel::validate_interface_name(&edited) {
+            Err(e) => Some(e),
+            Ok(()) if submit => app.apply_interface_name().err(),
+            Ok(()) => None,
+        };
+        let conflict = tunnel::interface_conflict(app.get_interface_name());
+        if let Some(message) = problem.or(conflict) {
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(message)
+                    .size(12.0)
+                    .color(theme::current().error),
+            );
+        }
+
+        ui.add_space(8.0);
+        preference_field(
+            ui,
+            app,
+            tr!("settings.dns"),
+            "1.1.1.1, 9.9.9.9",
+            VpnApp::get_pref_dns,
+            VpnApp::apply_dns_override,
+            |s| preferences::parse_dns_list(s).err(),
+        );
+        ui.add_space(8.0);
+        preference_field(
+            ui,
+            app,
+            "MTU",
+            &DEFAULT_MTU.to_string(),
+            VpnApp::get_pref_mtu,
+            VpnApp::apply_mtu,
+            |s| preferences::parse_mtu(s).err(),
+        );
+        ui.add_space(8.0);
+        preference_field(
+            ui,
+            app,
+            tr!("settings.keepalive"),
+            "25",
+            VpnApp::get_pref_keepalive,
+            VpnApp::apply_keepalive,
+            |s| preferences::parse_keepalive(s).err(),
+        );
+
+        ui.add_space(8.0);
+        ui.label(
+            egui::RichText::new(tr!("settings.next_connection"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+    });
+}
+
+// ── Split tunneling ────────────────────────────────────────────────────────────
+
+fn render_split_tunnel(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.split_tunnel"));
+
+    compact_card(ui, |ui| {
+        let mut bypass = app.is_bypass_local_network();
+        if ui
+            .checkbox(
+                &mut bypass,
+                egui::RichText::new(tr!("settings.bypass_local"))
+                    .size(13.0)
+                    .color(theme::current().text_primary),
+            )
+            .changed()
+        {
+            app.set_bypass_local_network(bypass);
+        }
+        ui.label(
+            egui::RichText::new(tr!("settings.bypass_local_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+
+        ui.add_space(12.0);
+
+        ui.label(
+            egui::RichText::new(tr!("settings.excluded_routes"))
+                .size(11.0)
+                .color(theme::current().text_secondary),
+        );
+        ui.add_space(4.0);
+
+        let routes = app.get_excluded_routes().to_vec();
+        if routes.is_empty() {
+            ui.label(
+                egui::RichText::new(tr!("settings.no_excluded_route"))
+                    .size(12.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+        let mut remove = None;
+        for (idx, route) in routes.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(route)
+                        .size(12.0)
+                        .color(theme::current().text_primary)
+                        .family(egui::FontFamily::Monospace),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add(
+                            egui::Label::new(
+                                egui::RichText::new("✕")
+                                    .size(12.0)
+                                    .color(theme::current().danger),
+                            )
+                            .sense(egui::Sense::click()),
+                        )
+                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        remove = Some(idx);
+                    }
+                });
+            });
+        }
+        if let Some(idx) = remove {
+            app.remove_excluded_route(idx);
+        }
+
+        ui.add_space(8.0);
+
+        let mut submit = false;
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(app.get_new_excluded_route())
+                    .desired_width(ui.available_width() - 70.0)
+                    .margin(egui::Margin::symmetric(10.0, 6.0))
+                    .font(egui::FontId::new(13.0, egui::FontFamily::Monospace))
+                    .hint_text("10.20.0.0/16"),
+            );
+            submit = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if theme::small_button(
+                ui,
+                tr!("action.add"),
+                theme::current().accent,
+                theme::current().text_on_accent,
+            ) {
+                submit = true;
+            }
+        });
+        if submit {
+            app.add_excluded_route();
+        }
+
+        if let Some(err) = app.get_settings_error() {
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(err)
+                    .size(12.0)
+                    .color(theme::current().error),
+            );
+        }
+
+        ui.add_space(8.0);
+        ui.label(
+            egui::RichText::new(tr!("settings.next_connection"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+    });
+}
+
+// ── Per-application routing ────────────────────────────────────────────────────
+
+fn render_app_rules(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.apps"));
+
+    compact_card(ui, |ui| {
+        ui.label(
+            egui::RichText::new(tr!("settings.apps_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+        ui.add_space(8.0);
+
+        let rules = app.get_app_rules().to_vec();
+        if rules.is_empty() {
+            ui.label(
+                egui::RichText::new(tr!("settings.no_app_rule"))
+                    .size(12.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+
+        enum Action {
+            Toggle(usize),
+            Launch(usize),
+            Remove(usize),
+        }
+        let mut action = None;
+        for (idx, rule) in rules.iter().enumerate() {
+            ui.horizontal(|ui| {
+                let name = std::path::Path::new(&rule.path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| rule.path.clone());
+                ui.label(
+                    egui::RichText::new(name)
+                        .size(12.0)
+                        .color(theme::current().text_primary),
+                )
+                .on_hover_text(&rule.path);
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add(
+                            egui::Label::new(
+                                egui::RichText::new("✕")
+                                    .size(12.0)
+                                    .color(theme::current().danger),
+                            )
+                            .sense(egui::Sense::click()),
+                        )
+                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        action = Some(Action::Remove(idx));
+                    }
+                    if ui
+                        .add(
+                            egui::Label::new(
+                                egui::RichText::new(tr!("settings.launch"))
+                                    .size(12.0)
+                                    .color(theme::current().accent),
+                            )
+                            .sense(egui::Sense::click()),
+                        )
+                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        action = Some(Action::Launch(idx));
+                    }
+                    let color = match rule.mode {
+                        AppMode::Tunnel => theme::current().success,
+                        AppMode::Bypass => theme::current().text_muted,
+                    };
+                    let pill = ui
+                        .scope(|ui| theme::status_pill(ui, rule.mode.label(), color))
+                        .response
+                        .interact(egui::Sense::click())
+                        .on_hover_cursor(egui::CursorIcon::PointingHand);
+                    if pill.clicked() {
+                        action = Some(Action::Toggle(idx));
+                    }
+                });
+            });
+        }
+        match action {
+            Some(Action::Toggle(idx)) => app.toggle_app_rule(idx),
+            Some(Action::Launch(idx)) => app.launch_app(idx),
+            Some(Action::Remove(idx)) => app.remove_app_rule(idx),
+            None => {}
+        }
+
+        ui.add_space(8.0);
+
+        // Picker from the installed applications, or a path typed by hand
+        let installed = app.get_installed_apps().to_vec();
+        egui::ComboBox::from_id_salt("app_picker")
+            .selected_text(tr!("settings.pick_app"))
+            .width(ui.available_width())
+            .show_ui(ui, |ui| {
+                for installed_app in &installed {
+                    if ui
+                        .selectable_label(false, &installed_app.name)
+                        .on_hover_text(&installed_app.path)
+                        .clicked()
+                    {
+                        *app.get_new_app_path() = installed_app.path.clone();
+                    }
+                }
+            });
+
+        ui.add_space(4.0);
+        ui.add(
+            egui::TextEdit::singleline(app.get_new_app_path())
+                .desired_width(ui.available_width())
+                .margin(egui::Margin::symmetric(10.0, 6.0))
+                .font(egui::FontId::new(13.0, egui::FontFamily::Monospace))
+                .hint_text("/usr/bin/firefox"),
+        );
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            if theme::small_button(
+                ui,
+                tr!("settings.app_bypass"),
+                theme::current().bg_card_hover,
+                theme::current().text_primary,
+            ) {
+                app.add_app_rule(AppMode::Bypass);
+            }
+            if theme::small_button(
+                ui,
+                tr!("settings.app_tunnel"),
+                theme::current().accent,
+                theme::current().text_on_accent,
+            ) {
+                app.add_app_rule(AppMode::Tunnel);
+            }
+        });
+        ui.add_space(4.0);
+        ui.label(
+            egui::RichText::new(tr!("settings.apps_moved"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+    });
+}
+
+// ── Domain-based routing ───────────────────────────────────────────────────────
+
+fn render_domain_rules(ui: &mut egui::Ui, app: &mut VpnApp) {
+    theme::section_heading(ui, tr!("settings.domains"));
+
+    compact_card(ui, |ui| {
+        ui.label(
+            egui::RichText::new(tr!("settings.domains_hint"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+        ui.add_space(8.0);
+
+        let servers: Vec<(u64, String)> = app
+            .get_servers()
+            .iter()
+            .map(|s| (s.id, s.name.clone()))
+            .collect();
+        let server_id = match app.get_domain_rule_server() {
+            Some(id) => id,
+            None => {
+                ui.label(
+                    egui::RichText::new(tr!("settings.no_server"))
+                        .size(12.0)
+                        .color(theme::current().text_muted),
+                );
+                return;
+            }
+        };
+        let selected_name = servers
+            .iter()
+            .find(|(id, _)| *id == server_id)
+            .map(|(_, name)| name.clone())
+            .unwrap_or_default();
+        egui::ComboBox::from_id_salt("domain_rule_server")
+            .selected_text(selected_name)
+            .width(ui.available_width())
+            .show_ui(ui, |ui| {
+                for (id, name) in &servers {
+                    if ui.selectable_label(*id == server_id, name).clicked() {
+                        app.set_domain_rule_server(*id);
+                    }
+                }
+            });
+
+        ui.add_space(4.0);
+
+        let rules = app.get_domain_rules(server_id).to_vec();
+        if rules.is_empty() {
+            ui.label(
+                egui::RichText::new(tr!("settings.no_domain_rule"))
+                    .size(12.0)
+                    .color(theme::current().text_muted),
+            );
+        }
+        let mut remove = None;
+        for (idx, rule) in rules.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(rule)
+                        .size(12.0)
+                        .color(theme::current().text_primary)
+                        .family(egui::FontFamily::Monospace),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add(
+                            egui::Label::new(
+                                egui::RichText::new("✕")
+                                    .size(12.0)
+                                    .color(theme::current().danger),
+                            )
+                            .sense(egui::Sense::click()),
+                        )
+                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        remove = Some(idx);
+                    }
+                });
+            });
+        }
+        if let Some(idx) = remove {
+            app.remove_domain_rule(server_id, idx);
+        }
+
+        ui.add_space(8.0);
+
+        let mut submit = false;
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(app.get_new_domain_rule())
+                    .desired_width(ui.available_width() - 70.0)
+                    .margin(egui::Margin::symmetric(10.0, 6.0))
+                    .font(egui::FontId::new(13.0, egui::FontFamily::Monospace))
+                    .hint_text("*.git.example.com"),
+            );
+            submit = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if theme::small_button(
+                ui,
+                tr!("action.add"),
+                theme::current().accent,
+                theme::current().text_on_accent,
+            ) {
+                submit = true;
+            }
+        });
+        if submit {
+            app.add_domain_rule();
+        }
+
+        ui.add_space(4.0);
+        ui.label(
+            egui::RichText::new(tr!("settings.domains_syntax"))
+                .size(11.0)
+                .color(theme::current().text_muted),
+        );
+    });
+}
+
+// ── Helpers ────────────────────────────────────────────────────────────────────
+
+/// Text field saved with "Apply" or Enter; `check` flags invalid input
+/// while typing.
+fn preference_field(
+    ui: &mut egui::Ui,
+    app: &mut VpnApp,
+    label: &str,
+    hint: &str,
+    field: fn(&mut VpnApp) -> &mut String,
+    apply: fn(&mut VpnApp) -> Result<(), String>,
+    check: fn(&str) -> Option<String>,
+) {
+    ui.label(
+        egui::RichText::new(label)
+            .size(11.0)
+            .color(theme::current().text_secondary),
+    );
+    ui.add_space(4.0);
+
+    let mut submit = false;
+    ui.horizontal(|ui| {
+        let response = ui.add(
+            egui::TextEdit::singleline(field(app))
+                .desired_width(ui.available_width() - 80.0)
+                .margin(egui::Margin::symmetric(10.0, 6.0))
+                .font(egui::FontId::new(13.0, egui::FontFamily::Monospace))
+                .hint_text(hint),
+        );
+        submit = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if theme::small_button(
+            ui,
+            tr!("action.apply"),
+            theme::current().accent,
+            theme::current().text_on_accent,
+        ) {
+            submit = true;
+        }
+    });
+
+    let problem = match check(field(app)) {
+        Some(e) => Some(e),
+        None if submit => apply(app).err(),
+        None => None,
+    };
+    if let Some(message) = problem {
+        ui.add_space(4.0);
+        ui.label(
+            egui::RichText::new(message)
+                .size(12.0)
+                .color(theme::current().error),
+        );
+    }
+}
+
+fn compact_card(ui: &mut egui::Ui, content: impl FnOnce(&mut egui::Ui)) {
+    egui::Frame::none()
+        .fill(theme::current().bg_card)
+        .rounding(Rounding::same(8.0))
+        .stroke(Stroke::new(1.0, theme::current().border))
+        .inner_margin(egui::Margin::symmetric(12.0, 10.0))
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            ui.spacing_mut().item_spacing = Vec2::new(6.0, 4.0);
+            content(ui);
+        });
+}
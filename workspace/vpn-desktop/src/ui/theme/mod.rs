@@ -0,0 +1,525 @@
+//! Colors and custom widgets of the interface.
+//!
+//! A [`Theme`] holds every color the screens paint with: the dark palette
+//! matched to the mobile app, a light one and a high-contrast one. The
+//! active theme is process-wide like the language; [`apply`] switches it
+//! together with egui's own visuals. With the "system" preference it follows
+//! the desktop's color scheme, read from the XDG desktop portal on Linux
+//! ([`DesktopWatcher`]).
+
+#[cfg(target_os = "linux")]
+mod portal;
+
+use crate::preferences::ThemeMode;
+use egui::{Color32, FontFamily, FontId, Margin, Pos2, Rect, Rounding, Stroke, Vec2};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::mpsc::Receiver;
+
+/// Colors of one variant of the interface.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// egui's widgets start from its dark visuals rather than its light ones.
+    pub dark: bool,
+
+    pub bg: Color32,
+    pub bg_card: Color32,
+    pub bg_card_hover: Color32,
+
+    pub accent: Color32,
+    pub accent_hover: Color32,
+    pub accent_dim: Color32,
+    pub success: Color32,
+    pub success_dim: Color32,
+    pub error: Color32,
+    pub warning: Color32,
+
+    pub text_primary: Color32,
+    pub text_secondary: Color32,
+    pub text_muted: Color32,
+    /// Text on the accent and danger fills.
+    pub text_on_accent: Color32,
+
+    pub border: Color32,
+
+    pub danger: Color32,
+    pub danger_hover: Color32,
+
+    // ── Holographic globe and connected screen ─────────────────────────────
+    /// Globe outline, arc, connected server and headings.
+    pub holo: Color32,
+    /// Grid lines and secondary headings.
+    pub holo_dim: Color32,
+    /// Dot running along the arc.
+    pub holo_bright: Color32,
+    /// Card behind the connection details.
+    pub holo_fill: Color32,
+    pub ocean: Color32,
+    pub land: Color32,
+    pub coast: Color32,
+    /// Sweep across the globe.
+    pub scan: Color32,
+    /// Our own position on the globe.
+    pub home: Color32,
+    /// Multiplies the opacity of faint lines and glows, which fade out on a
+    /// light background.
+    pub glow: f32,
+    /// Opacity of the ghost picture behind the server list.
+    pub backdrop_alpha: u8,
+}
+
+impl Theme {
+    /// Pure black, matched to the mobile app.
+    pub const DARK: Theme = Theme {
+        dark: true,
+        bg: Color32::from_rgb(0, 0, 0),                   // #000000
+        bg_card: Color32::from_rgb(26, 26, 26),           // #1a1a1a
+        bg_card_hover: Color32::from_rgb(27, 36, 80),     // #1B2450 (accent dim)
+        accent: Color32::from_rgb(75, 107, 251),          // #4B6BFB
+        accent_hover: Color32::from_rgb(108, 138, 255),   // #6C8AFF
+        accent_dim: Color32::from_rgb(27, 36, 80),        // #1B2450
+        success: Color32::from_rgb(63, 185, 80),          // #3FB950
+        success_dim: Color32::from_rgb(35, 92, 45),       // #235C2D
+        error: Color32::from_rgb(248, 81, 73),            // #F85149
+        warning: Color32::from_rgb(210, 153, 34),         // #D29922
+        text_primary: Color32::from_rgb(230, 237, 243),   // #E6EDF3
+        text_secondary: Color32::from_rgb(139, 148, 158), // #8B949E
+        text_muted: Color32::from_rgb(110, 118, 129),     // #6E7681
+        text_on_accent: Color32::from_rgb(230, 237, 243),
+        border: Color32::from_rgb(48, 60, 61),  // #303C3D
+        danger: Color32::from_rgb(218, 54, 51), // #DA3633
+        danger_hover: Color32::from_rgb(240, 70, 67),
+        holo: Color32::from_rgb(0, 220, 255),
+        holo_dim: Color32::from_rgb(0, 180, 220),
+        holo_bright: Color32::from_rgb(150, 240, 255),
+        holo_fill: Color32::from_rgb(0, 15, 25),
+        ocean: Color32::from_rgb(0, 40, 60),
+        land: Color32::from_rgb(0, 120, 80),
+        coast: Color32::from_rgb(0, 220, 160),
+        scan: Color32::from_rgb(0, 255, 200),
+        home: Color32::WHITE,
+        glow: 1.0,
+        backdrop_alpha: 30,
+    };
+
+    pub const LIGHT: Theme = Theme {
+        dark: false,
+        bg: Color32::from_rgb(255, 255, 255),
+        bg_card: Color32::from_rgb(246, 248, 250), // #F6F8FA
+        bg_card_hover: Color32::from_rgb(221, 228, 254), // #DDE4FE (accent dim)
+        accent: Color32::from_rgb(47, 84, 235),    // #2F54EB
+        accent_hover: Color32::from_rgb(75, 107, 251), // #4B6BFB
+        accent_dim: Color32::from_rgb(221, 228, 254), // #DDE4FE
+        success: Color32::from_rgb(26, 127, 55),   // #1A7F37
+        success_dim: Color32::from_rgb(218, 251, 225), // #DAFBE1
+        error: Color32::from_rgb(207, 34, 46),     // #CF222E
+        warning: Color32::from_rgb(154, 103, 0),   // #9A6700
+        text_primary: Color32::from_rgb(31, 35, 40), // #1F2328
+        text_secondary: Color32::from_rgb(89, 99, 110), // #59636E
+        text_muted: Color32::from_rgb(110, 119, 129), // #6E7781
+        text_on_accent: Color32::WHITE,
+        border: Color32::from_rgb(208, 215, 222), // #D0D7DE
+        danger: Color32::from_rgb(207, 34, 46),   // #CF222E
+        danger_hover: Color32::from_rgb(164, 14, 38),
+        holo: Color32::from_rgb(0, 132, 180),
+        holo_dim: Color32::from_rgb(0, 110, 150),
+        holo_bright: Color32::from_rgb(0, 90, 130),
+        holo_fill: Color32::from_rgb(234, 246, 251),
+        ocean: Color32::from_rgb(0, 110, 160),
+        land: Color32::from_rgb(0, 140, 90),
+        coast: Color32::from_rgb(0, 130, 100),
+        scan: Color32::from_rgb(0, 150, 130),
+        home: Color32::from_rgb(31, 35, 40),
+        glow: 2.0,
+        backdrop_alpha: 18,
+    };
+
+    /// Black, white text and saturated colors, with the accent in yellow.
+    pub const HIGH_CONTRAST: Theme = Theme {
+        dark: true,
+        bg: Color32::from_rgb(0, 0, 0),
+        bg_card: Color32::from_rgb(0, 0, 0),
+        bg_card_hover: Color32::from_rgb(0, 40, 110),
+        accent: Color32::from_rgb(255, 221, 0),
+        accent_hover: Color32::from_rgb(255, 240, 120),
+        accent_dim: Color32::from_rgb(90, 80, 0),
+        success: Color32::from_rgb(0, 255, 120),
+        success_dim: Color32::from_rgb(0, 90, 40),
+        error: Color32::from_rgb(255, 100, 100),
+        warning: Color32::from_rgb(255, 190, 0),
+        text_primary: Color32::WHITE,
+        text_secondary: Color32::from_rgb(235, 235, 235),
+        text_muted: Color32::from_rgb(205, 205, 205),
+        text_on_accent: Color32::BLACK,
+        border: Color32::WHITE,
+        danger: Color32::from_rgb(255, 70, 70),
+        danger_hover: Color32::from_rgb(255, 120, 120),
+        holo: Color32::from_rgb(0, 255, 255),
+        holo_dim: Color32::from_rgb(0, 230, 255),
+        holo_bright: Color32::WHITE,
+        holo_fill: Color32::from_rgb(0, 0, 0),
+        ocean: Color32::from_rgb(0, 70, 110),
+        land: Color32::from_rgb(0, 200, 120),
+        coast: Color32::from_rgb(0, 255, 180),
+        scan: Color32::from_rgb(0, 255, 200),
+        home: Color32::WHITE,
+        glow: 3.0,
+        backdrop_alpha: 0,
+    };
+
+    /// `color` at `alpha`, scaled by [`Theme::glow`]: for the faint strokes
+    /// and halos of the holographic drawings.
+    pub fn faint(&self, color: Color32, alpha: f32) -> Color32 {
+        let alpha = (alpha * self.glow).clamp(0.0, 255.0) as u8;
+        Color32::from_rgba_unmultiplied(color.r(), color.g(), color.b(), alpha)
+    }
+
+    /// egui's visuals for the built-in widgets: text fields, combo boxes,
+    /// checkboxes, scroll bars.
+    pub fn visuals(&self) -> egui::Visuals {
+        let mut visuals = if self.dark {
+            egui::Visuals::dark()
+        } else {
+            egui::Visuals::light()
+        };
+        visuals.panel_fill = self.bg;
+        visuals.window_fill = self.bg;
+        visuals.extreme_bg_color = self.bg;
+        visuals.faint_bg_color = self.bg_card;
+        visuals.widgets.noninteractive.bg_fill = self.bg;
+        visuals.widgets.inactive.bg_fill = self.bg_card;
+        visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, self.border);
+        visuals.widgets.active.bg_fill = self.bg_card;
+        visuals.widgets.hovered.bg_fill = self.bg_card;
+        visuals.selection.bg_fill = self.accent;
+        visuals.selection.stroke = Stroke::new(1.0, self.text_on_accent);
+        visuals
+    }
+}
+
+/// Theme the interface is shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+static VARIANT: AtomicU8 = AtomicU8::new(0);
+
+impl Variant {
+    /// `desktop` decides for [`ThemeMode::System`]; without a preference
+    /// from the desktop the interface stays dark, its original look.
+    pub fn resolve(mode: ThemeMode, desktop: DesktopAppearance) -> Variant {
+        match mode {
+            ThemeMode::Dark => Variant::Dark,
+            ThemeMode::Light => Variant::Light,
+            ThemeMode::HighContrast => Variant::HighContrast,
+            ThemeMode::System if desktop.high_contrast => Variant::HighContrast,
+            ThemeMode::System if desktop.prefers_dark == Some(false) => Variant::Light,
+            ThemeMode::System => Variant::Dark,
+        }
+    }
+
+    pub fn theme(self) -> &'static Theme {
+        match self {
+            Variant::Dark => &Theme::DARK,
+            Variant::Light => &Theme::LIGHT,
+            Variant::HighContrast => &Theme::HIGH_CONTRAST,
+        }
+    }
+}
+
+/// The active theme.
+pub fn current() -> &'static Theme {
+    match VARIANT.load(Ordering::Relaxed) {
+        1 => &Theme::LIGHT,
+        2 => &Theme::HIGH_CONTRAST,
+        _ => &Theme::DARK,
+    }
+}
+
+/// [`Theme::faint`] of the active theme.
+pub fn faint(color: Color32, alpha: f32) -> Color32 {
+    current().faint(color, alpha)
+}
+
+/// Makes `variant` the active theme, for the custom widgets and egui's.
+pub fn apply(ctx: &egui::Context, variant: Variant) {
+    let value = match variant {
+        Variant::Dark => 0,
+        Variant::Light => 1,
+        Variant::HighContrast => 2,
+    };
+    VARIANT.store(value, Ordering::Relaxed);
+    ctx.set_visuals(variant.theme().visuals());
+    ctx.request_repaint();
+}
+
+/// Appearance asked for by the desktop.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DesktopAppearance {
+    /// `None` when the desktop has no preference.
+    pub prefers_dark: Option<bool>,
+    pub high_contrast: bool,
+}
+
+/// Follows the desktop's color scheme and contrast in the background.
+pub struct DesktopWatcher {
+    changes: Receiver<DesktopAppearance>,
+}
+
+impl DesktopWatcher {
+    /// Reads the current appearance, then reports its changes; `on_change`
+    /// runs on the watcher thread to wake the UI. `None` without a desktop
+    /// portal.
+    #[cfg(target_os = "linux")]
+    pub fn start(on_change: impl Fn() + Send + 'static) -> Option<(DesktopAppearance, Self)> {
+        let (current, changes) = portal::watch(on_change)?;
+        Some((current, Self { changes }))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn start(_on_change: impl Fn() + Send + 'static) -> Option<(DesktopAppearance, Self)> {
+        None
+    }
+
+    /// Latest appearance if it changed since the previous call.
+    pub fn poll(&self) -> Option<DesktopAppearance> {
+        self.changes.try_iter().last()
+    }
+}
+
+// ── Top accent bar (solid accent like mobile) ──────────────────────────────────
+pub fn draw_top_accent(ui: &mut egui::Ui) {
+    let rect = ui.available_rect_before_wrap();
+    let bar = Rect::from_min_size(rect.min, Vec2::new(rect.width(), 3.0));
+    let painter = ui.painter();
+    painter.rect_filled(bar, Rounding::ZERO, current().accent);
+    ui.add_space(8.0);
+}
+
+// ── Centered accent bar (login screen style) ───────────────────────────────────
+pub fn draw_centered_accent_bar(ui: &mut egui::Ui, width: f32) {
+    let available = ui.available_rect_before_wrap();
+    let x = available.center().x - width / 2.0;
+    let bar = Rect::from_min_size(Pos2::new(x, available.min.y), Vec2::new(width, 3.0));
+    let painter = ui.painter();
+    painter.rect_filled(bar, Rounding::same(2.0), current().accent);
+    ui.add_space(8.0);
+}
+
+pub fn card_frame(ui: &mut egui::Ui, add_contents: impl FnOnce(&mut egui::Ui)) {
+    let theme = current();
+    egui::Frame::none()
+        .fill(theme.bg_card)
+        .rounding(Rounding::same(12.0))
+        .stroke(Stroke::new(1.0, theme.border))
+        .inner_margin(Margin::same(20.0))
+        .show(ui, |ui| {
+            add_contents(ui);
+        });
+}
+
+pub fn primary_button(ui: &mut egui::Ui, text: &str, enabled: bool) -> bool {
+    let size = Vec2::new(ui.available_width(), 44.0);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter();
+        let theme = current();
+        let (bg, text_color) = if !enabled {
+            (theme.accent_dim, theme.text_muted)
+        } else if response.hovered() {
+            (theme.accent_hover, theme.text_on_accent)
+        } else {
+            (theme.accent, theme.text_on_accent)
+        };
+
+        painter.rect_filled(rect, Rounding::same(10.0), bg);
+
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            text,
+            FontId::new(15.0, FontFamily::Proportional),
+            text_color,
+        );
+    }
+
+    let response = if enabled {
+        response.on_hover_cursor(egui::CursorIcon::PointingHand)
+    } else {
+        response
+    };
+
+    enabled && response.clicked()
+}
+
+pub fn danger_button(ui: &mut egui::Ui, text: &str) -> bool {
+    let size = Vec2::new(ui.available_width(), 44.0);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter();
+        let theme = current();
+        let bg = if response.hovered() {
+            theme.danger_hover
+        } else {
+            theme.danger
+        };
+
+        painter.rect_filled(rect, Rounding::same(10.0), bg);
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            text,
+            FontId::new(15.0, FontFamily::Proportional),
+            theme.text_on_accent,
+        );
+    }
+
+    response
+        .on_hover_cursor(egui::CursorIcon::PointingHand)
+        .clicked()
+}
+
+/// Styled text input field with label
+pub fn text_field(ui: &mut egui::Ui, label: &str, value: &mut String) -> egui::Response {
+    ui.label(
+        egui::RichText::new(label)
+            .size(13.0)
+            .color(current().text_secondary),
+    );
+    ui.add_space(4.0);
+    let response = ui.add(
+        egui::TextEdit::singleline(value)
+            .desired_width(ui.available_width())
+            .margin(Margin::symmetric(12.0, 12.0))
+            .font(FontId::new(15.0, FontFamily::Proportional)),
+    );
+    response
+}
+
+/// Password input field with label
+pub fn password_field(ui: &mut egui::Ui, label: &str, value: &mut String) -> egui::Response {
+    ui.label(
+        egui::RichText::new(label)
+            .size(13.0)
+            .color(current().text_secondary),
+    );
+    ui.add_space(4.0);
+    let response = ui.add(
+        egui::TextEdit::singleline(value)
+            .password(true)
+            .desired_width(ui.available_width())
+            .margin(Margin::symmetric(12.0, 12.0))
+            .font(FontId::new(15.0, FontFamily::Proportional)),
+    );
+    response
+}
+
+/// Section heading (uppercase, muted, with letter spacing)
+pub fn section_heading(ui: &mut egui::Ui, text: &str) {
+    ui.label(
+        egui::RichText::new(text)
+            .size(11.0)
+            .color(current().text_muted)
+            .strong(),
+    );
+    ui.add_space(8.0);
+}
+
+/// Status pill (small colored badge)
+pub fn status_pill(ui: &mut egui::Ui, text: &str, color: Color32) {
+    let galley = ui.painter().layout_no_wrap(
+        text.to_string(),
+        FontId::new(11.0, FontFamily::Proportional),
+        color,
+    );
+    let desired_size = galley.size() + Vec2::new(16.0, 6.0);
+    let (rect, _response) = ui.allocate_exact_size(desired_size, egui::Sense::hover());
+
+    if ui.is_rect_visible(rect) {
+        // A quarter of the color over the background, whatever its shade
+        let bg = Color32::from_rgba_unmultiplied(color.r(), color.g(), color.b(), 64);
+        ui.painter().rect_filled(rect, Rounding::same(10.0), bg);
+        ui.painter()
+            .rect_stroke(rect, Rounding::same(10.0), Stroke::new(1.0, color));
+        ui.painter().galley(
+            rect.center() - galley.size() / 2.0,
+            galley,
+            Color32::PLACEHOLDER,
+        );
+    }
+}
+
+/// Info row with bottom separator (matching mobile detail rows)
+pub fn info_row(ui: &mut egui::Ui, label: &str, value: &str) {
+    let theme = current();
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(label)
+                .size(13.0)
+                .color(theme.text_muted),
+        );
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            ui.label(
+                egui::RichText::new(value)
+                    .size(13.0)
+                    .color(theme.text_primary)
+                    .family(FontFamily::Monospace),
+            );
+        });
+    });
+    // Subtle separator line like mobile
+    let rect = ui.available_rect_before_wrap();
+    let line_rect = Rect::from_min_size(rect.min, Vec2::new(rect.width(), 1.0));
+    ui.painter()
+        .rect_filled(line_rect, Rounding::ZERO, theme.border);
+    ui.add_space(8.0);
+}
+
+/// Draw an avatar circle with initial letter (like mobile profile)
+pub fn draw_avatar(ui: &mut egui::Ui, initial: char, size: f32) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::splat(size), egui::Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter();
+        let center = rect.center();
+        let radius = size / 2.0;
+
+        let theme = current();
+        painter.circle_filled(center, radius, theme.accent);
+        painter.text(
+            center,
+            egui::Align2::CENTER_CENTER,
+            initial.to_uppercase().to_string(),
+            FontId::new(size * 0.4, FontFamily::Proportional),
+            theme.bg,
+        );
+    }
+}
+
+/// Small profile button (like mobile)
+pub fn small_button(ui: &mut egui::Ui, text: &str, bg: Color32, text_color: Color32) -> bool {
+    let galley = ui.painter().layout_no_wrap(
+        text.to_string(),
+        FontId::new(13.0, FontFamily::Proportional),
+        text_color,
+    );
+    let desired_size = galley.size() + Vec2::new(24.0, 16.0);
+    let (rect, response) = ui.allocate_exact_size(desired_size, egui::Sense::click());
+
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter();
+        painter.rect_filled(rect, Rounding::same(6.0), bg);
+        painter.galley(
+            rect.center() - galley.size() / 2.0,
+            galley,
+            Color32::PLACEHOLDER,
+        );
+    }
+
+    response
+        .on_hover_cursor(egui::CursorIcon::PointingHand)
+        .clicked()
+}
@@ -0,0 +1,105 @@
+//! Desktop appearance through the XDG desktop portal's Settings interface,
+//! which GNOME, KDE and the other portal backends expose alike.
+
+use super::DesktopAppearance;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::Duration;
+use zbus::zvariant::{OwnedValue, Value};
+
+const NAMESPACE: &str = "org.freedesktop.appearance";
+/// 0 no preference, 1 dark, 2 light.
+const COLOR_SCHEME: &str = "color-scheme";
+/// 0 no preference, 1 high contrast.
+const CONTRAST: &str = "contrast";
+/// Wait for the first reading before showing the window, so that a light
+/// desktop does not get a dark flash.
+const FIRST_READ_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Current appearance, then its changes. `None` without a portal; if the
+/// portal answers late, the first reading arrives through the channel.
+pub(super) fn watch(
+    on_change: impl Fn() + Send + 'static,
+) -> Option<(DesktopAppearance, Receiver<DesktopAppearance>)> {
+    let (sender, changes) = mpsc::channel();
+    std::thread::spawn(move || {
+        if let Err(e) = listen(&sender, &on_change) {
+            tracing::debug!(error = %e, "desktop portal unavailable");
+        }
+    });
+    match changes.recv_timeout(FIRST_READ_TIMEOUT) {
+        Ok(appearance) => Some((appearance, changes)),
+        Err(RecvTimeoutError::Timeout) => Some((DesktopAppearance::default(), changes)),
+        Err(RecvTimeoutError::Disconnected) => None,
+    }
+}
+
+fn listen(sender: &Sender<DesktopAppearance>, on_change: &dyn Fn()) -> zbus::Result<()> {
+    let conn = zbus::blocking::Connection::session()?;
+    let proxy: zbus::blocking::Proxy = zbus::blocking::ProxyBuilder::new(&conn)
+        .destination("org.freedesktop.portal.Desktop")?
+        .path("/org/freedesktop/portal/desktop")?
+        .interface("org.freedesktop.portal.Settings")?
+        .cache_properties(zbus::CacheProperties::No)
+        .build()?;
+    // Subscribed before reading so that no change slips in between
+    let signals = proxy.receive_signal("SettingChanged")?;
+
+    let mut appearance = DesktopAppearance {
+        prefers_dark: read(&proxy, COLOR_SCHEME).and_then(color_scheme),
+        high_contrast: read(&proxy, CONTRAST) == Some(1),
+    };
+    tracing::info!(appearance = ?appearance, "desktop appearance");
+    if sender.send(appearance).is_err() {
+        return Ok(());
+    }
+
+    for message in signals {
+        let (namespace, key, value) = message
+            .body()
+            .deserialize::<(String, String, OwnedValue)>()?;
+        if namespace != NAMESPACE {
+            continue;
+        }
+        let previous = appearance;
+        match key.as_str() {
+            COLOR_SCHEME => appearance.prefers_dark = as_u32(&value).and_then(color_scheme),
+            CONTRAST => appearance.high_contrast = as_u32(&value) == Some(1),
+            _ => continue,
+        }
+        if appearance == previous {
+            continue;
+        }
+        tracing::info!(appearance = ?appearance, "desktop appearance changed");
+        if sender.send(appearance).is_err() {
+            break;
+        }
+        on_change();
+    }
+    Ok(())
+}
+
+/// `ReadOne` since version 2 of the interface, `Read` before.
+fn read(proxy: &zbus::blocking::Proxy, key: &str) -> Option<u32> {
+    let value: OwnedValue = proxy
+        .call("ReadOne", &(NAMESPACE, key))
+        .or_else(|_| proxy.call("Read", &(NAMESPACE, key)))
+        .ok()?;
+    as_u32(&value)
+}
+
+/// `Read` wraps the value in one more variant.
+fn as_u32(value: &Value) -> Option<u32> {
+    match value {
+        Value::U32(n) => Some(*n),
+        Value::Value(inner) => as_u32(inner),
+        _ => None,
+    }
+}
+
+fn color_scheme(value: u32) -> Option<bool> {
+    match value {
+        1 => Some(true),
+        2 => Some(false),
+        _ => None,
+    }
+}
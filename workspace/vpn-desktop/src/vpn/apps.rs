@@ -0,0 +1,477 @@
+//! Per-application routing (Linux only).
+//!
+//! Each rule puts an executable in one of two cgroup v2 groups,
+//! `fire-vpn/bypass` or `fire-vpn/tunnel`. An nftables chain marks the sockets
+//! of processes that must not use the VPN, and a policy routing rule sends
+//! marked packets through a copy of the main routing table without the
+//! WireGuard routes.
+#![cfg_attr(not(target_os = "linux"), allow(dead_code))]
+
+#[cfg(target_os = "linux")]
+use super::root;
+use super::sudo;
+use crate::i18n::tr;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+const CGROUP_PARENT: &str = "fire-vpn";
+const NFT_TABLE: &str = "fire_vpn_apps";
+/// fwmark set on bypassed traffic, also used as routing table id.
+pub const BYPASS_MARK: u32 = 0x1f1e;
+const RULE_PRIORITY: &str = "5000";
+const SCAN_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AppMode {
+    /// Never goes through the VPN.
+    Bypass,
+    /// Always goes through the VPN; when at least one such rule exists, every
+    /// other application bypasses the tunnel.
+    Tunnel,
+}
+
+impl AppMode {
+    fn cgroup(self) -> &'static str {
+        match self {
+            AppMode::Bypass => "bypass",
+            AppMode::Tunnel => "tunnel",
+        }
+    }
+
+    /// Reverse of the cgroup name, for the privileged helper's arguments.
+    pub fn from_cgroup(name: &str) -> Option<Self> {
+        [AppMode::Bypass, AppMode::Tunnel]
+            .into_iter()
+            .find(|mode| mode.cgroup() == name)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AppMode::Bypass => tr!("apps.mode.bypass"),
+            AppMode::Tunnel => tr!("apps.mode.tunnel"),
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            AppMode::Bypass => AppMode::Tunnel,
+            AppMode::Tunnel => AppMode::Bypass,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppRule {
+    pub path: String,
+    pub mode: AppMode,
+}
+
+/// An application found in the freedesktop `.desktop` entries.
+#[derive(Debug, Clone)]
+pub struct InstalledApp {
+    pub name: String,
+    pub path: String,
+}
+
+pub fn is_supported() -> bool {
+    cfg!(target_os = "linux") && Path::new(CGROUP_ROOT).join("cgroup.controllers").exists()
+}
+
+fn cgroup_dir(mode: AppMode) -> PathBuf {
+    Path::new(CGROUP_ROOT)
+        .join(CGROUP_PARENT)
+        .join(mode.cgroup())
+}
+
+/// Keeps running processes of the configured executables in their cgroup
+/// while the tunnel is up.
+pub struct AppRouter {
+    running: Arc<AtomicBool>,
+}
+
+impl AppRouter {
+    pub fn start(rules: &[AppRule], interface: &str) -> Result<Self> {
+        activate(rules, interface)?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let running_flag = Arc::clone(&running);
+        let rules = rules.to_vec();
+        std::thread::spawn(move || {
+            while running_flag.load(Ordering::Relaxed) {
+                if let Err(e) = assign_running(&rules) {
+                    tracing::warn!(error = %e, "failed to assign processes to cgroups");
+                }
+                std::thread::sleep(SCAN_INTERVAL);
+            }
+        });
+
+        Ok(Self { running })
+    }
+
+    pub fn stop(&self) {
+        if self.running.swap(false, Ordering::Relaxed) {
+            if let Err(e) = deactivate() {
+                tracing::warn!(error = %e, "failed to remove per-application routing");
+            }
+        }
+    }
+}
+
+impl Drop for AppRouter {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Through `sudo vpn-desktop tunnel apps up`, see [`setup`].
+#[cfg(target_os = "linux")]
+fn activate(rules: &[AppRule], interface: &str) -> Result<()> {
+    if !is_supported() {
+        anyhow::bail!("cgroup v2 is not mounted on {}", CGROUP_ROOT);
+    }
+    let mut args = vec!["apps", "up", "--interface", interface];
+    if rules.iter().any(|r| r.mode == AppMode::Tunnel) {
+        args.push("--inclusive");
+    }
+    sudo::run(&args)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn activate(_rules: &[AppRule], _interface: &str) -> Result<()> {
+    anyhow::bail!("per-application routing is only supported on Linux")
+}
+
+#[cfg(target_os = "linux")]
+pub fn deactivate() -> Result<()> {
+    sudo::run(&["apps", "down"])
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn deactivate() -> Result<()> {
+    Ok(())
+}
+
+/// Creates the cgroups, the bypass routing table and rules, and the nftables
+/// chain; `inclusive` when at least one rule is [`AppMode::Tunnel`]. Run by
+/// the privileged helper, as root.
+#[cfg(target_os = "linux")]
+pub fn setup(interface: &str, inclusive: bool) -> Result<()> {
+    if !is_supported() {
+        anyhow::bail!("cgroup v2 is not mounted on {}", CGROUP_ROOT);
+    }
+    for mode in [AppMode::Bypass, AppMode::Tunnel] {
+        std::fs::create_dir_all(cgroup_dir(mode)).context("Failed to create the cgroups")?;
+    }
+
+    copy_main_table(interface)?;
+    let mark = BYPASS_MARK.to_string();
+    for family in ["-4", "-6"] {
+        // Remove a stale rule from a previous run before adding ours
+        let _ = root::run(&["ip", family, "rule", "del", "priority", RULE_PRIORITY]);
+        root::run(&[
+            "ip",
+            family,
+            "rule",
+            "add",
+            "fwmark",
+            &mark,
+            "lookup",
+            &mark,
+            "priority",
+            RULE_PRIORITY,
+        ])?;
+    }
+
+    root::run_with_input(&["nft", "-f", "-"], &nft_ruleset(inclusive))
+}
+
+/// Removes what [`setup`] added. Run by the privileged helper, as root.
+#[cfg(target_os = "linux")]
+pub fn teardown() {
+    let _ = root::run(&["nft", "delete", "table", "inet", NFT_TABLE]);
+    let table = BYPASS_MARK.to_string();
+    for family in ["-4", "-6"] {
+        let _ = root::run(&["ip", family, "rule", "del", "priority", RULE_PRIORITY]);
+        let _ = root::run(&["ip", family, "route", "flush", "table", &table]);
+    }
+}
+
+/// nftables chain marking the sockets of bypassed processes. Conntrack keeps
+/// the mark so that replies follow the same path.
+fn nft_ruleset(inclusive: bool) -> String {
+    let bypass = format!("{}/{}", CGROUP_PARENT, AppMode::Bypass.cgroup());
+    let tunnel = format!("{}/{}", CGROUP_PARENT, AppMode::Tunnel.cgroup());
+
+    let mut chain = String::new();
+    chain.push_str("        meta mark set ct mark\n");
+    chain.push_str(&format!(
+        "        socket cgroupv2 level 2 \"{}\" meta mark set {:#x}\n",
+        bypass, BYPASS_MARK
+    ));
+    if inclusive {
+        chain.push_str(&format!(
+            "        socket cgroupv2 level 2 != \"{}\" meta mark set {:#x}\n",
+            tunnel, BYPASS_MARK
+        ));
+    }
+    chain.push_str("        ct mark set meta mark\n");
+
+    format!(
+        "table inet {table}\n\
+         delete table inet {table}\n\
+         table inet {table} {{\n    \
+             chain output {{\n        \
+                 type route hook output priority mangle; policy accept;\n\
+                 {chain}    \
+             }}\n\
+         }}\n",
+        table = NFT_TABLE,
+        chain = chain,
+    )
+}
+
+/// Copies the main routing table, minus the tunnel routes, into the bypass table.
+#[cfg(target_os = "linux")]
+fn copy_main_table(interface: &str) -> Result<()> {
+    let table = BYPASS_MARK.to_string();
+    for family in ["-4", "-6"] {
+        let _ = root::run(&["ip", family, "route", "flush", "table", &table]);
+
+        let output = Command::new("ip")
+            .args([family, "route", "show", "table", "main"])
+            .output()
+            .context("Failed to execute ip route show")?;
+        let routes = String::from_utf8_lossy(&output.stdout);
+
+        for route in routes.lines() {
+            let fields: Vec<&str> = route.split_whitespace().collect();
+            let on_tunnel = fields
+                .windows(2)
+                .any(|w| w[0] == "dev" && w[1] == interface);
+            // IPv6 leak-prevention blackholes only apply to tunneled traffic
+            let blackhole = fields.first() == Some(&"blackhole");
+            if fields.is_empty() || on_tunnel || blackhole {
+                continue;
+            }
+            let mut args = vec!["ip", family, "route", "replace"];
+            args.extend(fields.iter().copied());
+            args.extend(["table", table.as_str()]);
+            if let Err(e) = root::run(&args) {
+                tracing::warn!(route, error = %e, "route not copied to the bypass table");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Moves running processes whose executable matches a rule into its cgroup.
+#[cfg(target_os = "linux")]
+fn assign_running(rules: &[AppRule]) -> Result<()> {
+    let targets: Vec<(PathBuf, AppMode)> = rules
+        .iter()
+        .filter_map(|r| resolve_executable(&r.path).map(|p| (p, r.mode)))
+        .collect();
+    if targets.is_empty() {
+        return Ok(());
+    }
+
+    let mut moves: Vec<(AppMode, String)> = Vec::new();
+    for entry in std::fs::read_dir("/proc")?.flatten() {
+        let pid = match entry
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u32>().ok())
+        {
+            Some(pid) => pid,
+            None => continue,
+        };
+        let exe = match std::fs::read_link(entry.path().join("exe")) {
+            Ok(exe) => exe,
+            Err(_) => continue,
+        };
+        let mode = match targets.iter().find(|(path, _)| *path == exe) {
+            Some((_, mode)) => *mode,
+            None => continue,
+        };
+        let current = std::fs::read_to_string(entry.path().join("cgroup")).unwrap_or_default();
+        let wanted = format!("/{}/{}", CGROUP_PARENT, mode.cgroup());
+        if current.trim().ends_with(&wanted) {
+            continue;
+        }
+        moves.push((mode, pid.to_string()));
+    }
+
+    for mode in [AppMode::Bypass, AppMode::Tunnel] {
+        let pids: Vec<&str> = moves
+            .iter()
+            .filter(|(m, _)| *m == mode)
+            .map(|(_, pid)| pid.as_str())
+            .collect();
+        if !pids.is_empty() {
+            move_through_helper(mode, &pids)?;
+        }
+    }
+    Ok(())
+}
+
+/// `sudo vpn-desktop tunnel apps move --mode <mode> <pid>...`
+#[cfg(target_os = "linux")]
+fn move_through_helper(mode: AppMode, pids: &[&str]) -> Result<()> {
+    let mut args = vec!["apps", "move", "--mode", mode.cgroup()];
+    args.extend(pids);
+    sudo::run(&args)
+}
+
+/// Moves processes of the user who ran sudo into the cgroup of `mode`.
+/// Run by the privileged helper, as root.
+#[cfg(target_os = "linux")]
+pub fn move_processes(mode: AppMode, pids: &[u32]) -> Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    if !is_supported() {
+        anyhow::bail!("cgroup v2 is not mounted on {}", CGROUP_ROOT);
+    }
+    let caller = std::env::var("SUDO_UID")
+        .ok()
+        .and_then(|uid| uid.parse::<u32>().ok());
+    let dir = cgroup_dir(mode);
+    std::fs::create_dir_all(&dir).context("Failed to create the cgroups")?;
+
+    for &pid in pids {
+        let owner = std::fs::metadata(format!("/proc/{}", pid))
+            .with_context(|| format!("No process {}", pid))?
+            .uid();
+        if caller.is_some_and(|uid| uid != owner) {
+            anyhow::bail!("Process {} belongs to another user", pid);
+        }
+        // The process may have exited since it was listed
+        if let Err(e) = std::fs::write(dir.join("cgroup.procs"), pid.to_string()) {
+            tracing::debug!(pid, error = %e, "process not moved");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn assign_running(_rules: &[AppRule]) -> Result<()> {
+    Ok(())
+}
+
+/// Starts `program` inside the bypass or tunnel cgroup.
+///
+/// The child stops itself before `exec`, is moved into the cgroup and then
+/// resumed, so it cannot open a socket from its original cgroup.
+#[cfg(target_os = "linux")]
+pub fn launch(mode: AppMode, program: &str, args: &[String]) -> Result<std::process::Child> {
+    if !is_supported() {
+        anyhow::bail!("cgroup v2 is not mounted on {}", CGROUP_ROOT);
+    }
+
+    let child = Command::new("sh")
+        .arg("-c")
+        .arg("kill -STOP $$ && exec \"$@\"")
+        .arg("fire-vpn-launch")
+        .arg(program)
+        .args(args)
+        .spawn()
+        .with_context(|| format!("Failed to start {}", program))?;
+    let pid = child.id();
+
+    // Wait until the shell has stopped itself
+    let stat_path = format!("/proc/{}/stat", pid);
+    for _ in 0..100 {
+        let stat = std::fs::read_to_string(&stat_path).unwrap_or_default();
+        if stat.rsplit(") ").next().is_some_and(|s| s.starts_with('T')) {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let moved = move_through_helper(mode, &[&pid.to_string()]);
+    Command::new("kill")
+        .args(["-CONT", &pid.to_string()])
+        .status()
+        .context("Failed to resume launched process")?;
+    moved?;
+
+    Ok(child)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn launch(_mode: AppMode, _program: &str, _args: &[String]) -> Result<std::process::Child> {
+    anyhow::bail!("per-application routing is only supported on Linux")
+}
+
+/// Applications listed in the freedesktop `.desktop` entries, for the picker.
+pub fn installed_applications() -> Vec<InstalledApp> {
+    let mut dirs = vec![PathBuf::from("/usr/share/applications")];
+    if let Some(data) = dirs::data_dir() {
+        dirs.push(data.join("applications"));
+    }
+
+    let mut apps: Vec<InstalledApp> = dirs
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "desktop"))
+        .filter_map(|e| parse_desktop_entry(&std::fs::read_to_string(e.path()).ok()?))
+        .collect();
+    apps.sort_by_key(|a| a.name.to_lowercase());
+    apps.dedup_by(|a, b| a.path == b.path);
+    apps
+}
+
+fn parse_desktop_entry(content: &str) -> Option<InstalledApp> {
+    let mut name = None;
+    let mut exec = None;
+    let mut in_entry = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_entry = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_entry {
+            continue;
+        }
+        if let Some(v) = line.strip_prefix("Name=") {
+            name.get_or_insert_with(|| v.to_string());
+        } else if let Some(v) = line.strip_prefix("Exec=") {
+            exec.get_or_insert_with(|| v.to_string());
+        } else if line == "NoDisplay=true" {
+            return None;
+        }
+    }
+
+    let program = exec?
+        .split_whitespace()
+        .next()?
+        .trim_matches('"')
+        .to_string();
+    let path = resolve_executable(&program)?;
+    Some(InstalledApp {
+        name: name?,
+        path: path.to_string_lossy().into_owned(),
+    })
+}
+
+/// Resolves a program name through `PATH` and symlinks, as seen in `/proc/<pid>/exe`.
+pub fn resolve_executable(program: &str) -> Option<PathBuf> {
+    let candidate = if program.contains('/') {
+        PathBuf::from(program)
+    } else {
+        std::env::var_os("PATH")
+            .map(|paths| std::env::split_paths(&paths).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|dir| dir.join(program))
+            .find(|p| p.is_file())?
+    };
+    std::fs::canonicalize(candidate).ok()
+}
@@ -0,0 +1,465 @@
+//! Domain-based routing.
+//!
+//! In this mode the tunnel installs no routes (`manual_routes`) and the system
+//! resolver points to a local DNS forwarder. Queries for a configured domain
+//! pattern are resolved through the VPN DNS and a host route to each returned
+//! address is added to the tunnel until the record's TTL expires; every other
+//! query goes to the original resolvers and its traffic stays direct.
+//!
+//! Queries come over UDP and, for answers too large for a datagram, over
+//! TCP; each is forwarded upstream over the same transport. The redirection
+//! and the host routes are set up by the privileged helper
+//! (`vpn-desktop tunnel dns ...`).
+#![cfg_attr(not(target_os = "linux"), allow(dead_code))]
+
+#[cfg(target_os = "linux")]
+use super::root;
+use super::sudo;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use vpn_core::dns::{self, DomainPattern};
+
+/// Resolver address written in the tunnel config (`DNS = 127.0.0.1`).
+pub const LOCAL_DNS: &str = "127.0.0.1";
+/// Port the forwarder listens on; port 53 on `LOCAL_DNS` is redirected to it.
+const FORWARDER_PORT: u16 = 53535;
+const NFT_TABLE: &str = "fire_vpn_dns";
+/// Host routes live at least this long, so short TTLs do not cut connections.
+const MIN_ROUTE_TTL: Duration = Duration::from_secs(300);
+const EXPIRY_INTERVAL: Duration = Duration::from_secs(5);
+const UPSTREAM_TIMEOUT: Duration = Duration::from_secs(3);
+/// Threads answering queries. Any local process can send to the forwarder,
+/// so neither the threads nor the waiting queries grow with the traffic; a
+/// TCP client is dropped after `UPSTREAM_TIMEOUT` of silence.
+const WORKERS: usize = 4;
+/// Queries waiting for a worker; more are dropped and the client retries.
+const QUEUE_LEN: usize = 64;
+
+/// Relies on nftables and iproute2.
+pub fn is_supported() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// A query waiting for a worker.
+enum Query {
+    Udp(Vec<u8>, SocketAddr),
+    /// Connection not read yet: a single length-prefixed query.
+    Tcp(TcpStream),
+}
+
+struct RouterState {
+    patterns: Vec<DomainPattern>,
+    tunnel_dns: Vec<IpAddr>,
+    upstream: Vec<IpAddr>,
+    interface: String,
+    /// Host routes installed in the tunnel and their expiry.
+    routes: Mutex<HashMap<IpAddr, Instant>>,
+}
+
+pub struct DomainRouter {
+    state: Arc<RouterState>,
+    running: Arc<AtomicBool>,
+}
+
+impl DomainRouter {
+    /// `upstream` must be read with [`system_resolvers`] before the tunnel
+    /// replaces the system DNS configuration.
+    pub fn start(
+        patterns: Vec<DomainPattern>,
+        tunnel_dns: Vec<IpAddr>,
+        upstream: Vec<IpAddr>,
+        interface: &str,
+    ) -> Result<Self> {
+        let socket = UdpSocket::bind((LOCAL_DNS, FORWARDER_PORT))
+            .with_context(|| format!("Failed to bind DNS forwarder on port {}", FORWARDER_PORT))?;
+        socket.set_read_timeout(Some(Duration::from_secs(1)))?;
+        let listener = TcpListener::bind((LOCAL_DNS, FORWARDER_PORT))
+            .with_context(|| format!("Failed to bind DNS forwarder on port {}", FORWARDER_PORT))?;
+        let reply_sockets = (0..WORKERS)
+            .map(|_| socket.try_clone())
+            .collect::<std::io::Result<Vec<_>>>()?;
+
+        sudo::run(&["dns", "up"])?;
+
+        // The VPN resolvers themselves are only reachable through the tunnel
+        for server in &tunnel_dns {
+            if let Err(e) = add_host_route(server, interface) {
+                deactivate();
+                return Err(e);
+            }
+        }
+
+        let upstream = if upstream.is_empty() {
+            tunnel_dns.clone()
+        } else {
+            upstream
+        };
+        let state = Arc::new(RouterState {
+            patterns,
+            tunnel_dns,
+            upstream,
+            interface: interface.to_string(),
+            routes: Mutex::new(HashMap::new()),
+        });
+        let running = Arc::new(AtomicBool::new(true));
+
+        // Workers stop once the listener drops the sending side
+        // Workers stop once both listeners drop their sending side
+        let (queries, pending) = mpsc::sync_channel::<Query>(QUEUE_LEN);
+        let pending = Arc::new(Mutex::new(pending));
+        for reply_socket in reply_sockets {
+            let pending = Arc::clone(&pending);
+            let state = Arc::clone(&state);
+            std::thread::spawn(move || loop {
+                let next = match pending.lock() {
+                    Ok(pending) => pending.recv(),
+                    Err(_) => return,
+                };
+                match next {
+                    Ok(Query::Udp(query, client)) => {
+                        if let Some(response) = state.forward(&query, false) {
+                            let _ = reply_socket.send_to(&response, client);
+                        }
+                    }
+                    Ok(Query::Tcp(stream)) => state.answer_tcp(stream),
+                    Err(_) => return,
+                }
+            });
+        }
+
+        let udp_running = Arc::clone(&running);
+        let udp_queries = queries.clone();
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 4096];
+            while udp_running.load(Ordering::Relaxed) {
+                let (len, client) = match socket.recv_from(&mut buf) {
+                    Ok(r) => r,
+                    Err(_) => continue,
+                };
+                let query = Query::Udp(buf[..len].to_vec(), client);
+                if let Err(TrySendError::Full(_)) = udp_queries.try_send(query) {
+                    tracing::debug!(%client, "DNS forwarder busy, query dropped");
+                }
+            }
+        });
+
+        // Woken up by `stop` with a last connection
+        let tcp_running = Arc::clone(&running);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if !tcp_running.load(Ordering::Relaxed) {
+                    return;
+                }
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+                if stream.set_read_timeout(Some(UPSTREAM_TIMEOUT)).is_err() {
+                    continue;
+                }
+                if let Err(TrySendError::Full(_)) = queries.try_send(Query::Tcp(stream)) {
+                    tracing::debug!("DNS forwarder busy, TCP query dropped");
+                }
+            }
+        });
+
+        let expiry_state = Arc::clone(&state);
+        let expiry_running = Arc::clone(&running);
+        std::thread::spawn(move || {
+            while expiry_running.load(Ordering::Relaxed) {
+                std::thread::sleep(EXPIRY_INTERVAL);
+                expiry_state.expire_routes();
+            }
+        });
+
+        Ok(Self { state, running })
+    }
+
+    /// Number of host routes currently installed for matched domains.
+    pub fn active_routes(&self) -> usize {
+        self.state.routes.lock().map(|r| r.len()).unwrap_or(0)
+    }
+
+    pub fn stop(&self) {
+        if !self.running.swap(false, Ordering::Relaxed) {
+            return;
+        }
+        let _ = TcpStream::connect((LOCAL_DNS, FORWARDER_PORT));
+        deactivate();
+        if let Ok(mut routes) = self.state.routes.lock() {
+            for addr in routes.keys() {
+                let _ = del_host_route(addr, &self.state.interface);
+            }
+            routes.clear();
+        }
+        for server in &self.state.tunnel_dns {
+            let _ = del_host_route(server, &self.state.interface);
+        }
+    }
+}
+
+/// Removes the DNS redirection left by a router that did not stop; its host
+/// routes go away with the tunnel interface.
+pub fn deactivate() {
+    if is_supported() {
+        let _ = sudo::run(&["dns", "down"]);
+    }
+}
+
+impl Drop for DomainRouter {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+impl RouterState {
+    /// Sends the query upstream, over TCP when the client used it, and for
+    /// matched domains routes the answers into the tunnel before the client
+    /// gets the response.
+    fn forward(&self, query: &[u8], tcp: bool) -> Option<Vec<u8>> {
+        let matched = dns::query_name(query)
+            .map(|name| self.patterns.iter().any(|p| p.matches(&name)))
+            .unwrap_or(false);
+        let servers = if matched {
+            &self.tunnel_dns
+        } else {
+            &self.upstream
+        };
+
+        let response = servers.iter().find_map(|server| {
+            if tcp {
+                ask_tcp(server, query)
+            } else {
+                ask(server, query)
+            }
+        })?;
+
+        if matched {
+            match dns::answers(&response) {
+                Ok(answers) => {
+                    for answer in answers {
+                        let ttl = Duration::from_secs(answer.ttl as u64).max(MIN_ROUTE_TTL);
+                        self.install_route(answer.addr, Instant::now() + ttl);
+                    }
+                }
+                Err(e) => tracing::debug!(error = %e, "invalid DNS response"),
+            }
+        }
+        Some(response)
+    }
+
+    /// Answers one query of a TCP client (two-byte length, then the
+    /// message, both ways).
+    fn answer_tcp(&self, mut stream: TcpStream) {
+        let query = match read_tcp_message(&mut stream) {
+            Some(query) => query,
+            None => return,
+        };
+        if let Some(response) = self.forward(&query, true) {
+            let _ = write_tcp_message(&mut stream, &response);
+        }
+    }
+
+    /// The lock is not held while the helper adds the route: the other
+    /// workers, the expiry and the UI would wait for sudo.
+    fn install_route(&self, addr: IpAddr, expires: Instant) {
+        match self.routes.lock() {
+            Ok(mut routes) => {
+                if let Some(current) = routes.get_mut(&addr) {
+                    *current = (*current).max(expires);
+                    return;
+                }
+            }
+            Err(_) => return,
+        }
+        if let Err(e) = add_host_route(&addr, &self.interface) {
+            tracing::warn!(%addr, error = %e, "failed to install host route");
+            return;
+        }
+        if let Ok(mut routes) = self.routes.lock() {
+            let current = routes.entry(addr).or_insert(expires);
+            *current = (*current).max(expires);
+        }
+    }
+
+    fn expire_routes(&self) {
+        let now = Instant::now();
+        let expired: Vec<IpAddr> = match self.routes.lock() {
+            Ok(mut routes) => {
+                let expired: Vec<IpAddr> = routes
+                    .iter()
+                    .filter(|(_, &expires)| expires <= now)
+                    .map(|(addr, _)| *addr)
+                    .collect();
+                for addr in &expired {
+                    routes.remove(addr);
+                }
+                expired
+            }
+            Err(_) => return,
+        };
+        for addr in expired {
+            let _ = del_host_route(&addr, &self.interface);
+        }
+    }
+}
+
+fn ask(server: &IpAddr, query: &[u8]) -> Option<Vec<u8>> {
+    let bind: SocketAddr = if server.is_ipv4() {
+        "0.0.0.0:0".parse().ok()?
+    } else {
+        "[::]:0".parse().ok()?
+    };
+    let id = query.get(..2)?;
+    let socket = UdpSocket::bind(bind).ok()?;
+    socket.send_to(query, (*server, 53)).ok()?;
+
+    // Anything else than the server's answer to this very query is skipped:
+    // a forged or stale reply would install routes
+    let deadline = Instant::now() + UPSTREAM_TIMEOUT;
+    let mut buf = vec![0u8; 4096];
+    loop {
+        let remaining = deadline.checked_duration_since(Instant::now())?;
+        socket.set_read_timeout(Some(remaining)).ok()?;
+        let (len, from) = socket.recv_from(&mut buf).ok()?;
+        if from == SocketAddr::new(*server, 53) && len >= 2 && &buf[..2] == id {
+            buf.truncate(len);
+            return Some(buf);
+        }
+        tracing::debug!(%from, "unexpected DNS reply ignored");
+    }
+}
+
+/// Same as [`ask`] over TCP, for answers truncated over UDP.
+fn ask_tcp(server: &IpAddr, query: &[u8]) -> Option<Vec<u8>> {
+    let id = query.get(..2)?;
+    let mut stream =
+        TcpStream::connect_timeout(&SocketAddr::new(*server, 53), UPSTREAM_TIMEOUT).ok()?;
+    stream.set_read_timeout(Some(UPSTREAM_TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(UPSTREAM_TIMEOUT)).ok()?;
+    write_tcp_message(&mut stream, query).ok()?;
+    let response = read_tcp_message(&mut stream)?;
+    if response.get(..2) != Some(id) {
+        tracing::debug!(%server, "unexpected DNS reply ignored");
+        return None;
+    }
+    Some(response)
+}
+
+fn read_tcp_message(stream: &mut TcpStream) -> Option<Vec<u8>> {
+    let mut len = [0u8; 2];
+    stream.read_exact(&mut len).ok()?;
+    let mut message = vec![0u8; u16::from_be_bytes(len) as usize];
+    stream.read_exact(&mut message).ok()?;
+    Some(message)
+}
+
+fn write_tcp_message(stream: &mut TcpStream, message: &[u8]) -> std::io::Result<()> {
+    let len = u16::try_from(message.len())
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+    let mut framed = len.to_be_bytes().to_vec();
+    framed.extend_from_slice(message);
+    stream.write_all(&framed)
+}
+
+/// Adds the redirection of [`nft_ruleset`]. Run by the privileged helper,
+/// as root.
+#[cfg(target_os = "linux")]
+pub fn redirect() -> Result<()> {
+    root::run_with_input(&["nft", "-f", "-"], &nft_ruleset())
+}
+
+/// Removes the redirection. Run by the privileged helper, as root.
+#[cfg(target_os = "linux")]
+pub fn remove_redirect() {
+    let _ = root::run(&["nft", "delete", "table", "ip", NFT_TABLE]);
+}
+
+/// Redirects queries sent to `LOCAL_DNS:53`, UDP and TCP, to the
+/// unprivileged forwarder port.
+fn nft_ruleset() -> String {
+    format!(
+        "table ip {table}\n\
+         delete table ip {table}\n\
+         table ip {table} {{\n    \
+             chain output {{\n        \
+                 type nat hook output priority -100; policy accept;\n        \
+                 ip daddr {dns} udp dport 53 redirect to :{port}\n        \
+                 ip daddr {dns} tcp dport 53 redirect to :{port}\n    \
+             }}\n\
+         }}\n",
+        table = NFT_TABLE,
+        dns = LOCAL_DNS,
+        port = FORWARDER_PORT,
+    )
+}
+
+/// Through `sudo vpn-desktop tunnel dns route add`, see [`host_route`].
+fn add_host_route(addr: &IpAddr, interface: &str) -> Result<()> {
+    let addr = addr.to_string();
+    sudo::run(&["dns", "route", "add", "--interface", interface, &addr])
+}
+
+fn del_host_route(addr: &IpAddr, interface: &str) -> Result<()> {
+    let addr = addr.to_string();
+    sudo::run(&["dns", "route", "del", "--interface", interface, &addr])
+}
+
+/// Adds or removes the route of a single address through the tunnel
+/// `interface`, which must be one the app created. Run by the privileged
+/// helper, as root.
+#[cfg(target_os = "linux")]
+pub fn host_route(add: bool, addr: IpAddr, interface: &str) -> Result<()> {
+    use super::kernel::LINK_ALIAS;
+    use super::netlink::route::{self, Route, RouteTarget, RT_TABLE_MAIN};
+    use super::netlink::{Socket, NETLINK_ROUTE};
+
+    let mut rtnl = Socket::open(NETLINK_ROUTE).context("Failed to open rtnetlink socket")?;
+    let index = match route::link(&mut rtnl, interface)? {
+        Some(link) if link.alias.as_deref() == Some(LINK_ALIAS) => link.index,
+        _ => anyhow::bail!(crate::i18n::tr!("interface.error.gone", name = interface)),
+    };
+    let host = Route {
+        dst: addr,
+        prefix: if addr.is_ipv4() { 32 } else { 128 },
+        table: RT_TABLE_MAIN,
+        target: RouteTarget::Device(index),
+    };
+    let result = if add {
+        route::add_route(&mut rtnl, &host)
+    } else {
+        route::del_route(&mut rtnl, &host)
+    };
+    result.with_context(|| format!("Failed to update the route to {}", addr))
+}
+
+/// Current upstream resolvers, skipping local stubs. With systemd-resolved the
+/// real upstream list lives in `/run/systemd/resolve/resolv.conf`.
+pub fn system_resolvers() -> Vec<IpAddr> {
+    ["/run/systemd/resolve/resolv.conf", "/etc/resolv.conf"]
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| line.trim().strip_prefix("nameserver"))
+                .filter_map(|addr| addr.trim().parse::<IpAddr>().ok())
+                .filter(|addr| !addr.is_loopback())
+                .collect::<Vec<_>>()
+        })
+        .find(|servers| !servers.is_empty())
+        .unwrap_or_default()
+}
+
+/// Parses the comma-separated `DNS` field of a WireGuard config.
+pub fn parse_dns_list(dns: &str) -> Vec<IpAddr> {
+    dns.split(',')
+        .filter_map(|s| s.trim().parse().ok())
+        .collect()
+}
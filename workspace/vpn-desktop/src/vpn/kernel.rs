@@ -0,0 +1,346 @@
+//! Kernel WireGuard backend (Linux), replacing wg-quick.
+//!
+//! The interface is created and configured over netlink. Routing follows
+//! wg-quick: a default route (`/0` in `AllowedIPs`) goes into a dedicated
+//! table, used by packets without the tunnel fwmark, and a
+//! `suppress_prefixlength 0` rule keeps the more specific routes of the main
+//! table in use; other prefixes are routed in the main table. Everything is
+//! owned by the interface or identified by fixed priorities, so teardown
+//! never depends on what was recorded at startup.
+//!
+//! Needs root (CAP_NET_ADMIN); the GUI runs it through
+//! `sudo vpn-desktop tunnel up|down|refresh`.
+
+use super::netlink::route::{self, Route, RouteTarget, Rule, RT_TABLE_MAIN};
+use super::netlink::wireguard::{self, PeerConfig};
+use super::netlink::{Socket, NETLINK_GENERIC, NETLINK_ROUTE};
+use super::root;
+use super::tunnel::TunnelOptions;
+use crate::i18n::tr;
+use anyhow::{Context, Result};
+use base64::Engine;
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv6Addr, ToSocketAddrs};
+use std::path::Path;
+use std::time::SystemTime;
+use vpn_core::wireguard::WireGuardConfig;
+use zeroize::Zeroizing;
+
+/// Set on the encrypted packets of the tunnel, and id of the table holding
+/// its default route.
+pub const FWMARK: u32 = 0x1f1d;
+/// Checked before the per-application rules (`apps::RULE_PRIORITY` is 5000).
+const SUPPRESS_RULE_PRIORITY: u32 = 5090;
+const TUNNEL_RULE_PRIORITY: u32 = 5100;
+/// Interface alias marking the interfaces created by the app, so a name
+/// collision with the user's own tunnels is never mistaken for a leftover.
+pub const LINK_ALIAS: &str = "fire-vpn";
+
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+const RESOLV_CONF_BACKUP: &str = "/etc/resolv.conf.fire-vpn";
+const RESOLV_CONF_HEADER: &str = "# Generated by FIRE-VPN";
+const RESOLVED_SOCKET: &str = "/run/systemd/resolve/io.systemd.Resolve";
+
+pub fn up(config: &WireGuardConfig, options: &TunnelOptions, interface: &str) -> Result<()> {
+    let mut rtnl = Socket::open(NETLINK_ROUTE).context("Failed to open rtnetlink socket")?;
+
+    if let Some(link) = route::link(&mut rtnl, interface)? {
+        if link.alias.as_deref() != Some(LINK_ALIAS) {
+            let kind = link.kind.as_deref().unwrap_or(tr!("interface.system_kind"));
+            anyhow::bail!(tr!(
+                "interface.error.exists_kind",
+                name = interface,
+                kind = kind
+            ));
+        }
+        // Left over by a previous session
+        down(interface)?;
+    }
+
+    let mut genl = Socket::open(NETLINK_GENERIC).context("Failed to open netlink socket")?;
+    let family = wireguard::family_id(&mut genl).map_err(|e| {
+        if e.raw_os_error() == Some(libc::ENOENT) {
+            anyhow::anyhow!(tr!("interface.error.no_module"))
+        } else {
+            anyhow::Error::from(e)
+        }
+    })?;
+
+    let index = route::add_link(&mut rtnl, interface, "wireguard")
+        .with_context(|| format!("Failed to create interface {}", interface))?;
+    if let Err(e) = route::set_alias(&mut rtnl, index, LINK_ALIAS) {
+        let _ = route::del_link(&mut rtnl, index);
+        return Err(e.into());
+    }
+
+    let result = configure(
+        &mut rtnl, &mut genl, family, index, config, options, interface,
+    );
+    if result.is_err() {
+        let _ = down(interface);
+    }
+    result
+}
+
+fn configure(
+    rtnl: &mut Socket,
+    genl: &mut Socket,
+    family: u16,
+    index: u32,
+    config: &WireGuardConfig,
+    options: &TunnelOptions,
+    interface: &str,
+) -> Result<()> {
+    let peer = peer_config(config)?;
+    let private_key = decode_key(config.private_key.expose())?;
+    wireguard::set_device(genl, family, interface, &private_key, FWMARK, &peer)
+        .context("Failed to configure WireGuard device")?;
+
+    for (addr, prefix) in config.interface_addresses()? {
+        route::add_address(rtnl, index, addr, prefix)
+            .with_context(|| format!("Failed to add address {}/{}", addr, prefix))?;
+    }
+    let mtu = options.mtu.unwrap_or(crate::preferences::DEFAULT_MTU);
+    route::set_link_up(rtnl, index, mtu.into())?;
+    install_routes(rtnl, index, &peer.allowed_ips, options)?;
+    set_dns(interface, &dns_servers(config))
+}
+
+/// Brings a running tunnel back after a network change or a resume: the
+/// endpoint is resolved again, the peer is replaced (dropping the stale
+/// session, so the next packet starts a handshake) and the routes, rules
+/// and DNS are put back where the new network may have removed them.
+pub fn refresh(config: &WireGuardConfig, options: &TunnelOptions, interface: &str) -> Result<()> {
+    let mut rtnl = Socket::open(NETLINK_ROUTE).context("Failed to open rtnetlink socket")?;
+    let index = match route::link(&mut rtnl, interface)? {
+        Some(link) if link.alias.as_deref() == Some(LINK_ALIAS) => link.index,
+        _ => anyhow::bail!(tr!("interface.error.gone", name = interface)),
+    };
+
+    let mut genl = Socket::open(NETLINK_GENERIC).context("Failed to open netlink socket")?;
+    let family = wireguard::family_id(&mut genl)?;
+    let peer = peer_config(config)?;
+    let private_key = decode_key(config.private_key.expose())?;
+    wireguard::set_device(&mut genl, family, interface, &private_key, FWMARK, &peer)
+        .context("Failed to configure WireGuard device")?;
+
+    let mtu = options.mtu.unwrap_or(crate::preferences::DEFAULT_MTU);
+    route::set_link_up(&mut rtnl, index, mtu.into())?;
+    install_routes(&mut rtnl, index, &peer.allowed_ips, options)?;
+    set_dns(interface, &dns_servers(config))
+}
+
+/// Peer of the config, its endpoint resolved now.
+fn peer_config(config: &WireGuardConfig) -> Result<PeerConfig> {
+    let allowed_ips = config
+        .allowed_cidrs()?
+        .iter()
+        .map(|c| (c.addr(), c.prefix()))
+        .collect();
+    let endpoint = config
+        .endpoint
+        .to_socket_addrs()
+        .with_context(|| format!("Invalid endpoint {}", config.endpoint))?
+        .next()
+        .with_context(|| format!("Invalid endpoint {}", config.endpoint))?;
+    Ok(PeerConfig {
+        public_key: *decode_key(&config.peer_public_key)?,
+        endpoint,
+        persistent_keepalive: config.persistent_keepalive,
+        allowed_ips,
+    })
+}
+
+/// Idempotent: routes are replaced and rules deleted before being added.
+fn install_routes(
+    rtnl: &mut Socket,
+    index: u32,
+    allowed: &[(IpAddr, u8)],
+    options: &TunnelOptions,
+) -> Result<()> {
+    if !options.manual_routes {
+        for &(dst, prefix) in allowed {
+            let table = if prefix == 0 { FWMARK } else { RT_TABLE_MAIN };
+            route::add_route(
+                rtnl,
+                &Route {
+                    dst,
+                    prefix,
+                    table,
+                    target: RouteTarget::Device(index),
+                },
+            )
+            .with_context(|| format!("Failed to add route {}/{}", dst, prefix))?;
+        }
+        for ipv6 in [false, true] {
+            let default_route = allowed
+                .iter()
+                .any(|(addr, prefix)| *prefix == 0 && addr.is_ipv6() == ipv6);
+            if default_route {
+                add_policy_rules(rtnl, ipv6)?;
+            }
+        }
+    }
+
+    if options.block_ipv6 {
+        for route in ipv6_blackholes() {
+            // Fails when IPv6 is disabled, nothing can leak then
+            let _ = route::add_route(rtnl, &route);
+        }
+    }
+    Ok(())
+}
+
+fn dns_servers(config: &WireGuardConfig) -> Vec<IpAddr> {
+    config
+        .dns
+        .split(',')
+        .filter_map(|s| s.trim().parse().ok())
+        .collect()
+}
+
+/// Same as wg-quick: `not fwmark FWMARK lookup FWMARK` and
+/// `lookup main suppress_prefixlength 0`.
+fn add_policy_rules(rtnl: &mut Socket, ipv6: bool) -> Result<()> {
+    for rule in policy_rules(ipv6) {
+        // Stale rule from a session that did not shut down
+        let _ = route::del_rule(rtnl, &rule);
+        route::add_rule(rtnl, &rule).context("Failed to add routing rule")?;
+    }
+    if !ipv6 {
+        // Replies to the marked packets must pass reverse path filtering
+        fs::write("/proc/sys/net/ipv4/conf/all/src_valid_mark", "1")
+            .context("Failed to enable src_valid_mark")?;
+    }
+    Ok(())
+}
+
+fn policy_rules(ipv6: bool) -> [Rule; 2] {
+    [
+        Rule {
+            ipv6,
+            priority: SUPPRESS_RULE_PRIORITY,
+            table: RT_TABLE_MAIN,
+            not_fwmark: None,
+            suppress_prefixlength: Some(0),
+        },
+        Rule {
+            ipv6,
+            priority: TUNNEL_RULE_PRIORITY,
+            table: FWMARK,
+            not_fwmark: Some(FWMARK),
+            suppress_prefixlength: None,
+        },
+    ]
+}
+
+/// Two /1 routes win over the default route without replacing it; on-link
+/// prefixes stay reachable.
+fn ipv6_blackholes() -> [Route; 2] {
+    [
+        Ipv6Addr::UNSPECIFIED,
+        Ipv6Addr::new(0x8000, 0, 0, 0, 0, 0, 0, 0),
+    ]
+    .map(|addr| Route {
+        dst: IpAddr::V6(addr),
+        prefix: 1,
+        table: RT_TABLE_MAIN,
+        target: RouteTarget::Blackhole,
+    })
+}
+
+/// Removes the rules, blackholes, DNS settings and interface. Safe to call
+/// when the tunnel is partially or not at all set up.
+pub fn down(interface: &str) -> Result<()> {
+    let mut rtnl = Socket::open(NETLINK_ROUTE).context("Failed to open rtnetlink socket")?;
+
+    for ipv6 in [false, true] {
+        for rule in policy_rules(ipv6) {
+            ignore_missing(route::del_rule(&mut rtnl, &rule))?;
+        }
+    }
+    for route in ipv6_blackholes() {
+        let _ = route::del_route(&mut rtnl, &route);
+    }
+    restore_dns()?;
+
+    // Addresses and routes go away with the interface
+    if let Some(link) = route::link(&mut rtnl, interface)? {
+        if link.alias.as_deref() == Some(LINK_ALIAS) {
+            route::del_link(&mut rtnl, link.index)
+                .with_context(|| format!("Failed to delete interface {}", interface))?;
+        }
+    }
+    Ok(())
+}
+
+/// Latest handshake with the peer.
+pub fn last_handshake(interface: &str) -> Result<Option<SystemTime>> {
+    let mut genl = Socket::open(NETLINK_GENERIC)?;
+    let family = wireguard::family_id(&mut genl)?;
+    let peers = wireguard::peers(&mut genl, family, interface)?;
+    Ok(peers.iter().filter_map(|p| p.last_handshake).max())
+}
+
+fn ignore_missing(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(e) if e.raw_os_error() == Some(libc::ENOENT) => Ok(()),
+        other => other,
+    }
+}
+
+fn decode_key(key: &str) -> Result<Zeroizing<[u8; 32]>> {
+    let bytes = Zeroizing::new(
+        base64::engine::general_purpose::STANDARD
+            .decode(key.trim())
+            .context("Invalid WireGuard key")?,
+    );
+    let key: [u8; 32] = bytes
+        .as_slice()
+        .try_into()
+        .ok()
+        .context("Invalid WireGuard key")?;
+    Ok(Zeroizing::new(key))
+}
+
+// ── DNS ────────────────────────────────────────────────────────────────────────
+
+/// With systemd-resolved the servers are attached to the interface and
+/// vanish with it. Otherwise `/etc/resolv.conf` is swapped for ours and the
+/// original (file or symlink) is kept next to it until `restore_dns`.
+fn set_dns(interface: &str, servers: &[IpAddr]) -> Result<()> {
+    if servers.is_empty() {
+        return Ok(());
+    }
+
+    if Path::new(RESOLVED_SOCKET).exists() {
+        let servers: Vec<String> = servers.iter().map(IpAddr::to_string).collect();
+        let mut args = vec!["resolvectl", "dns", interface];
+        args.extend(servers.iter().map(String::as_str));
+        root::run(&args)?;
+        root::run(&["resolvectl", "domain", interface, "~."])?;
+        return Ok(());
+    }
+
+    // A network change may have rewritten it since: that version is the one
+    // to restore
+    let ours = fs::read_to_string(RESOLV_CONF).is_ok_and(|c| c.starts_with(RESOLV_CONF_HEADER));
+    let backed_up = Path::new(RESOLV_CONF_BACKUP).exists();
+    if (!backed_up || !ours) && fs::symlink_metadata(RESOLV_CONF).is_ok() {
+        fs::rename(RESOLV_CONF, RESOLV_CONF_BACKUP).context("Failed to back up resolv.conf")?;
+    }
+    let mut content = format!("{}\n", RESOLV_CONF_HEADER);
+    for server in servers {
+        content.push_str(&format!("nameserver {}\n", server));
+    }
+    fs::write(RESOLV_CONF, content).context("Failed to write resolv.conf")?;
+    Ok(())
+}
+
+fn restore_dns() -> Result<()> {
+    if Path::new(RESOLV_CONF_BACKUP).exists() {
+        fs::rename(RESOLV_CONF_BACKUP, RESOLV_CONF).context("Failed to restore resolv.conf")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Kill switch (Linux only).
+//!
+//! While active, an nftables output chain drops everything that leaves
+//! neither through the tunnel interface nor as the tunnel's own encrypted
+//! packets (marked with the WireGuard fwmark). If the tunnel goes down
+//! without the user disconnecting, traffic stops instead of silently
+//! falling back to the physical network. Split tunneling exceptions, the
+//! bypassed applications, DHCP and IPv6 neighbour discovery stay allowed.
+//! The table is loaded by the privileged helper
+//! (`vpn-desktop tunnel killswitch ...`).
+#![cfg_attr(not(target_os = "linux"), allow(dead_code))]
+
+#[cfg(target_os = "linux")]
+use super::root;
+use super::sudo;
+use anyhow::Result;
+use vpn_core::cidr::Cidr;
+
+const NFT_TABLE: &str = "fire_vpn_killswitch";
+
+pub fn is_supported() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// Active kill switch; dropping it lets traffic out again.
+pub struct KillSwitch;
+
+impl KillSwitch {
+    /// `allowed` are the destinations excluded from the tunnel.
+    pub fn start(interface: &str, allowed: &[Cidr]) -> Result<Self> {
+        activate(interface, allowed)?;
+        Ok(Self)
+    }
+
+    /// Replaces the rules atomically, e.g. after a server switch.
+    pub fn update(&self, interface: &str, allowed: &[Cidr]) -> Result<()> {
+        activate(interface, allowed)
+    }
+}
+
+impl Drop for KillSwitch {
+    fn drop(&mut self) {
+        deactivate();
+    }
+}
+
+/// Through `sudo vpn-desktop tunnel killswitch up`, see [`apply`].
+#[cfg(target_os = "linux")]
+fn activate(interface: &str, allowed: &[Cidr]) -> Result<()> {
+    let allowed: Vec<String> = allowed.iter().map(Cidr::to_string).collect();
+    let mut args = vec!["killswitch", "up", "--interface", interface];
+    for cidr in &allowed {
+        args.extend(["--allow", cidr.as_str()]);
+    }
+    sudo::run(&args)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn activate(_interface: &str, _allowed: &[Cidr]) -> Result<()> {
+    anyhow::bail!("the kill switch is only supported on Linux")
+}
+
+/// Lets traffic out again, also after a session that did not shut down.
+pub fn deactivate() {
+    if is_supported() {
+        let _ = sudo::run(&["killswitch", "down"]);
+    }
+}
+
+/// Loads or replaces the table. Run by the privileged helper, as root.
+#[cfg(target_os = "linux")]
+pub fn apply(interface: &str, allowed: &[Cidr]) -> Result<()> {
+    root::run_with_input(&["nft", "-f", "-"], &nft_ruleset(interface, allowed))
+}
+
+/// Removes the table. Run by the privileged helper, as root.
+#[cfg(target_os = "linux")]
+pub fn remove() {
+    let _ = root::run(&["nft", "delete", "table", "inet", NFT_TABLE]);
+}
+
+#[cfg(target_os = "linux")]
+fn nft_ruleset(interface: &str, allowed: &[Cidr]) -> String {
+    let mut chain = String::new();
+    chain.push_str("        oifname \"lo\" accept\n");
+    chain.push_str(&format!("        oifname \"{}\" accept\n", interface));
+    chain.push_str(&format!(
+        "        meta mark {:#x} accept\n",
+        super::kernel::FWMARK
+    ));
+    chain.push_str(&format!(
+        "        meta mark {:#x} accept\n",
+        super::apps::BYPASS_MARK
+    ));
+    chain.push_str("        udp sport 68 udp dport 67 accept\n");
+    chain.push_str("        udp sport 546 udp dport 547 accept\n");
+    chain.push_str(
+        "        icmpv6 type { nd-router-solicit, nd-neighbor-solicit, nd-neighbor-advert } accept\n",
+    );
+    for cidr in allowed {
+        let family = if cidr.addr().is_ipv4() { "ip" } else { "ip6" };
+        chain.push_str(&format!("        {} daddr {} accept\n", family, cidr));
+    }
+
+    format!(
+        "table inet {table}\n\
+         delete table inet {table}\n\
+         table inet {table} {{\n    \
+             chain output {{\n        \
+                 type filter hook output priority filter; policy drop;\n\
+                 {chain}    \
+             }}\n\
+         }}\n",
+        table = NFT_TABLE,
+        chain = chain,
+    )
+}
@@ -1 +1,15 @@
+pub mod apps;
+pub mod domains;
+#[cfg(target_os = "linux")]
+pub mod kernel;
+pub mod killswitch;
+pub mod monitor;
+#[cfg(target_os = "linux")]
+mod netlink;
+pub mod proxy;
+pub mod recovery;
+#[cfg(target_os = "linux")]
+mod root;
+pub mod stats;
+pub mod sudo;
 pub mod tunnel;
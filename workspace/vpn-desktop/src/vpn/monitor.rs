@@ -0,0 +1,288 @@
+//! Repairs the kernel tunnel after a network change or a resume (Linux).
+//!
+//! After a suspend, or when the default route moves from Ethernet to Wi-Fi,
+//! the peer keeps the old endpoint address and session, and the routes or
+//! DNS may have been rewritten by the network manager: the tunnel dies
+//! without an error. [`LinkMonitor`] watches rtnetlink for links and default
+//! routes and systemd-logind for `PrepareForSleep`; [`repair`] then
+//! refreshes the tunnel and waits for a new handshake, reporting each step.
+#![cfg_attr(not(target_os = "linux"), allow(dead_code, unused_imports))]
+
+#[cfg(target_os = "linux")]
+use super::kernel::{FWMARK, LINK_ALIAS};
+#[cfg(target_os = "linux")]
+use super::netlink::route::{
+    self, Change, RTMGRP_IPV4_ROUTE, RTMGRP_IPV6_ROUTE, RTMGRP_LINK, RT_TABLE_MAIN,
+};
+#[cfg(target_os = "linux")]
+use super::netlink::{Socket, NETLINK_ROUTE};
+use super::tunnel::{self, TunnelOptions};
+use crate::i18n::tr;
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use vpn_core::wireguard::WireGuardConfig;
+
+/// Changes come in bursts (link, address, routes): wait for them to settle.
+const SETTLE_DELAY: Duration = Duration::from_secs(2);
+const RECV_TIMEOUT: Duration = Duration::from_millis(500);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Something that may have broken the tunnel.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetworkEvent {
+    /// Links or default routes changed, with what was seen last.
+    Changed(String),
+    Suspending,
+    Resumed,
+}
+
+impl NetworkEvent {
+    pub fn label(&self) -> String {
+        match self {
+            NetworkEvent::Changed(what) => tr!("repair.network_changed", what = what),
+            NetworkEvent::Suspending => tr!("repair.suspending").to_string(),
+            NetworkEvent::Resumed => tr!("repair.resumed").to_string(),
+        }
+    }
+}
+
+/// Watches the network and sleep in the background; stops when dropped.
+pub struct LinkMonitor {
+    events: Receiver<NetworkEvent>,
+    stop: Arc<AtomicBool>,
+}
+
+impl LinkMonitor {
+    /// `on_event` runs on the monitor threads after each event, to wake the UI.
+    /// `None` where the kernel backend does not exist.
+    #[cfg(target_os = "linux")]
+    pub fn start(on_event: impl Fn() + Send + Sync + 'static) -> Option<Self> {
+        let (sender, events) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let on_event = Arc::new(on_event);
+
+        let (links, stopped, wake) = (sender.clone(), Arc::clone(&stop), Arc::clone(&on_event));
+        std::thread::spawn(move || {
+            if let Err(e) = watch_links(&links, &stopped, &*wake) {
+                tracing::warn!(error = %e, "cannot watch network changes");
+            }
+        });
+        std::thread::spawn(move || {
+            if let Err(e) = watch_sleep(&sender, &*on_event) {
+                tracing::info!(error = %e, "cannot watch suspend and resume");
+            }
+        });
+        Some(Self { events, stop })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn start(_on_event: impl Fn() + Send + Sync + 'static) -> Option<Self> {
+        None
+    }
+
+    /// Events since the previous call.
+    pub fn poll(&self) -> Vec<NetworkEvent> {
+        self.events.try_iter().collect()
+    }
+}
+
+impl Drop for LinkMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn watch_links(
+    sender: &Sender<NetworkEvent>,
+    stop: &AtomicBool,
+    on_event: &dyn Fn(),
+) -> std::io::Result<()> {
+    let mut socket = Socket::subscribe(
+        NETLINK_ROUTE,
+        RTMGRP_LINK | RTMGRP_IPV4_ROUTE | RTMGRP_IPV6_ROUTE,
+        RECV_TIMEOUT,
+    )?;
+    // Carrier of each link, to tell state changes from other attributes
+    let mut carrier = HashMap::new();
+    // Our tunnels, whose own changes are not a reason to repair them
+    let mut ours = HashSet::new();
+    for link in route::links(&mut Socket::open(NETLINK_ROUTE)?)? {
+        if link.alias.as_deref() == Some(LINK_ALIAS) {
+            ours.insert(link.index);
+        }
+        carrier.insert(link.index, link.carrier);
+    }
+
+    let mut pending: Option<(String, Instant)> = None;
+    while !stop.load(Ordering::Relaxed) {
+        for (kind, payload) in socket.events()? {
+            let what = match route::parse_change(kind, &payload) {
+                Some(Change::Link(link)) => {
+                    if link.alias.as_deref() == Some(LINK_ALIAS) {
+                        ours.insert(link.index);
+                        None
+                    } else if link.name == "lo"
+                        || ours.contains(&link.index)
+                        || carrier.insert(link.index, link.carrier) == Some(link.carrier)
+                    {
+                        None
+                    } else if link.carrier {
+                        Some(tr!("repair.link_up", name = link.name))
+                    } else {
+                        Some(tr!("repair.link_down", name = link.name))
+                    }
+                }
+                Some(Change::DefaultRoute {
+                    ipv6,
+                    table,
+                    device,
+                }) => {
+                    let tunnel = table == FWMARK || device.is_some_and(|d| ours.contains(&d));
+                    (table == RT_TABLE_MAIN && !tunnel).then(|| {
+                        let family = if ipv6 { "IPv6" } else { "IPv4" };
+                        tr!("repair.default_route", family = family)
+                    })
+                }
+                Some(Change::Lost) => Some(tr!("repair.events_lost").to_string()),
+                None => None,
+            };
+            if let Some(what) = what {
+                tracing::debug!(change = %what, "network change");
+                pending = Some((what, Instant::now()));
+            }
+        }
+        if let Some((what, at)) = &pending {
+            if at.elapsed() >= SETTLE_DELAY {
+                tracing::info!(change = %what, "network changed");
+                if sender.send(NetworkEvent::Changed(what.clone())).is_err() {
+                    return Ok(());
+                }
+                on_event();
+                pending = None;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Blocks on logind's signals; only returns once the app is gone.
+#[cfg(target_os = "linux")]
+fn watch_sleep(sender: &Sender<NetworkEvent>, on_event: &dyn Fn()) -> zbus::Result<()> {
+    let conn = zbus::blocking::Connection::system()?;
+    let proxy: zbus::blocking::Proxy = zbus::blocking::ProxyBuilder::new(&conn)
+        .destination("org.freedesktop.login1")?
+        .path("/org/freedesktop/login1")?
+        .interface("org.freedesktop.login1.Manager")?
+        .cache_properties(zbus::CacheProperties::No)
+        .build()?;
+    for message in proxy.receive_signal("PrepareForSleep")? {
+        let event = match message.body().deserialize::<bool>()? {
+            true => NetworkEvent::Suspending,
+            false => NetworkEvent::Resumed,
+        };
+        tracing::info!(event = ?event, "sleep state");
+        if sender.send(event).is_err() {
+            break;
+        }
+        on_event();
+    }
+    Ok(())
+}
+
+/// Progress of [`repair`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepairStep {
+    Detected(NetworkEvent),
+    Refreshing,
+    WaitingHandshake,
+    Restored,
+    /// The tunnel could not be brought back, the caller reconnects.
+    Failed(String),
+    /// Reconnecting to the server, by name.
+    Reconnecting(String),
+}
+
+impl RepairStep {
+    pub fn label(&self) -> String {
+        match self {
+            RepairStep::Detected(event) => event.label(),
+            RepairStep::Refreshing => tr!("repair.refreshing").to_string(),
+            RepairStep::WaitingHandshake => tr!("repair.waiting_handshake").to_string(),
+            RepairStep::Restored => tr!("repair.restored").to_string(),
+            RepairStep::Failed(reason) => tr!("repair.failed", reason = reason),
+            RepairStep::Reconnecting(server) => tr!("repair.reconnecting", server = server),
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        matches!(self, RepairStep::Restored | RepairStep::Failed(_))
+    }
+}
+
+/// Refreshes the tunnel, then makes it send traffic and waits up to
+/// [`HANDSHAKE_TIMEOUT`] for a handshake newer than the refresh. Blocking.
+#[cfg(target_os = "linux")]
+pub fn repair(
+    config: &WireGuardConfig,
+    options: &TunnelOptions,
+    interface: &str,
+    report: impl Fn(RepairStep),
+) {
+    report(RepairStep::Refreshing);
+    let started = SystemTime::now();
+    if let Err(e) = tunnel::refresh_tunnel(config, options, interface) {
+        tracing::warn!(interface, error = %e, "tunnel refresh failed");
+        report(RepairStep::Failed(format!("{:#}", e)));
+        return;
+    }
+
+    report(RepairStep::WaitingHandshake);
+    let deadline = Instant::now() + HANDSHAKE_TIMEOUT;
+    while Instant::now() < deadline {
+        poke(config);
+        if tunnel::last_handshake(interface).is_some_and(|t| t >= started) {
+            tracing::info!(interface, "tunnel restored");
+            report(RepairStep::Restored);
+            return;
+        }
+        std::thread::sleep(Duration::from_secs(1));
+    }
+    tracing::warn!(interface, "no handshake after the refresh");
+    report(RepairStep::Failed(tr!("repair.no_answer").to_string()));
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn repair(
+    _config: &WireGuardConfig,
+    _options: &TunnelOptions,
+    _interface: &str,
+    report: impl Fn(RepairStep),
+) {
+    report(RepairStep::Failed(tr!("repair.unsupported").to_string()));
+}
+
+/// The peer was replaced with its session: without a keepalive, only
+/// outgoing traffic starts a handshake. An empty datagram to the tunnel's
+/// DNS servers is enough.
+fn poke(config: &WireGuardConfig) {
+    let servers = config
+        .dns
+        .split(',')
+        .filter_map(|s| s.trim().parse::<IpAddr>().ok());
+    for server in servers {
+        let local: SocketAddr = match server {
+            IpAddr::V4(_) => "0.0.0.0:0",
+            IpAddr::V6(_) => "[::]:0",
+        }
+        .parse()
+        .expect("valid wildcard address");
+        if let Ok(socket) = UdpSocket::bind(local) {
+            let _ = socket.send_to(&[], (server, 53));
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Minimal netlink client: message encoding, attribute parsing and the
+//! request/acknowledgement exchange. `route` speaks rtnetlink (links,
+//! addresses, routes, rules) and `wireguard` the WireGuard generic netlink
+//! family. Constants mirror the kernel UAPI headers.
+
+pub mod route;
+pub mod wireguard;
+
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::time::Duration;
+
+pub const NETLINK_ROUTE: i32 = 0;
+pub const NETLINK_GENERIC: i32 = 16;
+
+const NLMSG_HDRLEN: usize = 16;
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+
+pub const NLM_F_REQUEST: u16 = 0x1;
+const NLM_F_ACK: u16 = 0x4;
+pub const NLM_F_DUMP: u16 = 0x300;
+pub const NLM_F_REPLACE: u16 = 0x100;
+pub const NLM_F_EXCL: u16 = 0x200;
+pub const NLM_F_CREATE: u16 = 0x400;
+
+const NLA_HDRLEN: usize = 4;
+const NLA_F_NESTED: u16 = 0x8000;
+const NLA_TYPE_MASK: u16 = 0x3fff;
+
+const RECV_BUFFER: usize = 32 * 1024;
+
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// A netlink message being built: header, fixed family header, attributes.
+/// An attribute longer than its 16-bit length field marks the message as
+/// too long, [`Socket::request`] then refuses to send it.
+pub struct Message {
+    buf: Vec<u8>,
+    nests: Vec<usize>,
+    too_long: bool,
+}
+
+impl Message {
+    pub fn new(kind: u16, flags: u16) -> Self {
+        let mut buf = vec![0u8; NLMSG_HDRLEN];
+        buf[4..6].copy_from_slice(&kind.to_ne_bytes());
+        buf[6..8].copy_from_slice(&(flags | NLM_F_REQUEST).to_ne_bytes());
+        Self {
+            buf,
+            nests: Vec::new(),
+            too_long: false,
+        }
+    }
+
+    /// Family header (`ifinfomsg`, `rtmsg`, `genlmsghdr`...).
+    pub fn header(mut self, bytes: &[u8]) -> Self {
+        self.buf.extend_from_slice(bytes);
+        self.pad();
+        self
+    }
+
+    pub fn attr(mut self, kind: u16, value: &[u8]) -> Self {
+        let len = self.attr_len(NLA_HDRLEN + value.len());
+        self.buf.extend_from_slice(&len.to_ne_bytes());
+        self.buf.extend_from_slice(&kind.to_ne_bytes());
+        self.buf.extend_from_slice(value);
+        self.pad();
+        self
+    }
+
+    pub fn attr_u8(self, kind: u16, value: u8) -> Self {
+        self.attr(kind, &[value])
+    }
+
+    pub fn attr_u16(self, kind: u16, value: u16) -> Self {
+        self.attr(kind, &value.to_ne_bytes())
+    }
+
+    pub fn attr_u32(self, kind: u16, value: u32) -> Self {
+        self.attr(kind, &value.to_ne_bytes())
+    }
+
+    /// NUL-terminated string attribute.
+    pub fn attr_str(self, kind: u16, value: &str) -> Self {
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.push(0);
+        self.attr(kind, &bytes)
+    }
+
+    /// Opens a nested attribute, closed by [`Message::end_nested`].
+    pub fn nested(mut self, kind: u16) -> Self {
+        self.nests.push(self.buf.len());
+        self.buf.extend_from_slice(&0u16.to_ne_bytes());
+        self.buf
+            .extend_from_slice(&(kind | NLA_F_NESTED).to_ne_bytes());
+        self
+    }
+
+    pub fn end_nested(mut self) -> Self {
+        if let Some(start) = self.nests.pop() {
+            let len = self.attr_len(self.buf.len() - start);
+            self.buf[start..start + 2].copy_from_slice(&len.to_ne_bytes());
+        }
+        self
+    }
+
+    fn attr_len(&mut self, len: usize) -> u16 {
+        u16::try_from(len).unwrap_or_else(|_| {
+            self.too_long = true;
+            0
+        })
+    }
+
+    fn pad(&mut self) {
+        let padded = align(self.buf.len());
+        self.buf.resize(padded, 0);
+    }
+
+    fn finish(mut self, seq: u32) -> Vec<u8> {
+        let len = self.buf.len() as u32;
+        self.buf[0..4].copy_from_slice(&len.to_ne_bytes());
+        self.buf[8..12].copy_from_slice(&seq.to_ne_bytes());
+        self.buf
+    }
+}
+
+/// Iterates over the attributes of a message payload: `(type, value)`.
+pub fn attrs(mut buf: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+    std::iter::from_fn(move || {
+        if buf.len() < NLA_HDRLEN {
+            return None;
+        }
+        let len = u16::from_ne_bytes([buf[0], buf[1]]) as usize;
+        let kind = u16::from_ne_bytes([buf[2], buf[3]]) & NLA_TYPE_MASK;
+        if len < NLA_HDRLEN || len > buf.len() {
+            return None;
+        }
+        let value = &buf[NLA_HDRLEN..len];
+        buf = &buf[align(len).min(buf.len())..];
+        Some((kind, value))
+    })
+}
+
+pub fn attr_u64(value: &[u8]) -> Option<u64> {
+    Some(u64::from_ne_bytes(value.get(..8)?.try_into().ok()?))
+}
+
+pub fn attr_str(value: &[u8]) -> String {
+    let end = value.iter().position(|&b| b == 0).unwrap_or(value.len());
+    String::from_utf8_lossy(&value[..end]).into_owned()
+}
+
+pub struct Socket {
+    fd: OwnedFd,
+    seq: u32,
+}
+
+impl Socket {
+    pub fn open(protocol: i32) -> io::Result<Self> {
+        Self::bind(protocol, 0)
+    }
+
+    /// Socket receiving the notifications of the given multicast groups
+    /// (`RTMGRP_*`); [`Socket::events`] returns empty after `timeout` of
+    /// silence so that the reader can check whether to stop.
+    pub fn subscribe(protocol: i32, groups: u32, timeout: Duration) -> io::Result<Self> {
+        let socket = Self::bind(protocol, groups)?;
+        let tv = libc::timeval {
+            tv_sec: timeout.as_secs() as libc::time_t,
+            tv_usec: timeout.subsec_micros() as libc::suseconds_t,
+        };
+        // SAFETY: `tv` is a valid timeval of the given size
+        let ret = unsafe {
+            libc::setsockopt(
+                socket.fd.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_RCVTIMEO,
+                &tv as *const libc::timeval as *const libc::c_void,
+                std::mem::size_of::<libc::timeval>() as libc::socklen_t,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(socket)
+    }
+
+    fn bind(protocol: i32, groups: u32) -> io::Result<Self> {
+        // SAFETY: plain socket(2) call, the descriptor is owned right away
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                protocol,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: `fd` is a freshly created, valid descriptor
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        // SAFETY: sockaddr_nl is plain data, zero means "kernel picks the port"
+        let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        addr.nl_groups = groups;
+        // SAFETY: `addr` is a valid sockaddr_nl of the given size
+        let ret = unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { fd, seq: 1 })
+    }
+
+    /// Next batch of notifications of a [`Socket::subscribe`] socket:
+    /// `(message type, payload)`. Empty when the timeout expired; lost
+    /// notifications (`ENOBUFS`) are reported as a single empty message
+    /// of type 0, the state must then be read again.
+    pub fn events(&mut self) -> io::Result<Vec<(u16, Vec<u8>)>> {
+        let mut buf = vec![0u8; RECV_BUFFER];
+        // SAFETY: `buf` is a valid, writable buffer of the given length
+        let len = unsafe {
+            libc::recv(
+                self.fd.as_raw_fd(),
+                buf.as_mut_ptr() as *mut libc::c_void,
+                buf.len(),
+                0,
+            )
+        };
+        if len < 0 {
+            let error = io::Error::last_os_error();
+            return match error.raw_os_error() {
+                Some(libc::EAGAIN) | Some(libc::EINTR) => Ok(Vec::new()),
+                Some(libc::ENOBUFS) => Ok(vec![(0, Vec::new())]),
+                _ => Err(error),
+            };
+        }
+
+        let mut events = Vec::new();
+        let mut chunk = &buf[..len as usize];
+        while chunk.len() >= NLMSG_HDRLEN {
+            let msg_len = u32::from_ne_bytes(chunk[0..4].try_into().unwrap()) as usize;
+            let kind = u16::from_ne_bytes([chunk[4], chunk[5]]);
+            if msg_len < NLMSG_HDRLEN || msg_len > chunk.len() {
+                break;
+            }
+            events.push((kind, chunk[NLMSG_HDRLEN..msg_len].to_vec()));
+            chunk = &chunk[align(msg_len).min(chunk.len())..];
+        }
+        Ok(events)
+    }
+
+    /// Sends a request and returns the payloads of the reply messages (after
+    /// the netlink header). Dumps are read until `NLMSG_DONE`, other requests
+    /// until the acknowledgement; a negative acknowledgement becomes the
+    /// matching OS error (`EEXIST`, `ENODEV`...). A message with an attribute
+    /// too long to encode is not sent.
+    pub fn request(&mut self, mut message: Message) -> io::Result<Vec<Vec<u8>>> {
+        if message.too_long {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "netlink attribute longer than 65535 bytes",
+            ));
+        }
+        let flags = u16::from_ne_bytes([message.buf[6], message.buf[7]]);
+        if flags & NLM_F_DUMP != NLM_F_DUMP {
+            message.buf[6..8].copy_from_slice(&(flags | NLM_F_ACK).to_ne_bytes());
+        }
+
+        self.seq = self.seq.wrapping_add(1);
+        let seq = self.seq;
+        let bytes = message.finish(seq);
+        // SAFETY: `bytes` is a valid buffer of the given length
+        let sent = unsafe {
+            libc::send(
+                self.fd.as_raw_fd(),
+                bytes.as_ptr() as *const libc::c_void,
+                bytes.len(),
+                0,
+            )
+        };
+        if sent < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut replies = Vec::new();
+        let mut buf = vec![0u8; RECV_BUFFER];
+        loop {
+            // SAFETY: `buf` is a valid, writable buffer of the given length
+            let len = unsafe {
+                libc::recv(
+                    self.fd.as_raw_fd(),
+                    buf.as_mut_ptr() as *mut libc::c_void,
+                    buf.len(),
+                    0,
+                )
+            };
+            if len < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let mut chunk = &buf[..len as usize];
+
+            while chunk.len() >= NLMSG_HDRLEN {
+                let msg_len = u32::from_ne_bytes(chunk[0..4].try_into().unwrap()) as usize;
+                let kind = u16::from_ne_bytes([chunk[4], chunk[5]]);
+                let msg_seq = u32::from_ne_bytes(chunk[8..12].try_into().unwrap());
+                if msg_len < NLMSG_HDRLEN || msg_len > chunk.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "truncated netlink message",
+                    ));
+                }
+                let payload = &chunk[NLMSG_HDRLEN..msg_len];
+                chunk = &chunk[align(msg_len).min(chunk.len())..];
+
+                if msg_seq != seq {
+                    continue;
+                }
+                match kind {
+                    NLMSG_DONE => return Ok(replies),
+                    NLMSG_ERROR => {
+                        let code = payload
+                            .get(..4)
+                            .map(|b| i32::from_ne_bytes(b.try_into().unwrap()))
+                            .unwrap_or(0);
+                        if code == 0 {
+                            return Ok(replies);
+                        }
+                        return Err(io::Error::from_raw_os_error(-code));
+                    }
+                    _ => replies.push(payload.to_vec()),
+                }
+            }
+        }
+    }
+}
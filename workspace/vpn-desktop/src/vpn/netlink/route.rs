@@ -0,0 +1,328 @@
+//! rtnetlink: links, addresses, routes and policy routing rules.
+
+use super::{
+    attr_str, attrs, Message, Socket, NLM_F_CREATE, NLM_F_DUMP, NLM_F_EXCL, NLM_F_REPLACE,
+};
+use std::io;
+use std::net::IpAddr;
+
+const RTM_NEWLINK: u16 = 16;
+const RTM_DELLINK: u16 = 17;
+const RTM_GETLINK: u16 = 18;
+const RTM_NEWADDR: u16 = 20;
+const RTM_NEWROUTE: u16 = 24;
+const RTM_DELROUTE: u16 = 25;
+const RTM_NEWRULE: u16 = 32;
+const RTM_DELRULE: u16 = 33;
+
+const IFLA_IFNAME: u16 = 3;
+const IFLA_MTU: u16 = 4;
+const IFLA_LINKINFO: u16 = 18;
+const IFLA_INFO_KIND: u16 = 1;
+const IFLA_IFALIAS: u16 = 20;
+const IFF_UP: u32 = 0x1;
+const IFF_LOWER_UP: u32 = 0x10000;
+
+/// Multicast groups of [`Socket::subscribe`].
+pub const RTMGRP_LINK: u32 = 0x1;
+pub const RTMGRP_IPV4_ROUTE: u32 = 0x40;
+pub const RTMGRP_IPV6_ROUTE: u32 = 0x400;
+
+const IFA_ADDRESS: u16 = 1;
+const IFA_LOCAL: u16 = 2;
+
+const RTA_DST: u16 = 1;
+const RTA_OIF: u16 = 4;
+const RTA_TABLE: u16 = 15;
+const RTN_UNICAST: u8 = 1;
+const RTN_BLACKHOLE: u8 = 6;
+const RT_SCOPE_UNIVERSE: u8 = 0;
+const RT_SCOPE_LINK: u8 = 253;
+const RTPROT_BOOT: u8 = 3;
+const RT_TABLE_UNSPEC: u8 = 0;
+pub const RT_TABLE_MAIN: u32 = 254;
+
+const FRA_PRIORITY: u16 = 6;
+const FRA_FWMARK: u16 = 10;
+const FRA_SUPPRESS_PREFIXLEN: u16 = 14;
+const FRA_TABLE: u16 = 15;
+const FR_ACT_TO_TBL: u8 = 1;
+const FIB_RULE_INVERT: u32 = 0x2;
+
+#[derive(Debug, Clone)]
+pub struct Link {
+    pub index: u32,
+    pub name: String,
+    /// `IFLA_INFO_KIND` ("wireguard", "bridge"...), none for physical links.
+    pub kind: Option<String>,
+    pub alias: Option<String>,
+    /// Up, with a carrier.
+    pub carrier: bool,
+}
+
+/// A notification of the `RTMGRP_*` groups.
+#[derive(Debug, Clone)]
+pub enum Change {
+    /// Link added or changed, or removed (without carrier then).
+    Link(Link),
+    /// Default route added or removed.
+    DefaultRoute {
+        ipv6: bool,
+        table: u32,
+        device: Option<u32>,
+    },
+    /// Notifications were dropped, anything may have changed.
+    Lost,
+}
+
+/// Decodes a message from [`Socket::events`]; `None` for the ones that do
+/// not matter (other routes, other messages).
+pub fn parse_change(kind: u16, payload: &[u8]) -> Option<Change> {
+    match kind {
+        0 => Some(Change::Lost),
+        RTM_NEWLINK | RTM_DELLINK => {
+            let mut link = parse_link(payload)?;
+            link.carrier &= kind == RTM_NEWLINK;
+            Some(Change::Link(link))
+        }
+        RTM_NEWROUTE | RTM_DELROUTE => {
+            let header = payload.get(..12)?;
+            if header[1] != 0 {
+                return None;
+            }
+            let mut table = u32::from(header[4]);
+            let mut device = None;
+            for (attr, value) in attrs(&payload[12..]) {
+                let value = value.get(..4).and_then(|v| v.try_into().ok());
+                match (attr, value) {
+                    (RTA_TABLE, Some(v)) => table = u32::from_ne_bytes(v),
+                    (RTA_OIF, Some(v)) => device = Some(u32::from_ne_bytes(v)),
+                    _ => {}
+                }
+            }
+            Some(Change::DefaultRoute {
+                ipv6: header[0] == libc::AF_INET6 as u8,
+                table,
+                device,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Destination of a route: a device, or a blackhole.
+#[derive(Debug, Clone, Copy)]
+pub enum RouteTarget {
+    Device(u32),
+    Blackhole,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Route {
+    pub dst: IpAddr,
+    pub prefix: u8,
+    pub table: u32,
+    pub target: RouteTarget,
+}
+
+/// A `lookup <table>` rule; `not_fwmark` and `suppress_prefixlength` match
+/// wg-quick's `not fwmark X` and `suppress_prefixlength N` selectors.
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    pub ipv6: bool,
+    pub priority: u32,
+    pub table: u32,
+    pub not_fwmark: Option<u32>,
+    pub suppress_prefixlength: Option<u32>,
+}
+
+fn family(ipv6: bool) -> u8 {
+    if ipv6 {
+        libc::AF_INET6 as u8
+    } else {
+        libc::AF_INET as u8
+    }
+}
+
+fn addr_bytes(addr: &IpAddr) -> Vec<u8> {
+    match addr {
+        IpAddr::V4(v4) => v4.octets().to_vec(),
+        IpAddr::V6(v6) => v6.octets().to_vec(),
+    }
+}
+
+fn ifinfomsg(index: u32, flags: u32, change: u32) -> [u8; 16] {
+    let mut header = [0u8; 16];
+    header[4..8].copy_from_slice(&index.to_ne_bytes());
+    header[8..12].copy_from_slice(&flags.to_ne_bytes());
+    header[12..16].copy_from_slice(&change.to_ne_bytes());
+    header
+}
+
+pub fn links(socket: &mut Socket) -> io::Result<Vec<Link>> {
+    let replies =
+        socket.request(Message::new(RTM_GETLINK, NLM_F_DUMP).header(&ifinfomsg(0, 0, 0)))?;
+    Ok(replies
+        .iter()
+        .filter_map(|reply| parse_link(reply))
+        .collect())
+}
+
+pub fn link(socket: &mut Socket, name: &str) -> io::Result<Option<Link>> {
+    Ok(links(socket)?.into_iter().find(|l| l.name == name))
+}
+
+fn parse_link(payload: &[u8]) -> Option<Link> {
+    let index = u32::from_ne_bytes(payload.get(4..8)?.try_into().ok()?);
+    let flags = u32::from_ne_bytes(payload.get(8..12)?.try_into().ok()?);
+    let mut link = Link {
+        index,
+        name: String::new(),
+        kind: None,
+        alias: None,
+        carrier: flags & (IFF_UP | IFF_LOWER_UP) == IFF_UP | IFF_LOWER_UP,
+    };
+    for (kind, value) in attrs(payload.get(16..)?) {
+        match kind {
+            IFLA_IFNAME => link.name = attr_str(value),
+            IFLA_IFALIAS => link.alias = Some(attr_str(value)),
+            IFLA_LINKINFO => {
+                link.kind = attrs(value)
+                    .find(|(k, _)| *k == IFLA_INFO_KIND)
+                    .map(|(_, v)| attr_str(v));
+            }
+            _ => {}
+        }
+    }
+    Some(link)
+}
+
+/// Creates a virtual link and returns its index. Fails with `EEXIST` if the
+/// name is taken.
+pub fn add_link(socket: &mut Socket, name: &str, kind: &str) -> io::Result<u32> {
+    socket.request(
+        Message::new(RTM_NEWLINK, NLM_F_CREATE | NLM_F_EXCL)
+            .header(&ifinfomsg(0, 0, 0))
+            .attr_str(IFLA_IFNAME, name)
+            .nested(IFLA_LINKINFO)
+            .attr_str(IFLA_INFO_KIND, kind)
+            .end_nested(),
+    )?;
+    link(socket, name)?
+        .map(|l| l.index)
+        .ok_or_else(|| io::Error::from_raw_os_error(libc::ENODEV))
+}
+
+pub fn set_alias(socket: &mut Socket, index: u32, alias: &str) -> io::Result<()> {
+    socket.request(
+        Message::new(RTM_NEWLINK, 0)
+            .header(&ifinfomsg(index, 0, 0))
+            .attr_str(IFLA_IFALIAS, alias),
+    )?;
+    Ok(())
+}
+
+/// Sets the MTU of a link and brings it up.
+pub fn set_link_up(socket: &mut Socket, index: u32, mtu: u32) -> io::Result<()> {
+    socket.request(
+        Message::new(RTM_NEWLINK, 0)
+            .header(&ifinfomsg(index, IFF_UP, IFF_UP))
+            .attr_u32(IFLA_MTU, mtu),
+    )?;
+    Ok(())
+}
+
+/// Deleting a link also removes its addresses and routes.
+pub fn del_link(socket: &mut Socket, index: u32) -> io::Result<()> {
+    socket.request(Message::new(RTM_DELLINK, 0).header(&ifinfomsg(index, 0, 0)))?;
+    Ok(())
+}
+
+pub fn add_address(socket: &mut Socket, index: u32, addr: IpAddr, prefix: u8) -> io::Result<()> {
+    let mut header = [0u8; 8];
+    header[0] = family(addr.is_ipv6());
+    header[1] = prefix;
+    header[3] = RT_SCOPE_UNIVERSE;
+    header[4..8].copy_from_slice(&index.to_ne_bytes());
+
+    let bytes = addr_bytes(&addr);
+    socket.request(
+        Message::new(RTM_NEWADDR, NLM_F_CREATE | NLM_F_REPLACE)
+            .header(&header)
+            .attr(IFA_LOCAL, &bytes)
+            .attr(IFA_ADDRESS, &bytes),
+    )?;
+    Ok(())
+}
+
+fn route_message(kind: u16, flags: u16, route: &Route) -> Message {
+    let (scope, route_type) = match route.target {
+        RouteTarget::Device(_) => (RT_SCOPE_LINK, RTN_UNICAST),
+        RouteTarget::Blackhole => (RT_SCOPE_UNIVERSE, RTN_BLACKHOLE),
+    };
+    // rtmsg: family, dst_len, src_len, tos, table, protocol, scope, type, flags
+    let mut header = [0u8; 12];
+    header[0] = family(route.dst.is_ipv6());
+    header[1] = route.prefix;
+    header[4] = RT_TABLE_UNSPEC;
+    header[5] = RTPROT_BOOT;
+    header[6] = scope;
+    header[7] = route_type;
+
+    let mut message = Message::new(kind, flags)
+        .header(&header)
+        .attr_u32(RTA_TABLE, route.table);
+    if route.prefix > 0 {
+        message = message.attr(RTA_DST, &addr_bytes(&route.dst));
+    }
+    if let RouteTarget::Device(index) = route.target {
+        message = message.attr_u32(RTA_OIF, index);
+    }
+    message
+}
+
+pub fn add_route(socket: &mut Socket, route: &Route) -> io::Result<()> {
+    socket.request(route_message(
+        RTM_NEWROUTE,
+        NLM_F_CREATE | NLM_F_REPLACE,
+        route,
+    ))?;
+    Ok(())
+}
+
+pub fn del_route(socket: &mut Socket, route: &Route) -> io::Result<()> {
+    socket.request(route_message(RTM_DELROUTE, 0, route))?;
+    Ok(())
+}
+
+fn rule_message(kind: u16, flags: u16, rule: &Rule) -> Message {
+    // fib_rule_hdr: family, dst_len, src_len, tos, table, res1, res2, action, flags
+    let mut header = [0u8; 12];
+    header[0] = family(rule.ipv6);
+    header[7] = FR_ACT_TO_TBL;
+    if rule.not_fwmark.is_some() {
+        header[8..12].copy_from_slice(&FIB_RULE_INVERT.to_ne_bytes());
+    }
+
+    let mut message = Message::new(kind, flags)
+        .header(&header)
+        .attr_u32(FRA_PRIORITY, rule.priority)
+        .attr_u32(FRA_TABLE, rule.table);
+    if let Some(mark) = rule.not_fwmark {
+        message = message.attr_u32(FRA_FWMARK, mark);
+    }
+    if let Some(len) = rule.suppress_prefixlength {
+        message = message.attr_u32(FRA_SUPPRESS_PREFIXLEN, len);
+    }
+    message
+}
+
+pub fn add_rule(socket: &mut Socket, rule: &Rule) -> io::Result<()> {
+    socket.request(rule_message(RTM_NEWRULE, NLM_F_CREATE | NLM_F_EXCL, rule))?;
+    Ok(())
+}
+
+pub fn del_rule(socket: &mut Socket, rule: &Rule) -> io::Result<()> {
+    socket.request(rule_message(RTM_DELRULE, 0, rule))?;
+    Ok(())
+}
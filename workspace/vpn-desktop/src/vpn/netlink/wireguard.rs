@@ -0,0 +1,170 @@
+//! WireGuard generic netlink family (`linux/wireguard.h`).
+
+use super::{attr_u64, attrs, Message, Socket, NLM_F_DUMP};
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const GENL_ID_CTRL: u16 = 16;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+
+const WG_GENL_NAME: &str = "wireguard";
+const WG_GENL_VERSION: u8 = 1;
+const WG_CMD_GET_DEVICE: u8 = 0;
+const WG_CMD_SET_DEVICE: u8 = 1;
+
+const WGDEVICE_A_IFNAME: u16 = 2;
+const WGDEVICE_A_PRIVATE_KEY: u16 = 3;
+const WGDEVICE_A_FLAGS: u16 = 5;
+const WGDEVICE_A_FWMARK: u16 = 7;
+const WGDEVICE_A_PEERS: u16 = 8;
+const WGDEVICE_F_REPLACE_PEERS: u32 = 1;
+
+const WGPEER_A_PUBLIC_KEY: u16 = 1;
+const WGPEER_A_FLAGS: u16 = 3;
+const WGPEER_A_ENDPOINT: u16 = 4;
+const WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL: u16 = 5;
+const WGPEER_A_LAST_HANDSHAKE_TIME: u16 = 6;
+const WGPEER_A_RX_BYTES: u16 = 7;
+const WGPEER_A_TX_BYTES: u16 = 8;
+const WGPEER_A_ALLOWEDIPS: u16 = 9;
+const WGPEER_F_REPLACE_ALLOWEDIPS: u32 = 2;
+
+const WGALLOWEDIP_A_FAMILY: u16 = 1;
+const WGALLOWEDIP_A_IPADDR: u16 = 2;
+const WGALLOWEDIP_A_CIDR_MASK: u16 = 3;
+
+pub struct PeerConfig {
+    pub public_key: [u8; 32],
+    pub endpoint: SocketAddr,
+    pub persistent_keepalive: u16,
+    pub allowed_ips: Vec<(IpAddr, u8)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PeerStatus {
+    pub last_handshake: Option<SystemTime>,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+}
+
+/// Generic netlink family id of WireGuard; `ENOENT` when the module is not loaded.
+pub fn family_id(socket: &mut Socket) -> io::Result<u16> {
+    let replies = socket.request(
+        Message::new(GENL_ID_CTRL, 0)
+            .header(&[CTRL_CMD_GETFAMILY, 1, 0, 0])
+            .attr_str(CTRL_ATTR_FAMILY_NAME, WG_GENL_NAME),
+    )?;
+    let id = replies
+        .iter()
+        .filter_map(|reply| reply.get(4..))
+        .flat_map(attrs)
+        .find(|(kind, _)| *kind == CTRL_ATTR_FAMILY_ID)
+        .and_then(|(_, value)| Some(u16::from_ne_bytes(value.get(..2)?.try_into().ok()?)));
+    id.ok_or_else(|| io::Error::from_raw_os_error(libc::ENOENT))
+}
+
+/// Sets the private key and fwmark of the device and replaces its peers
+/// with `peer`.
+pub fn set_device(
+    socket: &mut Socket,
+    family: u16,
+    interface: &str,
+    private_key: &[u8; 32],
+    fwmark: u32,
+    peer: &PeerConfig,
+) -> io::Result<()> {
+    let mut message = Message::new(family, 0)
+        .header(&[WG_CMD_SET_DEVICE, WG_GENL_VERSION, 0, 0])
+        .attr_str(WGDEVICE_A_IFNAME, interface)
+        .attr(WGDEVICE_A_PRIVATE_KEY, private_key)
+        .attr_u32(WGDEVICE_A_FWMARK, fwmark)
+        .attr_u32(WGDEVICE_A_FLAGS, WGDEVICE_F_REPLACE_PEERS)
+        .nested(WGDEVICE_A_PEERS)
+        .nested(0)
+        .attr(WGPEER_A_PUBLIC_KEY, &peer.public_key)
+        .attr_u32(WGPEER_A_FLAGS, WGPEER_F_REPLACE_ALLOWEDIPS)
+        .attr(WGPEER_A_ENDPOINT, &sockaddr(&peer.endpoint))
+        .attr_u16(
+            WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL,
+            peer.persistent_keepalive,
+        )
+        .nested(WGPEER_A_ALLOWEDIPS);
+    for (index, (addr, prefix)) in peer.allowed_ips.iter().enumerate() {
+        let (family, bytes) = match addr {
+            IpAddr::V4(v4) => (libc::AF_INET as u16, v4.octets().to_vec()),
+            IpAddr::V6(v6) => (libc::AF_INET6 as u16, v6.octets().to_vec()),
+        };
+        message = message
+            .nested(index as u16)
+            .attr_u16(WGALLOWEDIP_A_FAMILY, family)
+            .attr(WGALLOWEDIP_A_IPADDR, &bytes)
+            .attr_u8(WGALLOWEDIP_A_CIDR_MASK, *prefix)
+            .end_nested();
+    }
+    let message = message.end_nested().end_nested().end_nested();
+
+    socket.request(message)?;
+    Ok(())
+}
+
+/// Handshake time and transfer counters of each peer of the device.
+pub fn peers(socket: &mut Socket, family: u16, interface: &str) -> io::Result<Vec<PeerStatus>> {
+    let replies = socket.request(
+        Message::new(family, NLM_F_DUMP)
+            .header(&[WG_CMD_GET_DEVICE, WG_GENL_VERSION, 0, 0])
+            .attr_str(WGDEVICE_A_IFNAME, interface),
+    )?;
+
+    let mut peers = Vec::new();
+    for reply in &replies {
+        let device = match reply.get(4..) {
+            Some(d) => d,
+            None => continue,
+        };
+        for (_, peer_list) in attrs(device).filter(|(k, _)| *k == WGDEVICE_A_PEERS) {
+            for (_, peer) in attrs(peer_list) {
+                let mut status = PeerStatus::default();
+                for (kind, value) in attrs(peer) {
+                    match kind {
+                        WGPEER_A_LAST_HANDSHAKE_TIME => {
+                            // struct __kernel_timespec { i64 sec; i64 nsec; }
+                            status.last_handshake = attr_u64(value)
+                                .filter(|&secs| secs > 0)
+                                .map(|secs| UNIX_EPOCH + Duration::from_secs(secs));
+                        }
+                        WGPEER_A_RX_BYTES => status.rx_bytes = attr_u64(value).unwrap_or(0),
+                        WGPEER_A_TX_BYTES => status.tx_bytes = attr_u64(value).unwrap_or(0),
+                        _ => {}
+                    }
+                }
+                peers.push(status);
+            }
+        }
+    }
+    Ok(peers)
+}
+
+/// `struct sockaddr_in` / `sockaddr_in6` as expected by `WGPEER_A_ENDPOINT`.
+fn sockaddr(addr: &SocketAddr) -> Vec<u8> {
+    match addr {
+        SocketAddr::V4(v4) => {
+            let mut bytes = vec![0u8; 16];
+            bytes[0..2].copy_from_slice(&(libc::AF_INET as u16).to_ne_bytes());
+            bytes[2..4].copy_from_slice(&v4.port().to_be_bytes());
+            bytes[4..8].copy_from_slice(&v4.ip().octets());
+            bytes
+        }
+        SocketAddr::V6(v6) => {
+            let mut bytes = vec![0u8; 28];
+            bytes[0..2].copy_from_slice(&(libc::AF_INET6 as u16).to_ne_bytes());
+            bytes[2..4].copy_from_slice(&v6.port().to_be_bytes());
+            bytes[4..8].copy_from_slice(&v6.flowinfo().to_be_bytes());
+            bytes[8..24].copy_from_slice(&v6.ip().octets());
+            bytes[24..28].copy_from_slice(&v6.scope_id().to_ne_bytes());
+            bytes
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Proxy mode: WireGuard runs in userspace with an in-process TCP/IP stack
+//! and only the applications configured to use the local SOCKS5 or HTTP
+//! CONNECT proxy go through the VPN. No root, routes or interfaces needed.
+
+mod netstack;
+mod noise;
+
+use super::stats::Counters;
+use anyhow::{Context, Result};
+use netstack::{ConnectError, Connection, NetStack, Target};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+use vpn_core::wireguard::WireGuardConfig;
+
+pub const DEFAULT_SOCKS_PORT: u16 = 1080;
+pub const DEFAULT_HTTP_PORT: u16 = 8118;
+
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(100);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_HTTP_HEADER: usize = 8192;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxySettings {
+    pub enabled: bool,
+    pub socks_port: u16,
+    pub http_port: u16,
+}
+
+impl Default for ProxySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            socks_port: DEFAULT_SOCKS_PORT,
+            http_port: DEFAULT_HTTP_PORT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Protocol {
+    Socks5,
+    HttpConnect,
+}
+
+pub struct ProxyServer {
+    stack: Arc<NetStack>,
+    running: Arc<AtomicBool>,
+    socks_addr: SocketAddr,
+    http_addr: SocketAddr,
+    /// Joined on drop so the ports are free again for a restart.
+    accept_threads: Vec<JoinHandle<()>>,
+}
+
+impl ProxyServer {
+    /// Starts the userspace tunnel and both proxies on localhost.
+    pub fn start(config: &WireGuardConfig, settings: &ProxySettings) -> Result<Self> {
+        let socks = TcpListener::bind((Ipv4Addr::LOCALHOST, settings.socks_port))
+            .with_context(|| format!("Failed to listen on SOCKS5 port {}", settings.socks_port))?;
+        let http = TcpListener::bind((Ipv4Addr::LOCALHOST, settings.http_port))
+            .with_context(|| format!("Failed to listen on HTTP port {}", settings.http_port))?;
+        let socks_addr = socks.local_addr()?;
+        let http_addr = http.local_addr()?;
+
+        let stack = Arc::new(NetStack::start(config)?);
+        let running = Arc::new(AtomicBool::new(true));
+
+        let mut accept_threads = Vec::new();
+        for (listener, protocol) in [(socks, Protocol::Socks5), (http, Protocol::HttpConnect)] {
+            listener.set_nonblocking(true)?;
+            let stack = Arc::clone(&stack);
+            let running = Arc::clone(&running);
+            accept_threads.push(std::thread::spawn(move || {
+                accept_loop(listener, protocol, stack, running)
+            }));
+        }
+
+        Ok(Self {
+            stack,
+            running,
+            socks_addr,
+            http_addr,
+            accept_threads,
+        })
+    }
+
+    pub fn socks_addr(&self) -> SocketAddr {
+        self.socks_addr
+    }
+
+    pub fn http_addr(&self) -> SocketAddr {
+        self.http_addr
+    }
+
+    pub fn counters(&self) -> Counters {
+        let stats = self.stack.stats();
+        Counters {
+            rx_bytes: stats.rx_bytes.load(Ordering::Relaxed),
+            tx_bytes: stats.tx_bytes.load(Ordering::Relaxed),
+            rx_packets: stats.rx_packets.load(Ordering::Relaxed),
+            tx_packets: stats.tx_packets.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn last_handshake(&self) -> Option<SystemTime> {
+        self.stack
+            .stats()
+            .last_handshake
+            .lock()
+            .ok()
+            .and_then(|h| *h)
+    }
+
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+        self.stack.stop();
+    }
+}
+
+impl Drop for ProxyServer {
+    fn drop(&mut self) {
+        self.stop();
+        for thread in self.accept_threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn accept_loop(
+    listener: TcpListener,
+    protocol: Protocol,
+    stack: Arc<NetStack>,
+    running: Arc<AtomicBool>,
+) {
+    while running.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let stack = Arc::clone(&stack);
+                std::thread::spawn(move || {
+                    let _ = stream.set_nonblocking(false);
+                    if let Err(e) = handle_client(stream, protocol, &stack) {
+                        tracing::debug!(
+                            ?protocol,
+                            error = format!("{:#}", e),
+                            "proxy client failed"
+                        );
+                    }
+                });
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(ACCEPT_INTERVAL);
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "proxy accept failed");
+                std::thread::sleep(ACCEPT_INTERVAL);
+            }
+        }
+    }
+}
+
+fn handle_client(mut stream: TcpStream, protocol: Protocol, stack: &NetStack) -> Result<()> {
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+    let connection = match protocol {
+        Protocol::Socks5 => socks5_handshake(&mut stream, stack)?,
+        Protocol::HttpConnect => http_connect_handshake(&mut stream, stack)?,
+    };
+    let connection = match connection {
+        Some(c) => c,
+        None => return Ok(()),
+    };
+    stream.set_read_timeout(None)?;
+    relay(stream, connection)
+}
+
+// ── SOCKS5 (RFC 1928, CONNECT without authentication) ─────────────────────────
+
+fn socks5_handshake(stream: &mut TcpStream, stack: &NetStack) -> Result<Option<Connection>> {
+    let mut header = [0u8; 2];
+    stream.read_exact(&mut header)?;
+    if header[0] != 5 {
+        anyhow::bail!("unsupported SOCKS version {}", header[0]);
+    }
+    let mut methods = vec![0u8; header[1] as usize];
+    stream.read_exact(&mut methods)?;
+    if !methods.contains(&0) {
+        stream.write_all(&[5, 0xff])?;
+        return Ok(None);
+    }
+    stream.write_all(&[5, 0])?;
+
+    let mut request = [0u8; 4];
+    stream.read_exact(&mut request)?;
+    if request[1] != 1 {
+        // Only CONNECT is supported
+        socks5_reply(stream, 7)?;
+        return Ok(None);
+    }
+
+    let target = match request[3] {
+        1 => {
+            let mut addr = [0u8; 6];
+            stream.read_exact(&mut addr)?;
+            let ip = Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3]);
+            Target::Addr(SocketAddr::new(
+                IpAddr::V4(ip),
+                u16::from_be_bytes([addr[4], addr[5]]),
+            ))
+        }
+        3 => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len)?;
+            let mut name = vec![0u8; len[0] as usize + 2];
+            stream.read_exact(&mut name)?;
+            let port = u16::from_be_bytes([name[name.len() - 2], name[name.len() - 1]]);
+            name.truncate(name.len() - 2);
+            Target::Name(String::from_utf8_lossy(&name).into_owned(), port)
+        }
+        4 => {
+            let mut addr = [0u8; 18];
+            stream.read_exact(&mut addr)?;
+            let mut ip = [0u8; 16];
+            ip.copy_from_slice(&addr[..16]);
+            Target::Addr(SocketAddr::new(
+                IpAddr::V6(Ipv6Addr::from(ip)),
+                u16::from_be_bytes([addr[16], addr[17]]),
+            ))
+        }
+        _ => {
+            socks5_reply(stream, 8)?;
+            return Ok(None);
+        }
+    };
+
+    match stack.connect(target.clone()) {
+        Ok(connection) => {
+            socks5_reply(stream, 0)?;
+            Ok(Some(connection))
+        }
+        Err(e) => {
+            let code = match e {
+                ConnectError::Resolve => 4,
+                ConnectError::Refused => 5,
+                ConnectError::TimedOut => 4,
+                ConnectError::Stopped => 1,
+            };
+            socks5_reply(stream, code)?;
+            anyhow::bail!("{}: {}", target, e)
+        }
+    }
+}
+
+fn socks5_reply(stream: &mut TcpStream, code: u8) -> Result<()> {
+    // Bound address is not meaningful here, report 0.0.0.0:0
+    stream.write_all(&[5, code, 0, 1, 0, 0, 0, 0, 0, 0])?;
+    Ok(())
+}
+
+// ── HTTP CONNECT ───────────────────────────────────────────────────────────────
+
+fn http_connect_handshake(stream: &mut TcpStream, stack: &NetStack) -> Result<Option<Connection>> {
+    let mut header = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !header.windows(4).any(|w| w == b"\r\n\r\n") {
+        if header.len() > MAX_HTTP_HEADER {
+            stream.write_all(b"HTTP/1.1 431 Request Header Fields Too Large\r\n\r\n")?;
+            return Ok(None);
+        }
+        let len = stream.read(&mut buf)?;
+        if len == 0 {
+            return Ok(None);
+        }
+        header.extend_from_slice(&buf[..len]);
+    }
+
+    let header = String::from_utf8_lossy(&header);
+    let mut request_line = header.lines().next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let authority = request_line.next().unwrap_or_default();
+    if !method.eq_ignore_ascii_case("CONNECT") {
+        stream.write_all(
+            b"HTTP/1.1 405 Method Not Allowed\r\nAllow: CONNECT\r\nContent-Length: 0\r\n\r\n",
+        )?;
+        return Ok(None);
+    }
+
+    let target = match parse_authority(authority) {
+        Some(t) => t,
+        None => {
+            stream.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n")?;
+            return Ok(None);
+        }
+    };
+
+    match stack.connect(target.clone()) {
+        Ok(connection) => {
+            stream.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")?;
+            Ok(Some(connection))
+        }
+        Err(e) => {
+            let status = match e {
+                ConnectError::TimedOut => "504 Gateway Timeout",
+                _ => "502 Bad Gateway",
+            };
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status)?;
+            anyhow::bail!("{}: {}", target, e)
+        }
+    }
+}
+
+/// "host:port" or "[v6]:port".
+fn parse_authority(authority: &str) -> Option<Target> {
+    if let Ok(addr) = authority.parse::<SocketAddr>() {
+        return Some(Target::Addr(addr));
+    }
+    let (host, port) = authority.rsplit_once(':')?;
+    let port = port.parse().ok()?;
+    if host.is_empty() {
+        return None;
+    }
+    Some(Target::Name(host.to_string(), port))
+}
+
+// ── Relay ──────────────────────────────────────────────────────────────────────
+
+fn relay(stream: TcpStream, connection: Connection) -> Result<()> {
+    let Connection { outgoing, incoming } = connection;
+
+    let mut reader = stream.try_clone()?;
+    let upload = std::thread::spawn(move || {
+        let mut buf = vec![0u8; 16 * 1024];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(len) => {
+                    if outgoing.send(buf[..len].to_vec()).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+        // Dropping `outgoing` closes the write side in the tunnel
+    });
+
+    let mut writer = stream;
+    for chunk in incoming {
+        if writer.write_all(&chunk).is_err() {
+            break;
+        }
+    }
+    let _ = writer.shutdown(Shutdown::Write);
+    let _ = upload.join();
+    Ok(())
+}
@@ -0,0 +1,663 @@
+//! In-process TCP/IP stack on top of the userspace WireGuard tunnel.
+//!
+//! A single thread owns the smoltcp interface, the UDP socket to the server
+//! and the WireGuard session. Proxy handlers ask it for TCP connections and
+//! exchange data with it over channels.
+
+use super::noise::{Received, Tunnel};
+use anyhow::{Context, Result};
+use smoltcp::iface::{Config, Interface, SocketHandle, SocketSet};
+use smoltcp::phy::{self, Device, DeviceCapabilities, Medium};
+use smoltcp::socket::dns::{self, GetQueryResultError, QueryHandle};
+use smoltcp::socket::tcp;
+use smoltcp::time::Instant as SmolInstant;
+use smoltcp::wire::{DnsQueryType, HardwareAddress, IpAddress, IpCidr};
+use std::collections::VecDeque;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender, TryRecvError, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use vpn_core::cidr::Cidr;
+use vpn_core::wireguard::WireGuardConfig;
+use zeroize::Zeroizing;
+
+/// Tunnel MTU, same default as wg-quick.
+const MTU: usize = 1420;
+const TCP_BUFFER: usize = 256 * 1024;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+/// Upper bound of a loop iteration when nothing happens.
+const IDLE_WAIT: Duration = Duration::from_millis(5);
+/// Chunks buffered per direction between a proxy handler and the stack.
+const CHANNEL_DEPTH: usize = 64;
+/// Packets kept while the handshake is not complete.
+const MAX_PENDING_PACKETS: usize = 256;
+
+/// Where a proxy client wants to go.
+#[derive(Debug, Clone)]
+pub enum Target {
+    Addr(SocketAddr),
+    /// Host name, resolved through the VPN DNS.
+    Name(String, u16),
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Addr(addr) => write!(f, "{}", addr),
+            Target::Name(host, port) => write!(f, "{}:{}", host, port),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConnectError {
+    Resolve,
+    Refused,
+    TimedOut,
+    Stopped,
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectError::Resolve => write!(f, "name resolution failed"),
+            ConnectError::Refused => write!(f, "connection refused"),
+            ConnectError::TimedOut => write!(f, "connection timed out"),
+            ConnectError::Stopped => write!(f, "tunnel stopped"),
+        }
+    }
+}
+
+/// Both directions of a TCP connection opened through the tunnel. Dropping
+/// `outgoing` closes the write side; `incoming` disconnects at EOF.
+pub struct Connection {
+    pub outgoing: SyncSender<Vec<u8>>,
+    pub incoming: Receiver<Vec<u8>>,
+}
+
+struct ConnectRequest {
+    target: Target,
+    reply: Sender<Result<Connection, ConnectError>>,
+}
+
+/// Tunnel counters for the connection screen.
+#[derive(Default)]
+pub struct StackStats {
+    pub rx_bytes: AtomicU64,
+    pub tx_bytes: AtomicU64,
+    pub rx_packets: AtomicU64,
+    pub tx_packets: AtomicU64,
+    pub last_handshake: Mutex<Option<SystemTime>>,
+}
+
+pub struct NetStack {
+    requests: Sender<ConnectRequest>,
+    running: Arc<AtomicBool>,
+    stats: Arc<StackStats>,
+}
+
+impl NetStack {
+    pub fn start(config: &WireGuardConfig) -> Result<Self> {
+        let private_key = decode_key(config.private_key.expose()).context("invalid PrivateKey")?;
+        let peer_key = decode_key(&config.peer_public_key).context("invalid PublicKey")?;
+        let keepalive = (config.persistent_keepalive > 0)
+            .then(|| Duration::from_secs(config.persistent_keepalive as u64));
+
+        let endpoint = config
+            .endpoint
+            .to_socket_addrs()
+            .with_context(|| format!("Failed to resolve endpoint {}", config.endpoint))?
+            .next()
+            .context("endpoint has no address")?;
+        let bind: SocketAddr = if endpoint.is_ipv4() {
+            "0.0.0.0:0".parse()?
+        } else {
+            "[::]:0".parse()?
+        };
+        let udp = UdpSocket::bind(bind).context("Failed to open UDP socket")?;
+        udp.connect(endpoint)?;
+        udp.set_read_timeout(Some(IDLE_WAIT))?;
+
+        let addresses = config.addresses()?;
+        let dns_server = config
+            .dns
+            .split(',')
+            .find_map(|s| s.trim().parse::<IpAddr>().ok());
+
+        let (requests, request_rx) = mpsc::channel();
+        let running = Arc::new(AtomicBool::new(true));
+        let stats = Arc::new(StackStats::default());
+
+        let mut worker = Worker::new(
+            Tunnel::new(*private_key, *peer_key, keepalive),
+            udp,
+            &addresses,
+            dns_server,
+            request_rx,
+            Arc::clone(&stats),
+        );
+        let worker_running = Arc::clone(&running);
+        std::thread::spawn(move || worker.run(&worker_running));
+
+        Ok(Self {
+            requests,
+            running,
+            stats,
+        })
+    }
+
+    /// Opens a TCP connection through the tunnel, blocking until it is
+    /// established or has failed.
+    pub fn connect(&self, target: Target) -> Result<Connection, ConnectError> {
+        let (reply, result) = mpsc::channel();
+        self.requests
+            .send(ConnectRequest { target, reply })
+            .map_err(|_| ConnectError::Stopped)?;
+        result.recv().unwrap_or(Err(ConnectError::Stopped))
+    }
+
+    pub fn stats(&self) -> &StackStats {
+        &self.stats
+    }
+
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl Drop for NetStack {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// TCP connection owned by the stack thread.
+struct Flow {
+    socket: SocketHandle,
+    from_client: Receiver<Vec<u8>>,
+    /// Dropped once the server has closed its side, as EOF for the client.
+    to_client: Option<SyncSender<Vec<u8>>>,
+    /// Client data not yet accepted by the TCP socket.
+    unsent: Vec<u8>,
+    /// Server data not yet accepted by the client channel.
+    unread: Option<Vec<u8>>,
+    client_closed: bool,
+    /// Pending connect request, answered once the handshake is done.
+    reply: Option<Sender<Result<Connection, ConnectError>>>,
+    client_side: Option<Connection>,
+    started: Instant,
+}
+
+/// Host name being resolved before connecting.
+struct Lookup {
+    query: QueryHandle,
+    port: u16,
+    reply: Sender<Result<Connection, ConnectError>>,
+    started: Instant,
+}
+
+struct Worker {
+    tunnel: Tunnel,
+    udp: UdpSocket,
+    device: VirtualDevice,
+    iface: Interface,
+    sockets: SocketSet<'static>,
+    dns: Option<SocketHandle>,
+    dns_type: DnsQueryType,
+    requests: Receiver<ConnectRequest>,
+    flows: Vec<Flow>,
+    lookups: Vec<Lookup>,
+    /// Packets waiting for a session.
+    pending: VecDeque<Vec<u8>>,
+    next_port: u16,
+    stats: Arc<StackStats>,
+}
+
+impl Worker {
+    fn new(
+        tunnel: Tunnel,
+        udp: UdpSocket,
+        addresses: &[Cidr],
+        dns_server: Option<IpAddr>,
+        requests: Receiver<ConnectRequest>,
+        stats: Arc<StackStats>,
+    ) -> Self {
+        let mut device = VirtualDevice::default();
+        let mut config = Config::new(HardwareAddress::Ip);
+        config.random_seed = u64::from_le_bytes(random_seed());
+        let mut iface = Interface::new(config, &mut device, SmolInstant::now());
+
+        iface.update_ip_addrs(|addrs| {
+            for cidr in addresses {
+                let _ = addrs.push(IpCidr::new(IpAddress::from(cidr.addr()), cidr.prefix()));
+            }
+        });
+        // Everything goes to the peer; the gateway is only a placeholder
+        for cidr in addresses {
+            match cidr.addr() {
+                IpAddr::V4(addr) => {
+                    let _ = iface.routes_mut().add_default_ipv4_route(addr);
+                }
+                IpAddr::V6(addr) => {
+                    let _ = iface.routes_mut().add_default_ipv6_route(addr);
+                }
+            }
+        }
+
+        let mut sockets = SocketSet::new(Vec::new());
+        let dns = dns_server.map(|server| {
+            sockets.add(dns::Socket::new(
+                &[IpAddress::from(server)],
+                (0..16).map(|_| None).collect::<Vec<_>>(),
+            ))
+        });
+        let dns_type = if addresses.iter().any(Cidr::is_ipv4) {
+            DnsQueryType::A
+        } else {
+            DnsQueryType::Aaaa
+        };
+
+        Self {
+            tunnel,
+            udp,
+            device,
+            iface,
+            sockets,
+            dns,
+            dns_type,
+            requests,
+            flows: Vec::new(),
+            lookups: Vec::new(),
+            pending: VecDeque::new(),
+            next_port: 49152,
+            stats,
+        }
+    }
+
+    fn run(&mut self, running: &AtomicBool) {
+        let mut buf = vec![0u8; 65536];
+        while running.load(Ordering::Relaxed) {
+            // Datagrams from the server; the read timeout paces the loop
+            if let Ok(len) = self.udp.recv(&mut buf) {
+                self.receive(&buf[..len]);
+                // Drain what else is already queued
+                let _ = self.udp.set_nonblocking(true);
+                while let Ok(len) = self.udp.recv(&mut buf) {
+                    self.receive(&buf[..len]);
+                }
+                let _ = self.udp.set_nonblocking(false);
+            }
+
+            if let Some(datagram) = self.tunnel.tick() {
+                let _ = self.udp.send(&datagram);
+            }
+
+            self.accept_requests();
+            self.iface
+                .poll(SmolInstant::now(), &mut self.device, &mut self.sockets);
+            self.resolve_names();
+            self.pump_flows();
+            self.iface
+                .poll(SmolInstant::now(), &mut self.device, &mut self.sockets);
+            self.send_packets();
+        }
+
+        for flow in &mut self.flows {
+            if let Some(reply) = flow.reply.take() {
+                let _ = reply.send(Err(ConnectError::Stopped));
+            }
+        }
+    }
+
+    fn receive(&mut self, datagram: &[u8]) {
+        match self.tunnel.decapsulate(datagram) {
+            Ok(Received::Packet(packet)) => {
+                self.stats
+                    .rx_bytes
+                    .fetch_add(packet.len() as u64, Ordering::Relaxed);
+                self.stats.rx_packets.fetch_add(1, Ordering::Relaxed);
+                self.device.rx.push_back(packet);
+            }
+            Ok(Received::HandshakeComplete) => {
+                if let Ok(mut last) = self.stats.last_handshake.lock() {
+                    *last = self.tunnel.last_handshake();
+                }
+                if self.pending.is_empty() {
+                    // Confirms the session to the server
+                    if let Some(keepalive) = self.tunnel.encapsulate(&[]) {
+                        let _ = self.udp.send(&keepalive);
+                    }
+                }
+                while let Some(packet) = self.pending.pop_front() {
+                    self.send_packet(&packet);
+                }
+            }
+            Ok(Received::Nothing) => {}
+            Err(e) => tracing::debug!(error = %e, "WireGuard packet dropped"),
+        }
+    }
+
+    fn send_packets(&mut self) {
+        while let Some(packet) = self.device.tx.pop_front() {
+            if self.tunnel.is_established() {
+                self.send_packet(&packet);
+            } else if self.pending.len() < MAX_PENDING_PACKETS {
+                self.pending.push_back(packet);
+            }
+        }
+    }
+
+    fn send_packet(&mut self, packet: &[u8]) {
+        if let Some(datagram) = self.tunnel.encapsulate(packet) {
+            if self.udp.send(&datagram).is_ok() {
+                self.stats
+                    .tx_bytes
+                    .fetch_add(packet.len() as u64, Ordering::Relaxed);
+                self.stats.tx_packets.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn accept_requests(&mut self) {
+        while let Ok(request) = self.requests.try_recv() {
+            match request.target {
+                Target::Addr(addr) => self.open_flow(addr, request.reply),
+                Target::Name(host, port) => {
+                    // Literal addresses given as names ("[::1]", "10.0.0.1")
+                    let literal = host.trim_start_matches('[').trim_end_matches(']');
+                    if let Ok(ip) = literal.parse::<IpAddr>() {
+                        self.open_flow(SocketAddr::new(ip, port), request.reply);
+                        continue;
+                    }
+                    let query = self.dns.and_then(|handle| {
+                        self.sockets
+                            .get_mut::<dns::Socket>(handle)
+                            .start_query(self.iface.context(), &host, self.dns_type)
+                            .ok()
+                    });
+                    match query {
+                        Some(query) => self.lookups.push(Lookup {
+                            query,
+                            port,
+                            reply: request.reply,
+                            started: Instant::now(),
+                        }),
+                        None => {
+                            let _ = request.reply.send(Err(ConnectError::Resolve));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve_names(&mut self) {
+        let handle = match self.dns {
+            Some(h) => h,
+            None => return,
+        };
+        let mut i = 0;
+        while i < self.lookups.len() {
+            let result = self
+                .sockets
+                .get_mut::<dns::Socket>(handle)
+                .get_query_result(self.lookups[i].query);
+            let timed_out = self.lookups[i].started.elapsed() > CONNECT_TIMEOUT;
+            match result {
+                Err(GetQueryResultError::Pending) if !timed_out => {
+                    i += 1;
+                    continue;
+                }
+                Ok(addrs) if !addrs.is_empty() => {
+                    let lookup = self.lookups.swap_remove(i);
+                    let addr = SocketAddr::new(IpAddr::from(addrs[0]), lookup.port);
+                    self.open_flow(addr, lookup.reply);
+                }
+                _ => {
+                    let lookup = self.lookups.swap_remove(i);
+                    if timed_out {
+                        self.sockets
+                            .get_mut::<dns::Socket>(handle)
+                            .cancel_query(lookup.query);
+                    }
+                    let _ = lookup.reply.send(Err(ConnectError::Resolve));
+                }
+            }
+        }
+    }
+
+    fn open_flow(&mut self, addr: SocketAddr, reply: Sender<Result<Connection, ConnectError>>) {
+        let mut socket = tcp::Socket::new(
+            tcp::SocketBuffer::new(vec![0; TCP_BUFFER]),
+            tcp::SocketBuffer::new(vec![0; TCP_BUFFER]),
+        );
+        socket.set_nagle_enabled(false);
+        socket.set_keep_alive(Some(smoltcp::time::Duration::from_secs(30)));
+
+        let port = self.next_local_port();
+        if socket
+            .connect(
+                self.iface.context(),
+                (IpAddress::from(addr.ip()), addr.port()),
+                port,
+            )
+            .is_err()
+        {
+            let _ = reply.send(Err(ConnectError::Refused));
+            return;
+        }
+        let handle = self.sockets.add(socket);
+
+        let (outgoing, from_client) = mpsc::sync_channel(CHANNEL_DEPTH);
+        let (to_client, incoming) = mpsc::sync_channel(CHANNEL_DEPTH);
+        self.flows.push(Flow {
+            socket: handle,
+            from_client,
+            to_client: Some(to_client),
+            unsent: Vec::new(),
+            unread: None,
+            client_closed: false,
+            reply: Some(reply),
+            client_side: Some(Connection { outgoing, incoming }),
+            started: Instant::now(),
+        });
+    }
+
+    fn next_local_port(&mut self) -> u16 {
+        let port = self.next_port;
+        self.next_port = if port == u16::MAX { 49152 } else { port + 1 };
+        port
+    }
+
+    /// Moves data between the client channels and the TCP sockets, and
+    /// drops finished connections.
+    fn pump_flows(&mut self) {
+        let mut i = 0;
+        while i < self.flows.len() {
+            let flow = &mut self.flows[i];
+            let socket = self.sockets.get_mut::<tcp::Socket>(flow.socket);
+
+            if flow.reply.is_some() {
+                match socket.state() {
+                    tcp::State::Established => {
+                        if let (Some(reply), Some(conn)) =
+                            (flow.reply.take(), flow.client_side.take())
+                        {
+                            if reply.send(Ok(conn)).is_err() {
+                                socket.abort();
+                            }
+                        }
+                    }
+                    tcp::State::Closed => {
+                        if let Some(reply) = flow.reply.take() {
+                            let _ = reply.send(Err(ConnectError::Refused));
+                        }
+                    }
+                    _ if flow.started.elapsed() > CONNECT_TIMEOUT => {
+                        socket.abort();
+                        if let Some(reply) = flow.reply.take() {
+                            let _ = reply.send(Err(ConnectError::TimedOut));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if flow.reply.is_none() && flow.client_side.is_none() {
+                // Client -> server
+                loop {
+                    if !flow.unsent.is_empty() {
+                        if !socket.can_send() {
+                            break;
+                        }
+                        let sent = socket.send_slice(&flow.unsent).unwrap_or(0);
+                        flow.unsent.drain(..sent);
+                        if !flow.unsent.is_empty() {
+                            break;
+                        }
+                    }
+                    match flow.from_client.try_recv() {
+                        Ok(data) => flow.unsent = data,
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => {
+                            if !flow.client_closed {
+                                flow.client_closed = true;
+                                socket.close();
+                            }
+                            break;
+                        }
+                    }
+                }
+
+                // Server -> client
+                while let Some(to_client) = &flow.to_client {
+                    let chunk = match flow.unread.take() {
+                        Some(chunk) => chunk,
+                        None if socket.can_recv() => {
+                            let mut chunk = vec![0u8; 16 * 1024];
+                            let len = socket.recv_slice(&mut chunk).unwrap_or(0);
+                            chunk.truncate(len);
+                            chunk
+                        }
+                        None => {
+                            if !socket.may_recv() {
+                                flow.to_client = None;
+                            }
+                            break;
+                        }
+                    };
+                    match to_client.try_send(chunk) {
+                        Ok(()) => {}
+                        Err(TrySendError::Full(chunk)) => {
+                            flow.unread = Some(chunk);
+                            break;
+                        }
+                        Err(TrySendError::Disconnected(_)) => {
+                            socket.abort();
+                            flow.to_client = None;
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Connect failures, and connections closed on both sides
+            let failed = flow.reply.is_none() && flow.client_side.is_some();
+            let closed = flow.reply.is_none()
+                && flow.client_side.is_none()
+                && matches!(socket.state(), tcp::State::Closed | tcp::State::TimeWait);
+            if failed || closed {
+                let flow = self.flows.swap_remove(i);
+                self.sockets.remove(flow.socket);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+/// smoltcp device backed by two packet queues: `rx` is filled with decrypted
+/// packets, `tx` is drained into the tunnel.
+#[derive(Default)]
+struct VirtualDevice {
+    rx: VecDeque<Vec<u8>>,
+    tx: VecDeque<Vec<u8>>,
+}
+
+impl Device for VirtualDevice {
+    type RxToken<'a> = RxToken;
+    type TxToken<'a> = TxToken<'a>;
+
+    fn receive(
+        &mut self,
+        _timestamp: SmolInstant,
+    ) -> Option<(Self::RxToken<'_>, Self::TxToken<'_>)> {
+        let packet = self.rx.pop_front()?;
+        Some((
+            RxToken { packet },
+            TxToken {
+                queue: &mut self.tx,
+            },
+        ))
+    }
+
+    fn transmit(&mut self, _timestamp: SmolInstant) -> Option<Self::TxToken<'_>> {
+        Some(TxToken {
+            queue: &mut self.tx,
+        })
+    }
+
+    fn capabilities(&self) -> DeviceCapabilities {
+        let mut caps = DeviceCapabilities::default();
+        caps.medium = Medium::Ip;
+        caps.max_transmission_unit = MTU;
+        caps
+    }
+}
+
+struct RxToken {
+    packet: Vec<u8>,
+}
+
+impl phy::RxToken for RxToken {
+    fn consume<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        f(&self.packet)
+    }
+}
+
+struct TxToken<'a> {
+    queue: &'a mut VecDeque<Vec<u8>>,
+}
+
+impl phy::TxToken for TxToken<'_> {
+    fn consume<R, F>(self, len: usize, f: F) -> R
+    where
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        let mut packet = vec![0u8; len];
+        let result = f(&mut packet);
+        self.queue.push_back(packet);
+        result
+    }
+}
+
+fn decode_key(key: &str) -> Result<Zeroizing<[u8; 32]>> {
+    use base64::Engine;
+    let bytes = Zeroizing::new(base64::engine::general_purpose::STANDARD.decode(key.trim())?);
+    let key: [u8; 32] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("key must be 32 bytes"))?;
+    Ok(Zeroizing::new(key))
+}
+
+fn random_seed() -> [u8; 8] {
+    let mut seed = [0u8; 8];
+    let _ = getrandom::getrandom(&mut seed);
+    seed
+}
@@ -0,0 +1,572 @@
+//! WireGuard protocol for the userspace tunnel: Noise_IKpsk2 handshake as
+//! initiator, transport data encryption and session timers.
+//!
+//! Only what a client needs is implemented: we always initiate, never answer
+//! initiations, and cookie replies (server under load) are ignored so the
+//! handshake is simply retried.
+
+use anyhow::{bail, Context, Result};
+use blake2::digest::consts::U16;
+use blake2::digest::{FixedOutput, Mac};
+use blake2::{Blake2s256, Blake2sMac, Digest};
+use chacha20poly1305::aead::{Aead, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, KeyInit, Nonce};
+use hmac::SimpleHmac;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+const CONSTRUCTION: &[u8] = b"Noise_IKpsk2_25519_ChaChaPoly_BLAKE2s";
+const IDENTIFIER: &[u8] = b"WireGuard v1 zx2c4 Jason@zx2c4.com";
+const LABEL_MAC1: &[u8] = b"mac1----";
+
+const MSG_INITIATION: u8 = 1;
+const MSG_RESPONSE: u8 = 2;
+const MSG_COOKIE: u8 = 3;
+const MSG_DATA: u8 = 4;
+
+const INITIATION_LEN: usize = 148;
+const RESPONSE_LEN: usize = 92;
+const DATA_HEADER_LEN: usize = 16;
+const TAG_LEN: usize = 16;
+
+const REKEY_AFTER_TIME: Duration = Duration::from_secs(120);
+const REJECT_AFTER_TIME: Duration = Duration::from_secs(180);
+const REKEY_TIMEOUT: Duration = Duration::from_secs(5);
+const REKEY_AFTER_MESSAGES: u64 = 1 << 60;
+const REJECT_AFTER_MESSAGES: u64 = u64::MAX - (1 << 13);
+
+type HmacBlake2s = SimpleHmac<Blake2s256>;
+
+/// Result of processing a datagram received from the server.
+pub enum Received {
+    /// Decrypted IP packet for the network stack.
+    Packet(Vec<u8>),
+    /// Handshake response accepted, a new session is ready.
+    HandshakeComplete,
+    /// Keepalive or message that needs no further action.
+    Nothing,
+}
+
+/// Handshake in progress, waiting for the server response.
+struct Initiation {
+    local_index: u32,
+    chaining_key: [u8; 32],
+    hash: [u8; 32],
+    ephemeral: StaticSecret,
+    sent_at: Instant,
+}
+
+/// Transport keys derived from a completed handshake.
+struct Session {
+    local_index: u32,
+    remote_index: u32,
+    send: ChaCha20Poly1305,
+    recv: ChaCha20Poly1305,
+    send_counter: u64,
+    replay: ReplayWindow,
+    established: Instant,
+}
+
+impl Session {
+    /// Past `REJECT_AFTER_TIME`, the keys must no longer be used either way.
+    fn is_expired(&self, now: Instant) -> bool {
+        now.duration_since(self.established) >= REJECT_AFTER_TIME
+    }
+}
+
+pub struct Tunnel {
+    private: StaticSecret,
+    public: PublicKey,
+    peer: PublicKey,
+    /// Keys of the mac1 field for messages sent to and received from the peer.
+    peer_mac1_key: [u8; 32],
+    own_mac1_key: [u8; 32],
+    keepalive: Option<Duration>,
+    initiation: Option<Initiation>,
+    current: Option<Session>,
+    /// Previous session, kept to decrypt packets in flight during a rekey
+    /// until it expires.
+    previous: Option<Session>,
+    last_sent: Instant,
+    last_handshake: Option<SystemTime>,
+}
+
+impl Tunnel {
+    pub fn new(
+        private_key: [u8; 32],
+        peer_public_key: [u8; 32],
+        keepalive: Option<Duration>,
+    ) -> Self {
+        let private = StaticSecret::from(private_key);
+        let public = PublicKey::from(&private);
+        let peer = PublicKey::from(peer_public_key);
+        Self {
+            peer_mac1_key: hash(&[LABEL_MAC1, peer.as_bytes()]),
+            own_mac1_key: hash(&[LABEL_MAC1, public.as_bytes()]),
+            private,
+            public,
+            peer,
+            keepalive,
+            initiation: None,
+            current: None,
+            previous: None,
+            last_sent: Instant::now(),
+            last_handshake: None,
+        }
+    }
+
+    pub fn is_established(&self) -> bool {
+        self.current.is_some()
+    }
+
+    pub fn last_handshake(&self) -> Option<SystemTime> {
+        self.last_handshake
+    }
+
+    /// Encrypts an IP packet. Returns `None` without a usable session; the
+    /// caller keeps the packet until [`Received::HandshakeComplete`].
+    pub fn encapsulate(&mut self, packet: &[u8]) -> Option<Vec<u8>> {
+        let session = self.current.as_mut()?;
+        if session.send_counter >= REKEY_AFTER_MESSAGES || session.is_expired(Instant::now()) {
+            return None;
+        }
+        let counter = session.send_counter;
+        session.send_counter += 1;
+
+        // Pad to a multiple of 16 bytes to hide the exact packet length
+        let padded_len = packet.len().div_ceil(16) * 16;
+        let mut plaintext = packet.to_vec();
+        plaintext.resize(padded_len, 0);
+
+        let ciphertext = session
+            .send
+            .encrypt(&nonce(counter), plaintext.as_slice())
+            .ok()?;
+
+        let mut datagram = Vec::with_capacity(DATA_HEADER_LEN + ciphertext.len());
+        datagram.extend_from_slice(&[MSG_DATA, 0, 0, 0]);
+        datagram.extend_from_slice(&session.remote_index.to_le_bytes());
+        datagram.extend_from_slice(&counter.to_le_bytes());
+        datagram.extend_from_slice(&ciphertext);
+        self.last_sent = Instant::now();
+        Some(datagram)
+    }
+
+    pub fn decapsulate(&mut self, datagram: &[u8]) -> Result<Received> {
+        match datagram.first() {
+            Some(&MSG_RESPONSE) => self.consume_response(datagram),
+            Some(&MSG_DATA) => self.decrypt_data(datagram),
+            Some(&MSG_COOKIE) => Ok(Received::Nothing),
+            Some(&MSG_INITIATION) => bail!("unexpected handshake initiation from server"),
+            _ => bail!("unknown message type"),
+        }
+    }
+
+    /// Datagram to send now, if any: handshake (first one, retry or rekey)
+    /// or keepalive.
+    pub fn tick(&mut self) -> Option<Vec<u8>> {
+        let now = Instant::now();
+
+        if self.current.as_ref().is_some_and(|s| s.is_expired(now)) {
+            self.current = None;
+        }
+        if self.previous.as_ref().is_some_and(|s| s.is_expired(now)) {
+            self.previous = None;
+        }
+
+        let retry_due = match &self.initiation {
+            Some(initiation) => now.duration_since(initiation.sent_at) >= REKEY_TIMEOUT,
+            None => match &self.current {
+                Some(session) => {
+                    now.duration_since(session.established) >= REKEY_AFTER_TIME
+                        || session.send_counter >= REKEY_AFTER_MESSAGES
+                }
+                None => true,
+            },
+        };
+        if retry_due {
+            return Some(self.initiate());
+        }
+
+        let keepalive = self.keepalive?;
+        if self.current.is_some() && now.duration_since(self.last_sent) >= keepalive {
+            return self.encapsulate(&[]);
+        }
+        None
+    }
+
+    /// Builds a handshake initiation message.
+    fn initiate(&mut self) -> Vec<u8> {
+        let local_index = random_index();
+        let ephemeral = StaticSecret::from(random_bytes());
+        let ephemeral_public = PublicKey::from(&ephemeral);
+
+        let mut chaining_key = hash(&[CONSTRUCTION]);
+        let mut h = hash(&[&chaining_key, IDENTIFIER]);
+        h = hash(&[&h, self.peer.as_bytes()]);
+
+        chaining_key = kdf1(&chaining_key, ephemeral_public.as_bytes());
+        h = hash(&[&h, ephemeral_public.as_bytes()]);
+
+        let (ck, key) = kdf2(
+            &chaining_key,
+            ephemeral.diffie_hellman(&self.peer).as_bytes(),
+        );
+        chaining_key = ck;
+        let encrypted_static = aead_seal(&key, self.public.as_bytes(), &h);
+        h = hash(&[&h, &encrypted_static]);
+
+        let (ck, key) = kdf2(
+            &chaining_key,
+            self.private.diffie_hellman(&self.peer).as_bytes(),
+        );
+        chaining_key = ck;
+        let encrypted_timestamp = aead_seal(&key, &tai64n(), &h);
+        h = hash(&[&h, &encrypted_timestamp]);
+
+        let mut msg = Vec::with_capacity(INITIATION_LEN);
+        msg.extend_from_slice(&[MSG_INITIATION, 0, 0, 0]);
+        msg.extend_from_slice(&local_index.to_le_bytes());
+        msg.extend_from_slice(ephemeral_public.as_bytes());
+        msg.extend_from_slice(&encrypted_static);
+        msg.extend_from_slice(&encrypted_timestamp);
+        let mac1 = mac(&self.peer_mac1_key, &msg);
+        msg.extend_from_slice(&mac1);
+        // No cookie: mac2 stays zero
+        msg.extend_from_slice(&[0u8; 16]);
+
+        self.initiation = Some(Initiation {
+            local_index,
+            chaining_key,
+            hash: h,
+            ephemeral,
+            sent_at: Instant::now(),
+        });
+        self.last_sent = Instant::now();
+        msg
+    }
+
+    fn consume_response(&mut self, msg: &[u8]) -> Result<Received> {
+        if msg.len() != RESPONSE_LEN {
+            bail!("invalid handshake response length");
+        }
+        let mac1_offset = RESPONSE_LEN - 32;
+        if mac(&self.own_mac1_key, &msg[..mac1_offset]) != msg[mac1_offset..mac1_offset + 16] {
+            bail!("invalid handshake response mac");
+        }
+
+        let sender = u32::from_le_bytes(msg[4..8].try_into()?);
+        let receiver = u32::from_le_bytes(msg[8..12].try_into()?);
+        let initiation = match &self.initiation {
+            Some(i) if i.local_index == receiver => i,
+            _ => return Ok(Received::Nothing),
+        };
+
+        let ephemeral: [u8; 32] = msg[12..44].try_into()?;
+        let ephemeral = PublicKey::from(ephemeral);
+        let encrypted_nothing = &msg[44..60];
+
+        let mut chaining_key = kdf1(&initiation.chaining_key, ephemeral.as_bytes());
+        let mut h = hash(&[&initiation.hash, ephemeral.as_bytes()]);
+        chaining_key = kdf1(
+            &chaining_key,
+            initiation.ephemeral.diffie_hellman(&ephemeral).as_bytes(),
+        );
+        chaining_key = kdf1(
+            &chaining_key,
+            self.private.diffie_hellman(&ephemeral).as_bytes(),
+        );
+        // No preshared key: Q is all zeros
+        let (ck, tau, key) = kdf3(&chaining_key, &[0u8; 32]);
+        chaining_key = ck;
+        h = hash(&[&h, &tau]);
+        aead_open(&key, encrypted_nothing, &h).context("handshake response rejected")?;
+
+        let (send, recv) = kdf2(&chaining_key, &[]);
+        let session = Session {
+            local_index: initiation.local_index,
+            remote_index: sender,
+            send: ChaCha20Poly1305::new(Key::from_slice(&send)),
+            recv: ChaCha20Poly1305::new(Key::from_slice(&recv)),
+            send_counter: 0,
+            replay: ReplayWindow::default(),
+            established: Instant::now(),
+        };
+
+        self.initiation = None;
+        self.previous = self.current.replace(session);
+        self.last_handshake = Some(SystemTime::now());
+        Ok(Received::HandshakeComplete)
+    }
+
+    fn decrypt_data(&mut self, msg: &[u8]) -> Result<Received> {
+        if msg.len() < DATA_HEADER_LEN + TAG_LEN {
+            bail!("truncated data message");
+        }
+        let receiver = u32::from_le_bytes(msg[4..8].try_into()?);
+        let counter = u64::from_le_bytes(msg[8..16].try_into()?);
+
+        let session = [self.current.as_mut(), self.previous.as_mut()]
+            .into_iter()
+            .flatten()
+            .find(|s| s.local_index == receiver);
+        let session = match session {
+            Some(s) => s,
+            None => bail!("data message for unknown session"),
+        };
+        if session.is_expired(Instant::now()) {
+            bail!("data message for an expired session");
+        }
+        if counter >= REJECT_AFTER_MESSAGES {
+            bail!("data message counter past the limit");
+        }
+        if !session.replay.check(counter) {
+            bail!("replayed data message");
+        }
+
+        let plaintext = session
+            .recv
+            .decrypt(&nonce(counter), &msg[DATA_HEADER_LEN..])
+            .map_err(|_| anyhow::anyhow!("data message authentication failed"))?;
+        session.replay.update(counter);
+
+        if plaintext.is_empty() {
+            return Ok(Received::Nothing);
+        }
+        Ok(Received::Packet(strip_padding(plaintext)))
+    }
+}
+
+/// Removes the padding using the length field of the IP header.
+fn strip_padding(mut packet: Vec<u8>) -> Vec<u8> {
+    let len = match packet.first().map(|b| b >> 4) {
+        Some(4) if packet.len() >= 4 => u16::from_be_bytes([packet[2], packet[3]]) as usize,
+        Some(6) if packet.len() >= 6 => 40 + u16::from_be_bytes([packet[4], packet[5]]) as usize,
+        _ => packet.len(),
+    };
+    packet.truncate(len.min(packet.len()));
+    packet
+}
+
+/// Sliding window over the last 64 counters (RFC 6479, simplified).
+#[derive(Default)]
+struct ReplayWindow {
+    highest: Option<u64>,
+    bitmap: u64,
+}
+
+impl ReplayWindow {
+    fn check(&self, counter: u64) -> bool {
+        match self.highest {
+            None => true,
+            Some(highest) if counter > highest => true,
+            Some(highest) => {
+                let offset = highest - counter;
+                offset < 64 && self.bitmap & (1 << offset) == 0
+            }
+        }
+    }
+
+    fn update(&mut self, counter: u64) {
+        match self.highest {
+            Some(highest) if counter <= highest => {
+                self.bitmap |= 1 << (highest - counter);
+            }
+            Some(highest) => {
+                let shift = counter - highest;
+                self.bitmap = if shift >= 64 { 0 } else { self.bitmap << shift };
+                self.bitmap |= 1;
+                self.highest = Some(counter);
+            }
+            None => {
+                self.bitmap = 1;
+                self.highest = Some(counter);
+            }
+        }
+    }
+}
+
+fn hash(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Blake2s256::new();
+    for part in parts {
+        Digest::update(&mut hasher, part);
+    }
+    hasher.finalize().into()
+}
+
+fn hmac(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac = <HmacBlake2s as Mac>::new_from_slice(key).expect("HMAC accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// Keyed BLAKE2s with a 16-byte output, used for mac1.
+fn mac(key: &[u8; 32], data: &[u8]) -> [u8; 16] {
+    let mut mac = <Blake2sMac<U16> as Mac>::new_from_slice(key).expect("32-byte BLAKE2s key");
+    Mac::update(&mut mac, data);
+    mac.finalize_fixed().into()
+}
+
+fn kdf1(key: &[u8; 32], input: &[u8]) -> [u8; 32] {
+    let prk = hmac(key, &[input]);
+    hmac(&prk, &[&[1]])
+}
+
+fn kdf2(key: &[u8; 32], input: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let prk = hmac(key, &[input]);
+    let t1 = hmac(&prk, &[&[1]]);
+    let t2 = hmac(&prk, &[&t1, &[2]]);
+    (t1, t2)
+}
+
+fn kdf3(key: &[u8; 32], input: &[u8]) -> ([u8; 32], [u8; 32], [u8; 32]) {
+    let prk = hmac(key, &[input]);
+    let t1 = hmac(&prk, &[&[1]]);
+    let t2 = hmac(&prk, &[&t1, &[2]]);
+    let t3 = hmac(&prk, &[&t2, &[3]]);
+    (t1, t2, t3)
+}
+
+/// 96-bit nonce: 32 zero bits followed by the little-endian counter.
+fn nonce(counter: u64) -> Nonce {
+    let mut nonce = [0u8; 12];
+    nonce[4..].copy_from_slice(&counter.to_le_bytes());
+    *Nonce::from_slice(&nonce)
+}
+
+fn aead_seal(key: &[u8; 32], plaintext: &[u8], aad: &[u8]) -> Vec<u8> {
+    ChaCha20Poly1305::new(Key::from_slice(key))
+        .encrypt(
+            &nonce(0),
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .expect("in-memory encryption does not fail")
+}
+
+fn aead_open(key: &[u8; 32], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    ChaCha20Poly1305::new(Key::from_slice(key))
+        .decrypt(
+            &nonce(0),
+            Payload {
+                msg: ciphertext,
+                aad,
+            },
+        )
+        .map_err(|_| anyhow::anyhow!("authentication failed"))
+}
+
+/// TAI64N timestamp; the server rejects initiations older than the last one.
+fn tai64n() -> [u8; 12] {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let mut out = [0u8; 12];
+    out[..8].copy_from_slice(&(0x400000000000000a + now.as_secs()).to_be_bytes());
+    out[8..].copy_from_slice(&now.subsec_nanos().to_be_bytes());
+    out
+}
+
+fn random_bytes() -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    getrandom::getrandom(&mut bytes).expect("system random generator unavailable");
+    bytes
+}
+
+fn random_index() -> u32 {
+    let mut bytes = [0u8; 4];
+    getrandom::getrandom(&mut bytes).expect("system random generator unavailable");
+    u32::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 32] = [7; 32];
+
+    fn session(local_index: u32, age: Duration) -> Session {
+        Session {
+            local_index,
+            remote_index: 1,
+            send: ChaCha20Poly1305::new(Key::from_slice(&KEY)),
+            recv: ChaCha20Poly1305::new(Key::from_slice(&KEY)),
+            send_counter: 0,
+            replay: ReplayWindow::default(),
+            established: Instant::now().checked_sub(age).unwrap(),
+        }
+    }
+
+    fn tunnel(current: Option<Session>, previous: Option<Session>) -> Tunnel {
+        let mut tunnel = Tunnel::new([1; 32], [2; 32], None);
+        tunnel.current = current;
+        tunnel.previous = previous;
+        tunnel
+    }
+
+    /// Data message carrying an empty IPv4 header, as the server sends it.
+    fn data(receiver: u32, counter: u64) -> Vec<u8> {
+        let mut packet = [0u8; 32];
+        packet[0] = 0x45;
+        packet[2..4].copy_from_slice(&20u16.to_be_bytes());
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&KEY));
+        let ciphertext = cipher.encrypt(&nonce(counter), &packet[..]).unwrap();
+
+        let mut msg = vec![MSG_DATA, 0, 0, 0];
+        msg.extend_from_slice(&receiver.to_le_bytes());
+        msg.extend_from_slice(&counter.to_le_bytes());
+        msg.extend_from_slice(&ciphertext);
+        msg
+    }
+
+    #[test]
+    fn current_and_previous_sessions_decrypt() {
+        let mut tunnel = tunnel(
+            Some(session(10, Duration::ZERO)),
+            Some(session(9, Duration::from_secs(150))),
+        );
+        for receiver in [10, 9] {
+            match tunnel.decapsulate(&data(receiver, 0)).unwrap() {
+                Received::Packet(packet) => assert_eq!(packet.len(), 20),
+                _ => panic!("no packet from session {}", receiver),
+            }
+        }
+    }
+
+    #[test]
+    fn expired_sessions_are_rejected() {
+        let expired = REJECT_AFTER_TIME + Duration::from_secs(1);
+        let mut tunnel = tunnel(Some(session(10, expired)), Some(session(9, expired)));
+        assert!(tunnel.decapsulate(&data(10, 0)).is_err());
+        assert!(tunnel.decapsulate(&data(9, 0)).is_err());
+        assert!(tunnel.encapsulate(&[0x45; 20]).is_none());
+
+        // Both are dropped and a new handshake starts
+        let initiation = tunnel.tick().unwrap();
+        assert_eq!(initiation[0], MSG_INITIATION);
+        assert!(tunnel.current.is_none());
+        assert!(tunnel.previous.is_none());
+    }
+
+    #[test]
+    fn counter_past_the_limit_is_rejected() {
+        let mut tunnel = tunnel(Some(session(10, Duration::ZERO)), None);
+        assert!(tunnel
+            .decapsulate(&data(10, REJECT_AFTER_MESSAGES))
+            .is_err());
+        assert!(tunnel
+            .decapsulate(&data(10, REJECT_AFTER_MESSAGES - 1))
+            .is_ok());
+    }
+
+    #[test]
+    fn replayed_message_is_rejected() {
+        let mut tunnel = tunnel(Some(session(10, Duration::ZERO)), None);
+        assert!(tunnel.decapsulate(&data(10, 5)).is_ok());
+        assert!(tunnel.decapsulate(&data(10, 5)).is_err());
+    }
+}
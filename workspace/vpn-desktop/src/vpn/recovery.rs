@@ -0,0 +1,212 @@
+//! Crash safety for the tunnel.
+//!
+//! While a tunnel is up, a small state file records what has to be undone:
+//! the local interface and the peer registered on the backend. Signals and
+//! panics tear it down in process; after a SIGKILL or a power loss the file
+//! outlives the process and is reported on the next launch, which offers to
+//! resume the session or clean it up. There is one file per process, so
+//! several instances (GUI and `vpn-desktop proxy`) never clear each other's.
+
+use super::{apps, domains, killswitch, tunnel};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use vpn_core::api::ApiClient;
+use vpn_core::secret::Secret;
+
+const STATE_PREFIX: &str = "session-";
+const STATE_SUFFIX: &str = ".json";
+
+/// What a running session has set up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeState {
+    pub pid: u32,
+    /// Kernel tunnel interface, `None` in proxy mode.
+    pub interface: Option<String>,
+    pub server_id: u64,
+    pub server_name: String,
+    pub api_url: String,
+}
+
+impl RuntimeState {
+    pub fn new(interface: Option<&str>, server_id: u64, server_name: &str, api_url: &str) -> Self {
+        Self {
+            pid: std::process::id(),
+            interface: interface.map(str::to_string),
+            server_id,
+            server_name: server_name.to_string(),
+            api_url: api_url.to_string(),
+        }
+    }
+}
+
+/// Session of this process, torn down by the signal and panic handlers.
+struct Active {
+    state: RuntimeState,
+    token: Secret,
+}
+
+static ACTIVE: Mutex<Option<Active>> = Mutex::new(None);
+
+fn state_dir() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("vpn-client");
+    path
+}
+
+fn state_path(pid: u32) -> PathBuf {
+    state_dir().join(format!("{}{}{}", STATE_PREFIX, pid, STATE_SUFFIX))
+}
+
+/// Records the session once its tunnel is up; replaces the previous one.
+pub fn begin(state: RuntimeState, token: &str) {
+    if let Err(e) = write_state(&state) {
+        tracing::warn!(error = format!("{:#}", e), "failed to record tunnel state");
+    }
+    *lock() = Some(Active {
+        state,
+        token: Secret::new(token),
+    });
+}
+
+/// Forgets the session after a normal teardown.
+pub fn end() {
+    *lock() = None;
+    let _ = fs::remove_file(state_path(std::process::id()));
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Active>> {
+    ACTIVE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn write_state(state: &RuntimeState) -> Result<()> {
+    let path = state_path(state.pid);
+    crate::private_file::write(&path, serde_json::to_string_pretty(state)?.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Sessions whose process is gone without tearing them down.
+pub fn leftovers() -> Vec<RuntimeState> {
+    let entries = match fs::read_dir(state_dir()) {
+        Ok(e) => e,
+        Err(_) => return Vec::new(),
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with(STATE_PREFIX) && name.ends_with(STATE_SUFFIX)
+        })
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .filter_map(|content| serde_json::from_str::<RuntimeState>(&content).ok())
+        .filter(|state| !is_running(state.pid))
+        .collect()
+}
+
+/// Another instance still alive. Pids are reused after a reboot, so the
+/// process name must match too.
+#[cfg(target_os = "linux")]
+fn is_running(pid: u32) -> bool {
+    if pid == std::process::id() {
+        return true;
+    }
+    let comm =
+        |pid: String| fs::read_to_string(std::path::Path::new("/proc").join(pid).join("comm"));
+    match (comm(pid.to_string()), comm("self".to_string())) {
+        (Ok(theirs), Ok(ours)) => theirs == ours,
+        _ => false,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_running(pid: u32) -> bool {
+    pid == std::process::id()
+}
+
+/// Removes what a session set up on this machine: interface, routing rules,
+/// DNS redirection and kill switch. Each step tolerates what is already gone.
+pub fn cleanup_local(state: &RuntimeState) -> Result<()> {
+    if let Some(interface) = &state.interface {
+        let _ = apps::deactivate();
+        domains::deactivate();
+        tunnel::stop_tunnel(interface)?;
+        killswitch::deactivate();
+    }
+    Ok(())
+}
+
+/// Unregisters the session's peer from the backend, which refuses a new
+/// connection to the server while it exists. A peer already gone is fine.
+pub fn cleanup_backend(state: &RuntimeState, token: &str) -> Result<()> {
+    let client = ApiClient::new(&state.api_url, token);
+    let peers = client.status().context("Failed to list the peers")?;
+    if peers.iter().any(|p| p.server_id == state.server_id) {
+        client
+            .disconnect(state.server_id)
+            .context("Failed to unregister the peer")?;
+    }
+    Ok(())
+}
+
+/// Forgets a leftover once handled.
+pub fn discard(state: &RuntimeState) {
+    let _ = fs::remove_file(state_path(state.pid));
+}
+
+/// Tears down the session of this process, if any. Used where the normal
+/// shutdown path will not run.
+fn teardown() {
+    let active = match lock().take() {
+        Some(a) => a,
+        None => return,
+    };
+    if let Err(e) = cleanup_local(&active.state) {
+        tracing::error!(error = format!("{:#}", e), "failed to stop the tunnel");
+    }
+    if let Err(e) = cleanup_backend(&active.state, active.token.expose()) {
+        tracing::error!(error = format!("{:#}", e), "failed to unregister the peer");
+    }
+    discard(&active.state);
+}
+
+/// Tears down the tunnel when the main thread panics; panics in worker
+/// threads leave the application running.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        if std::thread::current().name() == Some("main") {
+            tracing::error!(%info, "main thread panicked");
+            teardown();
+        }
+    }));
+}
+
+/// SIGTERM, SIGINT and SIGHUP tear down the tunnel, then exit with the
+/// usual `128 + signal` status.
+#[cfg(unix)]
+pub fn install_signal_handlers() {
+    use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+    use signal_hook::iterator::Signals;
+
+    let mut signals = match Signals::new([SIGTERM, SIGINT, SIGHUP]) {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to install signal handlers");
+            return;
+        }
+    };
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            tracing::info!(signal, "tearing down on signal");
+            teardown();
+            std::process::exit(128 + signal);
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub fn install_signal_handlers() {}
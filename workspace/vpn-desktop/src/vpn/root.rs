@@ -0,0 +1,43 @@
+//! Commands run by the privileged helper (`vpn-desktop tunnel ...`), which
+//! already is root: no sudo, the arguments were checked by the caller.
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Runs `args` and fails with its stderr on a non-zero exit status.
+pub fn run(args: &[&str]) -> Result<()> {
+    let output = Command::new(args[0])
+        .args(&args[1..])
+        .output()
+        .with_context(|| format!("Failed to execute {}", args[0]))?;
+    crate::logging::command(args, &output);
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(())
+}
+
+/// Same as [`run`], writing `input` to the command's stdin.
+pub fn run_with_input(args: &[&str], input: &str) -> Result<()> {
+    let mut child = Command::new(args[0])
+        .args(&args[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to execute {}", args[0]))?;
+
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    crate::logging::command(args, &output);
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use std::collections::VecDeque;
+#[cfg(not(target_os = "linux"))]
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+#[cfg(not(target_os = "linux"))]
+use std::time::UNIX_EPOCH;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Number of samples kept for the throughput sparkline (one per second).
+pub const HISTORY_LEN: usize = 60;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Reading the handshake of a kernel tunnel goes through sudo when not
+/// root, so once one is known it is read far less often than the counters.
+/// Peers rekey every two minutes, well above this.
+const HANDSHAKE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Raw tunnel counters, from the kernel or the userspace tunnel.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Counters {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+}
+
+/// Snapshot of the tunnel traffic, shared with the UI.
+#[derive(Debug, Clone, Default)]
+pub struct TrafficStats {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    /// Bytes per second over the last poll interval.
+    pub rx_rate: f64,
+    pub tx_rate: f64,
+    /// Rolling (rx, tx) rates, oldest first.
+    pub history: VecDeque<(f64, f64)>,
+    pub last_handshake: Option<SystemTime>,
+}
+
+impl TrafficStats {
+    pub fn handshake_age(&self) -> Option<Duration> {
+        self.last_handshake
+            .and_then(|t| SystemTime::now().duration_since(t).ok())
+    }
+
+    /// Highest rx or tx rate in the history, used to scale the graph.
+    pub fn peak_rate(&self) -> f64 {
+        self.history
+            .iter()
+            .map(|&(rx, tx)| rx.max(tx))
+            .fold(0.0, f64::max)
+    }
+
+    fn push(&mut self, delta: Counters, elapsed: Duration) {
+        self.rx_bytes += delta.rx_bytes;
+        self.tx_bytes += delta.tx_bytes;
+        self.rx_packets += delta.rx_packets;
+        self.tx_packets += delta.tx_packets;
+
+        let secs = elapsed.as_secs_f64().max(0.001);
+        self.rx_rate = delta.rx_bytes as f64 / secs;
+        self.tx_rate = delta.tx_bytes as f64 / secs;
+
+        if self.history.len() == HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back((self.rx_rate, self.tx_rate));
+    }
+}
+
+/// Polls the tunnel interface in a background thread while connected.
+pub struct TrafficMonitor {
+    stats: Arc<Mutex<TrafficStats>>,
+    running: Arc<AtomicBool>,
+    started_at: Instant,
+}
+
+impl TrafficMonitor {
+    /// Monitors a kernel WireGuard interface.
+    pub fn start(interface: &str) -> Self {
+        let interface = interface.to_string();
+        // Read every poll until the first handshake is known
+        let mut handshake_read: Option<Instant> = None;
+        Self::start_with(move || {
+            let counters = read_counters(&interface)?;
+            let due = handshake_read.is_none_or(|at| at.elapsed() >= HANDSHAKE_POLL_INTERVAL);
+            let handshake = if due {
+                read_last_handshake(&interface)
+            } else {
+                None
+            };
+            if handshake.is_some() {
+                handshake_read = Some(Instant::now());
+            }
+            Some((counters, handshake))
+        })
+    }
+
+    /// Monitors any source of cumulative counters and handshake time; a
+    /// `None` handshake keeps the previous one.
+    pub fn start_with<F>(mut read: F) -> Self
+    where
+        F: FnMut() -> Option<(Counters, Option<SystemTime>)> + Send + 'static,
+    {
+        let stats = Arc::new(Mutex::new(TrafficStats::default()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let stats_holder = Arc::clone(&stats);
+        let running_flag = Arc::clone(&running);
+        std::thread::spawn(move || {
+            let (mut previous, handshake) = read().unwrap_or_default();
+            if let (Some(handshake), Ok(mut lock)) = (handshake, stats_holder.lock()) {
+                lock.last_handshake = Some(handshake);
+            }
+            let mut last_poll = Instant::now();
+
+            while running_flag.load(Ordering::Relaxed) {
+                std::thread::sleep(POLL_INTERVAL);
+
+                let (current, handshake) = match read() {
+                    Some(sample) => sample,
+                    None => continue,
+                };
+                let elapsed = last_poll.elapsed();
+                last_poll = Instant::now();
+
+                let delta = Counters {
+                    rx_bytes: counter_delta(previous.rx_bytes, current.rx_bytes),
+                    tx_bytes: counter_delta(previous.tx_bytes, current.tx_bytes),
+                    rx_packets: counter_delta(previous.rx_packets, current.rx_packets),
+                    tx_packets: counter_delta(previous.tx_packets, current.tx_packets),
+                };
+                previous = current;
+
+                if let Ok(mut lock) = stats_holder.lock() {
+                    lock.push(delta, elapsed);
+                    if handshake.is_some() {
+                        lock.last_handshake = handshake;
+                    }
+                }
+            }
+        });
+
+        Self {
+            stats,
+            running,
+            started_at: Instant::now(),
+        }
+    }
+
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> TrafficStats {
+        self.stats.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+}
+
+impl Drop for TrafficMonitor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Counters restart from zero when the interface is recreated.
+fn counter_delta(previous: u64, current: u64) -> u64 {
+    if current >= previous {
+        current - previous
+    } else {
+        current
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_counters(interface: &str) -> Option<Counters> {
+    let read = |name: &str| -> Option<u64> {
+        let path = format!("/sys/class/net/{}/statistics/{}", interface, name);
+        std::fs::read_to_string(path).ok()?.trim().parse().ok()
+    };
+
+    Some(Counters {
+        rx_bytes: read("rx_bytes")?,
+        tx_bytes: read("tx_bytes")?,
+        rx_packets: read("rx_packets")?,
+        tx_packets: read("tx_packets")?,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_counters(interface: &str) -> Option<Counters> {
+    // `wg show <if> transfer` prints "<peer>\t<rx>\t<tx>" per peer; no packet counts.
+    let output = wg_show(interface, "transfer")?;
+    let mut counters = Counters::default();
+    for line in output.lines() {
+        let mut fields = line.split_whitespace().skip(1);
+        counters.rx_bytes += fields.next()?.parse::<u64>().ok()?;
+        counters.tx_bytes += fields.next()?.parse::<u64>().ok()?;
+    }
+    Some(counters)
+}
+
+#[cfg(target_os = "linux")]
+fn read_last_handshake(interface: &str) -> Option<SystemTime> {
+    super::tunnel::last_handshake(interface)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_last_handshake(interface: &str) -> Option<SystemTime> {
+    // `wg show <if> latest-handshakes` prints "<peer>\t<unix seconds>", 0 if none yet.
+    let output = wg_show(interface, "latest-handshakes")?;
+    output
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1)?.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .max()
+        .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn wg_show(interface: &str, field: &str) -> Option<String> {
+    #[cfg(unix)]
+    let output = Command::new("sudo")
+        .args(["-n", "wg", "show", interface, field])
+        .output()
+        .ok()?;
+
+    #[cfg(not(unix))]
+    let output = Command::new("wg")
+        .args(["show", interface, field])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// "hh:mm:ss" for the connection timer.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn priming_sample_keeps_its_handshake() {
+        let handshake = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut first = true;
+        let monitor = TrafficMonitor::start_with(move || {
+            let sample = (Counters::default(), first.then_some(handshake));
+            first = false;
+            Some(sample)
+        });
+
+        let deadline = Instant::now() + Duration::from_millis(500);
+        while monitor.snapshot().last_handshake.is_none() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        // Before the first poll interval has gone by
+        assert_eq!(monitor.snapshot().last_handshake, Some(handshake));
+        monitor.stop();
+    }
+}
@@ -0,0 +1,78 @@
+//! Runs the privileged helper, `vpn-desktop tunnel <args>`, through
+//! `sudo -n`: the only command the documented sudoers rule allows. When
+//! the GUI already runs as root the helper is started directly.
+#![cfg_attr(not(unix), allow(dead_code))]
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// `[sudo -n] vpn-desktop tunnel <args>`, and its command line for the logs.
+fn helper(args: &[&str]) -> Result<(Command, String)> {
+    let exe = std::env::current_exe().context("Failed to locate the vpn-desktop executable")?;
+    let line = format!("{} tunnel {}", exe.display(), args.join(" "));
+
+    #[cfg(target_os = "linux")]
+    let root = super::tunnel::is_root();
+    #[cfg(not(target_os = "linux"))]
+    let root = false;
+
+    let mut command = if root {
+        Command::new(&exe)
+    } else {
+        let mut sudo = Command::new("sudo");
+        sudo.arg("-n").arg(&exe);
+        sudo
+    };
+    command.arg("tunnel").args(args);
+    Ok((command, line))
+}
+
+fn check(line: &str, output: &std::process::Output) -> Result<()> {
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{} failed: {}", line, stderr.trim());
+    }
+    Ok(())
+}
+
+/// Runs the helper and fails with its stderr on a non-zero exit status.
+pub fn run(args: &[&str]) -> Result<()> {
+    let (mut command, line) = helper(args)?;
+    let output = command
+        .output()
+        .context("Failed to execute vpn-desktop tunnel (sudo required)")?;
+    crate::logging::command(&[&line], &output);
+    check(&line, &output)
+}
+
+/// Same as [`run`], returning the helper's stdout. For read-only
+/// queries: a success is not logged, see [`crate::logging::query`].
+pub fn output(args: &[&str]) -> Result<String> {
+    let (mut command, line) = helper(args)?;
+    let output = command
+        .output()
+        .context("Failed to execute vpn-desktop tunnel (sudo required)")?;
+    crate::logging::query(&[&line], &output);
+    check(&line, &output)?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Same as [`run`], writing `input` to the helper's stdin, which is not
+/// logged.
+pub fn run_with_input(args: &[&str], input: &str) -> Result<()> {
+    let (mut command, line) = helper(args)?;
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to execute vpn-desktop tunnel (sudo required)")?;
+
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    crate::logging::command(&[&line], &output);
+    check(&line, &output)
+}
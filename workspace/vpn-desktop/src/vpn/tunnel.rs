@@ -1,118 +1,288 @@
-use anyhow::{Context, Result};
+use crate::i18n::tr;
+#[cfg(not(target_os = "linux"))]
+use anyhow::Context;
+use anyhow::Result;
+#[cfg(not(target_os = "linux"))]
 use std::fs;
+#[cfg(not(target_os = "linux"))]
 use std::path::PathBuf;
+#[cfg(not(target_os = "linux"))]
 use std::process::Command;
-use vpn_core::wireguard::WireGuardConfig;
+#[cfg(not(target_os = "linux"))]
+use vpn_core::secret::Secret;
+use vpn_core::wireguard::{WireGuardConfig, WireGuardError};
+
+/// Distinct from the usual `wg0` so the user's own tunnels keep their name.
+pub const DEFAULT_INTERFACE: &str = "firevpn0";
+/// IFNAMSIZ minus the trailing NUL.
+const MAX_INTERFACE_LEN: usize = 15;
+
+/// Client-side tweaks applied on top of the config returned by the API.
+#[derive(Debug, Clone, Default)]
+pub struct TunnelOptions {
+    /// Install no routes for `AllowedIPs` (`Table = off`); routes are then
+    /// managed by the caller, e.g. domain-based routing.
+    pub manual_routes: bool,
+    /// Blackhole the IPv6 internet while the tunnel is up.
+    pub block_ipv6: bool,
+    /// Interface MTU, the backend's default if unset.
+    pub mtu: Option<u16>,
+}
+
+/// How IPv6 traffic is handled by the tunnel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ipv6Mode {
+    /// The server provides IPv6, traffic goes through the tunnel.
+    Tunneled,
+    /// No IPv6 on the server, traffic is dropped instead of leaking.
+    Blocked,
+    /// Left untouched: only matched domains use the tunnel.
+    Direct,
+}
+
+impl Ipv6Mode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Ipv6Mode::Tunneled => tr!("ipv6.tunneled"),
+            Ipv6Mode::Blocked => tr!("ipv6.blocked"),
+            Ipv6Mode::Direct => tr!("ipv6.direct"),
+        }
+    }
+}
 
-const INTERFACE_NAME: &str = "wg0";
+/// Without IPv6 on the server, dual-stack hosts would send all IPv6 traffic
+/// outside the VPN. On Linux it is blackholed (immediate failure, so apps fall
+/// back to IPv4 quickly); elsewhere `::/0` is routed into the tunnel where the
+/// server drops it.
+pub fn protect_ipv6(
+    config: &mut WireGuardConfig,
+    options: &mut TunnelOptions,
+) -> Result<Ipv6Mode, WireGuardError> {
+    if config.has_ipv6() {
+        return Ok(Ipv6Mode::Tunneled);
+    }
+    if options.manual_routes {
+        return Ok(Ipv6Mode::Direct);
+    }
+    let ipv6_endpoint = config.endpoint_ip().is_some_and(|ip| ip.is_ipv6());
+    if cfg!(target_os = "linux") && !ipv6_endpoint {
+        options.block_ipv6 = true;
+    } else {
+        config.sink_ipv6()?;
+    }
+    Ok(Ipv6Mode::Blocked)
+}
 
-pub fn apply_config(config: &WireGuardConfig) -> Result<()> {
-    let config_path = create_config_file(config)?;
+/// Checks a tunnel interface name as the kernel would.
+pub fn validate_interface_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > MAX_INTERFACE_LEN {
+        return Err(tr!("interface.error.length", max = MAX_INTERFACE_LEN));
+    }
+    if name == "." || name == ".." {
+        return Err(tr!("interface.error.reserved").into());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(tr!("interface.error.characters").into());
+    }
+    Ok(())
+}
+
+/// An existing interface with this name that was not created by the app,
+/// typically one of the user's own WireGuard tunnels.
+#[cfg(target_os = "linux")]
+pub fn interface_conflict(name: &str) -> Option<String> {
+    let dir = std::path::Path::new("/sys/class/net").join(name);
+    if !dir.exists() {
+        return None;
+    }
+    let alias = std::fs::read_to_string(dir.join("ifalias")).unwrap_or_default();
+    if alias.trim() == super::kernel::LINK_ALIAS {
+        return None;
+    }
+    Some(tr!("interface.error.exists", name = name))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn interface_conflict(_name: &str) -> Option<String> {
+    None
+}
+
+pub fn apply_config(
+    config: &WireGuardConfig,
+    options: &TunnelOptions,
+    interface: &str,
+) -> Result<()> {
+    tracing::info!(
+        interface,
+        endpoint = %config.endpoint,
+        allowed_ips = %config.allowed_ips,
+        manual_routes = options.manual_routes,
+        block_ipv6 = options.block_ipv6,
+        "starting tunnel"
+    );
 
     #[cfg(target_os = "linux")]
     {
-        start_tunnel_linux(&config_path)?;
+        start_tunnel_linux(config, options, interface)?;
     }
 
     #[cfg(target_os = "windows")]
     {
+        let config_path = create_config_file(config, options, interface)?;
         start_tunnel_windows(&config_path)?;
     }
 
     #[cfg(target_os = "macos")]
     {
+        let config_path = create_config_file(config, options, interface)?;
         start_tunnel_macos(&config_path)?;
     }
 
     Ok(())
 }
 
-pub fn stop_tunnel() -> Result<()> {
+pub fn stop_tunnel(interface: &str) -> Result<()> {
+    tracing::info!(interface, "stopping tunnel");
+
     #[cfg(target_os = "linux")]
     {
-        stop_tunnel_linux()?;
+        stop_tunnel_linux(interface)?;
     }
 
     #[cfg(target_os = "windows")]
     {
-        stop_tunnel_windows()?;
+        stop_tunnel_windows(interface)?;
     }
 
     #[cfg(target_os = "macos")]
     {
-        stop_tunnel_macos()?;
+        stop_tunnel_macos(interface)?;
     }
 
-    let config_path = get_config_path();
-    if config_path.exists() {
-        fs::remove_file(config_path).ok();
+    #[cfg(not(target_os = "linux"))]
+    {
+        let config_path = get_config_path(interface);
+        if config_path.exists() {
+            fs::remove_file(config_path).ok();
+        }
     }
 
     Ok(())
 }
 
-fn create_config_file(config: &WireGuardConfig) -> Result<PathBuf> {
-    let config_path = get_config_path();
-
-    if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent)?;
+/// Latest handshake of the tunnel, if any yet.
+#[cfg(target_os = "linux")]
+pub fn last_handshake(interface: &str) -> Option<std::time::SystemTime> {
+    if is_root() {
+        return super::kernel::last_handshake(interface).ok().flatten();
     }
+    let output = super::sudo::output(&["status", "--interface", interface]).ok()?;
+    let secs = output.trim().parse::<u64>().ok().filter(|&s| s > 0)?;
+    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+}
 
-    let config_content = config.to_ini();
-    fs::write(&config_path, config_content).context("Failed to write WireGuard config file")?;
-
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let mut perms = fs::metadata(&config_path)?.permissions();
-        perms.set_mode(0o600);
-        fs::set_permissions(&config_path, perms)?;
+#[cfg(not(target_os = "linux"))]
+fn create_config_file(
+    config: &WireGuardConfig,
+    options: &TunnelOptions,
+    interface: &str,
+) -> Result<PathBuf> {
+    let config_path = get_config_path(interface);
+
+    let mut interface_lines = String::new();
+    if options.manual_routes {
+        interface_lines.push_str("Table = off\n");
     }
+    if let Some(mtu) = options.mtu {
+        interface_lines.push_str(&format!("MTU = {}\n", mtu));
+    }
+    let config_content = Secret::new(config.to_ini().expose().replacen(
+        "[Interface]\n",
+        &format!("[Interface]\n{}", interface_lines),
+        1,
+    ));
+    crate::private_file::write(&config_path, config_content.expose().as_bytes())
+        .context("Failed to write WireGuard config file")?;
 
     Ok(config_path)
 }
 
-fn get_config_path() -> PathBuf {
-    let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+/// In the per-user runtime directory (temporary directory where there is
+/// none): the file only lives as long as the tunnel.
+#[cfg(not(target_os = "linux"))]
+fn get_config_path(interface: &str) -> PathBuf {
+    let mut path = dirs::runtime_dir().unwrap_or_else(std::env::temp_dir);
     path.push("vpn-client");
-    path.push(format!("{}.conf", INTERFACE_NAME));
+    path.push(format!("{}.conf", interface));
     path
 }
 
 #[cfg(target_os = "linux")]
-fn start_tunnel_linux(config_path: &PathBuf) -> Result<()> {
-    let output = Command::new("sudo")
-        .arg("wg-quick")
-        .arg("up")
-        .arg(config_path)
-        .output()
-        .context("Failed to execute wg-quick up (sudo required)")?;
+pub fn is_root() -> bool {
+    // SAFETY: geteuid has no preconditions
+    unsafe { libc::geteuid() == 0 }
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("wg-quick up failed: {}", stderr);
+/// The kernel backend needs root: run it in place when we already are,
+/// otherwise through `sudo vpn-desktop tunnel up` with the config on stdin.
+#[cfg(target_os = "linux")]
+fn start_tunnel_linux(
+    config: &WireGuardConfig,
+    options: &TunnelOptions,
+    interface: &str,
+) -> Result<()> {
+    if is_root() {
+        return super::kernel::up(config, options, interface);
     }
-
-    Ok(())
+    run_privileged("up", config, options, interface)
 }
 
+/// Re-resolves the endpoint and puts the routes and DNS of a running tunnel
+/// back, after a network change or a resume.
 #[cfg(target_os = "linux")]
-fn stop_tunnel_linux() -> Result<()> {
-    let config_path = get_config_path();
-
-    let output = Command::new("sudo")
-        .arg("wg-quick")
-        .arg("down")
-        .arg(config_path)
-        .output()
-        .context("Failed to execute wg-quick down")?;
+pub fn refresh_tunnel(
+    config: &WireGuardConfig,
+    options: &TunnelOptions,
+    interface: &str,
+) -> Result<()> {
+    tracing::info!(interface, endpoint = %config.endpoint, "refreshing tunnel");
+    if is_root() {
+        return super::kernel::refresh(config, options, interface);
+    }
+    run_privileged("refresh", config, options, interface)
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        eprintln!("wg-quick down warning: {}", stderr);
+/// `sudo vpn-desktop tunnel <action>` with the config on stdin.
+#[cfg(target_os = "linux")]
+fn run_privileged(
+    action: &str,
+    config: &WireGuardConfig,
+    options: &TunnelOptions,
+    interface: &str,
+) -> Result<()> {
+    let mut args = vec![action, "--interface", interface];
+    if options.manual_routes {
+        args.push("--manual-routes");
+    }
+    if options.block_ipv6 {
+        args.push("--block-ipv6");
     }
+    let mtu = options.mtu.map(|m| m.to_string());
+    if let Some(mtu) = &mtu {
+        args.extend(["--mtu", mtu]);
+    }
+    super::sudo::run_with_input(&args, config.to_ini().expose())
+}
 
-    Ok(())
+#[cfg(target_os = "linux")]
+fn stop_tunnel_linux(interface: &str) -> Result<()> {
+    if is_root() {
+        return super::kernel::down(interface);
+    }
+    super::sudo::run(&["down", "--interface", interface])
 }
 
 #[cfg(target_os = "windows")]
@@ -132,16 +302,16 @@ fn start_tunnel_windows(config_path: &PathBuf) -> Result<()> {
 }
 
 #[cfg(target_os = "windows")]
-fn stop_tunnel_windows() -> Result<()> {
+fn stop_tunnel_windows(interface: &str) -> Result<()> {
     let output = Command::new("wireguard")
         .arg("/uninstalltunnelservice")
-        .arg(INTERFACE_NAME)
+        .arg(interface)
         .output()
         .context("Failed to stop WireGuard tunnel")?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        eprintln!("WireGuard stop warning: {}", stderr);
+        tracing::warn!(stderr = %stderr.trim(), "WireGuard stop failed");
     }
 
     Ok(())
@@ -165,8 +335,8 @@ fn start_tunnel_macos(config_path: &PathBuf) -> Result<()> {
 }
 
 #[cfg(target_os = "macos")]
-fn stop_tunnel_macos() -> Result<()> {
-    let config_path = get_config_path();
+fn stop_tunnel_macos(interface: &str) -> Result<()> {
+    let config_path = get_config_path(interface);
 
     let output = Command::new("sudo")
         .arg("wg-quick")
@@ -177,7 +347,7 @@ fn stop_tunnel_macos() -> Result<()> {
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        eprintln!("wg-quick down warning: {}", stderr);
+        tracing::warn!(stderr = %stderr.trim(), "wg-quick down failed");
     }
 
     Ok(())